@@ -71,9 +71,21 @@ impl Diagnostic {
             message: Box::new(message),
             location,
             related_information: Vec::new(),
+            severity: Severity::Error,
         }))
     }
 
+    /// Creates a new warning Diagnostic, which is reported without failing
+    /// the build.
+    pub fn warning<T: 'static + DiagnosticDisplay>(message: T, location: Location) -> Self {
+        Self::error(message, location).with_severity(Severity::Warning)
+    }
+
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.0.severity = severity;
+        self
+    }
+
     /// Annotates this error with an additional location and associated message.
     pub fn annotate<T: 'static + DiagnosticDisplay>(
         mut self,
@@ -101,11 +113,16 @@ impl Diagnostic {
         &self.0.related_information
     }
 
+    pub fn severity(&self) -> Severity {
+        self.0.severity
+    }
+
     pub fn print_without_source(&self) -> String {
         let mut result = String::new();
         writeln!(
             result,
-            "{message}:{location:?}",
+            "{severity}: {message}:{location:?}",
+            severity = self.0.severity,
             message = &self.0.message,
             location = self.0.location
         )
@@ -152,6 +169,25 @@ struct DiagnosticData {
     /// Related diagnostic information, such as other definitions in the case of
     /// a duplicate definition error.
     related_information: Vec<DiagnosticRelatedInformation>,
+
+    /// Whether this diagnostic fails the build.
+    severity: Severity,
+}
+
+/// The severity of a diagnostic, only errors fail the build.
+#[derive(Clone, Copy, fmt::Debug, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
 }
 
 /// Secondary locations attached to a diagnostic.
@@ -221,4 +257,17 @@ mod tests {
             "err2"
         );
     }
+
+    #[test]
+    fn test_severity() {
+        let error = Diagnostic::error("err0", Location::generated());
+        assert_eq!(error.severity(), Severity::Error);
+        assert!(error.print_without_source().starts_with("error: err0:"));
+
+        let warning = Diagnostic::warning("warn0", Location::generated());
+        assert_eq!(warning.severity(), Severity::Warning);
+        assert!(warning
+            .print_without_source()
+            .starts_with("warning: warn0:"));
+    }
 }
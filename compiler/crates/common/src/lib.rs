@@ -14,13 +14,15 @@ mod diagnostic;
 mod location;
 mod named_item;
 mod perf_logger;
+mod profile_logger;
 mod span;
 
 pub use console_logger::{print_time, ConsoleLogEvent, ConsoleLogger};
 pub use diagnostic::{
-    combined_result, diagnostics_result, Diagnostic, DiagnosticsResult, WithDiagnostics,
+    combined_result, diagnostics_result, Diagnostic, DiagnosticsResult, Severity, WithDiagnostics,
 };
 pub use location::{Location, SourceLocationKey, WithLocation};
 pub use named_item::{Named, NamedItem};
 pub use perf_logger::{NoopPerfLogger, NoopPerfLoggerEvent, PerfLogEvent, PerfLogger};
+pub use profile_logger::{ProfileLogEvent, ProfileLogger};
 pub use span::Span;
@@ -111,6 +111,18 @@ impl Location {
     pub fn contains(&self, subspan: Span) -> bool {
         self.span.contains(subspan)
     }
+
+    /// The LSP range of this location in `source`, the text its span is
+    /// relative to, which starts at `line_offset` and `character_offset` in
+    /// the document. See `Span::to_range`.
+    pub fn to_lsp_range(
+        &self,
+        source: &str,
+        line_offset: usize,
+        character_offset: usize,
+    ) -> lsp_types::Range {
+        self.span.to_range(source, line_offset, character_offset)
+    }
 }
 
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
@@ -0,0 +1,154 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use super::perf_logger::{PerfLogEvent, PerfLogger};
+use log::info;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A timer of an event, with the project of the event if it has one.
+struct Timing {
+    project: Option<String>,
+    phase: String,
+    duration: Duration,
+}
+
+pub struct ProfileLogEvent {
+    name: String,
+    project: Mutex<Option<String>>,
+    timings: Mutex<Vec<(String, Duration)>>,
+}
+
+/// A logger that collects the durations of all timers and prints them as a
+/// table sorted by the total duration of each phase when flushed. Phases
+/// with the same name in the same event and project are added up.
+#[derive(Default)]
+pub struct ProfileLogger {
+    timings: Mutex<Vec<Timing>>,
+}
+
+impl PerfLogEvent for ProfileLogEvent {
+    type Timer = (String, Instant);
+    fn number(&self, _name: impl Copy + Into<String>, _number: usize) {}
+    fn string(&self, name: impl Copy + Into<String>, value: String) {
+        if name.into() == "project" {
+            *self.project.lock().unwrap() = Some(value);
+        }
+    }
+    fn start(&self, name: impl Copy + Into<String>) -> Self::Timer {
+        (name.into(), Instant::now())
+    }
+    fn stop(&self, timer: Self::Timer) {
+        let (name, time) = timer;
+        self.timings.lock().unwrap().push((name, time.elapsed()));
+    }
+}
+
+impl PerfLogger for ProfileLogger {
+    type PerfLogEvent = ProfileLogEvent;
+    fn create_event(&self, name: impl Copy + Into<String>) -> Self::PerfLogEvent {
+        ProfileLogEvent {
+            name: name.into(),
+            project: Mutex::new(None),
+            timings: Mutex::new(Vec::new()),
+        }
+    }
+    fn complete_event(&self, event: Self::PerfLogEvent) {
+        let project = event.project.into_inner().unwrap();
+        let mut timings = self.timings.lock().unwrap();
+        for (name, duration) in event.timings.into_inner().unwrap() {
+            timings.push(Timing {
+                project: project.clone(),
+                phase: format!("{} > {}", event.name, name),
+                duration,
+            });
+        }
+    }
+    fn flush(&self) {
+        let timings = std::mem::take(&mut *self.timings.lock().unwrap());
+        for line in format_timings(timings) {
+            info!("{}", line);
+        }
+    }
+}
+
+/// Adds up the timings of the same phase and project and formats them as
+/// table rows, the phase that took longest first.
+fn format_timings(timings: Vec<Timing>) -> Vec<String> {
+    if timings.is_empty() {
+        return Vec::new();
+    }
+    let mut totals: HashMap<(Option<String>, String), (Duration, usize)> = HashMap::new();
+    for timing in timings {
+        let total = totals
+            .entry((timing.project, timing.phase))
+            .or_insert((Duration::default(), 0));
+        total.0 += timing.duration;
+        total.1 += 1;
+    }
+    let mut totals = totals.into_iter().collect::<Vec<_>>();
+    totals.sort_by(|(a_key, (a_duration, _)), (b_key, (b_duration, _))| {
+        b_duration.cmp(a_duration).then_with(|| a_key.cmp(b_key))
+    });
+    let project_width = totals
+        .iter()
+        .filter_map(|((project, _), _)| project.as_ref().map(|project| project.len()))
+        .max()
+        .unwrap_or(0)
+        .max("project".len());
+    let mut lines = vec![format!(
+        "{:>10} {:>5}  {:project_width$}  phase",
+        "duration",
+        "count",
+        "project",
+        project_width = project_width
+    )];
+    for ((project, phase), (duration, count)) in totals {
+        lines.push(format!(
+            "{:>8}ms {:>5}  {:project_width$}  {}",
+            duration.as_millis(),
+            count,
+            project.as_deref().unwrap_or("-"),
+            phase,
+            project_width = project_width
+        ));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(project: Option<&str>, phase: &str, millis: u64) -> Timing {
+        Timing {
+            project: project.map(|project| project.to_string()),
+            phase: phase.to_string(),
+            duration: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn test_format_timings() {
+        assert!(format_timings(vec![]).is_empty());
+        assert_eq!(
+            format_timings(vec![
+                timing(Some("web"), "apply_reader_transforms > flatten", 20),
+                timing(None, "compiler_setup > query", 5),
+                timing(Some("mobile"), "apply_reader_transforms > flatten", 30),
+                timing(Some("web"), "apply_reader_transforms > flatten", 15),
+            ]),
+            vec![
+                "  duration count  project  phase",
+                "      35ms     2  web      apply_reader_transforms > flatten",
+                "      30ms     1  mobile   apply_reader_transforms > flatten",
+                "       5ms     1  -        compiler_setup > query",
+            ]
+        );
+    }
+}
@@ -36,6 +36,10 @@ impl Span {
         subspan.start >= self.start && subspan.start < self.end && subspan.end <= self.end
     }
 
+    /// Converts the span, a range of byte offsets into `source`, to an LSP
+    /// range. `line_offset` and `character_offset` are the position of the
+    /// start of `source` in the document. LSP positions count characters in
+    /// UTF-16 code units, so e.g. an emoji counts as two characters.
     pub fn to_range(
         self,
         source: &str,
@@ -46,13 +50,20 @@ impl Span {
         let end = self.end as usize;
         // Zero-indexed line offset in the document
         let mut line = line_offset;
-        // Zero-indexed character offset on the line
+        // Zero-indexed character offset on the line, in UTF-16 code units
         let mut character = character_offset;
-        let mut start_position = lsp_types::Position::default();
-        let mut end_position = lsp_types::Position::default();
-        let mut chars = source.chars().enumerate().peekable();
+        let mut start_position = None;
+        let mut end_position = None;
+        let mut chars = source.char_indices().peekable();
 
         while let Some((index, chr)) = chars.next() {
+            if index == start {
+                start_position = Some(lsp_types::Position::new(line as u64, character as u64));
+            }
+            if index == end {
+                end_position = Some(lsp_types::Position::new(line as u64, character as u64));
+                break;
+            }
             let is_newline = match chr {
                 // Line terminators: https://www.ecma-international.org/ecma-262/#sec-line-terminators
                 '\u{000A}' | '\u{000D}' | '\u{2028}' | '\u{2029}' => match (chr, chars.peek()) {
@@ -68,21 +79,16 @@ impl Span {
                 // character offset.
                 line += 1;
                 character = 0;
-            }
-            if index == start {
-                start_position = lsp_types::Position::new(line as u64, character as u64);
-            }
-            if index == end {
-                end_position = lsp_types::Position::new(line as u64, character as u64);
-                break;
-            }
-            // Make sure to only increment the character offset if this
-            // isn't a newline.
-            if !is_newline {
-                character += 1;
+            } else {
+                character += chr.len_utf16();
             }
         }
-        lsp_types::Range::new(start_position, end_position)
+        // Spans can end at (or, if empty, start at) the end of the source.
+        let end_of_source = lsp_types::Position::new(line as u64, character as u64);
+        lsp_types::Range::new(
+            start_position.unwrap_or(end_of_source),
+            end_position.unwrap_or(end_of_source),
+        )
     }
 }
 
@@ -101,6 +107,7 @@ impl From<std::ops::Range<usize>> for Span {
 #[cfg(test)]
 mod test {
     use super::Span;
+    use lsp_types::{Position, Range};
     #[test]
     fn span_contains() {
         let outer_span = Span::new(1, 10);
@@ -122,4 +129,43 @@ mod test {
             "A span doesn't contain a subspan whose start is less than the start of the current span",
         );
     }
+
+    #[test]
+    fn span_to_range() {
+        let source = "query {\n  me\n}";
+        assert_eq!(
+            Span::new(10, 12).to_range(source, 0, 0),
+            Range::new(Position::new(1, 2), Position::new(1, 4))
+        );
+        // Offsets only apply to the first line of the source.
+        assert_eq!(
+            Span::new(0, 5).to_range(source, 3, 4),
+            Range::new(Position::new(3, 4), Position::new(3, 9))
+        );
+        assert_eq!(
+            Span::new(10, 12).to_range(source, 3, 4),
+            Range::new(Position::new(4, 2), Position::new(4, 4))
+        );
+        // A span at the end of the source.
+        assert_eq!(
+            Span::new(13, 14).to_range(source, 0, 0),
+            Range::new(Position::new(2, 0), Position::new(2, 1))
+        );
+    }
+
+    #[test]
+    fn span_to_range_counts_utf16_code_units() {
+        // "é" is 2 bytes and 1 UTF-16 code unit, "🔥" 4 bytes and 2 code units.
+        let source = "# é🔥\n{ me } # 🔥 me";
+        let me = source.rfind("me").unwrap();
+        assert_eq!(
+            Span::from_usize(me, me + 2).to_range(source, 0, 0),
+            Range::new(Position::new(1, 12), Position::new(1, 14))
+        );
+        let fire = source.find('🔥').unwrap();
+        assert_eq!(
+            Span::from_usize(fire, fire + '🔥'.len_utf8()).to_range(source, 0, 0),
+            Range::new(Position::new(0, 3), Position::new(0, 5))
+        );
+    }
 }
@@ -7,7 +7,8 @@
 
 use crate::SourcePrinter;
 use colored::*;
-use common::{Diagnostic, Location, SourceLocationKey};
+use common::{Diagnostic, Location, Severity, SourceLocationKey};
+use graphql_syntax::GraphQLSource;
 use std::fmt::Write;
 
 pub struct DiagnosticPrinter<T: Sources> {
@@ -38,13 +39,25 @@ impl<TSources: Sources> DiagnosticPrinter<TSources> {
         writer: &mut W,
         diagnostic: &Diagnostic,
     ) -> std::fmt::Result {
-        writeln!(writer, "{}\n", format!("✖︎ {}", diagnostic.message()).red())?;
+        let color = match diagnostic.severity() {
+            Severity::Error => Color::Red,
+            Severity::Warning => Color::Yellow,
+        };
+        let symbol = match diagnostic.severity() {
+            Severity::Error => "✖︎",
+            Severity::Warning => "⚠︎",
+        };
+        writeln!(
+            writer,
+            "{}\n",
+            format!("{} {}", symbol, diagnostic.message()).color(color)
+        )?;
         self.write_source(writer, diagnostic.location())?;
         for related_information in diagnostic.related_information() {
             writeln!(
                 writer,
                 "\n{}\n",
-                format!("  ℹ︎ {}", related_information.message).red()
+                format!("  ℹ︎ {}", related_information.message).color(color)
             )?;
             self.write_source(writer, related_information.location)?;
         }
@@ -55,14 +68,17 @@ impl<TSources: Sources> DiagnosticPrinter<TSources> {
     fn write_source<W: Write>(&self, writer: &mut W, location: Location) -> std::fmt::Result {
         let source_printer = SourcePrinter::new();
         if let Some(source) = self.sources.get(location.source_location()) {
-            let range = location.span().to_range(&source, 0, 0);
+            let range =
+                location
+                    .span()
+                    .to_range(&source.text, source.line_index, source.column_index);
             writeln!(
                 writer,
                 "  {}{}",
                 location.source_location().path().underline(),
                 format!(":{}:{}", range.start.line + 1, range.start.character + 1).dimmed()
             )?;
-            source_printer.write_span(writer, location.span(), &source)?;
+            source_printer.write_span(writer, location.span(), &source.text, source.line_index)?;
         } else {
             writeln!(
                 writer,
@@ -75,14 +91,16 @@ impl<TSources: Sources> DiagnosticPrinter<TSources> {
 }
 
 pub trait Sources {
-    fn get(&self, source_location: SourceLocationKey) -> Option<String>;
+    /// Returns the source text for the location, including where the text
+    /// starts in its file so embedded sources print file-relative positions.
+    fn get(&self, source_location: SourceLocationKey) -> Option<GraphQLSource>;
 }
 
 impl<F> Sources for F
 where
     F: Fn(SourceLocationKey) -> Option<String>,
 {
-    fn get(&self, source_location: SourceLocationKey) -> Option<String> {
-        self(source_location)
+    fn get(&self, source_location: SourceLocationKey) -> Option<GraphQLSource> {
+        self(source_location).map(|text| GraphQLSource::new(text, 0, 0))
     }
 }
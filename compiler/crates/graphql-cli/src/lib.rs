@@ -8,5 +8,5 @@
 mod diagnostic_printer;
 mod source_printer;
 
-pub use diagnostic_printer::DiagnosticPrinter;
+pub use diagnostic_printer::{DiagnosticPrinter, Sources};
 pub use source_printer::SourcePrinter;
@@ -8,12 +8,16 @@
 use crate::constants::ARGUMENT_DEFINITION;
 use crate::errors::ValidationMessage;
 use crate::ir::*;
-use crate::signatures::{build_signatures, FragmentSignature, FragmentSignatures};
+use crate::program::Program;
+use crate::signatures::{
+    build_fragment_signature, build_signatures, FragmentSignature, FragmentSignatures,
+};
+use crate::visitor::Visitor;
 use common::{Diagnostic, DiagnosticsResult, Location, NamedItem, Span, WithLocation};
 use core::cmp::Ordering;
 use errors::{par_try_map, try2, try3, try_map};
 use fnv::{FnvBuildHasher, FnvHashMap, FnvHashSet};
-use graphql_syntax::{DirectiveLocation, List, OperationKind};
+use graphql_syntax::{DirectiveLocation, List, OperationKind, TokenKind};
 use indexmap::IndexMap;
 use interner::Intern;
 use interner::StringKey;
@@ -21,6 +25,7 @@ use lazy_static::lazy_static;
 use schema::{
     ArgumentDefinitions, Enum, FieldID, InputObject, Scalar, Schema, Type, TypeReference,
 };
+use std::sync::Arc;
 
 lazy_static! {
     static ref TYPENAME_FIELD_NAME: StringKey = "__typename".intern();
@@ -35,6 +40,33 @@ lazy_static! {
 
     static ref DIRECTIVE_UNCHECKED_ARGUMENTS: StringKey = "uncheckedArguments_DEPRECATED".intern();
     pub static ref DIRECTIVE_ARGUMENTS: StringKey = "arguments".intern();
+
+    /// Required arguments of the Relay client directives, checked in relay mode
+    /// instead of the required arguments of their schema definitions. The
+    /// `key` of the connection directives is checked when validating the
+    /// connections instead, since Relay can generate it.
+    static ref CLIENT_DIRECTIVE_REQUIRED_ARGUMENTS: FnvHashMap<StringKey, Vec<StringKey>> = [
+        ("__clientField", &["handle"][..]),
+        ("alias", &["as"]),
+        ("appendEdge", &["connections"]),
+        ("appendNode", &["connections", "edgeTypeName"]),
+        ("connection", &[]),
+        ("deleteEdge", &["connections"]),
+        ("module", &["name"]),
+        ("prependEdge", &["connections"]),
+        ("prependNode", &["connections", "edgeTypeName"]),
+        ("refetchable", &["queryName"]),
+        ("required", &["action"]),
+        ("stream_connection", &["initial_count"]),
+    ]
+    .iter()
+    .map(|(directive_name, argument_names)| {
+        (
+            directive_name.intern(),
+            argument_names.iter().map(|name| name.intern()).collect(),
+        )
+    })
+    .collect();
 }
 
 /// The semantic of defining variables on a fragment definition.
@@ -61,6 +93,8 @@ pub struct BuilderOptions {
     /// Enable a Relay special cases:
     /// - Fields with a @match directive do not require to pass the non-nullable
     ///   `supported` argument.
+    /// - The required arguments of Relay client directives are looked up in a
+    ///   fixed table instead of the schema.
     pub relay_mode: bool,
 }
 
@@ -102,6 +136,148 @@ pub fn build_ir_with_extra_features(
     })
 }
 
+/// Applies the definitions of a single changed file to an already built
+/// `program` without rebuilding the definitions of any other file.
+///
+/// Every definition previously built from `file_path` is replaced by the
+/// ones built from `definitions`, which is empty when the file was deleted.
+/// Fragment spreads in the remaining definitions that reference a fragment
+/// that was removed, renamed or redefined are re-checked against the new
+/// fragment signatures. Definitions whose name is already used by another
+/// file, that fail to build, or that contain such an invalid spread are left
+/// out of the returned program and reported in the returned diagnostics.
+/// NOTE: Uses Relay defaults.
+pub fn update_program_file(
+    program: &Program,
+    file_path: StringKey,
+    definitions: &[graphql_syntax::ExecutableDefinition],
+) -> (Program, Vec<Diagnostic>) {
+    let schema = &program.schema;
+    let is_in_file = |location: Location| location.source_location().path() == file_path.lookup();
+
+    let mut updated_program = Program::new(Arc::clone(schema));
+    let mut signatures = FragmentSignatures::default();
+    let mut previous_signatures = FragmentSignatures::default();
+    let mut changed_fragments = FnvHashSet::default();
+    let mut defined_names = FnvHashMap::default();
+    for fragment in program.fragments() {
+        if is_in_file(fragment.name.location) {
+            changed_fragments.insert(fragment.name.item);
+            previous_signatures.insert(
+                fragment.name.item,
+                FragmentSignature::from(fragment.as_ref()),
+            );
+        } else {
+            defined_names.insert(fragment.name.item, fragment.name.location);
+            signatures.insert(
+                fragment.name.item,
+                FragmentSignature::from(fragment.as_ref()),
+            );
+        }
+    }
+    for operation in program.operations() {
+        if !is_in_file(operation.name.location) {
+            defined_names.insert(operation.name.item, operation.name.location);
+        }
+    }
+
+    let mut diagnostics = Vec::new();
+    let mut unbuildable_definitions = FnvHashSet::default();
+    for (index, definition) in definitions.iter().enumerate() {
+        let (name, signature) = match definition {
+            graphql_syntax::ExecutableDefinition::Fragment(fragment) => {
+                match build_fragment_signature(schema, fragment) {
+                    Ok(signature) => (signature.name, Some(signature)),
+                    Err(errors) => {
+                        diagnostics.extend(errors);
+                        unbuildable_definitions.insert(index);
+                        continue;
+                    }
+                }
+            }
+            graphql_syntax::ExecutableDefinition::Operation(operation) => match &operation.name {
+                Some(name) => (
+                    name.name_with_location(definition.location().source_location()),
+                    None,
+                ),
+                None => continue,
+            },
+        };
+        if let Some(previous_location) = defined_names.get(&name.item) {
+            diagnostics.push(
+                Diagnostic::error(
+                    ValidationMessage::DuplicateDefinition(name.item),
+                    *previous_location,
+                )
+                .annotate("also defined here", name.location),
+            );
+            unbuildable_definitions.insert(index);
+            continue;
+        }
+        defined_names.insert(name.item, name.location);
+        if let Some(signature) = signature {
+            changed_fragments.insert(name.item);
+            signatures.insert(name.item, signature);
+        }
+    }
+
+    for (index, definition) in definitions.iter().enumerate() {
+        if unbuildable_definitions.contains(&index) {
+            continue;
+        }
+        let mut builder = Builder::new(
+            schema,
+            &signatures,
+            definition.location(),
+            BuilderOptions {
+                allow_undefined_fragment_spreads: false,
+                fragment_variables_semantic: FragmentVariablesSemantic::PassedValue,
+                relay_mode: true,
+            },
+        );
+        match builder.build_definition(definition) {
+            Ok(ExecutableDefinition::Operation(operation)) => {
+                updated_program.insert_operation(Arc::new(operation))
+            }
+            Ok(ExecutableDefinition::Fragment(fragment)) => {
+                updated_program.insert_fragment(Arc::new(fragment))
+            }
+            Err(errors) => diagnostics.extend(errors),
+        }
+    }
+
+    let mut spread_validator = ChangedFragmentSpreadValidator {
+        schema,
+        signatures: &signatures,
+        previous_signatures: &previous_signatures,
+        changed_fragments: &changed_fragments,
+        parent_types: Vec::new(),
+        defined_variables: Default::default(),
+        errors: Vec::new(),
+    };
+    for operation in program.operations() {
+        if !is_in_file(operation.name.location) {
+            let error_count = spread_validator.errors.len();
+            spread_validator.visit_operation(operation);
+            if spread_validator.errors.len() == error_count {
+                updated_program.insert_operation(Arc::clone(operation));
+            }
+        }
+    }
+    for fragment in program.fragments() {
+        if !is_in_file(fragment.name.location) {
+            let error_count = spread_validator.errors.len();
+            spread_validator.visit_fragment(fragment);
+            if spread_validator.errors.len() == error_count {
+                updated_program.insert_fragment(Arc::clone(fragment));
+            }
+        }
+    }
+    diagnostics.extend(spread_validator.errors);
+
+    (updated_program, diagnostics)
+}
+
 pub fn build_type_annotation(
     schema: &Schema,
     annotation: &graphql_syntax::TypeAnnotation,
@@ -161,6 +337,271 @@ pub fn build_variable_definitions(
     builder.build_variable_definitions(definitions)
 }
 
+/// Re-checks fragment spreads built against a previous version of a fragment
+/// signature, see `update_program_file`. The spreads are converted back to
+/// syntax and built again, so they are validated like in a full build.
+struct ChangedFragmentSpreadValidator<'a> {
+    schema: &'a Schema,
+    signatures: &'a FragmentSignatures,
+    previous_signatures: &'a FragmentSignatures,
+    changed_fragments: &'a FnvHashSet<StringKey>,
+    parent_types: Vec<Type>,
+    /// The variables defined by the definition that is being visited.
+    defined_variables: VariableDefinitions,
+    errors: Vec<Diagnostic>,
+}
+
+impl ChangedFragmentSpreadValidator<'_> {
+    fn set_defined_variables(&mut self, variable_definitions: &[VariableDefinition]) {
+        self.defined_variables = variable_definitions
+            .iter()
+            .map(|variable_definition| (variable_definition.name.item, variable_definition.clone()))
+            .collect();
+    }
+}
+
+impl Visitor for ChangedFragmentSpreadValidator<'_> {
+    const NAME: &'static str = "ChangedFragmentSpreadValidator";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_operation(&mut self, operation: &OperationDefinition) {
+        self.set_defined_variables(&operation.variable_definitions);
+        self.parent_types.push(operation.type_);
+        self.default_visit_operation(operation);
+        self.parent_types.pop();
+    }
+
+    fn visit_fragment(&mut self, fragment: &FragmentDefinition) {
+        self.set_defined_variables(&fragment.variable_definitions);
+        self.parent_types.push(fragment.type_condition);
+        self.default_visit_fragment(fragment);
+        self.parent_types.pop();
+    }
+
+    fn visit_linked_field(&mut self, field: &LinkedField) {
+        self.parent_types
+            .push(self.schema.field(field.definition.item).type_.inner());
+        self.default_visit_linked_field(field);
+        self.parent_types.pop();
+    }
+
+    fn visit_inline_fragment(&mut self, fragment: &InlineFragment) {
+        if let Some(type_condition) = fragment.type_condition {
+            self.parent_types.push(type_condition);
+            self.default_visit_inline_fragment(fragment);
+            self.parent_types.pop();
+        } else {
+            self.default_visit_inline_fragment(fragment);
+        }
+    }
+
+    fn visit_fragment_spread(&mut self, spread: &FragmentSpread) {
+        if !self.changed_fragments.contains(&spread.fragment.item) {
+            return;
+        }
+        // The spread was already validated against an identical signature.
+        if let (Some(signature), Some(previous_signature)) = (
+            self.signatures.get(&spread.fragment.item),
+            self.previous_signatures.get(&spread.fragment.item),
+        ) {
+            if have_same_interface(signature, previous_signature) {
+                return;
+            }
+        }
+        let parent_type = TypeReference::Named(
+            *self
+                .parent_types
+                .last()
+                .expect("Expected fragment spreads to be in a definition."),
+        );
+        let mut builder = Builder::new(
+            self.schema,
+            self.signatures,
+            spread.fragment.location,
+            BuilderOptions {
+                allow_undefined_fragment_spreads: false,
+                fragment_variables_semantic: FragmentVariablesSemantic::PassedValue,
+                relay_mode: true,
+            },
+        );
+        builder.defined_variables = self.defined_variables.clone();
+        if let Err(errors) =
+            builder.build_fragment_spread(&to_syntax_fragment_spread(spread), &parent_type)
+        {
+            self.errors.extend(errors);
+        }
+    }
+}
+
+/// Whether spreads built against `previous_signature` are also valid
+/// spreads of a fragment with `signature`.
+fn have_same_interface(
+    signature: &FragmentSignature,
+    previous_signature: &FragmentSignature,
+) -> bool {
+    signature.type_condition == previous_signature.type_condition
+        && signature.variable_definitions.len() == previous_signature.variable_definitions.len()
+        && signature
+            .variable_definitions
+            .iter()
+            .zip(&previous_signature.variable_definitions)
+            .all(|(definition, previous_definition)| {
+                definition.name.item == previous_definition.name.item
+                    && definition.type_ == previous_definition.type_
+                    && definition.default_value == previous_definition.default_value
+            })
+}
+
+/// Converts a built fragment spread back to syntax, keeping only its
+/// arguments. Values without a location of their own use the location of the
+/// enclosing value. Integral floats are converted to ints since an `Int`
+/// literal passed as a `Float` was built as a float.
+fn to_syntax_fragment_spread(spread: &FragmentSpread) -> graphql_syntax::FragmentSpread {
+    let span = *spread.fragment.location.span();
+    let directives = if spread.arguments.is_empty() {
+        Vec::new()
+    } else {
+        vec![graphql_syntax::Directive {
+            span,
+            at: generated_token(span, TokenKind::At),
+            name: generated_identifier(span, *DIRECTIVE_ARGUMENTS),
+            arguments: Some(generated_list(
+                span,
+                spread.arguments.iter().map(to_syntax_argument).collect(),
+            )),
+        }]
+    };
+    graphql_syntax::FragmentSpread {
+        span,
+        spread: generated_token(span, TokenKind::Spread),
+        name: generated_identifier(span, spread.fragment.item),
+        directives,
+    }
+}
+
+fn to_syntax_argument(argument: &Argument) -> graphql_syntax::Argument {
+    let name_span = *argument.name.location.span();
+    let value_span = *argument.value.location.span();
+    graphql_syntax::Argument {
+        span: name_span,
+        name: generated_identifier(name_span, argument.name.item),
+        colon: generated_token(name_span, TokenKind::Colon),
+        value: to_syntax_value(&argument.value.item, value_span),
+    }
+}
+
+fn to_syntax_value(value: &Value, span: Span) -> graphql_syntax::Value {
+    match value {
+        Value::Constant(value) => graphql_syntax::Value::Constant(to_syntax_constant(value, span)),
+        Value::Variable(variable) => {
+            let span = *variable.name.location.span();
+            graphql_syntax::Value::Variable(graphql_syntax::VariableIdentifier {
+                span,
+                token: generated_token(span, TokenKind::Identifier),
+                name: variable.name.item,
+            })
+        }
+        Value::List(items) => graphql_syntax::Value::List(generated_list(
+            span,
+            items
+                .iter()
+                .map(|item| to_syntax_value(item, span))
+                .collect(),
+        )),
+        Value::Object(fields) => graphql_syntax::Value::Object(generated_list(
+            span,
+            fields.iter().map(to_syntax_argument).collect(),
+        )),
+    }
+}
+
+fn to_syntax_constant(value: &ConstantValue, span: Span) -> graphql_syntax::ConstantValue {
+    match value {
+        ConstantValue::Int(value) => graphql_syntax::ConstantValue::Int(graphql_syntax::IntNode {
+            token: generated_token(span, TokenKind::IntegerLiteral),
+            value: *value,
+        }),
+        ConstantValue::Float(value) if value.as_float().fract() == 0.0 => {
+            graphql_syntax::ConstantValue::Int(graphql_syntax::IntNode {
+                token: generated_token(span, TokenKind::IntegerLiteral),
+                value: value.as_float() as i64,
+            })
+        }
+        ConstantValue::Float(value) => {
+            graphql_syntax::ConstantValue::Float(graphql_syntax::FloatNode {
+                token: generated_token(span, TokenKind::FloatLiteral),
+                value: *value,
+                source_value: value.to_string().intern(),
+            })
+        }
+        ConstantValue::String(value) => {
+            graphql_syntax::ConstantValue::String(graphql_syntax::StringNode {
+                token: generated_token(span, TokenKind::StringLiteral),
+                value: *value,
+            })
+        }
+        ConstantValue::Boolean(value) => {
+            graphql_syntax::ConstantValue::Boolean(graphql_syntax::BooleanNode {
+                token: generated_token(span, TokenKind::Identifier),
+                value: *value,
+            })
+        }
+        ConstantValue::Null() => {
+            graphql_syntax::ConstantValue::Null(generated_token(span, TokenKind::Identifier))
+        }
+        ConstantValue::Enum(value) => {
+            graphql_syntax::ConstantValue::Enum(graphql_syntax::EnumNode {
+                token: generated_token(span, TokenKind::Identifier),
+                value: *value,
+            })
+        }
+        ConstantValue::List(items) => graphql_syntax::ConstantValue::List(generated_list(
+            span,
+            items
+                .iter()
+                .map(|item| to_syntax_constant(item, span))
+                .collect(),
+        )),
+        ConstantValue::Object(fields) => graphql_syntax::ConstantValue::Object(generated_list(
+            span,
+            fields
+                .iter()
+                .map(|field| {
+                    let name_span = *field.name.location.span();
+                    graphql_syntax::ConstantArgument {
+                        span: name_span,
+                        name: generated_identifier(name_span, field.name.item),
+                        colon: generated_token(name_span, TokenKind::Colon),
+                        value: to_syntax_constant(&field.value.item, *field.value.location.span()),
+                    }
+                })
+                .collect(),
+        )),
+    }
+}
+
+fn generated_token(span: Span, kind: TokenKind) -> graphql_syntax::Token {
+    graphql_syntax::Token { span, kind }
+}
+
+fn generated_identifier(span: Span, value: StringKey) -> graphql_syntax::Identifier {
+    graphql_syntax::Identifier {
+        span,
+        token: generated_token(span, TokenKind::Identifier),
+        value,
+    }
+}
+
+fn generated_list<T>(span: Span, items: Vec<T>) -> List<T> {
+    List {
+        span,
+        start: generated_token(span, TokenKind::Empty),
+        items,
+        end: generated_token(span, TokenKind::Empty),
+    }
+}
+
 // Helper Types
 
 type VariableDefinitions = FnvHashMap<StringKey, VariableDefinition>;
@@ -537,6 +978,23 @@ impl<'schema, 'signatures> Builder<'schema, 'signatures> {
                     if arg_result.is_err() && validation_level == ValidationLevel::Loose {
                         has_invalid_arg = true;
                         self.build_argument(arg, &argument_definition.type_, ValidationLevel::Loose)
+                    } else if arg_result.is_err() && arg.value.is_constant() {
+                        Err(vec![
+                            Diagnostic::error(
+                                ValidationMessage::InvalidFragmentArgumentType {
+                                    argument_name: arg.name.value,
+                                    fragment_name: signature.name.item,
+                                    expected_type: self
+                                        .schema
+                                        .get_type_string(&argument_definition.type_),
+                                },
+                                self.location.with_span(arg.value.span()),
+                            )
+                            .annotate(
+                                "defined on the fragment here",
+                                argument_definition.name.location,
+                            ),
+                        ])
                     } else {
                         arg_result
                     }
@@ -548,10 +1006,16 @@ impl<'schema, 'signatures> Builder<'schema, 'signatures> {
                         ValidationLevel::Loose,
                     )?)
                 } else {
-                    Err(vec![Diagnostic::error(
-                        ValidationMessage::UnknownArgument(arg.name.value),
-                        self.location.with_span(arg.span),
-                    )])
+                    Err(vec![
+                        Diagnostic::error(
+                            ValidationMessage::UnknownFragmentArgument {
+                                argument_name: arg.name.value,
+                                fragment_name: signature.name.item,
+                            },
+                            self.location.with_span(arg.span),
+                        )
+                        .annotate("fragment defined here", signature.name.location),
+                    ])
                 }
             })
             .collect();
@@ -654,6 +1118,7 @@ impl<'schema, 'signatures> Builder<'schema, 'signatures> {
         let arguments = if let Some(graphql_syntax::Directive {
             name,
             arguments: Some(arg_list),
+            span,
             ..
         }) = argument_directives.pop()
         {
@@ -662,6 +1127,20 @@ impl<'schema, 'signatures> Builder<'schema, 'signatures> {
             } else {
                 ValidationLevel::Strict
             };
+            // Likely copied from a spread of another fragment, report it once
+            // instead of every argument as unknown.
+            if validation_level == ValidationLevel::Strict
+                && signature.variable_definitions.is_empty()
+                && !arg_list.items.is_empty()
+            {
+                return Err(vec![Diagnostic::error(
+                    ValidationMessage::ArgumentsPassedToFragmentWithoutDefinitions {
+                        fragment_name: signature.name.item,
+                    },
+                    self.location.with_span(*span),
+                )
+                .annotate("fragment defined here", signature.name.location)]);
+            }
             self.build_fragment_spread_arguments(&signature, &arg_list, validation_level)
         } else {
             let errors: Vec<_> = signature
@@ -785,12 +1264,10 @@ impl<'schema, 'signatures> Builder<'schema, 'signatures> {
         ) {
             Some(field_id) => field_id,
             None => {
-                return Err(vec![Diagnostic::error(
-                    ValidationMessage::UnknownField {
-                        type_: self.schema.get_type_name(parent_type.inner()),
-                        field: field.name.value,
-                    },
-                    self.location.with_span(span),
+                return Err(vec![self.unknown_field_error(
+                    parent_type.inner(),
+                    field.name.value,
+                    span,
                 )]);
             }
         };
@@ -852,12 +1329,10 @@ impl<'schema, 'signatures> Builder<'schema, 'signatures> {
         ) {
             Some(field_id) => field_id,
             None => {
-                return Err(vec![Diagnostic::error(
-                    ValidationMessage::UnknownField {
-                        type_: self.schema.get_type_name(parent_type.inner()),
-                        field: field_name,
-                    },
-                    self.location.with_span(span),
+                return Err(vec![self.unknown_field_error(
+                    parent_type.inner(),
+                    field_name,
+                    span,
                 )]);
             }
         };
@@ -1120,11 +1595,45 @@ impl<'schema, 'signatures> Builder<'schema, 'signatures> {
                 self.location.with_span(directive.name.span),
             )]);
         }
+        // check for missing required arguments (non-nullable without a default value)
+        let required_arg_names = match CLIENT_DIRECTIVE_REQUIRED_ARGUMENTS
+            .get(&directive.name.value)
+        {
+            Some(required_arg_names) if self.options.relay_mode => required_arg_names.clone(),
+            _ => directive_definition
+                .arguments
+                .iter()
+                .filter(|arg_def| arg_def.type_.is_non_null() && arg_def.default_value.is_none())
+                .map(|arg_def| arg_def.name)
+                .collect(),
+        };
+        let missing_arg_errors: Vec<_> = required_arg_names
+            .into_iter()
+            .filter(|required_arg_name| {
+                directive
+                    .arguments
+                    .iter()
+                    .flat_map(|args| &args.items)
+                    .all(|arg| arg.name.value != *required_arg_name)
+            })
+            .map(|missing_arg_name| {
+                Diagnostic::error(
+                    ValidationMessage::MissingRequiredDirectiveArgument {
+                        directive: directive.name.value,
+                        argument: missing_arg_name,
+                    },
+                    self.location.with_span(directive.name.span),
+                )
+            })
+            .collect();
+        if !missing_arg_errors.is_empty() {
+            return Err(missing_arg_errors);
+        }
         let arguments = self.build_arguments(
             directive.name.span,
             &directive.arguments,
             &directive_definition.arguments,
-            |_| true,
+            |_| false,
         )?;
         Ok(Directive {
             name: directive
@@ -1590,6 +2099,47 @@ impl<'schema, 'signatures> Builder<'schema, 'signatures> {
             )]),
         }
     }
+
+    /// The error for a field that `parent_type` doesn't have. Only
+    /// `__typename` can be selected directly on a union, suggest an inline
+    /// fragment on a member defining the field, or on the first member.
+    fn unknown_field_error(
+        &self,
+        parent_type: Type,
+        field_name: StringKey,
+        span: Span,
+    ) -> Diagnostic {
+        if let Type::Union(id) = parent_type {
+            let union = self.schema.union(id);
+            let member = union
+                .members
+                .iter()
+                .find(|member| {
+                    self.schema
+                        .named_field(Type::Object(**member), field_name)
+                        .is_some()
+                })
+                .or_else(|| union.members.first());
+            if let Some(member) = member {
+                return Diagnostic::error(
+                    ValidationMessage::FieldOnUnionRequiresInlineFragment {
+                        union_name: union.name,
+                        field_name,
+                        member_name: self.schema.object(*member).name,
+                    },
+                    self.location.with_span(span),
+                );
+            }
+        }
+        Diagnostic::error(
+            ValidationMessage::UnknownField {
+                type_: self.schema.get_type_name(parent_type),
+                field: field_name,
+            },
+            self.location.with_span(span),
+        )
+    }
+
     fn lookup_field(
         &self,
         parent_type: Type,
@@ -0,0 +1,621 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Builders to construct IR programmatically instead of parsing GraphQL text,
+//! e.g. for tests of transforms or tooling that generates documents.
+//!
+//! All nodes get `Location::generated()` locations. Fields, field arguments
+//! and type conditions are checked against the schema on `build()`;
+//! directives and fragment spreads are passed through as-is.
+
+use crate::errors::ValidationMessage;
+use crate::ir::*;
+use common::{Diagnostic, DiagnosticsResult, Location, WithLocation};
+use errors::{try2, try_map};
+use graphql_syntax::OperationKind;
+use interner::StringKey;
+use schema::{ArgumentDefinitions, Schema, Type, TypeReference};
+use std::sync::Arc;
+
+/// Any of the selection builders, used as the children of a linked field,
+/// inline fragment or definition.
+pub enum SelectionBuilder {
+    ScalarField(ScalarFieldBuilder),
+    LinkedField(LinkedFieldBuilder),
+    InlineFragment(InlineFragmentBuilder),
+    FragmentSpread(FragmentSpreadBuilder),
+}
+
+impl SelectionBuilder {
+    pub fn build(self, schema: &Schema, parent_type: Type) -> DiagnosticsResult<Selection> {
+        Ok(match self {
+            SelectionBuilder::ScalarField(builder) => {
+                Selection::ScalarField(Arc::new(builder.build(schema, parent_type)?))
+            }
+            SelectionBuilder::LinkedField(builder) => {
+                Selection::LinkedField(Arc::new(builder.build(schema, parent_type)?))
+            }
+            SelectionBuilder::InlineFragment(builder) => {
+                Selection::InlineFragment(Arc::new(builder.build(schema, parent_type)?))
+            }
+            SelectionBuilder::FragmentSpread(builder) => {
+                Selection::FragmentSpread(Arc::new(builder.build()))
+            }
+        })
+    }
+}
+
+impl From<ScalarFieldBuilder> for SelectionBuilder {
+    fn from(builder: ScalarFieldBuilder) -> Self {
+        SelectionBuilder::ScalarField(builder)
+    }
+}
+
+impl From<LinkedFieldBuilder> for SelectionBuilder {
+    fn from(builder: LinkedFieldBuilder) -> Self {
+        SelectionBuilder::LinkedField(builder)
+    }
+}
+
+impl From<InlineFragmentBuilder> for SelectionBuilder {
+    fn from(builder: InlineFragmentBuilder) -> Self {
+        SelectionBuilder::InlineFragment(builder)
+    }
+}
+
+impl From<FragmentSpreadBuilder> for SelectionBuilder {
+    fn from(builder: FragmentSpreadBuilder) -> Self {
+        SelectionBuilder::FragmentSpread(builder)
+    }
+}
+
+/// Name Arguments?
+pub struct ScalarFieldBuilder {
+    name: StringKey,
+    alias: Option<StringKey>,
+    arguments: Vec<(StringKey, Value)>,
+    directives: Vec<Directive>,
+}
+
+impl ScalarFieldBuilder {
+    pub fn new(name: StringKey) -> Self {
+        Self {
+            name,
+            alias: None,
+            arguments: Vec::new(),
+            directives: Vec::new(),
+        }
+    }
+
+    pub fn alias(mut self, alias: StringKey) -> Self {
+        self.alias = Some(alias);
+        self
+    }
+
+    pub fn argument(mut self, name: StringKey, value: Value) -> Self {
+        self.arguments.push((name, value));
+        self
+    }
+
+    pub fn directive(mut self, name: StringKey, arguments: Vec<(StringKey, Value)>) -> Self {
+        self.directives.push(build_directive(name, arguments));
+        self
+    }
+
+    pub fn build(self, schema: &Schema, parent_type: Type) -> DiagnosticsResult<ScalarField> {
+        let field_id = lookup_field(schema, parent_type, self.name)?;
+        let field = schema.field(field_id);
+        let field_type = field.type_.inner();
+        if !field_type.is_scalar() && !field_type.is_enum() {
+            return Err(vec![Diagnostic::error(
+                ValidationMessage::ExpectedSelectionsOnObjectField {
+                    type_name: schema.get_type_name(parent_type),
+                    field_name: self.name,
+                },
+                Location::generated(),
+            )]);
+        }
+        Ok(ScalarField {
+            alias: self.alias.map(WithLocation::generated),
+            definition: WithLocation::generated(field_id),
+            arguments: build_arguments(&field.arguments, self.arguments)?,
+            directives: self.directives,
+        })
+    }
+}
+
+/// Name Arguments? SelectionSet
+pub struct LinkedFieldBuilder {
+    name: StringKey,
+    alias: Option<StringKey>,
+    arguments: Vec<(StringKey, Value)>,
+    directives: Vec<Directive>,
+    selections: Vec<SelectionBuilder>,
+}
+
+impl LinkedFieldBuilder {
+    pub fn new(name: StringKey) -> Self {
+        Self {
+            name,
+            alias: None,
+            arguments: Vec::new(),
+            directives: Vec::new(),
+            selections: Vec::new(),
+        }
+    }
+
+    pub fn alias(mut self, alias: StringKey) -> Self {
+        self.alias = Some(alias);
+        self
+    }
+
+    pub fn argument(mut self, name: StringKey, value: Value) -> Self {
+        self.arguments.push((name, value));
+        self
+    }
+
+    pub fn directive(mut self, name: StringKey, arguments: Vec<(StringKey, Value)>) -> Self {
+        self.directives.push(build_directive(name, arguments));
+        self
+    }
+
+    pub fn selection(mut self, selection: impl Into<SelectionBuilder>) -> Self {
+        self.selections.push(selection.into());
+        self
+    }
+
+    pub fn build(self, schema: &Schema, parent_type: Type) -> DiagnosticsResult<LinkedField> {
+        let field_id = lookup_field(schema, parent_type, self.name)?;
+        let field = schema.field(field_id);
+        let field_type = field.type_.inner();
+        if field_type.is_scalar() || field_type.is_enum() {
+            return Err(vec![Diagnostic::error(
+                ValidationMessage::InvalidSelectionsOnScalarField {
+                    type_name: schema.get_type_name(parent_type),
+                    field_name: self.name,
+                },
+                Location::generated(),
+            )]);
+        }
+        let (arguments, selections) = try2(
+            build_arguments(&field.arguments, self.arguments),
+            build_selections(schema, field_type, self.selections),
+        )?;
+        Ok(LinkedField {
+            alias: self.alias.map(WithLocation::generated),
+            definition: WithLocation::generated(field_id),
+            arguments,
+            directives: self.directives,
+            selections,
+        })
+    }
+}
+
+/// ... on Type? SelectionSet
+pub struct InlineFragmentBuilder {
+    type_condition: Option<StringKey>,
+    directives: Vec<Directive>,
+    selections: Vec<SelectionBuilder>,
+}
+
+impl InlineFragmentBuilder {
+    pub fn new(type_condition: Option<StringKey>) -> Self {
+        Self {
+            type_condition,
+            directives: Vec::new(),
+            selections: Vec::new(),
+        }
+    }
+
+    pub fn directive(mut self, name: StringKey, arguments: Vec<(StringKey, Value)>) -> Self {
+        self.directives.push(build_directive(name, arguments));
+        self
+    }
+
+    pub fn selection(mut self, selection: impl Into<SelectionBuilder>) -> Self {
+        self.selections.push(selection.into());
+        self
+    }
+
+    pub fn build(self, schema: &Schema, parent_type: Type) -> DiagnosticsResult<InlineFragment> {
+        let type_condition = match self.type_condition {
+            Some(type_name) => Some(lookup_composite_type(schema, type_name)?),
+            None => None,
+        };
+        let selections = build_selections(
+            schema,
+            type_condition.unwrap_or(parent_type),
+            self.selections,
+        )?;
+        Ok(InlineFragment {
+            type_condition,
+            directives: self.directives,
+            selections,
+        })
+    }
+}
+
+/// ... Name
+pub struct FragmentSpreadBuilder {
+    fragment_name: StringKey,
+    arguments: Vec<(StringKey, Value)>,
+    directives: Vec<Directive>,
+}
+
+impl FragmentSpreadBuilder {
+    pub fn new(fragment_name: StringKey) -> Self {
+        Self {
+            fragment_name,
+            arguments: Vec::new(),
+            directives: Vec::new(),
+        }
+    }
+
+    pub fn argument(mut self, name: StringKey, value: Value) -> Self {
+        self.arguments.push((name, value));
+        self
+    }
+
+    pub fn directive(mut self, name: StringKey, arguments: Vec<(StringKey, Value)>) -> Self {
+        self.directives.push(build_directive(name, arguments));
+        self
+    }
+
+    /// Fragment spreads are not validated: the spread fragment is not
+    /// necessarily known when building a single definition.
+    pub fn build(self) -> FragmentSpread {
+        FragmentSpread {
+            fragment: WithLocation::generated(self.fragment_name),
+            arguments: self
+                .arguments
+                .into_iter()
+                .map(|(name, value)| build_argument(name, value))
+                .collect(),
+            directives: self.directives,
+        }
+    }
+}
+
+/// A query, mutation or subscription definition
+pub struct OperationDefinitionBuilder {
+    kind: OperationKind,
+    name: StringKey,
+    variable_definitions: Vec<VariableDefinition>,
+    directives: Vec<Directive>,
+    selections: Vec<SelectionBuilder>,
+}
+
+impl OperationDefinitionBuilder {
+    pub fn new(kind: OperationKind, name: StringKey) -> Self {
+        Self {
+            kind,
+            name,
+            variable_definitions: Vec::new(),
+            directives: Vec::new(),
+            selections: Vec::new(),
+        }
+    }
+
+    pub fn variable(
+        mut self,
+        name: StringKey,
+        type_: TypeReference,
+        default_value: Option<ConstantValue>,
+    ) -> Self {
+        self.variable_definitions
+            .push(build_variable_definition(name, type_, default_value));
+        self
+    }
+
+    pub fn directive(mut self, name: StringKey, arguments: Vec<(StringKey, Value)>) -> Self {
+        self.directives.push(build_directive(name, arguments));
+        self
+    }
+
+    pub fn selection(mut self, selection: impl Into<SelectionBuilder>) -> Self {
+        self.selections.push(selection.into());
+        self
+    }
+
+    pub fn build(self, schema: &Schema) -> DiagnosticsResult<OperationDefinition> {
+        let type_ = match self.kind {
+            OperationKind::Query => schema.query_type(),
+            OperationKind::Mutation => schema.mutation_type(),
+            OperationKind::Subscription => schema.subscription_type(),
+        }
+        .ok_or_else(|| {
+            vec![Diagnostic::error(
+                ValidationMessage::UnsupportedOperation(self.kind),
+                Location::generated(),
+            )]
+        })?;
+        let selections = build_selections(schema, type_, self.selections)?;
+        Ok(OperationDefinition {
+            kind: self.kind,
+            name: WithLocation::generated(self.name),
+            type_,
+            variable_definitions: self.variable_definitions,
+            directives: self.directives,
+            selections,
+        })
+    }
+}
+
+/// A fragment definition, local variables are added with
+/// `argument_definition` and global ones with `used_global_variable`.
+pub struct FragmentDefinitionBuilder {
+    name: StringKey,
+    type_condition: StringKey,
+    variable_definitions: Vec<VariableDefinition>,
+    used_global_variables: Vec<VariableDefinition>,
+    directives: Vec<Directive>,
+    selections: Vec<SelectionBuilder>,
+}
+
+impl FragmentDefinitionBuilder {
+    pub fn new(name: StringKey, type_condition: StringKey) -> Self {
+        Self {
+            name,
+            type_condition,
+            variable_definitions: Vec::new(),
+            used_global_variables: Vec::new(),
+            directives: Vec::new(),
+            selections: Vec::new(),
+        }
+    }
+
+    pub fn argument_definition(
+        mut self,
+        name: StringKey,
+        type_: TypeReference,
+        default_value: Option<ConstantValue>,
+    ) -> Self {
+        self.variable_definitions
+            .push(build_variable_definition(name, type_, default_value));
+        self
+    }
+
+    pub fn used_global_variable(mut self, name: StringKey, type_: TypeReference) -> Self {
+        self.used_global_variables
+            .push(build_variable_definition(name, type_, None));
+        self
+    }
+
+    pub fn directive(mut self, name: StringKey, arguments: Vec<(StringKey, Value)>) -> Self {
+        self.directives.push(build_directive(name, arguments));
+        self
+    }
+
+    pub fn selection(mut self, selection: impl Into<SelectionBuilder>) -> Self {
+        self.selections.push(selection.into());
+        self
+    }
+
+    pub fn build(self, schema: &Schema) -> DiagnosticsResult<FragmentDefinition> {
+        let type_condition = lookup_composite_type(schema, self.type_condition)?;
+        let selections = build_selections(schema, type_condition, self.selections)?;
+        Ok(FragmentDefinition {
+            name: WithLocation::generated(self.name),
+            variable_definitions: self.variable_definitions,
+            used_global_variables: self.used_global_variables,
+            type_condition,
+            directives: self.directives,
+            selections,
+        })
+    }
+}
+
+fn build_selections(
+    schema: &Schema,
+    parent_type: Type,
+    selections: Vec<SelectionBuilder>,
+) -> DiagnosticsResult<Vec<Selection>> {
+    try_map(selections, |selection| selection.build(schema, parent_type))
+}
+
+fn lookup_field(
+    schema: &Schema,
+    parent_type: Type,
+    name: StringKey,
+) -> DiagnosticsResult<schema::FieldID> {
+    let field_id = if parent_type.is_object() || parent_type.is_abstract_type() {
+        schema.named_field(parent_type, name)
+    } else {
+        None
+    };
+    field_id.ok_or_else(|| {
+        vec![Diagnostic::error(
+            ValidationMessage::UnknownField {
+                type_: schema.get_type_name(parent_type),
+                field: name,
+            },
+            Location::generated(),
+        )]
+    })
+}
+
+fn lookup_composite_type(schema: &Schema, type_name: StringKey) -> DiagnosticsResult<Type> {
+    match schema.get_type(type_name) {
+        Some(type_) if type_.is_object() || type_.is_abstract_type() => Ok(type_),
+        Some(type_) => Err(vec![Diagnostic::error(
+            ValidationMessage::ExpectedCompositeType(type_),
+            Location::generated(),
+        )]),
+        None => Err(vec![Diagnostic::error(
+            ValidationMessage::UnknownType(type_name),
+            Location::generated(),
+        )]),
+    }
+}
+
+fn build_arguments(
+    argument_definitions: &ArgumentDefinitions,
+    arguments: Vec<(StringKey, Value)>,
+) -> DiagnosticsResult<Vec<Argument>> {
+    try_map(arguments, |(name, value)| {
+        if argument_definitions.contains(name) {
+            Ok(build_argument(name, value))
+        } else {
+            Err(vec![Diagnostic::error(
+                ValidationMessage::UnknownArgument(name),
+                Location::generated(),
+            )])
+        }
+    })
+}
+
+fn build_argument(name: StringKey, value: Value) -> Argument {
+    Argument {
+        name: WithLocation::generated(name),
+        value: WithLocation::generated(value),
+    }
+}
+
+fn build_directive(name: StringKey, arguments: Vec<(StringKey, Value)>) -> Directive {
+    Directive {
+        name: WithLocation::generated(name),
+        arguments: arguments
+            .into_iter()
+            .map(|(name, value)| build_argument(name, value))
+            .collect(),
+    }
+}
+
+fn build_variable_definition(
+    name: StringKey,
+    type_: TypeReference,
+    default_value: Option<ConstantValue>,
+) -> VariableDefinition {
+    VariableDefinition {
+        name: WithLocation::generated(name),
+        type_,
+        default_value,
+        directives: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::SourceLocationKey;
+    use graphql_syntax::parse_executable;
+    use interner::Intern;
+    use relay_test_schema::get_test_schema;
+    use schema::{build_schema_with_extensions, SchemaConfig};
+
+    #[test]
+    fn builds_operation() {
+        let schema = get_test_schema();
+        let operation = OperationDefinitionBuilder::new(OperationKind::Query, "UserQuery".intern())
+            .variable(
+                "id".intern(),
+                TypeReference::NonNull(Box::new(TypeReference::Named(
+                    schema.get_type("ID".intern()).unwrap(),
+                ))),
+                None,
+            )
+            .selection(
+                LinkedFieldBuilder::new("node".intern())
+                    .argument(
+                        "id".intern(),
+                        Value::Variable(Variable {
+                            name: WithLocation::generated("id".intern()),
+                            type_: TypeReference::Named(schema.get_type("ID".intern()).unwrap()),
+                        }),
+                    )
+                    .selection(ScalarFieldBuilder::new("id".intern()))
+                    .selection(
+                        InlineFragmentBuilder::new(Some("User".intern()))
+                            .selection(
+                                ScalarFieldBuilder::new("name".intern()).alias("userName".intern()),
+                            )
+                            .selection(FragmentSpreadBuilder::new("UserFragment".intern())),
+                    ),
+            )
+            .build(&schema)
+            .unwrap();
+
+        assert_eq!(operation.type_, schema.query_type().unwrap());
+        assert_eq!(operation.variable_definitions.len(), 1);
+        let node = match &operation.selections[..] {
+            [Selection::LinkedField(node)] => node,
+            selections => panic!("Unexpected selections: {:?}", selections),
+        };
+        assert_eq!(node.alias_or_name(&schema), "node".intern());
+        assert_eq!(node.arguments.len(), 1);
+        let inline_fragment = match &node.selections[..] {
+            [Selection::ScalarField(_), Selection::InlineFragment(inline_fragment)] => {
+                inline_fragment
+            }
+            selections => panic!("Unexpected selections: {:?}", selections),
+        };
+        assert_eq!(
+            inline_fragment.type_condition,
+            schema.get_type("User".intern())
+        );
+        match &inline_fragment.selections[..] {
+            [Selection::ScalarField(name), Selection::FragmentSpread(spread)] => {
+                assert_eq!(name.alias_or_name(&schema), "userName".intern());
+                assert_eq!(spread.fragment.item, "UserFragment".intern());
+            }
+            selections => panic!("Unexpected selections: {:?}", selections),
+        }
+    }
+
+    #[test]
+    fn builds_operation_on_configured_root_type() {
+        let schema = build_schema_with_extensions::<_, &str>(
+            &[(
+                "type QueryRoot { me: String }",
+                SourceLocationKey::generated(),
+            )],
+            &[],
+            &SchemaConfig {
+                query_type_name: "QueryRoot".intern(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let query_root = schema.get_type("QueryRoot".intern());
+        assert_eq!(schema.query_type(), query_root);
+
+        let operation = OperationDefinitionBuilder::new(OperationKind::Query, "MeQuery".intern())
+            .selection(ScalarFieldBuilder::new("me".intern()))
+            .build(&schema)
+            .unwrap();
+        assert_eq!(Some(operation.type_), query_root);
+
+        let document =
+            parse_executable("query MeQuery { me }", SourceLocationKey::generated()).unwrap();
+        assert!(crate::build(&schema, &document.definitions).is_ok());
+    }
+
+    #[test]
+    fn reports_invalid_selections() {
+        let schema = get_test_schema();
+        let errors = FragmentDefinitionBuilder::new("UserFragment".intern(), "User".intern())
+            .selection(ScalarFieldBuilder::new("unknownField".intern()))
+            .selection(ScalarFieldBuilder::new("address".intern()))
+            .selection(LinkedFieldBuilder::new("name".intern()))
+            .selection(ScalarFieldBuilder::new("name".intern()).argument(
+                "unknownArgument".intern(),
+                Value::Constant(ConstantValue::Null()),
+            ))
+            .build(&schema)
+            .unwrap_err();
+
+        let messages = errors
+            .iter()
+            .map(|error| error.print_without_source())
+            .collect::<Vec<_>>();
+        assert_eq!(messages.len(), 4);
+        assert!(messages[0].starts_with("error: The type `User` has no field `unknownField`"));
+        assert!(messages[1].starts_with("error: Expected selections on field `address`"));
+        assert!(messages[2].starts_with("error: Expected no selections on scalar field `name`"));
+        assert!(messages[3].starts_with("error: Unknown argument 'unknownArgument'"));
+    }
+}
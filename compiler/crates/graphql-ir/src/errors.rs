@@ -25,6 +25,14 @@ pub enum ValidationMessage {
     ExpectedType(TypeReference),
     #[error("The type `{type_}` has no field `{field}`")]
     UnknownField { type_: StringKey, field: StringKey },
+    #[error(
+        "Only `__typename` can be selected directly on the union type `{union_name}`, select `{field_name}` in an inline fragment, e.g. `... on {member_name} {{ {field_name} }}`"
+    )]
+    FieldOnUnionRequiresInlineFragment {
+        union_name: StringKey,
+        field_name: StringKey,
+        member_name: StringKey,
+    },
     #[error("Expected no selections on scalar field `{field_name}` of type `{type_name}`")]
     InvalidSelectionsOnScalarField {
         field_name: StringKey,
@@ -89,6 +97,11 @@ pub enum ValidationMessage {
         "Expected @argumentDefinitions value to have a 'type' field with a literal string value (e.g. 'type: \"Int!\"')"
     )]
     ExpectedArgumentDefinitionLiteralType(),
+    #[error("Invalid type '{type_string}' in @argumentDefinitions: {message}")]
+    InvalidArgumentDefinitionType {
+        type_string: StringKey,
+        message: String,
+    },
     #[error(
         "Expected @argumentDefinitions value to be an object with 'type' and (optionally) 'defaultValue' properties"
     )]
@@ -151,9 +164,24 @@ pub enum ValidationMessage {
     #[error("Relay does not allow aliasing fields to `{0}`.")]
     DisallowReservedAliasError(StringKey),
 
+    #[error(
+        "Relay does not allow aliasing field `{field_name}` to `__typename`, the store uses `__typename` to determine the type of a record."
+    )]
+    DisallowTypenameAlias { field_name: StringKey },
+
+    #[error(
+        "Relay does not allow aliasing `__typename` to `{alias}`, the store uses `{alias}` to identify a record."
+    )]
+    DisallowAliasingTypename { alias: StringKey },
+
     #[error("Relay does not allow `__typename` field on Query, Mutation or Subscription.")]
     DisallowTypenameOnRoot(),
 
+    #[error(
+        "Subscription '{subscription_name}' must select exactly one root field, GraphQL servers reject subscriptions with multiple root fields."
+    )]
+    SubscriptionMultipleRootFields { subscription_name: StringKey },
+
     #[error(
         "Unexpected directive: '{0}'. This directive can only be used on fields/fragments that are fetched from the server schema, but it is used inside a client-only selection."
     )]
@@ -263,6 +291,15 @@ pub enum ValidationMessage {
         dynamic_key_arg_name: StringKey,
     },
 
+    #[error(
+        "Expected the {arg_name} argument to @{connection_directive_name} to be a literal boolean for field '{connection_field_name}'."
+    )]
+    InvalidConnectionBooleanArg {
+        connection_directive_name: StringKey,
+        connection_field_name: StringKey,
+        arg_name: StringKey,
+    },
+
     #[error(
         "Expected the {key_arg_name} argument to @{connection_directive_name} to be of form '<SomeName>_{postfix}', got '{key_arg_value}'. For a detailed explanation, check out https://relay.dev/docs/en/pagination-container#connection"
     )]
@@ -274,6 +311,43 @@ pub enum ValidationMessage {
         postfix: String,
     },
 
+    #[error(
+        "The key '{key_arg_value}' of @{connection_directive_name} in `{definition_name}` is also used by `{base_definition_name}` of the base project. Connections with the same key share their records at runtime, use a unique key instead."
+    )]
+    DuplicateBaseProjectConnectionKey {
+        connection_directive_name: StringKey,
+        key_arg_value: StringKey,
+        definition_name: StringKey,
+        base_definition_name: StringKey,
+    },
+
+    #[error(
+        "The key '{key_arg_value}' of @{connection_directive_name} in `{definition_name}` is also used by `{other_definition_name}`. Connections with the same key share their records at runtime, use a unique key instead."
+    )]
+    DuplicateDefinitionConnectionKey {
+        connection_directive_name: StringKey,
+        key_arg_value: StringKey,
+        definition_name: StringKey,
+        other_definition_name: StringKey,
+    },
+
+    #[error(
+        "The key '{key}' of the connection field '{connection_field_name}' is already used by another connection on the same parent field. Connections with the same key on the same record share their edges at runtime, use a unique key instead."
+    )]
+    DuplicateConnectionKey {
+        key: StringKey,
+        connection_field_name: StringKey,
+    },
+
+    #[error(
+        "The generated {key_arg_name} '{key_arg_value}' of @{connection_directive_name} is also used by another connection. Connections with the same key share their records at runtime, pass a unique {key_arg_name} instead."
+    )]
+    DuplicateGeneratedConnectionKey {
+        connection_directive_name: StringKey,
+        key_arg_name: StringKey,
+        key_arg_value: StringKey,
+    },
+
     #[error(
         "Expected the {filters_arg_name} argument to @{connection_directive_name} to be a list of string literals for field '{connection_field_name}'."
     )]
@@ -283,9 +357,113 @@ pub enum ValidationMessage {
         filters_arg_name: StringKey,
     },
 
+    #[error(
+        "Expected only one of the {filters_arg_name} and {excluded_filters_arg_name} arguments to @{connection_directive_name} for field '{connection_field_name}'."
+    )]
+    ConflictingConnectionFiltersArgs {
+        connection_directive_name: StringKey,
+        connection_field_name: StringKey,
+        filters_arg_name: StringKey,
+        excluded_filters_arg_name: StringKey,
+    },
+
+    #[error(
+        "Expected the {excluded_filters_arg_name} argument to @{connection_directive_name} to only list arguments of field '{connection_field_name}', got '{filter_name}'."
+    )]
+    UnknownConnectionExcludedFilter {
+        connection_directive_name: StringKey,
+        connection_field_name: StringKey,
+        excluded_filters_arg_name: StringKey,
+        filter_name: StringKey,
+    },
+
     #[error("@stream_connection does not support aliasing the '{field_name}' field.")]
     UnsupportedAliasingInStreamConnection { field_name: StringKey },
 
+    #[error(
+        "@{connection_directive_name} is not supported on field '{connection_field_name}' because it is conditionally included with @{condition_directive_name}(if: ${condition_variable_name}). The connection metadata would be registered even when the field is not fetched. Move the @{condition_directive_name} to a fragment spread containing the connection instead."
+    )]
+    ConnectionWithinCondition {
+        connection_directive_name: StringKey,
+        connection_field_name: StringKey,
+        condition_directive_name: StringKey,
+        condition_variable_name: StringKey,
+    },
+
+    #[error(
+        "@{connection_directive_name} field '{connection_field_name}' is selected in a plural position without a {dynamic_key_arg_name} argument. Every item of the list will share the same connection records; consider passing a {dynamic_key_arg_name} that is unique per item."
+    )]
+    PluralConnectionWithoutDynamicKey {
+        connection_directive_name: StringKey,
+        connection_field_name: StringKey,
+        dynamic_key_arg_name: StringKey,
+    },
+
+    #[error(
+        "Fragment '{fragment_name}' is spread in a plural position but selects @{connection_directive_name} field '{connection_field_name}' without a {dynamic_key_arg_name} argument. Every item of the list will share the same connection records; consider passing a {dynamic_key_arg_name} that is unique per item."
+    )]
+    PluralFragmentSpreadWithConnectionWithoutDynamicKey {
+        fragment_name: StringKey,
+        connection_directive_name: StringKey,
+        connection_field_name: StringKey,
+        dynamic_key_arg_name: StringKey,
+    },
+
+    #[error(
+        "@{connection_directive_name} field '{connection_field_name}' is selected on type '{parent_type_name}', which the store can't identify: it isn't a root type and doesn't have an '{id_field_name}' field. The connection records can't be found again; consider passing a {dynamic_key_arg_name} argument."
+    )]
+    ConnectionParentNotIdentifiable {
+        connection_directive_name: StringKey,
+        connection_field_name: StringKey,
+        parent_type_name: StringKey,
+        id_field_name: StringKey,
+        dynamic_key_arg_name: StringKey,
+    },
+
+    #[error(
+        "Field '{field_path}' of @{connection_directive_name} field '{connection_field_name}' is selected manually. The connection transform adds the pagination fields it needs, selecting them manually can result in duplicate or conflicting selections."
+    )]
+    ManualConnectionPaginationFieldSelection {
+        connection_directive_name: StringKey,
+        connection_field_name: StringKey,
+        field_path: String,
+    },
+
+    #[error(
+        "This selection is never fetched because of @{condition_directive_name}(if: {condition_value}). Remove the selection or the condition."
+    )]
+    DeadSelection {
+        condition_directive_name: StringKey,
+        condition_value: bool,
+    },
+
+    #[error(
+        "The field `{parent_type}.{field_name}` is deprecated{}",
+        match reason {
+            Some(reason) => format!(": {}", reason),
+            None => ".".to_string(),
+        }
+    )]
+    DeprecatedField {
+        parent_type: StringKey,
+        field_name: StringKey,
+        reason: Option<StringKey>,
+    },
+
+    #[error(
+        "The argument `{argument_name}` of the field `{parent_type}.{field_name}` is deprecated{}",
+        match reason {
+            Some(reason) => format!(": {}", reason),
+            None => ".".to_string(),
+        }
+    )]
+    DeprecatedArgument {
+        parent_type: StringKey,
+        field_name: StringKey,
+        argument_name: StringKey,
+        reason: Option<StringKey>,
+    },
+
     #[error("Expected the `{0}` argument to @relay to be a boolean literal if specified.")]
     InvalidRelayDirectiveArg(StringKey),
     #[error("Cannot use @relay(mask: false) on fragment spreads for fragments with directives.")]
@@ -304,6 +482,13 @@ pub enum ValidationMessage {
         next_arg_type: String,
     },
 
+    #[error("Fragment `{fragment_name}` can't use both `{directive_a}` and `{directive_b}`.")]
+    ConflictingFragmentDirectives {
+        fragment_name: StringKey,
+        directive_a: &'static str,
+        directive_b: &'static str,
+    },
+
     #[error("Found a circular reference from fragment '{fragment_name}'.")]
     CircularFragmentReference { fragment_name: StringKey },
 
@@ -385,6 +570,14 @@ pub enum ValidationMessage {
         type_name: StringKey,
         alias_path: String,
     },
+    #[error(
+        "Invalid @module selection: component '{component}' was used for multiple fragments on concrete type '{type_name}' at path '{alias_path}', only one of the fragments would be loaded for this type."
+    )]
+    DuplicateModuleComponent {
+        component: StringKey,
+        type_name: StringKey,
+        alias_path: String,
+    },
     #[error("Found conflicting @module selections: use a unique alias on the parent fields")]
     ConflictingModuleSelections,
 
@@ -410,6 +603,11 @@ pub enum ValidationMessage {
     #[error("Invalid use of @stream, the 'initial_count' argument is required.")]
     StreamInitialCountRequired,
 
+    #[error(
+        "Invalid use of @stream, expected the '{arg_name}' argument to be a non-negative integer or a variable of type 'Int'."
+    )]
+    InvalidStreamInitialCount { arg_name: StringKey },
+
     #[error("Variable `${variable_name}` is never used in operation `{operation_name}`")]
     UnusedVariable {
         variable_name: StringKey,
@@ -427,6 +625,14 @@ pub enum ValidationMessage {
         variables_string: String,
     },
 
+    #[error(
+        "Variable '${variable_name}' is not defined in the @argumentDefinitions of fragment '{fragment_name}'. Fragments can't reference the variables of the operation implicitly in strict fragment arguments mode."
+    )]
+    UndefinedFragmentArgument {
+        fragment_name: StringKey,
+        variable_name: StringKey,
+    },
+
     #[error("Expected the 'queryName' argument of @refetchable to be provided")]
     QueryNameRequired,
 
@@ -496,7 +702,7 @@ pub enum ValidationMessage {
     InvalidRefetchDirectiveDefinition { fragment_name: StringKey },
 
     #[error(
-        "Invalid use of @refetchable on fragment '{fragment_name}', the type '{type_name}' is @fetchable but the identifying field '{identifier_field_name}' does not have type 'ID'."
+        "Invalid use of @refetchable on fragment '{fragment_name}', the type '{type_name}' is @fetchable but the identifying field '{identifier_field_name}' does not exist or is not a scalar field."
     )]
     InvalidRefetchIdentifyingField {
         fragment_name: StringKey,
@@ -505,18 +711,24 @@ pub enum ValidationMessage {
     },
 
     #[error(
-        "Invalid use of @refetchable on fragment '{fragment_name}', the type '{type_name}' is @fetchable but there is no corresponding '{fetch_field_name}' field or it is invalid (expected '{fetch_field_name}(id: ID!): ${type_name}')."
+        "Invalid use of @refetchable on fragment '{fragment_name}', the type '{type_name}' is @fetchable but there is no corresponding '{fetch_field_name}' field or it is invalid (expected '{fetch_field_name}(id: {identifier_type_name}!): {type_name}')."
     )]
     InvalidRefetchFetchField {
         fetch_field_name: StringKey,
         fragment_name: StringKey,
         type_name: StringKey,
+        identifier_type_name: StringKey,
     },
     #[error("Variables are not yet supported inside @inline fragments.")]
     InlineDataFragmentArgumentsNotSupported,
     #[error("Directives on fragment spreads for @inline fragments are not yet supported")]
     InlineDataFragmentDirectivesNotSupported,
 
+    #[error(
+        "Expected the selections of a field with @{directive_name} to be exactly one fragment spread."
+    )]
+    ActorChangeExpectsSingleFragmentSpread { directive_name: StringKey },
+
     #[error("A relay_early_flush field should be defined on Query on the server schema.")]
     UnavailableRelayEarlyFlushServerSchema,
 
@@ -631,6 +843,11 @@ pub enum ValidationMessage {
     )]
     RequiredWithinAbstractInlineFragment,
 
+    #[error(
+        "@required on the fragment spread '{fragment_name}' requires an @alias naming the key that is asserted to be present, e.g. `...{fragment_name} @alias(as: \"...\") @required(action: THROW)`."
+    )]
+    RequiredFragmentSpreadWithoutAlias { fragment_name: StringKey },
+
     #[error("@required is not supported within @inline fragments.")]
     RequiredWithinInlineDirective,
 
@@ -683,6 +900,12 @@ pub enum ValidationMessage {
     ]
     MissingRequiredArguments { missing_arg_names: Vec<StringKey> },
 
+    #[error("Missing required argument `{argument}` on directive `@{directive}`")]
+    MissingRequiredDirectiveArgument {
+        directive: StringKey,
+        argument: StringKey,
+    },
+
     #[error("Duplicate argument `{name}`")]
     DuplicateArgument { name: StringKey },
 
@@ -699,9 +922,82 @@ pub enum ValidationMessage {
     #[error("Missing required argument `{argument_name}` on this fragment spread.")]
     MissingRequiredFragmentArgument { argument_name: StringKey },
 
+    #[error("Unknown argument `{argument_name}` on fragment `{fragment_name}`.")]
+    UnknownFragmentArgument {
+        argument_name: StringKey,
+        fragment_name: StringKey,
+    },
+
+    #[error(
+        "Arguments are passed to fragment `{fragment_name}` with `@arguments`, but the fragment doesn't define any with `@argumentDefinitions`."
+    )]
+    ArgumentsPassedToFragmentWithoutDefinitions { fragment_name: StringKey },
+
+    #[error(
+        "Invalid value for argument `{argument_name}` on fragment `{fragment_name}`, expected a value of type `{expected_type}`."
+    )]
+    InvalidFragmentArgumentType {
+        argument_name: StringKey,
+        fragment_name: StringKey,
+        expected_type: String,
+    },
+
+    #[error(
+        "Expected @{directive_name} on an inline fragment to have a type condition, e.g. `... on User @{directive_name}(as: \"asUser\")`."
+    )]
+    InlineFragmentAliasWithoutTypeCondition { directive_name: StringKey },
+
+    #[error(
+        "Fragment `{fragment_name}` is never spread in an operation or in a fragment used by one and can be removed."
+    )]
+    UnusedFragment { fragment_name: StringKey },
+
+    #[error(
+        "Fragment spreads in `{definition_name}` are nested deeper than the maximum depth of {max_depth}: {}.",
+        chain
+            .iter()
+            .map(|name| name.lookup())
+            .collect::<Vec<_>>()
+            .join(" -> ")
+    )]
+    FragmentSpreadDepthExceeded {
+        definition_name: StringKey,
+        max_depth: usize,
+        chain: Vec<StringKey>,
+    },
+
+    #[error(
+        "Scalar '{scalar_name}' is output only and cannot be used in an input position, found in the type of variable '${variable_name}'{}.",
+        if input_field_path.is_empty() {
+            "".to_string()
+        } else {
+            format!(
+                " at input field '{}'",
+                input_field_path
+                    .iter()
+                    .map(|name| name.lookup())
+                    .collect::<Vec<_>>()
+                    .join(".")
+            )
+        }
+    )]
+    InputPositionNotAllowedForScalar {
+        scalar_name: StringKey,
+        variable_name: StringKey,
+        input_field_path: Vec<StringKey>,
+    },
+
     #[error("Duplicate variable `{name}`")]
     DuplicateVariable { name: StringKey },
 
+    #[error(
+        "The `{transform_name}` transform failed unexpectedly: {message}. This is a bug in the compiler, please report it with the documents that cause it."
+    )]
+    TransformPanicked {
+        transform_name: &'static str,
+        message: String,
+    },
+
     #[error("{definition} `{name}` must start with an uppercase letter.")]
     RequiredUppercaseNaming {
         definition: StringKey,
@@ -11,6 +11,7 @@
 #![deny(clippy::clone_on_ref_ptr)]
 
 mod build;
+mod builder;
 mod constants;
 mod errors;
 mod ir;
@@ -22,8 +23,12 @@ mod visitor;
 
 pub use crate::errors::ValidationMessage;
 pub use build::{
-    build_ir_with_extra_features, build_ir_with_relay_options as build, BuilderOptions,
-    FragmentVariablesSemantic, DIRECTIVE_ARGUMENTS,
+    build_ir_with_extra_features, build_ir_with_relay_options as build, update_program_file,
+    BuilderOptions, FragmentVariablesSemantic, DIRECTIVE_ARGUMENTS,
+};
+pub use builder::{
+    FragmentDefinitionBuilder, FragmentSpreadBuilder, InlineFragmentBuilder, LinkedFieldBuilder,
+    OperationDefinitionBuilder, ScalarFieldBuilder, SelectionBuilder,
 };
 pub use constants::ARGUMENT_DEFINITION;
 pub use ir::*;
@@ -5,7 +5,10 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use crate::errors::ValidationMessage;
 use crate::ir::{ExecutableDefinition, FragmentDefinition, OperationDefinition};
+use common::{Diagnostic, DiagnosticsResult, Location};
+use fnv::FnvHashMap;
 use indexmap::IndexMap;
 use interner::StringKey;
 use rayon::{iter::ParallelIterator, prelude::*};
@@ -59,6 +62,70 @@ impl Program {
         };
     }
 
+    /// Inserts all operations and fragments of `other` into this program
+    /// without rebuilding them, e.g. to update the definitions that changed
+    /// in an incremental build. `source_hashes` and `other_source_hashes` map
+    /// the names of the definitions of the programs to the hash of their
+    /// source. A definition with the same name and source hash as one already
+    /// in the program is skipped. If a name is already used by a definition
+    /// with a different source, nothing is inserted and the duplicates are
+    /// reported.
+    ///
+    /// NOTE: Incremental builds currently rebuild the program of the affected
+    ///       definitions from their ASTs, there's no program kept between
+    ///       builds to merge them into yet.
+    pub fn merge(
+        &mut self,
+        source_hashes: &FnvHashMap<StringKey, String>,
+        other: Program,
+        other_source_hashes: &FnvHashMap<StringKey, String>,
+    ) -> DiagnosticsResult<()> {
+        let is_same_source = |name: StringKey| {
+            matches!(
+                (source_hashes.get(&name), other_source_hashes.get(&name)),
+                (Some(hash), Some(other_hash)) if hash == other_hash
+            )
+        };
+        let operations = self
+            .operations()
+            .map(|operation| (operation.name.item, operation))
+            .collect::<FnvHashMap<_, _>>();
+        let mut errors = Vec::new();
+        let mut new_operations = Vec::new();
+        for operation in other.operations {
+            let name = operation.name.item;
+            match operations.get(&name) {
+                Some(_) if is_same_source(name) => {}
+                Some(previous) => errors.push(duplicate_definition(
+                    name,
+                    previous.name.location,
+                    operation.name.location,
+                )),
+                None => new_operations.push(operation),
+            }
+        }
+        let mut new_fragments = Vec::new();
+        for (name, fragment) in other.fragments {
+            match self.fragments.get(&name) {
+                Some(_) if is_same_source(name) => {}
+                Some(previous) => errors.push(duplicate_definition(
+                    name,
+                    previous.name.location,
+                    fragment.name.location,
+                )),
+                None => new_fragments.push(fragment),
+            }
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        self.operations.extend(new_operations);
+        for fragment in new_fragments {
+            self.insert_fragment(fragment);
+        }
+        Ok(())
+    }
+
     pub fn fragment(&self, name: StringKey) -> Option<&Arc<FragmentDefinition>> {
         self.fragments.get(&name)
     }
@@ -97,3 +164,8 @@ impl Program {
         self.fragments.len() + self.operations.len()
     }
 }
+
+fn duplicate_definition(name: StringKey, previous: Location, location: Location) -> Diagnostic {
+    Diagnostic::error(ValidationMessage::DuplicateDefinition(name), previous)
+        .annotate("also defined here", location)
+}
@@ -10,7 +10,7 @@ use crate::build::{
 };
 use crate::constants::ARGUMENT_DEFINITION;
 use crate::errors::ValidationMessage;
-use crate::ir::{ConstantValue, VariableDefinition};
+use crate::ir::{ConstantValue, FragmentDefinition, VariableDefinition};
 use common::{Diagnostic, DiagnosticsResult, Location, NamedItem, WithLocation};
 use errors::{par_try_map, try2};
 use fnv::{FnvHashMap, FnvHashSet};
@@ -81,7 +81,19 @@ pub fn build_signatures(
     }
 }
 
-fn build_fragment_signature(
+impl From<&FragmentDefinition> for FragmentSignature {
+    /// Recovers the signature of an already built fragment, used when only
+    /// some of the definitions of a program are rebuilt from source.
+    fn from(fragment: &FragmentDefinition) -> Self {
+        Self {
+            name: fragment.name,
+            variable_definitions: fragment.variable_definitions.clone(),
+            type_condition: fragment.type_condition,
+        }
+    }
+}
+
+pub(crate) fn build_fragment_signature(
     schema: &Schema,
     fragment: &graphql_syntax::FragmentDefinition,
 ) -> DiagnosticsResult<FragmentSignature> {
@@ -195,15 +207,6 @@ fn build_fragment_variable_definitions(
 
                     // Convert variable type, validate that it's an input type
                     let type_ = get_argument_type(schema, fragment.location, &object)?;
-                    if !type_.inner().is_input_type() {
-                        return Err(Diagnostic::error(
-                            ValidationMessage::ExpectedFragmentArgumentToHaveInputType(
-                                schema.get_type_name(type_.inner()),
-                            ),
-                            fragment.location.with_span(variable_arg.value.span()),
-                        )
-                        .into());
-                    }
 
                     let default_value =
                         get_default_value(schema, fragment.location, &object, &type_)?;
@@ -229,6 +232,9 @@ fn build_fragment_variable_definitions(
     }
 }
 
+/// Parses the `type` of an `@argumentDefinitions` value and checks that it
+/// refers to an input type of the schema. Errors are reported at the type
+/// string.
 fn get_argument_type(
     schema: &Schema,
     location: Location,
@@ -238,33 +244,51 @@ fn get_argument_type(
     let type_name_and_span = match type_arg {
         Some(graphql_syntax::ConstantArgument {
             value: graphql_syntax::ConstantValue::String(type_name_node),
-            span,
             ..
-        }) => Some((type_name_node.value, span)),
+        }) => Some((type_name_node.value, type_name_node.token.span)),
         Some(graphql_syntax::ConstantArgument {
             value: graphql_syntax::ConstantValue::Enum(type_name_node),
-            span,
             ..
-        }) => Some((type_name_node.value, span)),
+        }) => Some((type_name_node.value, type_name_node.token.span)),
         _ => None,
     };
-    if let Some((type_name, &span)) = type_name_and_span {
+    if let Some((type_name, span)) = type_name_and_span {
+        let type_location = location.with_span(span);
         let type_ast = graphql_syntax::parse_type(type_name.lookup(), location.source_location())
             .map_err(|diagnostics| {
             diagnostics
                 .into_iter()
                 .map(|diagnostic| {
-                    let message = diagnostic.message().to_string();
                     Diagnostic::error(
-                        message,
+                        ValidationMessage::InvalidArgumentDefinitionType {
+                            type_string: type_name,
+                            message: diagnostic.message().to_string(),
+                        },
                         // TODO: ideally, `parse_type()` would take in the offset
                         // location and report the error at the right location.
-                        location.with_span(span),
+                        type_location,
                     )
                 })
                 .collect::<Vec<_>>()
         })?;
-        let type_ = build_type_annotation(schema, &type_ast, location)?;
+        // The spans of the parsed type are relative to the type string.
+        let type_ = build_type_annotation(schema, &type_ast, location).map_err(|diagnostics| {
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| {
+                    Diagnostic::error(diagnostic.message().to_string(), type_location)
+                })
+                .collect::<Vec<_>>()
+        })?;
+        if !type_.inner().is_input_type() {
+            return Err(Diagnostic::error(
+                ValidationMessage::ExpectedFragmentArgumentToHaveInputType(
+                    schema.get_type_name(type_.inner()),
+                ),
+                type_location,
+            )
+            .into());
+        }
         Ok(type_)
     } else {
         Err(Diagnostic::error(
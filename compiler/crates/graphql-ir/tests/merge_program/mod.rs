@@ -0,0 +1,87 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use fnv::FnvHashMap;
+use graphql_cli::DiagnosticPrinter;
+use graphql_ir::{build, Program};
+use graphql_syntax::parse_executable;
+use relay_test_schema::get_test_schema;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let parts: Vec<_> = fixture.content.split("%other%").collect();
+    if parts.len() != 2 {
+        panic!("Expected exactly one %other% section marker.")
+    }
+    let (base_text, other_text) = (parts[0], parts[1]);
+
+    let base_location = SourceLocationKey::standalone("base.graphql");
+    let other_location = SourceLocationKey::standalone("other.graphql");
+
+    let schema = get_test_schema();
+    // Hashes the source text of each definition, like the compiler hashes the
+    // printed source.
+    let build_program = |text: &str, source_location: SourceLocationKey| {
+        let ast = parse_executable(text, source_location).unwrap();
+        let source_hashes = ast
+            .definitions
+            .iter()
+            .map(|definition| {
+                let span = *definition.location().span();
+                let mut hasher = DefaultHasher::new();
+                text[span.start as usize..span.end as usize].hash(&mut hasher);
+                (definition.name().unwrap(), format!("{:x}", hasher.finish()))
+            })
+            .collect::<FnvHashMap<_, _>>();
+        let program = Program::from_definitions(
+            Arc::clone(&schema),
+            build(&schema, &ast.definitions).expect("Expected the programs to be valid"),
+        );
+        (program, source_hashes)
+    };
+    let (mut program, source_hashes) = build_program(base_text, base_location);
+    let (other_program, other_source_hashes) = build_program(other_text, other_location);
+
+    match program.merge(&source_hashes, other_program, &other_source_hashes) {
+        Ok(()) => {
+            let mut names = program
+                .operations()
+                .map(|operation| (operation.name.item.lookup(), operation.name.location))
+                .chain(
+                    program
+                        .fragments()
+                        .map(|fragment| (fragment.name.item.lookup(), fragment.name.location)),
+                )
+                .map(|(name, location)| {
+                    format!("{} in {}", name, location.source_location().path())
+                })
+                .collect::<Vec<_>>();
+            names.sort();
+            Ok(names.join("\n"))
+        }
+        Err(diagnostics) => {
+            let printer = DiagnosticPrinter::new(|source_location| {
+                if source_location == base_location {
+                    Some(base_text.to_string())
+                } else if source_location == other_location {
+                    Some(other_text.to_string())
+                } else {
+                    None
+                }
+            });
+            Err(diagnostics
+                .iter()
+                .map(|diagnostic| printer.diagnostic_to_string(diagnostic))
+                .collect::<Vec<_>>()
+                .join("\n\n"))
+        }
+    }
+}
@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<3146ff0ff1e809e23e6c14c43abebcbc>>
+ */
+
+mod merge_program;
+
+use merge_program::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn duplicate_definition_invalid() {
+    let input = include_str!("merge_program/fixtures/duplicate-definition.invalid.graphql");
+    let expected = include_str!("merge_program/fixtures/duplicate-definition.invalid.expected");
+    test_fixture(transform_fixture, "duplicate-definition.invalid.graphql", "merge_program/fixtures/duplicate-definition.invalid.expected", input, expected);
+}
+
+#[test]
+fn merge_programs() {
+    let input = include_str!("merge_program/fixtures/merge-programs.graphql");
+    let expected = include_str!("merge_program/fixtures/merge-programs.expected");
+    test_fixture(transform_fixture, "merge-programs.graphql", "merge_program/fixtures/merge-programs.expected", input, expected);
+}
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<5e66518bd5787f41788b165885ced829>>
+ * @generated SignedSource<<9dc44a48055ba03e38536e379fa25d05>>
  */
 
 mod parse;
@@ -26,6 +26,13 @@ fn argument_definitions_with_typo_invalid() {
     test_fixture(transform_fixture, "argument-definitions-with-typo.invalid.graphql", "parse/fixtures/argument-definitions-with-typo.invalid.expected", input, expected);
 }
 
+#[test]
+fn client_directive_missing_required_argument_invalid() {
+    let input = include_str!("parse/fixtures/client-directive-missing-required-argument.invalid.graphql");
+    let expected = include_str!("parse/fixtures/client-directive-missing-required-argument.invalid.expected");
+    test_fixture(transform_fixture, "client-directive-missing-required-argument.invalid.graphql", "parse/fixtures/client-directive-missing-required-argument.invalid.expected", input, expected);
+}
+
 #[test]
 fn complex_object_with_missing_fields_invalid() {
     let input = include_str!("parse/fixtures/complex-object-with-missing-fields.invalid.graphql");
@@ -54,6 +61,13 @@ fn directive_match_on_fragment_invalid() {
     test_fixture(transform_fixture, "directive-match-on-fragment.invalid.graphql", "parse/fixtures/directive-match-on-fragment.invalid.expected", input, expected);
 }
 
+#[test]
+fn directive_missing_multiple_required_arguments_invalid() {
+    let input = include_str!("parse/fixtures/directive_missing_multiple_required_arguments.invalid.graphql");
+    let expected = include_str!("parse/fixtures/directive_missing_multiple_required_arguments.invalid.expected");
+    test_fixture(transform_fixture, "directive_missing_multiple_required_arguments.invalid.graphql", "parse/fixtures/directive_missing_multiple_required_arguments.invalid.expected", input, expected);
+}
+
 #[test]
 fn directive_missing_required_argument_invalid() {
     let input = include_str!("parse/fixtures/directive_missing_required_argument.invalid.graphql");
@@ -103,6 +117,13 @@ fn duplicate_variables_invalid() {
     test_fixture(transform_fixture, "duplicate_variables_invalid.graphql", "parse/fixtures/duplicate_variables_invalid.expected", input, expected);
 }
 
+#[test]
+fn duplicate_variables_same_type_invalid() {
+    let input = include_str!("parse/fixtures/duplicate-variables-same-type.invalid.graphql");
+    let expected = include_str!("parse/fixtures/duplicate-variables-same-type.invalid.expected");
+    test_fixture(transform_fixture, "duplicate-variables-same-type.invalid.graphql", "parse/fixtures/duplicate-variables-same-type.invalid.expected", input, expected);
+}
+
 #[test]
 fn enum_values() {
     let input = include_str!("parse/fixtures/enum-values.graphql");
@@ -152,6 +173,13 @@ fn field_arguments() {
     test_fixture(transform_fixture, "field-arguments.graphql", "parse/fixtures/field-arguments.expected", input, expected);
 }
 
+#[test]
+fn field_on_union_invalid() {
+    let input = include_str!("parse/fixtures/field-on-union.invalid.graphql");
+    let expected = include_str!("parse/fixtures/field-on-union.invalid.expected");
+    test_fixture(transform_fixture, "field-on-union.invalid.graphql", "parse/fixtures/field-on-union.invalid.expected", input, expected);
+}
+
 #[test]
 fn fixme_fat_interface_on_union() {
     let input = include_str!("parse/fixtures/fixme_fat_interface_on_union.graphql");
@@ -166,6 +194,13 @@ fn fixme_fat_interface_on_union_invalid() {
     test_fixture(transform_fixture, "fixme_fat_interface_on_union.invalid.graphql", "parse/fixtures/fixme_fat_interface_on_union.invalid.expected", input, expected);
 }
 
+#[test]
+fn fragment_spread_arguments_without_argument_definitions_invalid() {
+    let input = include_str!("parse/fixtures/fragment-spread-arguments-without-argument-definitions.invalid.graphql");
+    let expected = include_str!("parse/fixtures/fragment-spread-arguments-without-argument-definitions.invalid.expected");
+    test_fixture(transform_fixture, "fragment-spread-arguments-without-argument-definitions.invalid.graphql", "parse/fixtures/fragment-spread-arguments-without-argument-definitions.invalid.expected", input, expected);
+}
+
 #[test]
 fn fragment_spread_on_wrong_type_invalid() {
     let input = include_str!("parse/fixtures/fragment-spread-on-wrong-type.invalid.graphql");
@@ -173,6 +208,13 @@ fn fragment_spread_on_wrong_type_invalid() {
     test_fixture(transform_fixture, "fragment-spread-on-wrong-type.invalid.graphql", "parse/fixtures/fragment-spread-on-wrong-type.invalid.expected", input, expected);
 }
 
+#[test]
+fn fragment_with_argument_type_double_non_null_invalid() {
+    let input = include_str!("parse/fixtures/fragment-with-argument-type-double-non-null.invalid.graphql");
+    let expected = include_str!("parse/fixtures/fragment-with-argument-type-double-non-null.invalid.expected");
+    test_fixture(transform_fixture, "fragment-with-argument-type-double-non-null.invalid.graphql", "parse/fixtures/fragment-with-argument-type-double-non-null.invalid.expected", input, expected);
+}
+
 #[test]
 fn fragment_with_argument_type_invalid() {
     let input = include_str!("parse/fixtures/fragment-with-argument-type.invalid.graphql");
@@ -222,6 +264,13 @@ fn fragment_with_arguments_invalid_type_invalid() {
     test_fixture(transform_fixture, "fragment-with-arguments-invalid-type.invalid.graphql", "parse/fixtures/fragment-with-arguments-invalid-type.invalid.expected", input, expected);
 }
 
+#[test]
+fn fragment_with_literal_argument_wrong_type_invalid() {
+    let input = include_str!("parse/fixtures/fragment-with-literal-argument-wrong-type.invalid.graphql");
+    let expected = include_str!("parse/fixtures/fragment-with-literal-argument-wrong-type.invalid.expected");
+    test_fixture(transform_fixture, "fragment-with-literal-argument-wrong-type.invalid.graphql", "parse/fixtures/fragment-with-literal-argument-wrong-type.invalid.expected", input, expected);
+}
+
 #[test]
 fn fragment_with_literal_arguments() {
     let input = include_str!("parse/fixtures/fragment-with-literal-arguments.graphql");
@@ -271,6 +320,13 @@ fn fragment_with_literal_object_list_arguments() {
     test_fixture(transform_fixture, "fragment-with-literal-object-list-arguments.graphql", "parse/fixtures/fragment-with-literal-object-list-arguments.expected", input, expected);
 }
 
+#[test]
+fn fragment_with_missing_required_argument_invalid() {
+    let input = include_str!("parse/fixtures/fragment-with-missing-required-argument.invalid.graphql");
+    let expected = include_str!("parse/fixtures/fragment-with-missing-required-argument.invalid.expected");
+    test_fixture(transform_fixture, "fragment-with-missing-required-argument.invalid.graphql", "parse/fixtures/fragment-with-missing-required-argument.invalid.expected", input, expected);
+}
+
 #[test]
 fn fragment_with_undefined_literal_arguments_invalid() {
     let input = include_str!("parse/fixtures/fragment-with-undefined-literal-arguments.invalid.graphql");
@@ -285,6 +341,13 @@ fn fragment_with_undefined_variable_arguments_invalid() {
     test_fixture(transform_fixture, "fragment-with-undefined-variable-arguments.invalid.graphql", "parse/fixtures/fragment-with-undefined-variable-arguments.invalid.expected", input, expected);
 }
 
+#[test]
+fn fragment_with_unknown_argument_invalid() {
+    let input = include_str!("parse/fixtures/fragment-with-unknown-argument.invalid.graphql");
+    let expected = include_str!("parse/fixtures/fragment-with-unknown-argument.invalid.expected");
+    test_fixture(transform_fixture, "fragment-with-unknown-argument.invalid.graphql", "parse/fixtures/fragment-with-unknown-argument.invalid.expected", input, expected);
+}
+
 #[test]
 fn fragment_with_unnecessary_unchecked_arguments_invalid() {
     let input = include_str!("parse/fixtures/fragment-with-unnecessary-unchecked-arguments.invalid.graphql");
@@ -474,6 +537,13 @@ fn simple_query() {
     test_fixture(transform_fixture, "simple-query.graphql", "parse/fixtures/simple-query.expected", input, expected);
 }
 
+#[test]
+fn typename_on_union() {
+    let input = include_str!("parse/fixtures/typename-on-union.graphql");
+    let expected = include_str!("parse/fixtures/typename-on-union.expected");
+    test_fixture(transform_fixture, "typename-on-union.graphql", "parse/fixtures/typename-on-union.expected", input, expected);
+}
+
 #[test]
 fn typename_with_arguments_invalid() {
     let input = include_str!("parse/fixtures/typename_with_arguments.invalid.graphql");
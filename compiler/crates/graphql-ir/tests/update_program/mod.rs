@@ -0,0 +1,170 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::{Diagnostic, Location, SourceLocationKey};
+use fixture_tests::Fixture;
+use fnv::FnvHashSet;
+use graphql_cli::DiagnosticPrinter;
+use graphql_ir::{build, update_program_file, Program, ValidationMessage};
+use graphql_syntax::{parse_executable, ExecutableDefinition};
+use interner::Intern;
+use relay_test_schema::get_test_schema;
+use schema::Schema;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let parts: Vec<_> = fixture.content.split("%update%").collect();
+    if parts.len() != 2 {
+        panic!("Expected exactly one %update% section marker.")
+    }
+    let original_parts: Vec<_> = parts[0].split("%other%").collect();
+    if original_parts.len() != 2 {
+        panic!("Expected exactly one %other% section marker before %update%.")
+    }
+    let (changed_text, other_text, updated_text) = (original_parts[0], original_parts[1], parts[1]);
+
+    let changed_location = SourceLocationKey::standalone("changed.graphql");
+    let other_location = SourceLocationKey::standalone("other.graphql");
+
+    let schema = get_test_schema();
+    let mut definitions = parse_executable(changed_text, changed_location)
+        .unwrap()
+        .definitions;
+    definitions.extend(
+        parse_executable(other_text, other_location)
+            .unwrap()
+            .definitions,
+    );
+    let program = Program::from_definitions(
+        Arc::clone(&schema),
+        build(&schema, &definitions).expect("Expected the original files to be valid"),
+    );
+
+    let updated_ast = parse_executable(updated_text, changed_location).unwrap();
+    let (updated_program, diagnostics) = update_program_file(
+        &program,
+        changed_location.path().intern(),
+        &updated_ast.definitions,
+    );
+
+    // The incremental update has to agree with a full build of the updated
+    // files.
+    let mut updated_definitions = updated_ast.definitions.clone();
+    updated_definitions.extend(
+        parse_executable(other_text, other_location)
+            .unwrap()
+            .definitions,
+    );
+    assert_eq!(
+        messages(&diagnostics),
+        messages(&full_build_diagnostics(&schema, &updated_definitions)),
+        "Expected the incremental and the full build to report the same errors."
+    );
+    if diagnostics.is_empty() {
+        let full_program = Program::from_definitions(
+            Arc::clone(&schema),
+            build(&schema, &updated_definitions).unwrap(),
+        );
+        // Definitions of other files keep the argument types they were built
+        // with, only the ones of the changed file are rebuilt.
+        let is_changed = |location: Location| location.source_location() == changed_location;
+        for operation in full_program.operations() {
+            let updated_operation = updated_program.operation(operation.name.item);
+            assert!(updated_operation.is_some());
+            if is_changed(operation.name.location) {
+                assert_eq!(Some(operation), updated_operation);
+            }
+        }
+        for fragment in full_program.fragments() {
+            let updated_fragment = updated_program.fragment(fragment.name.item);
+            assert!(updated_fragment.is_some());
+            if is_changed(fragment.name.location) {
+                assert_eq!(Some(fragment), updated_fragment);
+            }
+        }
+        assert_eq!(
+            full_program.document_count(),
+            updated_program.document_count()
+        );
+    }
+
+    let mut names = updated_program
+        .operations()
+        .map(|operation| (operation.name.item.lookup(), operation.name.location))
+        .chain(
+            updated_program
+                .fragments()
+                .map(|fragment| (fragment.name.item.lookup(), fragment.name.location)),
+        )
+        .map(|(name, location)| format!("{} in {}", name, location.source_location().path()))
+        .collect::<Vec<_>>();
+    names.sort();
+
+    if diagnostics.is_empty() {
+        Ok(names.join("\n"))
+    } else {
+        let printer = DiagnosticPrinter::new(|source_location| {
+            if source_location == changed_location {
+                Some(updated_text.to_string())
+            } else if source_location == other_location {
+                Some(other_text.to_string())
+            } else {
+                None
+            }
+        });
+        Err(format!(
+            "{}\n\nKept definitions:\n{}",
+            diagnostics
+                .iter()
+                .map(|diagnostic| printer.diagnostic_to_string(diagnostic))
+                .collect::<Vec<_>>()
+                .join("\n\n"),
+            names.join("\n")
+        ))
+    }
+}
+
+/// The diagnostics of building all definitions at once, including the
+/// duplicate names the compiler reports before building.
+fn full_build_diagnostics(
+    schema: &Schema,
+    definitions: &[ExecutableDefinition],
+) -> Vec<Diagnostic> {
+    let mut diagnostics = build(schema, definitions).err().unwrap_or_default();
+    let mut names = FnvHashSet::default();
+    for definition in definitions {
+        if let ExecutableDefinition::Operation(operation) = definition {
+            if let Some(name) = &operation.name {
+                if !names.insert(name.value) {
+                    diagnostics.push(Diagnostic::error(
+                        ValidationMessage::DuplicateDefinition(name.value),
+                        definition.location(),
+                    ));
+                }
+            }
+        }
+    }
+    for definition in definitions {
+        if let ExecutableDefinition::Fragment(fragment) = definition {
+            if names.contains(&fragment.name.value) {
+                diagnostics.push(Diagnostic::error(
+                    ValidationMessage::DuplicateDefinition(fragment.name.value),
+                    definition.location(),
+                ));
+            }
+        }
+    }
+    diagnostics
+}
+
+fn messages(diagnostics: &[Diagnostic]) -> BTreeSet<String> {
+    diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.message().to_string())
+        .collect()
+}
@@ -0,0 +1,111 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<ec8de1d7c5bab9b089158523f3898ca4>>
+ */
+
+mod update_program;
+
+use update_program::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn add_required_fragment_argument_invalid() {
+    let input = include_str!("update_program/fixtures/add-required-fragment-argument.invalid.graphql");
+    let expected = include_str!("update_program/fixtures/add-required-fragment-argument.invalid.expected");
+    test_fixture(transform_fixture, "add-required-fragment-argument.invalid.graphql", "update_program/fixtures/add-required-fragment-argument.invalid.expected", input, expected);
+}
+
+#[test]
+fn change_fragment_argument_to_other_type_invalid() {
+    let input = include_str!("update_program/fixtures/change-fragment-argument-to-other-type.invalid.graphql");
+    let expected = include_str!("update_program/fixtures/change-fragment-argument-to-other-type.invalid.expected");
+    test_fixture(transform_fixture, "change-fragment-argument-to-other-type.invalid.graphql", "update_program/fixtures/change-fragment-argument-to-other-type.invalid.expected", input, expected);
+}
+
+#[test]
+fn change_fragment_argument_type() {
+    let input = include_str!("update_program/fixtures/change-fragment-argument-type.graphql");
+    let expected = include_str!("update_program/fixtures/change-fragment-argument-type.expected");
+    test_fixture(transform_fixture, "change-fragment-argument-type.graphql", "update_program/fixtures/change-fragment-argument-type.expected", input, expected);
+}
+
+#[test]
+fn change_fragment_argument_type_invalid() {
+    let input = include_str!("update_program/fixtures/change-fragment-argument-type.invalid.graphql");
+    let expected = include_str!("update_program/fixtures/change-fragment-argument-type.invalid.expected");
+    test_fixture(transform_fixture, "change-fragment-argument-type.invalid.graphql", "update_program/fixtures/change-fragment-argument-type.invalid.expected", input, expected);
+}
+
+#[test]
+fn change_fragment_list_and_object_arguments_invalid() {
+    let input = include_str!("update_program/fixtures/change-fragment-list-and-object-arguments.invalid.graphql");
+    let expected = include_str!("update_program/fixtures/change-fragment-list-and-object-arguments.invalid.expected");
+    test_fixture(transform_fixture, "change-fragment-list-and-object-arguments.invalid.graphql", "update_program/fixtures/change-fragment-list-and-object-arguments.invalid.expected", input, expected);
+}
+
+#[test]
+fn change_fragment_type_condition() {
+    let input = include_str!("update_program/fixtures/change-fragment-type-condition.graphql");
+    let expected = include_str!("update_program/fixtures/change-fragment-type-condition.expected");
+    test_fixture(transform_fixture, "change-fragment-type-condition.graphql", "update_program/fixtures/change-fragment-type-condition.expected", input, expected);
+}
+
+#[test]
+fn change_fragment_type_condition_invalid() {
+    let input = include_str!("update_program/fixtures/change-fragment-type-condition.invalid.graphql");
+    let expected = include_str!("update_program/fixtures/change-fragment-type-condition.invalid.expected");
+    test_fixture(transform_fixture, "change-fragment-type-condition.invalid.graphql", "update_program/fixtures/change-fragment-type-condition.invalid.expected", input, expected);
+}
+
+#[test]
+fn delete_file() {
+    let input = include_str!("update_program/fixtures/delete-file.graphql");
+    let expected = include_str!("update_program/fixtures/delete-file.expected");
+    test_fixture(transform_fixture, "delete-file.graphql", "update_program/fixtures/delete-file.expected", input, expected);
+}
+
+#[test]
+fn duplicate_definition_invalid() {
+    let input = include_str!("update_program/fixtures/duplicate-definition.invalid.graphql");
+    let expected = include_str!("update_program/fixtures/duplicate-definition.invalid.expected");
+    test_fixture(transform_fixture, "duplicate-definition.invalid.graphql", "update_program/fixtures/duplicate-definition.invalid.expected", input, expected);
+}
+
+#[test]
+fn duplicate_operation_invalid() {
+    let input = include_str!("update_program/fixtures/duplicate-operation.invalid.graphql");
+    let expected = include_str!("update_program/fixtures/duplicate-operation.invalid.expected");
+    test_fixture(transform_fixture, "duplicate-operation.invalid.graphql", "update_program/fixtures/duplicate-operation.invalid.expected", input, expected);
+}
+
+#[test]
+fn invalid_definition_in_changed_file_invalid() {
+    let input = include_str!("update_program/fixtures/invalid-definition-in-changed-file.invalid.graphql");
+    let expected = include_str!("update_program/fixtures/invalid-definition-in-changed-file.invalid.expected");
+    test_fixture(transform_fixture, "invalid-definition-in-changed-file.invalid.graphql", "update_program/fixtures/invalid-definition-in-changed-file.invalid.expected", input, expected);
+}
+
+#[test]
+fn operation_named_like_fragment_invalid() {
+    let input = include_str!("update_program/fixtures/operation-named-like-fragment.invalid.graphql");
+    let expected = include_str!("update_program/fixtures/operation-named-like-fragment.invalid.expected");
+    test_fixture(transform_fixture, "operation-named-like-fragment.invalid.graphql", "update_program/fixtures/operation-named-like-fragment.invalid.expected", input, expected);
+}
+
+#[test]
+fn rename_fragment_invalid() {
+    let input = include_str!("update_program/fixtures/rename-fragment.invalid.graphql");
+    let expected = include_str!("update_program/fixtures/rename-fragment.invalid.expected");
+    test_fixture(transform_fixture, "rename-fragment.invalid.graphql", "update_program/fixtures/rename-fragment.invalid.expected", input, expected);
+}
+
+#[test]
+fn update_fragment_selections() {
+    let input = include_str!("update_program/fixtures/update-fragment-selections.graphql");
+    let expected = include_str!("update_program/fixtures/update-fragment-selections.expected");
+    test_fixture(transform_fixture, "update-fragment-selections.graphql", "update_program/fixtures/update-fragment-selections.expected", input, expected);
+}
@@ -166,7 +166,10 @@ fn lex_block_string(lexer: &mut Lexer<'_, TokenKind>) -> bool {
                 return true;
             }
             BlockStringToken::EscapedTripleQuote | BlockStringToken::Other => {}
-            BlockStringToken::Error => unreachable!(),
+            BlockStringToken::Error => {
+                lexer.extras.error_token = Some(TokenKind::ErrorUnsupportedStringCharacter);
+                return false;
+            }
         }
     }
     lexer.extras.error_token = Some(TokenKind::ErrorUnterminatedBlockString);
@@ -507,4 +510,15 @@ mod tests {
         // Unterminated string just consumes the starting quotes
         assert_eq!(lexer.slice(), r#"""""#);
     }
+
+    #[test]
+    fn test_block_string_unsupported_character_lexing() {
+        let mut lexer = TokenKind::lexer("\"\"\"control \u{1} character\"\"\"");
+
+        assert_eq!(lexer.next(), Some(TokenKind::Error));
+        assert_eq!(
+            lexer.extras.error_token,
+            Some(TokenKind::ErrorUnsupportedStringCharacter)
+        );
+    }
 }
@@ -1538,6 +1538,16 @@ impl<'a> Parser<'a> {
                     value: value.intern(),
                 }))
             }
+            TokenKind::BlockStringLiteral => {
+                // String values keep their escaped source, so the block string
+                // is stored as the equivalent content of a quoted string.
+                let value =
+                    escape_string_characters(&block_string_value(&source[3..source.len() - 3]));
+                Ok(ConstantValue::String(StringNode {
+                    token,
+                    value: value.intern(),
+                }))
+            }
             TokenKind::IntegerLiteral => {
                 let value = source.parse::<i64>();
                 match value {
@@ -1929,3 +1939,56 @@ impl<'a> Parser<'a> {
         }
     }
 }
+
+/// The value of a block string from its raw content between the triple
+/// quotes, following `BlockStringValue` of the spec: the common indentation
+/// and leading and trailing blank lines are removed.
+fn block_string_value(raw_value: &str) -> String {
+    let raw_value = raw_value.replace(r#"\""""#, r#"""""#);
+    let lines = raw_value
+        .split("\r\n")
+        .flat_map(|line| line.split(&['\n', '\r'][..]))
+        .collect::<Vec<_>>();
+    let indent = |line: &str| line.len() - line.trim_start_matches(&[' ', '\t'][..]).len();
+    let common_indent = lines
+        .iter()
+        .skip(1)
+        .filter(|line| indent(line) < line.len())
+        .map(|line| indent(line))
+        .min()
+        .unwrap_or(0);
+    let is_blank = |line: &&str| line.trim_start_matches(&[' ', '\t'][..]).is_empty();
+    let lines = lines
+        .iter()
+        .enumerate()
+        .map(|(ix, line)| {
+            if ix == 0 {
+                line
+            } else {
+                // Lines shorter than the indentation only contain whitespace
+                &line[common_indent.min(line.len())..]
+            }
+        })
+        .collect::<Vec<_>>();
+    let start = lines.iter().position(|line| !is_blank(line));
+    let end = lines.iter().rposition(|line| !is_blank(line));
+    match (start, end) {
+        (Some(start), Some(end)) => lines[start..=end].join("\n"),
+        _ => String::new(),
+    }
+}
+
+/// Escapes the characters of a string value that can't appear as is in a
+/// quoted string.
+fn escape_string_characters(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
@@ -32,7 +32,7 @@ impl GraphQLSource {
     pub fn to_range(&self) -> Range {
         // Zero-indexed line offset in the document
         let mut line = self.line_index;
-        // Zero-indexed character offset on the line
+        // Zero-indexed character offset on the line, in UTF-16 code units
         let mut character = self.column_index;
         let mut chars = self.text.chars().peekable();
         let start_position = Position::new(line as u64, character as u64);
@@ -56,7 +56,7 @@ impl GraphQLSource {
             // Make sure to only increment the character offset if this
             // isn't a newline.
             if !is_newline {
-                character += 1;
+                character += chr.len_utf16();
             }
         }
         let end_position = Position::new(line as u64, character as u64);
@@ -50,7 +50,10 @@ impl Printer {
             self.print_variable_definitions(variable_definitions)?;
         }
         self.print_directives(&operation.directives)?;
-        writeln!(self.output, " {{")?;
+        if !self.output.is_empty() {
+            write!(self.output, " ")?;
+        }
+        writeln!(self.output, "{{")?;
         self.print_selections(&operation.selections, "  ")?;
         write!(self.output, "}}")?;
 
@@ -58,11 +61,11 @@ impl Printer {
     }
 
     fn print_fragment(&mut self, fragment: &FragmentDefinition) -> Result {
-        write!(
-            self.output,
-            "fragment {} {}",
-            fragment.name, fragment.type_condition
-        )?;
+        write!(self.output, "fragment {}", fragment.name)?;
+        if let Some(variable_definitions) = &fragment.variable_definitions {
+            self.print_variable_definitions(variable_definitions)?;
+        }
+        write!(self.output, " {}", fragment.type_condition)?;
         self.print_directives(&fragment.directives)?;
         writeln!(self.output, " {{")?;
         self.print_selections(&fragment.selections, "  ")?;
@@ -99,6 +102,7 @@ impl Printer {
         if let Some(default_value) = &variable_definition.default_value {
             write!(self.output, " = {}", default_value)?;
         }
+        self.print_directives(&variable_definition.directives)?;
 
         Ok(())
     }
@@ -0,0 +1,723 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Generates random executable documents and checks that parsing and
+//! printing them is stable: the printed text of a document written with
+//! arbitrary whitespace, commas, comments and block strings is the canonical
+//! text of the document, and parsing the canonical text prints it unchanged.
+
+use common::SourceLocationKey;
+use graphql_syntax::{parse_executable_with_features, ParserFeatures};
+use graphql_text_printer::print_executable_definition_ast;
+use proptest::collection::vec;
+use proptest::option;
+use proptest::prelude::*;
+
+/// Ignored tokens put between all tokens of the non-canonical text.
+const IGNORED: &str = " ,\n\t# comment\n ";
+
+#[derive(Clone, Debug)]
+enum StringValue {
+    /// The characters of a quoted string, with escape sequences as written.
+    Quoted(String),
+    /// The lines of a block string, without indentation.
+    Block(Vec<String>),
+}
+
+#[derive(Clone, Debug)]
+enum Value {
+    Int(i64),
+    Float(String),
+    String(StringValue),
+    Boolean(bool),
+    Null,
+    Enum(String),
+    Variable(String),
+    List(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+#[derive(Clone, Debug)]
+enum Type {
+    Named(String),
+    List(Box<Type>),
+    NonNull(Box<Type>),
+}
+
+#[derive(Clone, Debug)]
+struct Directive {
+    name: String,
+    arguments: Option<Vec<(String, Value)>>,
+}
+
+#[derive(Clone, Debug)]
+struct VariableDefinition {
+    name: String,
+    type_: Type,
+    default_value: Option<Value>,
+    directives: Vec<Directive>,
+}
+
+#[derive(Clone, Debug)]
+enum Selection {
+    Field {
+        alias: Option<String>,
+        name: String,
+        arguments: Option<Vec<(String, Value)>>,
+        directives: Vec<Directive>,
+        selections: Option<Vec<Selection>>,
+    },
+    FragmentSpread {
+        name: String,
+        directives: Vec<Directive>,
+    },
+    InlineFragment {
+        type_condition: Option<String>,
+        directives: Vec<Directive>,
+        selections: Vec<Selection>,
+    },
+}
+
+#[derive(Clone, Debug)]
+enum Definition {
+    /// An operation, `kind` is `None` for the query shorthand.
+    Operation {
+        kind: Option<&'static str>,
+        name: Option<String>,
+        variable_definitions: Option<Vec<VariableDefinition>>,
+        directives: Vec<Directive>,
+        selections: Vec<Selection>,
+    },
+    Fragment {
+        name: String,
+        variable_definitions: Option<Vec<VariableDefinition>>,
+        type_condition: String,
+        directives: Vec<Directive>,
+        selections: Vec<Selection>,
+    },
+}
+
+/// Escapes a string value the way it's stored in a quoted string.
+fn escape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+fn join<T>(items: &[T], separator: &str, print: impl Fn(&T) -> String) -> String {
+    items.iter().map(print).collect::<Vec<_>>().join(separator)
+}
+
+impl Value {
+    fn canonical(&self) -> String {
+        match self {
+            Value::Int(value) => value.to_string(),
+            Value::Float(value) | Value::Enum(value) => value.clone(),
+            Value::String(StringValue::Quoted(value)) => format!("\"{}\"", value),
+            Value::String(StringValue::Block(lines)) => {
+                format!("\"{}\"", escape(&lines.join("\n")))
+            }
+            Value::Boolean(value) => value.to_string(),
+            Value::Null => "null".to_string(),
+            Value::Variable(name) => format!("${}", name),
+            Value::List(items) => format!("[{}]", join(items, ", ", Value::canonical)),
+            Value::Object(fields) => format!(
+                "{{{}}}",
+                join(fields, ", ", |(name, value)| format!(
+                    "{}: {}",
+                    name,
+                    value.canonical()
+                ))
+            ),
+        }
+    }
+
+    fn source(&self) -> String {
+        match self {
+            Value::String(StringValue::Block(lines)) => {
+                // The opening and closing quotes are on their own lines, so
+                // the content can start and end with a quote.
+                let mut result = "\"\"\"\n".to_string();
+                for line in lines {
+                    result.push_str("    ");
+                    result.push_str(&line.replace("\"\"\"", "\\\"\"\""));
+                    result.push('\n');
+                }
+                result.push_str("  \"\"\"");
+                result
+            }
+            Value::List(items) => format!(
+                "[{}{}{}]",
+                IGNORED,
+                join(items, IGNORED, Value::source),
+                IGNORED
+            ),
+            Value::Object(fields) => format!(
+                "{{{}{}{}}}",
+                IGNORED,
+                join(fields, IGNORED, |(name, value)| format!(
+                    "{}{}:{}{}",
+                    name,
+                    IGNORED,
+                    IGNORED,
+                    value.source()
+                )),
+                IGNORED
+            ),
+            _ => self.canonical(),
+        }
+    }
+}
+
+impl Type {
+    fn canonical(&self) -> String {
+        match self {
+            Type::Named(name) => name.clone(),
+            Type::List(type_) => format!("[{}]", type_.canonical()),
+            Type::NonNull(type_) => format!("{}!", type_.canonical()),
+        }
+    }
+
+    fn source(&self) -> String {
+        match self {
+            Type::Named(name) => name.clone(),
+            Type::List(type_) => format!("[{}{}{}]", IGNORED, type_.source(), IGNORED),
+            Type::NonNull(type_) => format!("{}{}!", type_.source(), IGNORED),
+        }
+    }
+}
+
+fn canonical_arguments(arguments: &Option<Vec<(String, Value)>>) -> String {
+    match arguments {
+        Some(arguments) => format!(
+            "({})",
+            join(arguments, ", ", |(name, value)| format!(
+                "{}: {}",
+                name,
+                value.canonical()
+            ))
+        ),
+        None => String::new(),
+    }
+}
+
+fn source_arguments(arguments: &Option<Vec<(String, Value)>>) -> String {
+    match arguments {
+        Some(arguments) => format!(
+            "{}({}{}{})",
+            IGNORED,
+            IGNORED,
+            join(arguments, IGNORED, |(name, value)| format!(
+                "{}{}:{}{}",
+                name,
+                IGNORED,
+                IGNORED,
+                value.source()
+            )),
+            IGNORED
+        ),
+        None => String::new(),
+    }
+}
+
+fn canonical_directives(directives: &[Directive]) -> String {
+    join(directives, "", |directive| {
+        format!(
+            " @{}{}",
+            directive.name,
+            canonical_arguments(&directive.arguments)
+        )
+    })
+}
+
+fn source_directives(directives: &[Directive]) -> String {
+    join(directives, "", |directive| {
+        format!(
+            "{}@{}{}",
+            IGNORED,
+            directive.name,
+            source_arguments(&directive.arguments)
+        )
+    })
+}
+
+fn canonical_variable_definitions(
+    variable_definitions: &Option<Vec<VariableDefinition>>,
+) -> String {
+    match variable_definitions {
+        Some(variable_definitions) => format!(
+            "({})",
+            join(variable_definitions, ", ", |definition| {
+                let mut result = format!("${}: {}", definition.name, definition.type_.canonical());
+                if let Some(default_value) = &definition.default_value {
+                    result.push_str(&format!(" = {}", default_value.canonical()));
+                }
+                result.push_str(&canonical_directives(&definition.directives));
+                result
+            })
+        ),
+        None => String::new(),
+    }
+}
+
+fn source_variable_definitions(variable_definitions: &Option<Vec<VariableDefinition>>) -> String {
+    match variable_definitions {
+        Some(variable_definitions) => format!(
+            "{}({}{}{})",
+            IGNORED,
+            IGNORED,
+            join(variable_definitions, IGNORED, |definition| {
+                let mut result = format!(
+                    "${}{}:{}{}",
+                    definition.name,
+                    IGNORED,
+                    IGNORED,
+                    definition.type_.source()
+                );
+                if let Some(default_value) = &definition.default_value {
+                    result.push_str(&format!(
+                        "{}={}{}",
+                        IGNORED,
+                        IGNORED,
+                        default_value.source()
+                    ));
+                }
+                result.push_str(&source_directives(&definition.directives));
+                result
+            }),
+            IGNORED
+        ),
+        None => String::new(),
+    }
+}
+
+fn canonical_selections(selections: &[Selection], indent: &str) -> String {
+    let nested_indent = format!("  {}", indent);
+    let mut result = " {\n".to_string();
+    for selection in selections {
+        result.push_str(indent);
+        match selection {
+            Selection::Field {
+                alias,
+                name,
+                arguments,
+                directives,
+                selections,
+            } => {
+                if let Some(alias) = alias {
+                    result.push_str(&format!("{}: ", alias));
+                }
+                result.push_str(name);
+                result.push_str(&canonical_arguments(arguments));
+                result.push_str(&canonical_directives(directives));
+                if let Some(selections) = selections {
+                    result.push_str(&canonical_selections(selections, &nested_indent));
+                    result.push_str(indent);
+                    result.push('}');
+                }
+            }
+            Selection::FragmentSpread { name, directives } => {
+                result.push_str(&format!("...{}", name));
+                result.push_str(&canonical_directives(directives));
+            }
+            Selection::InlineFragment {
+                type_condition,
+                directives,
+                selections,
+            } => {
+                result.push_str("...");
+                if let Some(type_condition) = type_condition {
+                    result.push_str(&format!(" on {}", type_condition));
+                }
+                result.push_str(&canonical_directives(directives));
+                result.push_str(&canonical_selections(selections, &nested_indent));
+                result.push_str(indent);
+                result.push('}');
+            }
+        }
+        result.push('\n');
+    }
+    result
+}
+
+fn source_selections(selections: &[Selection]) -> String {
+    let mut result = format!("{}{{", IGNORED);
+    for selection in selections {
+        result.push_str(IGNORED);
+        match selection {
+            Selection::Field {
+                alias,
+                name,
+                arguments,
+                directives,
+                selections,
+            } => {
+                if let Some(alias) = alias {
+                    result.push_str(&format!("{}{}:{}", alias, IGNORED, IGNORED));
+                }
+                result.push_str(name);
+                result.push_str(&source_arguments(arguments));
+                result.push_str(&source_directives(directives));
+                if let Some(selections) = selections {
+                    result.push_str(&source_selections(selections));
+                }
+            }
+            Selection::FragmentSpread { name, directives } => {
+                result.push_str(&format!("...{}{}", IGNORED, name));
+                result.push_str(&source_directives(directives));
+            }
+            Selection::InlineFragment {
+                type_condition,
+                directives,
+                selections,
+            } => {
+                result.push_str("...");
+                if let Some(type_condition) = type_condition {
+                    result.push_str(&format!("{}on{}{}", IGNORED, IGNORED, type_condition));
+                }
+                result.push_str(&source_directives(directives));
+                result.push_str(&source_selections(selections));
+            }
+        }
+    }
+    result.push_str(IGNORED);
+    result.push('}');
+    result
+}
+
+impl Definition {
+    fn canonical(&self) -> String {
+        match self {
+            Definition::Operation {
+                kind,
+                name,
+                variable_definitions,
+                directives,
+                selections,
+            } => {
+                let mut result = kind.unwrap_or_default().to_string();
+                if let Some(name) = name {
+                    result.push_str(&format!(" {}", name));
+                }
+                result.push_str(&canonical_variable_definitions(variable_definitions));
+                result.push_str(&canonical_directives(directives));
+                result.push_str(&canonical_selections(selections, "  "));
+                result.push('}');
+                result.trim_start().to_string()
+            }
+            Definition::Fragment {
+                name,
+                variable_definitions,
+                type_condition,
+                directives,
+                selections,
+            } => {
+                let mut result = format!("fragment {}", name);
+                result.push_str(&canonical_variable_definitions(variable_definitions));
+                result.push_str(&format!(" on {}", type_condition));
+                result.push_str(&canonical_directives(directives));
+                result.push_str(&canonical_selections(selections, "  "));
+                result.push('}');
+                result
+            }
+        }
+    }
+
+    fn source(&self) -> String {
+        match self {
+            Definition::Operation {
+                kind,
+                name,
+                variable_definitions,
+                directives,
+                selections,
+            } => {
+                let mut result = kind.unwrap_or_default().to_string();
+                if let Some(name) = name {
+                    result.push_str(&format!("{}{}", IGNORED, name));
+                }
+                result.push_str(&source_variable_definitions(variable_definitions));
+                result.push_str(&source_directives(directives));
+                result.push_str(&source_selections(selections));
+                result
+            }
+            Definition::Fragment {
+                name,
+                variable_definitions,
+                type_condition,
+                directives,
+                selections,
+            } => {
+                let mut result = format!("fragment{}{}", IGNORED, name);
+                result.push_str(&source_variable_definitions(variable_definitions));
+                result.push_str(&format!("{}on{}{}", IGNORED, IGNORED, type_condition));
+                result.push_str(&source_directives(directives));
+                result.push_str(&source_selections(selections));
+                result
+            }
+        }
+    }
+}
+
+fn name() -> impl Strategy<Value = String> {
+    "[a-z_][a-zA-Z0-9_]{0,6}".prop_filter("keywords are not names", |name| {
+        ![
+            "on",
+            "true",
+            "false",
+            "null",
+            "query",
+            "mutation",
+            "subscription",
+            "fragment",
+        ]
+        .contains(&name.as_str())
+    })
+}
+
+fn type_name() -> impl Strategy<Value = String> {
+    "[A-Z][a-zA-Z0-9_]{0,6}"
+}
+
+fn string_value() -> impl Strategy<Value = StringValue> {
+    prop_oneof![
+        vec(
+            prop_oneof![
+                "[a-zA-Z0-9 _#{}(),:$@!/'é中]".boxed(),
+                prop_oneof![
+                    Just(r#"\""#),
+                    Just(r#"\\"#),
+                    Just(r#"\/"#),
+                    Just(r#"\b"#),
+                    Just(r#"\f"#),
+                    Just(r#"\n"#),
+                    Just(r#"\r"#),
+                    Just(r#"\t"#),
+                    Just(r#"\u00e9"#),
+                ]
+                .prop_map(str::to_string)
+                .boxed(),
+            ],
+            0..8
+        )
+        .prop_map(|parts| StringValue::Quoted(parts.concat())),
+        // Lines start with a non-whitespace character, so the block string
+        // has no leading or trailing blank lines and the indentation of all
+        // lines is removed.
+        vec(
+            r#"[a-zA-Z0-9_#{}"\\é中][a-zA-Z0-9 _#{}(),"\\é中]{0,8}"#,
+            1..4
+        )
+        .prop_map(StringValue::Block),
+    ]
+}
+
+fn constant_leaf() -> BoxedStrategy<Value> {
+    prop_oneof![
+        any::<i64>().prop_map(Value::Int),
+        (any::<i32>(), "[0-9]{1,3}", option::of(-20i8..20)).prop_map(
+            |(integer, fraction, exponent)| Value::Float(match exponent {
+                Some(exponent) => format!("{}.{}e{}", integer, fraction, exponent),
+                None => format!("{}.{}", integer, fraction),
+            })
+        ),
+        string_value().prop_map(Value::String),
+        any::<bool>().prop_map(Value::Boolean),
+        Just(Value::Null),
+        "[A-Z][A-Z0-9_]{0,6}".prop_map(Value::Enum),
+    ]
+    .boxed()
+}
+
+fn nested_value(leaf: BoxedStrategy<Value>) -> impl Strategy<Value = Value> {
+    leaf.prop_recursive(3, 16, 4, |inner| {
+        prop_oneof![
+            vec(inner.clone(), 0..4).prop_map(Value::List),
+            vec((name(), inner), 0..4).prop_map(Value::Object),
+        ]
+    })
+}
+
+fn constant_value() -> impl Strategy<Value = Value> {
+    nested_value(constant_leaf())
+}
+
+fn value() -> impl Strategy<Value = Value> {
+    nested_value(prop_oneof![constant_leaf(), name().prop_map(Value::Variable)].boxed())
+}
+
+fn arguments() -> impl Strategy<Value = Vec<(String, Value)>> {
+    vec((name(), value()), 1..3)
+}
+
+fn directives() -> impl Strategy<Value = Vec<Directive>> {
+    vec(
+        (name(), option::of(arguments()))
+            .prop_map(|(name, arguments)| Directive { name, arguments }),
+        0..3,
+    )
+}
+
+fn type_annotation() -> impl Strategy<Value = Type> {
+    type_name()
+        .prop_map(Type::Named)
+        .prop_recursive(3, 6, 1, |inner| {
+            prop_oneof![
+                inner.clone().prop_map(|type_| Type::List(Box::new(type_))),
+                inner.prop_map(|type_| match type_ {
+                    Type::NonNull(_) => type_,
+                    type_ => Type::NonNull(Box::new(type_)),
+                }),
+            ]
+        })
+}
+
+fn variable_definitions() -> impl Strategy<Value = Option<Vec<VariableDefinition>>> {
+    option::of(vec(
+        (
+            name(),
+            type_annotation(),
+            option::of(constant_value()),
+            directives(),
+        )
+            .prop_map(
+                |(name, type_, default_value, directives)| VariableDefinition {
+                    name,
+                    type_,
+                    default_value,
+                    directives,
+                },
+            ),
+        1..3,
+    ))
+}
+
+fn selections() -> impl Strategy<Value = Vec<Selection>> {
+    let leaf = prop_oneof![
+        (
+            option::of(name()),
+            name(),
+            option::of(arguments()),
+            directives()
+        )
+            .prop_map(|(alias, name, arguments, directives)| Selection::Field {
+                alias,
+                name,
+                arguments,
+                directives,
+                selections: None,
+            }),
+        (name(), directives())
+            .prop_map(|(name, directives)| Selection::FragmentSpread { name, directives }),
+    ];
+    let selection = leaf.prop_recursive(3, 24, 3, |inner| {
+        let selections = vec(inner, 1..4);
+        prop_oneof![
+            (
+                option::of(name()),
+                name(),
+                option::of(arguments()),
+                directives(),
+                selections.clone(),
+            )
+                .prop_map(|(alias, name, arguments, directives, selections)| {
+                    Selection::Field {
+                        alias,
+                        name,
+                        arguments,
+                        directives,
+                        selections: Some(selections),
+                    }
+                }),
+            (option::of(type_name()), directives(), selections).prop_map(
+                |(type_condition, directives, selections)| Selection::InlineFragment {
+                    type_condition,
+                    directives,
+                    selections,
+                }
+            ),
+        ]
+    });
+    vec(selection, 1..4)
+}
+
+fn definition() -> impl Strategy<Value = Definition> {
+    prop_oneof![
+        (
+            prop_oneof![Just("query"), Just("mutation"), Just("subscription")],
+            option::of(name()),
+            variable_definitions(),
+            directives(),
+            selections(),
+        )
+            .prop_map(
+                |(kind, name, variable_definitions, directives, selections)| {
+                    Definition::Operation {
+                        kind: Some(kind),
+                        name,
+                        variable_definitions,
+                        directives,
+                        selections,
+                    }
+                }
+            ),
+        selections().prop_map(|selections| Definition::Operation {
+            kind: None,
+            name: None,
+            variable_definitions: None,
+            directives: vec![],
+            selections,
+        }),
+        (
+            name(),
+            variable_definitions(),
+            type_name(),
+            directives(),
+            selections(),
+        )
+            .prop_map(
+                |(name, variable_definitions, type_condition, directives, selections)| {
+                    Definition::Fragment {
+                        name,
+                        variable_definitions,
+                        type_condition,
+                        directives,
+                        selections,
+                    }
+                }
+            ),
+    ]
+}
+
+fn print(source: &str) -> String {
+    let document = parse_executable_with_features(
+        source,
+        SourceLocationKey::standalone("round_trip.graphql"),
+        ParserFeatures {
+            enable_variable_definitions: true,
+        },
+    )
+    .unwrap_or_else(|diagnostics| panic!("Failed to parse {}: {:?}", source, diagnostics));
+    assert_eq!(document.definitions.len(), 1);
+    print_executable_definition_ast(&document.definitions[0])
+}
+
+proptest! {
+    #[test]
+    fn print_ast_round_trip(definition in definition()) {
+        let canonical = definition.canonical();
+        prop_assert_eq!(print(&canonical), canonical.clone());
+        prop_assert_eq!(print(&definition.source()), canonical);
+    }
+}
@@ -51,6 +51,8 @@ pub enum Primitive {
     StorageKey(StringKey, AstKey),
     RawString(String),
     ModuleDependency(StringKey),
+    /// A `require()` of the module at the given path.
+    JSModuleDependency(StringKey),
 }
 
 impl Primitive {
@@ -23,7 +23,8 @@ use relay_transforms::{
     ConnectionConstants, ConnectionMetadata, DeferDirective, RelayDirective, StreamDirective,
     ACTION_ARGUMENT, CLIENT_EXTENSION_DIRECTIVE_NAME, DEFER_STREAM_CONSTANTS,
     DIRECTIVE_SPLIT_OPERATION, INLINE_DATA_CONSTANTS, INTERNAL_METADATA_DIRECTIVE, MATCH_CONSTANTS,
-    PATH_METADATA_ARGUMENT, REACT_FLIGHT_SCALAR_FLIGHT_FIELD_METADATA_KEY, REQUIRED_METADATA_KEY,
+    PATH_METADATA_ARGUMENT, REACT_FLIGHT_SCALAR_FLIGHT_FIELD_METADATA_KEY,
+    RELAY_ACTOR_CHANGE_DIRECTIVE_FOR_CODEGEN, REQUIRED_METADATA_KEY,
     TYPE_DISCRIMINATOR_DIRECTIVE_NAME,
 };
 use schema::Schema;
@@ -417,6 +418,12 @@ impl<'schema, 'builder> CodegenBuilder<'schema, 'builder> {
                         value: Primitive::Bool(true),
                     })
                 }
+                if metadata.is_prefetchable {
+                    object.push(ObjectEntry {
+                        key: CODEGEN_CONSTANTS.prefetchable,
+                        value: Primitive::Bool(true),
+                    })
+                }
                 Primitive::Key(self.object(object))
             })
             .collect::<Vec<_>>();
@@ -481,7 +488,15 @@ impl<'schema, 'builder> CodegenBuilder<'schema, 'builder> {
                 {
                     self.build_module_import_selections(match_directive)
                 } else {
-                    vec![self.build_inline_fragment(&inline_frag)]
+                    let primitive = self.build_inline_fragment(inline_frag);
+                    // `@required` of an aliased fragment spread is moved to
+                    // the inline fragment wrapping it
+                    match inline_frag.directives.named(*REQUIRED_METADATA_KEY) {
+                        Some(required_directive) => {
+                            vec![self.build_required_field(required_directive, primitive)]
+                        }
+                        None => vec![primitive],
+                    }
                 }
             }
             Selection::LinkedField(field) => {
@@ -489,7 +504,17 @@ impl<'schema, 'builder> CodegenBuilder<'schema, 'builder> {
 
                 match stream {
                     Some(stream) => vec![self.build_stream(&field, stream)],
-                    None => self.build_linked_field_and_handles(field),
+                    None => {
+                        if field
+                            .directives
+                            .named(*RELAY_ACTOR_CHANGE_DIRECTIVE_FOR_CODEGEN)
+                            .is_some()
+                        {
+                            vec![self.build_actor_change(field)]
+                        } else {
+                            self.build_linked_field_and_handles(field)
+                        }
+                    }
                 }
             }
             Selection::ScalarField(field) => {
@@ -575,7 +600,7 @@ impl<'schema, 'builder> CodegenBuilder<'schema, 'builder> {
             Some(_flight_directive) => Primitive::String(CODEGEN_CONSTANTS.flight_field),
             None => Primitive::String(CODEGEN_CONSTANTS.scalar_field),
         };
-        let primitive = Primitive::Key(self.object(vec![
+        let mut object = vec![
             build_alias(alias, name),
             ObjectEntry {
                 key: CODEGEN_CONSTANTS.args,
@@ -605,7 +630,9 @@ impl<'schema, 'builder> CodegenBuilder<'schema, 'builder> {
                     }
                 },
             },
-        ]));
+        ];
+        self.insert_field_metadata(&mut object, &field.directives);
+        let primitive = Primitive::Key(self.object(object));
 
         match field.directives.named(*REQUIRED_METADATA_KEY) {
             Some(required_directive) => self.build_required_field(required_directive, primitive),
@@ -687,7 +714,7 @@ impl<'schema, 'builder> CodegenBuilder<'schema, 'builder> {
             self.build_field_name_and_alias(schema_field.name, field.alias, &field.directives);
         let args = self.build_arguments(&field.arguments);
         let selections = self.build_selections(field.selections.iter());
-        let primitive = Primitive::Key(self.object(vec![
+        let mut object = vec![
             build_alias(alias, name),
             ObjectEntry {
                 key: CODEGEN_CONSTANTS.args,
@@ -733,7 +760,9 @@ impl<'schema, 'builder> CodegenBuilder<'schema, 'builder> {
                     }
                 },
             },
-        ]));
+        ];
+        self.insert_field_metadata(&mut object, &field.directives);
+        let primitive = Primitive::Key(self.object(object));
 
         match field.directives.named(*REQUIRED_METADATA_KEY) {
             Some(required_directive) => self.build_required_field(required_directive, primitive),
@@ -741,6 +770,76 @@ impl<'schema, 'builder> CodegenBuilder<'schema, 'builder> {
         }
     }
 
+    /// The reader node of an actor change field describes the field and the
+    /// fragment spread read in the store of the other actor, the
+    /// normalization node wraps the linked field.
+    fn build_actor_change(&mut self, field: &LinkedField) -> Primitive {
+        match self.variant {
+            CodegenVariant::Reader => {
+                let schema_field = self.schema.field(field.definition.item);
+                let (name, alias) = self.build_field_name_and_alias(
+                    schema_field.name,
+                    field.alias,
+                    &field.directives,
+                );
+                let args = self.build_arguments(&field.arguments);
+                let fragment_spread = field
+                    .selections
+                    .iter()
+                    .find_map(|selection| match selection {
+                        Selection::FragmentSpread(spread) => Some(spread),
+                        _ => None,
+                    })
+                    .expect("Expected the actor change field to select a fragment spread.");
+                let fragment_spread = self.build_fragment_spread(fragment_spread);
+                Primitive::Key(self.object(vec![
+                    build_alias(alias, name),
+                    ObjectEntry {
+                        key: CODEGEN_CONSTANTS.args,
+                        value: match args {
+                            None => Primitive::Null,
+                            Some(key) => Primitive::Key(key),
+                        },
+                    },
+                    ObjectEntry {
+                        key: CODEGEN_CONSTANTS.fragment_spread_property,
+                        value: fragment_spread,
+                    },
+                    ObjectEntry {
+                        key: CODEGEN_CONSTANTS.kind,
+                        value: Primitive::String(CODEGEN_CONSTANTS.actor_change),
+                    },
+                    ObjectEntry {
+                        key: CODEGEN_CONSTANTS.name,
+                        value: Primitive::String(name),
+                    },
+                    ObjectEntry {
+                        key: CODEGEN_CONSTANTS.storage_key,
+                        value: match args {
+                            Some(key) if is_static_storage_key_available(&field.arguments) => {
+                                Primitive::StorageKey(name, key)
+                            }
+                            _ => Primitive::Null,
+                        },
+                    },
+                ]))
+            }
+            CodegenVariant::Normalization => {
+                let linked_field = self.build_linked_field(field);
+                Primitive::Key(self.object(vec![
+                    ObjectEntry {
+                        key: CODEGEN_CONSTANTS.kind,
+                        value: Primitive::String(CODEGEN_CONSTANTS.actor_change),
+                    },
+                    ObjectEntry {
+                        key: CODEGEN_CONSTANTS.linked_field_property,
+                        value: linked_field,
+                    },
+                ]))
+            }
+        }
+    }
+
     fn build_linked_handles(&mut self, result: &mut Vec<Primitive>, field: &LinkedField) {
         let schema_field = self.schema.field(field.definition.item);
         let field_name = schema_field.name;
@@ -803,6 +902,12 @@ impl<'schema, 'builder> CodegenBuilder<'schema, 'builder> {
                     });
                 }
             };
+            if let Some(handler_module) = values.handler_module {
+                object.push(ObjectEntry {
+                    key: CODEGEN_CONSTANTS.handler_module,
+                    value: Primitive::JSModuleDependency(handler_module),
+                });
+            }
             result.push(Primitive::Key(self.object(object)))
         }
     }
@@ -1476,13 +1581,9 @@ impl<'schema, 'builder> CodegenBuilder<'schema, 'builder> {
         ]))
     }
 
-    fn build_request_parameters(
-        &mut self,
-        operation: &OperationDefinition,
-        mut request_parameters: RequestParameters,
-    ) -> AstKey {
-        let mut metadata_items: Vec<ObjectEntry> = operation
-            .directives
+    /// Builds an entry for each `@__metadata` directive.
+    fn build_internal_metadata_entries(&mut self, directives: &[Directive]) -> Vec<ObjectEntry> {
+        directives
             .iter()
             .filter_map(|directive| {
                 if directive.name.item == *INTERNAL_METADATA_DIRECTIVE {
@@ -1504,7 +1605,36 @@ impl<'schema, 'builder> CodegenBuilder<'schema, 'builder> {
                     None
                 }
             })
-            .collect();
+            .collect()
+    }
+
+    /// Inserts the `metadata` of a field after its `kind`, if the field has
+    /// `@__metadata` directives.
+    fn insert_field_metadata(&mut self, object: &mut Vec<ObjectEntry>, directives: &[Directive]) {
+        let mut metadata_items = self.build_internal_metadata_entries(directives);
+        if metadata_items.is_empty() {
+            return;
+        }
+        metadata_items.sort_unstable_by_key(|entry| entry.key);
+        let index = object
+            .iter()
+            .position(|entry| entry.key == CODEGEN_CONSTANTS.kind)
+            .map_or(object.len(), |index| index + 1);
+        object.insert(
+            index,
+            ObjectEntry {
+                key: CODEGEN_CONSTANTS.metadata,
+                value: Primitive::Key(self.object(metadata_items)),
+            },
+        );
+    }
+
+    fn build_request_parameters(
+        &mut self,
+        operation: &OperationDefinition,
+        mut request_parameters: RequestParameters,
+    ) -> AstKey {
+        let mut metadata_items = self.build_internal_metadata_entries(&operation.directives);
 
         // add connection metadata
         let connection_metadata = extract_connection_metadata_from_directive(
@@ -11,6 +11,7 @@ use lazy_static::lazy_static;
 pub struct CodegenConstants {
     pub abstract_key: StringKey,
     pub action: StringKey,
+    pub actor_change: StringKey,
     pub alias: StringKey,
     pub args: StringKey,
     pub argument_definitions: StringKey,
@@ -40,10 +41,12 @@ pub struct CodegenConstants {
     pub fragment_path_in_result: StringKey,
     pub fragment_prop_name: StringKey,
     pub fragment_spread: StringKey,
+    pub fragment_spread_property: StringKey,
     pub fragment_value: StringKey,
     pub fragment: StringKey,
     pub handle: StringKey,
     pub handle_args: StringKey,
+    pub handler_module: StringKey,
     pub id: StringKey,
     pub identifier_field: StringKey,
     pub if_: StringKey,
@@ -55,6 +58,7 @@ pub struct CodegenConstants {
     pub kind: StringKey,
     pub label: StringKey,
     pub linked_field: StringKey,
+    pub linked_field_property: StringKey,
     pub linked_handle: StringKey,
     pub list_value: StringKey,
     pub literal: StringKey,
@@ -72,6 +76,7 @@ pub struct CodegenConstants {
     pub passing_value: StringKey,
     pub path: StringKey,
     pub plural: StringKey,
+    pub prefetchable: StringKey,
     pub query: StringKey,
     pub refetch: StringKey,
     pub request: StringKey,
@@ -97,6 +102,7 @@ lazy_static! {
     pub static ref CODEGEN_CONSTANTS: CodegenConstants = CodegenConstants {
         abstract_key: "abstractKey".intern(),
         action: "action".intern(),
+        actor_change: "ActorChange".intern(),
         alias: "alias".intern(),
         args: "args".intern(),
         argument_definitions: "argumentDefinitions".intern(),
@@ -126,10 +132,12 @@ lazy_static! {
         fragment_path_in_result: "fragmentPathInResult".intern(),
         fragment_prop_name: "fragmentPropName".intern(),
         fragment_spread: "FragmentSpread".intern(),
+        fragment_spread_property: "fragmentSpread".intern(),
         fragment_value: "Fragment".intern(),
         fragment: "fragment".intern(),
         handle: "handle".intern(),
         handle_args: "handleArgs".intern(),
+        handler_module: "handlerModule".intern(),
         id: "id".intern(),
         identifier_field: "identifierField".intern(),
         if_: "if".intern(),
@@ -141,6 +149,7 @@ lazy_static! {
         kind: "kind".intern(),
         label: "label".intern(),
         linked_field: "LinkedField".intern(),
+        linked_field_property: "linkedField".intern(),
         linked_handle: "LinkedHandle".intern(),
         list_value: "ListValue".intern(),
         literal: "Literal".intern(),
@@ -158,6 +167,7 @@ lazy_static! {
         passing_value: "passingValue".intern(),
         path: "path".intern(),
         plural: "plural".intern(),
+        prefetchable: "prefetchable".intern(),
         query: "query".intern(),
         refetch: "refetch".intern(),
         request: "Request".intern(),
@@ -288,6 +288,7 @@ impl<'b> JSONPrinter<'b> {
                 write_static_storage_key(f, &self.builder, *field_name, *key)
             }
             Primitive::ModuleDependency(key) => write!(f, "require('{}.graphql')", key),
+            Primitive::JSModuleDependency(path) => write!(f, "require('{}')", path),
         }
     }
 }
@@ -431,5 +432,6 @@ fn write_constant_value(f: &mut String, builder: &AstBuilder, value: &Primitive)
         Primitive::StorageKey(_, _) => panic!("Unexpected StorageKey"),
         Primitive::RawString(_) => panic!("Unexpected RawString"),
         Primitive::ModuleDependency(_) => panic!("Unexpected ModuleDependency"),
+        Primitive::JSModuleDependency(_) => panic!("Unexpected JSModuleDependency"),
     }
 }
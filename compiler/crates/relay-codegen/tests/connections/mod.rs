@@ -12,7 +12,9 @@ use graphql_syntax::parse_executable;
 use graphql_test_helpers::diagnostics_to_sorted_string;
 use relay_codegen::{build_request_params, Printer};
 use relay_test_schema::get_test_schema;
-use relay_transforms::{transform_connections, validate_connections, ConnectionInterface};
+use relay_transforms::{
+    transform_connections, validate_connections, ConnectionInterface, FeatureFlags,
+};
 use std::sync::Arc;
 
 pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
@@ -29,10 +31,13 @@ pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
 
     let connection_interface = ConnectionInterface::default();
 
-    validate_connections(&program, &connection_interface)
+    let feature_flags = FeatureFlags::default();
+
+    validate_connections(&program, &connection_interface, &feature_flags)
         .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
 
-    let next_program = transform_connections(&program, &connection_interface);
+    let next_program = transform_connections(&program, &connection_interface, &feature_flags)
+        .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
 
     let mut printed = next_program
         .operations()
@@ -0,0 +1,36 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_ir::{build, Program};
+use graphql_syntax::parse_executable;
+use graphql_test_helpers::diagnostics_to_sorted_string;
+use relay_codegen::print_fragment;
+use relay_test_schema::get_test_schema;
+use relay_transforms::handle_field_transform;
+use std::sync::Arc;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let schema = get_test_schema();
+    let ast = parse_executable(
+        fixture.content,
+        SourceLocationKey::standalone(fixture.file_name),
+    )
+    .unwrap();
+    let ir = build(&schema, &ast.definitions)
+        .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
+    let program = Program::from_definitions(Arc::clone(&schema), ir);
+
+    let keep_original_field = fixture.content.contains("%keep_original_field%");
+    let next_program = handle_field_transform(&program, keep_original_field);
+    Ok(next_program
+        .fragments()
+        .map(|def| print_fragment(&schema, def))
+        .collect::<Vec<_>>()
+        .join("\n\n"))
+}
@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<1e9a31f0d6d04e0757e5587c8ba59f00>>
+ */
+
+mod handle_field_codegen;
+
+use handle_field_codegen::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn handle_field() {
+    let input = include_str!("handle_field_codegen/fixtures/handle-field.graphql");
+    let expected = include_str!("handle_field_codegen/fixtures/handle-field.expected");
+    test_fixture(transform_fixture, "handle-field.graphql", "handle_field_codegen/fixtures/handle-field.expected", input, expected);
+}
+
+#[test]
+fn keep_original_field() {
+    let input = include_str!("handle_field_codegen/fixtures/keep-original-field.graphql");
+    let expected = include_str!("handle_field_codegen/fixtures/keep-original-field.expected");
+    test_fixture(transform_fixture, "keep-original-field.graphql", "handle_field_codegen/fixtures/keep-original-field.expected", input, expected);
+}
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<60c1b1263e2d35528792da979dcebe3f>>
+ * @generated SignedSource<<8cbfa0d4564f37e2b918754de96a3e1e>>
  */
 
 mod json_codegen;
@@ -25,3 +25,10 @@ fn stable_literals() {
     let expected = include_str!("json_codegen/fixtures/stable-literals.expected");
     test_fixture(transform_fixture, "stable-literals.graphql", "json_codegen/fixtures/stable-literals.expected", input, expected);
 }
+
+#[test]
+fn storage_keys() {
+    let input = include_str!("json_codegen/fixtures/storage-keys.graphql");
+    let expected = include_str!("json_codegen/fixtures/storage-keys.expected");
+    test_fixture(transform_fixture, "storage-keys.graphql", "json_codegen/fixtures/storage-keys.expected", input, expected);
+}
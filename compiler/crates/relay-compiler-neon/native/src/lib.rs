@@ -13,10 +13,8 @@ use neon::prelude::*;
 use relay_codegen::Printer;
 use relay_compiler::{
     apply_transforms,
-    config::{Config, ProjectConfig, SchemaLocation},
-    generate_artifacts,
-    status_reporter::ConsoleStatusReporter,
-    ArtifactFileWriter, SourceHashes,
+    config::{Config, ProjectConfig},
+    generate_artifacts, SourceHashes,
 };
 use relay_transforms::{ConnectionInterface, FeatureFlags};
 use schema::build_schema;
@@ -76,46 +74,9 @@ fn create_configs() -> (Config, ProjectConfig) {
     // TODO: Handle configuration from JS
     let project_config = ProjectConfig {
         name: "relay_compiler".intern(),
-        base: None,
-        output: None,
-        extra_artifacts_output: None,
-        shard_output: false,
-        shard_strip_regex: None,
-        extensions: vec![],
-        enabled: true,
-        schema_location: SchemaLocation::File(Default::default()),
-        typegen_config: Default::default(),
-        persist: None,
-        variable_names_comment: false,
-        extra: None,
-        feature_flags: Default::default(),
-        rollout: Default::default(),
+        ..Default::default()
     };
-
-    let config = Config {
-        name: None,
-        artifact_writer: Box::new(ArtifactFileWriter::default()),
-        codegen_command: None,
-        excludes: vec![],
-        generate_extra_operation_artifacts: None,
-        header: vec![],
-        load_saved_state_file: None,
-        projects: Default::default(),
-        root_dir: Default::default(),
-        sources: Default::default(),
-        saved_state_config: None,
-        saved_state_loader: None,
-        saved_state_version: "0".to_owned(),
-        connection_interface: Default::default(),
-        feature_flags: FeatureFlags::default(),
-        operation_persister: None,
-        compile_everything: false,
-        repersist_operations: false,
-        post_artifacts_write: None,
-        status_reporter: Box::new(ConsoleStatusReporter::new(Default::default())),
-    };
-
-    (config, project_config)
+    (Config::default(), project_config)
 }
 
 fn compile(mut cx: FunctionContext<'_>) -> JsResult<'_, JsObject> {
@@ -134,7 +95,7 @@ fn compile(mut cx: FunctionContext<'_>) -> JsResult<'_, JsObject> {
     let program = Program::from_definitions(schema_arc, ir);
 
     let programs = apply_transforms(
-        project_config.name,
+        &project_config,
         Arc::new(program),
         Arc::new(Default::default()),
         &ConnectionInterface::default(),
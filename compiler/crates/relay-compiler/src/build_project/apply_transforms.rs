@@ -5,11 +5,15 @@
  * LICENSE file in the root directory of this source tree.
  */
 
-use common::{DiagnosticsResult, PerfLogEvent, PerfLogger};
+use crate::config::ProjectConfig;
+use common::{Diagnostic, DiagnosticsResult, Location, PerfLogEvent, PerfLogger};
 use fnv::FnvHashSet;
-use graphql_ir::Program;
+use graphql_ir::{FragmentSpread, Program, ValidationMessage, Visitor};
 use interner::StringKey;
+use log::debug;
 use relay_transforms::*;
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
 use std::sync::Arc;
 
 #[derive(Debug)]
@@ -21,8 +25,59 @@ pub struct Programs {
     pub typegen: Arc<Program>,
 }
 
+/// Transforms that can be skipped with the `disabledTransforms` option of a
+/// project, to see the artifacts without them when debugging the compiler.
+/// A name skips the transform in every chain that applies it.
+pub const DISABLEABLE_TRANSFORMS: &[&str] = &[
+    "apply_fragment_argument_defaults",
+    "client_extensions",
+    "declarative_connection",
+    "generate_catch_metadata",
+    "generate_data_driven_dependency_metadata",
+    "generate_field_metadata",
+    "generate_id_field",
+    "generate_live_query_metadata",
+    "generate_subscription_name_metadata",
+    "generate_test_operation_metadata",
+    "generate_typename",
+    "handle_field_transform",
+    "inline_data_fragment",
+    "mask",
+    "react_flight",
+    "relay_actor_change",
+    "relay_early_flush",
+    "required_directive",
+    "skip_client_directives",
+    "skip_client_directives_for_server_text",
+    "skip_client_extensions",
+    "skip_redundant_nodes",
+    "skip_split_operation",
+    "skip_unused_variables",
+    "transform_connections",
+    "transform_defer_stream",
+    "transform_refetchable_fragment",
+];
+
+/// Transforms that other transforms or the code generation depend on, e.g.
+/// codegen expects conditions to be normalized and fragments to be flattened,
+/// split operations are generated from `@match` selections, base project
+/// fragments must not produce artifacts and the reader reads aliased fragment
+/// spreads like aliased inline fragments. These can't be disabled.
+pub const REQUIRED_TRANSFORMS: &[&str] = &[
+    "apply_fragment_arguments",
+    "flatten",
+    "inline_fragments",
+    "normalize_conditions",
+    "remove_base_fragments",
+    "skip_unreachable_node",
+    "split_module_import",
+    "transform_match",
+    "unwrap_custom_directive_selection",
+    "wrap_aliased_fragment_spreads",
+];
+
 pub fn apply_transforms<TPerfLogger>(
-    project_name: StringKey,
+    project_config: &ProjectConfig,
     program: Arc<Program>,
     base_fragment_names: Arc<FnvHashSet<StringKey>>,
     connection_interface: &ConnectionInterface,
@@ -47,7 +102,7 @@ where
     let (((normalization_program, text_program), reader_program), typegen_program) = try_join(
         || {
             let common_program = apply_common_transforms(
-                project_name,
+                project_config,
                 Arc::clone(&program),
                 connection_interface,
                 Arc::clone(&feature_flags),
@@ -58,7 +113,7 @@ where
             try_join(
                 || {
                     let operation_program = apply_operation_transforms(
-                        project_name,
+                        project_config,
                         Arc::clone(&common_program),
                         connection_interface,
                         Arc::clone(&base_fragment_names),
@@ -68,14 +123,14 @@ where
                     try_join(
                         || {
                             apply_normalization_transforms(
-                                project_name,
+                                project_config,
                                 Arc::clone(&operation_program),
                                 Arc::clone(&perf_logger),
                             )
                         },
                         || {
                             apply_operation_text_transforms(
-                                project_name,
+                                project_config,
                                 Arc::clone(&operation_program),
                                 Arc::clone(&perf_logger),
                             )
@@ -84,7 +139,7 @@ where
                 },
                 || {
                     apply_reader_transforms(
-                        project_name,
+                        project_config,
                         Arc::clone(&common_program),
                         Arc::clone(&feature_flags),
                         Arc::clone(&base_fragment_names),
@@ -95,7 +150,7 @@ where
         },
         || {
             apply_typegen_transforms(
-                project_name,
+                project_config,
                 Arc::clone(&program),
                 Arc::clone(&feature_flags),
                 Arc::clone(&base_fragment_names),
@@ -115,7 +170,7 @@ where
 
 /// Applies transforms that apply to every output.
 fn apply_common_transforms(
-    project_name: StringKey,
+    project_config: &ProjectConfig,
     program: Arc<Program>,
     connection_interface: &ConnectionInterface,
     feature_flags: Arc<FeatureFlags>,
@@ -131,20 +186,75 @@ fn apply_common_transforms(
     // + RefetchableFragmentTransform
     // + DeferStreamTransform
     let log_event = perf_logger.create_event("apply_common_transforms");
-    log_event.string("project", project_name.to_string());
-    let program = log_event.time("transform_connections", || {
-        transform_connections(&program, connection_interface)
-    });
-    let program = log_event.time("mask", || mask(&program));
-    let program = log_event.time("transform_defer_stream", || {
-        transform_defer_stream(&program)
+    log_event.string("project", project_config.name.to_string());
+    let program = time_transform(&log_event, "normalize_conditions", &program, |program| {
+        Ok(normalize_conditions(program))
     })?;
-    let program = log_event.time("transform_match", || transform_match(&program))?;
-    let program = log_event.time("transform_refetchable_fragment", || {
-        transform_refetchable_fragment(&program, &base_fragment_names, false)
+    let program = if feature_flags.enable_fragment_argument_defaults {
+        run_transform(
+            &log_event,
+            &project_config.disabled_transforms,
+            "apply_fragment_argument_defaults",
+            &program,
+            apply_fragment_argument_defaults,
+        )?
+    } else {
+        program
+    };
+    let program = try_run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "transform_connections",
+        &program,
+        |program| transform_connections(program, connection_interface, &feature_flags),
+    )?;
+    let program = time_transform(&log_event, "skip_unreachable_node", &program, |program| {
+        Ok(skip_unreachable_node_keeping_fragments(program))
     })?;
+    let program = run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "mask",
+        &program,
+        mask,
+    )?;
+    let program = try_run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "transform_defer_stream",
+        &program,
+        transform_defer_stream,
+    )?;
+    let program = time_transform(&log_event, "transform_match", &program, transform_match)?;
+    let program = try_run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "relay_actor_change",
+        &program,
+        |program| relay_actor_change_transform(program, &feature_flags),
+    )?;
+    let program = run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "generate_field_metadata",
+        &program,
+        |program| generate_field_metadata(program, &project_config.field_metadata_directives),
+    )?;
+    let program = try_run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "transform_refetchable_fragment",
+        &program,
+        |program| transform_refetchable_fragment(program, &base_fragment_names, false),
+    )?;
     let program = if feature_flags.enable_flight_transform {
-        log_event.time("react_flight", || react_flight(&program))?
+        try_run_transform(
+            &log_event,
+            &project_config.disabled_transforms,
+            "react_flight",
+            &program,
+            react_flight,
+        )?
     } else {
         program
     };
@@ -156,7 +266,7 @@ fn apply_common_transforms(
 /// Applies transforms only for generated reader code.
 /// Corresponds to the "fragment transforms" in the JS compiler.
 fn apply_reader_transforms(
-    project_name: StringKey,
+    project_config: &ProjectConfig,
     program: Arc<Program>,
     feature_flags: Arc<FeatureFlags>,
     base_fragment_names: Arc<FnvHashSet<StringKey>>,
@@ -169,24 +279,91 @@ fn apply_reader_transforms(
     // + FlattenTransform, flattenAbstractTypes: true
     // + SkipRedundantNodesTransform
     let log_event = perf_logger.create_event("apply_reader_transforms");
-    log_event.string("project", project_name.to_string());
-    let program = log_event.time("required_directive", || {
-        required_directive(&program, &feature_flags)
-    })?;
+    log_event.string("project", project_config.name.to_string());
+    let program = try_run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "required_directive",
+        &program,
+        |program| required_directive(program, &feature_flags),
+    )?;
+    let program = time_transform(
+        &log_event,
+        "wrap_aliased_fragment_spreads",
+        &program,
+        |program| Ok(wrap_aliased_fragment_spreads(program)),
+    )?;
 
-    let program = log_event.time("client_extensions", || client_extensions(&program));
-    let program = log_event.time("handle_field_transform", || {
-        handle_field_transform(&program)
-    });
-    let program = log_event.time("inline_data_fragment", || inline_data_fragment(&program))?;
-    let program = log_event.time("remove_base_fragments", || {
-        remove_base_fragments(&program, base_fragment_names)
-    });
-    let program = log_event.time("flatten", || flatten(&program, true))?;
-    let program = log_event.time("skip_redundant_nodes", || skip_redundant_nodes(&program));
-    let program = log_event.time("generate_data_driven_dependency_metadata", || {
-        generate_data_driven_dependency_metadata(&program)
-    });
+    let program = run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "client_extensions",
+        &program,
+        client_extensions,
+    )?;
+    let program = run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "handle_field_transform",
+        &program,
+        |program| handle_field_transform(program, project_config.keep_original_handle_fields),
+    )?;
+    let program = try_run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "inline_data_fragment",
+        &program,
+        inline_data_fragment,
+    )?;
+    let program = time_transform(&log_event, "remove_base_fragments", &program, |program| {
+        let (program, removed_fragment_names) =
+            remove_base_fragments_with_report(program, Arc::clone(&base_fragment_names));
+        if !removed_fragment_names.is_empty() {
+            debug!(
+                "[{}] removed base fragments: {}",
+                project_config.name,
+                removed_fragment_names
+                    .iter()
+                    .map(|name| name.lookup())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+        Ok(program)
+    })?;
+    let program = time_transform(&log_event, "flatten", &program, |program| {
+        flatten_preserving_inline_fragments(
+            program,
+            true,
+            feature_flags.preserve_reader_inline_fragment_depth,
+        )
+    })?;
+    let program = run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "skip_redundant_nodes",
+        &program,
+        |program| {
+            if feature_flags.preserve_reader_selection_order {
+                skip_redundant_nodes_in_source_order(
+                    program,
+                    &project_config.skip_redundant_nodes_preserved_directives,
+                )
+            } else {
+                skip_redundant_nodes(
+                    program,
+                    &project_config.skip_redundant_nodes_preserved_directives,
+                )
+            }
+        },
+    )?;
+    let program = run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "generate_data_driven_dependency_metadata",
+        &program,
+        generate_data_driven_dependency_metadata,
+    )?;
 
     perf_logger.complete_event(log_event);
 
@@ -196,7 +373,7 @@ fn apply_reader_transforms(
 /// Applies transforms that apply to all operation artifacts.
 /// Corresponds to the "query transforms" in the JS compiler.
 fn apply_operation_transforms(
-    project_name: StringKey,
+    project_config: &ProjectConfig,
     program: Arc<Program>,
     connection_interface: &ConnectionInterface,
     base_fragment_names: Arc<FnvHashSet<StringKey>>,
@@ -210,29 +387,60 @@ fn apply_operation_transforms(
     // + GenerateIDFieldTransform
     // * TestOperationTransform - part of relay_codegen
     let log_event = perf_logger.create_event("apply_operation_transforms");
-    log_event.string("project", project_name.to_string());
+    log_event.string("project", project_config.name.to_string());
 
-    let program = log_event.time("split_module_import", || {
-        split_module_import(&program, &base_fragment_names)
-    });
-    let program = log_event.time("apply_fragment_arguments", || {
-        apply_fragment_arguments(&program)
-    })?;
-    log_event.time("validate_global_variables", || {
-        validate_global_variables(&program)
-    })?;
-    let program = log_event.time("generate_id_field", || generate_id_field(&program));
-    let program = log_event.time("declarative_connection", || {
-        transform_declarative_connection(&program, connection_interface)
+    let program = time_transform(&log_event, "split_module_import", &program, |program| {
+        Ok(split_module_import(program, &base_fragment_names))
     })?;
+    let program = time_transform(
+        &log_event,
+        "apply_fragment_arguments",
+        &program,
+        apply_fragment_arguments,
+    )?;
+    time_transform(
+        &log_event,
+        "validate_global_variables",
+        &program,
+        validate_global_variables,
+    )?;
+    let program = run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "generate_id_field",
+        &program,
+        generate_id_field,
+    )?;
+    let program = try_run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "declarative_connection",
+        &program,
+        |program| transform_declarative_connection(program, connection_interface),
+    )?;
 
     // TODO(T67052528): execute FB-specific transforms only if config options is provided
-    let program = log_event.time("generate_subscription_name_metadata", || {
-        generate_subscription_name_metadata(&program)
-    })?;
-    let program = log_event.time("generate_live_query_metadata", || {
-        generate_live_query_metadata(&program)
-    })?;
+    let program = try_run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "generate_subscription_name_metadata",
+        &program,
+        generate_subscription_name_metadata,
+    )?;
+    let program = try_run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "generate_live_query_metadata",
+        &program,
+        generate_live_query_metadata,
+    )?;
+    let program = run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "generate_catch_metadata",
+        &program,
+        generate_catch_metadata,
+    )?;
 
     perf_logger.complete_event(log_event);
 
@@ -244,7 +452,7 @@ fn apply_operation_transforms(
 ///
 /// Corresponds to the "codegen transforms" in the JS compiler
 fn apply_normalization_transforms(
-    project_name: StringKey,
+    project_config: &ProjectConfig,
     program: Arc<Program>,
     perf_logger: Arc<impl PerfLogger>,
 ) -> DiagnosticsResult<Arc<Program>> {
@@ -256,18 +464,57 @@ fn apply_normalization_transforms(
     // + FlattenTransform, flattenAbstractTypes: true
     // + SkipRedundantNodesTransform
     let log_event = perf_logger.create_event("apply_normalization_transforms");
-    log_event.string("project", project_name.to_string());
-
-    let program = log_event.time("relay_early_flush", || relay_early_flush(&program))?;
-    let program = log_event.time("skip_unreachable_node", || skip_unreachable_node(&program));
-    let program = log_event.time("inline_fragments", || inline_fragments(&program));
-    let program = log_event.time("client_extensions", || client_extensions(&program));
-    let program = log_event.time("generate_typename", || generate_typename(&program, true));
-    let program = log_event.time("flatten", || flatten(&program, true))?;
-    let program = log_event.time("skip_redundant_nodes", || skip_redundant_nodes(&program));
-    let program = log_event.time("generate_test_operation_metadata", || {
-        generate_test_operation_metadata(&program)
-    });
+    log_event.string("project", project_config.name.to_string());
+
+    let program = try_run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "relay_early_flush",
+        &program,
+        relay_early_flush,
+    )?;
+    let program = time_transform(&log_event, "skip_unreachable_node", &program, |program| {
+        Ok(skip_unreachable_node(program))
+    })?;
+    let program = time_transform(&log_event, "inline_fragments", &program, |program| {
+        Ok(inline_fragments(program))
+    })?;
+    let program = run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "client_extensions",
+        &program,
+        client_extensions,
+    )?;
+    let program = run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "generate_typename",
+        &program,
+        |program| generate_typename(program, true, project_config.skip_selected_typename),
+    )?;
+    let program = time_transform(&log_event, "flatten", &program, |program| {
+        flatten(program, true)
+    })?;
+    let program = run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "skip_redundant_nodes",
+        &program,
+        |program| {
+            skip_redundant_nodes(
+                program,
+                &project_config.skip_redundant_nodes_preserved_directives,
+            )
+        },
+    )?;
+    let program = run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "generate_test_operation_metadata",
+        &program,
+        generate_test_operation_metadata,
+    )?;
 
     perf_logger.complete_event(log_event);
 
@@ -279,7 +526,7 @@ fn apply_normalization_transforms(
 ///
 /// Corresponds to the "print transforms" in the JS compiler
 fn apply_operation_text_transforms(
-    project_name: StringKey,
+    project_config: &ProjectConfig,
     program: Arc<Program>,
     perf_logger: Arc<impl PerfLogger>,
 ) -> DiagnosticsResult<Arc<Program>> {
@@ -295,33 +542,84 @@ fn apply_operation_text_transforms(
     // + SkipUnusedVariablesTransform
     // + ValidateRequiredArgumentsTransform
     let log_event = perf_logger.create_event("apply_operation_text_transforms");
-    log_event.string("project", project_name.to_string());
+    log_event.string("project", project_config.name.to_string());
 
-    let program = log_event.time("relay_early_flush", || relay_early_flush(&program))?;
-    let program = log_event.time("skip_split_operation", || skip_split_operation(&program));
-    let program = log_event.time("skip_client_extensions", || {
-        skip_client_extensions(&program)
-    });
-    let program = log_event.time("skip_unreachable_node", || skip_unreachable_node(&program));
-    let program = log_event.time("generate_typename", || generate_typename(&program, false));
-    let program = log_event.time("flatten", || flatten(&program, false))?;
-    let program = log_event.time("skip_unused_variables", || skip_unused_variables(&program));
-    let program = log_event.time("skip_client_directives", || {
-        skip_client_directives(&program)
-    });
-    log_event.time("validate_required_arguments", || {
-        validate_required_arguments(&program)
+    let program = try_run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "relay_early_flush",
+        &program,
+        relay_early_flush,
+    )?;
+    let program = run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "skip_split_operation",
+        &program,
+        skip_split_operation,
+    )?;
+    let program = run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "skip_client_extensions",
+        &program,
+        skip_client_extensions,
+    )?;
+    let program = time_transform(&log_event, "skip_unreachable_node", &program, |program| {
+        Ok(skip_unreachable_node(program))
     })?;
-    let program = log_event.time("unwrap_custom_directive_selection", || {
-        unwrap_custom_directive_selection(&program)
-    });
+    let program = run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "generate_typename",
+        &program,
+        |program| generate_typename(program, false, project_config.skip_selected_typename),
+    )?;
+    let program = time_transform(&log_event, "flatten", &program, |program| {
+        flatten(program, false)
+    })?;
+    let program = run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "skip_unused_variables",
+        &program,
+        skip_unused_variables,
+    )?;
+    let program = run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "skip_client_directives",
+        &program,
+        |program| {
+            skip_client_directives(program, &project_config.operation_text_excluded_directives)
+        },
+    )?;
+    time_transform(
+        &log_event,
+        "validate_required_arguments",
+        &program,
+        validate_required_arguments,
+    )?;
+    let program = time_transform(
+        &log_event,
+        "unwrap_custom_directive_selection",
+        &program,
+        |program| Ok(unwrap_custom_directive_selection(program)),
+    )?;
+    let program = run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "skip_client_directives_for_server_text",
+        &program,
+        skip_client_directives_for_server_text,
+    )?;
     perf_logger.complete_event(log_event);
 
     Ok(Arc::new(program))
 }
 
 fn apply_typegen_transforms(
-    project_name: StringKey,
+    project_config: &ProjectConfig,
     program: Arc<Program>,
     feature_flags: Arc<FeatureFlags>,
     base_fragment_names: Arc<FnvHashSet<StringKey>>,
@@ -334,25 +632,208 @@ fn apply_typegen_transforms(
     // + FlattenTransform, flattenAbstractTypes: false
     // + RefetchableFragmentTransform,
     let log_event = perf_logger.create_event("apply_typegen_transforms");
-    log_event.string("project", project_name.to_string());
+    log_event.string("project", project_config.name.to_string());
 
-    let program = log_event.time("mask", || mask(&program));
-    let program = log_event.time("transform_match", || transform_match(&program))?;
-    let program = log_event.time("required_directive", || {
-        required_directive(&program, &feature_flags)
+    let program = time_transform(&log_event, "skip_unreachable_node", &program, |program| {
+        Ok(skip_unreachable_node_keeping_fragments(program))
     })?;
-    let program = log_event.time("flatten", || flatten(&program, false))?;
-    let program = log_event.time("transform_refetchable_fragment", || {
-        transform_refetchable_fragment(&program, &base_fragment_names, true)
+    let program = run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "mask",
+        &program,
+        mask,
+    )?;
+    let program = time_transform(&log_event, "transform_match", &program, transform_match)?;
+    let program = try_run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "required_directive",
+        &program,
+        |program| required_directive(program, &feature_flags),
+    )?;
+    // The reader artifacts read the kept original fields, they need types too.
+    let program = if project_config.keep_original_handle_fields {
+        run_transform(
+            &log_event,
+            &project_config.disabled_transforms,
+            "handle_field_transform",
+            &program,
+            |program| handle_field_transform(program, true),
+        )?
+    } else {
+        program
+    };
+    let program = time_transform(&log_event, "flatten", &program, |program| {
+        flatten(program, false)
+    })?;
+    let program = try_run_transform(
+        &log_event,
+        &project_config.disabled_transforms,
+        "transform_refetchable_fragment",
+        &program,
+        |program| transform_refetchable_fragment(program, &base_fragment_names, true),
+    )?;
+    let program = time_transform(&log_event, "remove_base_fragments", &program, |program| {
+        Ok(remove_base_fragments(
+            program,
+            Arc::clone(&base_fragment_names),
+        ))
     })?;
-    let program = log_event.time("remove_base_fragments", || {
-        remove_base_fragments(&program, base_fragment_names)
-    });
     perf_logger.complete_event(log_event);
 
     Ok(Arc::new(program))
 }
 
+/// Times the transform, or skips it and keeps the program as is if it's one of
+/// the `disabled_transforms`.
+fn run_transform(
+    log_event: &impl PerfLogEvent,
+    disabled_transforms: &[String],
+    name: &'static str,
+    program: &Program,
+    transform: impl Fn(&Program) -> Program,
+) -> DiagnosticsResult<Program> {
+    debug_assert!(DISABLEABLE_TRANSFORMS.contains(&name));
+    if disabled_transforms.iter().any(|disabled| disabled == name) {
+        Ok(program.clone())
+    } else {
+        time_transform(log_event, name, program, |program| Ok(transform(program)))
+    }
+}
+
+/// Like `run_transform`, for transforms that can fail.
+fn try_run_transform(
+    log_event: &impl PerfLogEvent,
+    disabled_transforms: &[String],
+    name: &'static str,
+    program: &Program,
+    transform: impl Fn(&Program) -> DiagnosticsResult<Program>,
+) -> DiagnosticsResult<Program> {
+    debug_assert!(DISABLEABLE_TRANSFORMS.contains(&name));
+    if disabled_transforms.iter().any(|disabled| disabled == name) {
+        Ok(program.clone())
+    } else {
+        time_transform(log_event, name, program, transform)
+    }
+}
+
+/// Times the transform of the program, reporting a panic in it as an error of
+/// the build instead of crashing the compiler. Transforms expect validated
+/// documents and may panic on input that slipped through validation.
+fn time_transform<T>(
+    log_event: &impl PerfLogEvent,
+    name: &'static str,
+    program: &Program,
+    transform: impl Fn(&Program) -> DiagnosticsResult<T>,
+) -> DiagnosticsResult<T> {
+    let result = log_event.time(name, || {
+        panic::catch_unwind(AssertUnwindSafe(|| transform(program)))
+    });
+    match result {
+        Ok(result) => result,
+        Err(payload) => {
+            log_event.string("panicked_transform", name.to_string());
+            Err(vec![Diagnostic::error(
+                ValidationMessage::TransformPanicked {
+                    transform_name: name,
+                    message: panic_message(payload.as_ref()),
+                },
+                find_panicking_definition(program, &transform).unwrap_or_else(Location::generated),
+            )])
+        }
+    }
+}
+
+/// Finds the definition a transform panicked on by transforming each
+/// definition on its own, with the fragments it spreads. Transforms may work on
+/// the definitions in parallel, so the panic doesn't tell which one it was.
+/// A definition spreading the fragment that panics panics too, the fragment is
+/// the one that doesn't spread another panicking definition.
+fn find_panicking_definition<T>(
+    program: &Program,
+    transform: &impl Fn(&Program) -> DiagnosticsResult<T>,
+) -> Option<Location> {
+    let operations = program.operations().map(|operation| {
+        let mut definition_program = Program::new(Arc::clone(&program.schema));
+        definition_program.insert_operation(Arc::clone(operation));
+        let mut spreads = FragmentSpreads::new(program);
+        spreads.visit_operation(operation);
+        (operation.name, definition_program, spreads)
+    });
+    let fragments = program.fragments().map(|fragment| {
+        let mut definition_program = Program::new(Arc::clone(&program.schema));
+        definition_program.insert_fragment(Arc::clone(fragment));
+        let mut spreads = FragmentSpreads::new(program);
+        spreads.visit_fragment(fragment);
+        (fragment.name, definition_program, spreads)
+    });
+    let panicking_definitions = operations
+        .chain(fragments)
+        .filter_map(|(name, mut definition_program, spreads)| {
+            for fragment_name in &spreads.fragment_names {
+                if let Some(fragment) = program.fragment(*fragment_name) {
+                    definition_program.insert_fragment(Arc::clone(fragment));
+                }
+            }
+            if panic::catch_unwind(AssertUnwindSafe(|| transform(&definition_program))).is_err() {
+                Some((name, spreads.fragment_names))
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+    panicking_definitions
+        .iter()
+        .find(|(_, fragment_names)| {
+            !panicking_definitions
+                .iter()
+                .any(|(other_name, _)| fragment_names.contains(&other_name.item))
+        })
+        .map(|(name, _)| name.location)
+}
+
+/// Collects the names of the fragments a definition spreads, directly or
+/// through other fragments.
+struct FragmentSpreads<'program> {
+    program: &'program Program,
+    fragment_names: FnvHashSet<StringKey>,
+}
+
+impl<'program> FragmentSpreads<'program> {
+    fn new(program: &'program Program) -> Self {
+        Self {
+            program,
+            fragment_names: Default::default(),
+        }
+    }
+}
+
+impl Visitor for FragmentSpreads<'_> {
+    const NAME: &'static str = "FragmentSpreads";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_fragment_spread(&mut self, spread: &FragmentSpread) {
+        if self.fragment_names.insert(spread.fragment.item) {
+            if let Some(fragment) = self.program.fragment(spread.fragment.item) {
+                self.visit_fragment(fragment);
+            }
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    let message = if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.as_str()
+    } else {
+        "unknown error"
+    };
+    message.trim_end_matches('.').to_string()
+}
+
 fn try_join<T1, F1, T2, F2, E>(f1: F1, f2: F2) -> Result<(T1, T2), Vec<E>>
 where
     F1: FnOnce() -> Result<T1, Vec<E>> + Send,
@@ -364,3 +845,85 @@ where
     let (v1, v2) = rayon::join(f1, f2);
     Ok((v1?, v2?))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{NoopPerfLoggerEvent, SourceLocationKey};
+    use graphql_ir::build;
+    use graphql_syntax::parse_executable;
+    use interner::Intern;
+    use rayon::prelude::*;
+    use relay_test_schema::get_test_schema;
+
+    #[test]
+    fn reports_panicking_transforms_as_errors() {
+        let program = Program::new(get_test_schema());
+        let result: DiagnosticsResult<()> =
+            time_transform(&NoopPerfLoggerEvent, "transform_match", &program, |_| {
+                panic!("Expected to have a fragment spread")
+            });
+        let errors = result.unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].message().to_string(),
+            "The `transform_match` transform failed unexpectedly: Expected to have a fragment spread. This is a bug in the compiler, please report it with the documents that cause it."
+        );
+    }
+
+    #[test]
+    fn keeps_errors_of_transforms() {
+        let program = Program::new(get_test_schema());
+        let result: DiagnosticsResult<()> =
+            time_transform(&NoopPerfLoggerEvent, "flatten", &program, |_| {
+                Err(vec![Diagnostic::error(
+                    ValidationMessage::DuplicateVariable {
+                        name: "id".intern(),
+                    },
+                    Location::generated(),
+                )])
+            });
+        assert_eq!(
+            result.unwrap_err()[0].message().to_string(),
+            "Duplicate variable `id`"
+        );
+    }
+
+    #[test]
+    fn reports_panics_in_parallel_transforms_at_the_definition() {
+        let schema = get_test_schema();
+        let ast = parse_executable(
+            "query PanicQuery { me { ...PanicSpreading_user } }
+            fragment PanicSpreading_user on User { ...Panic_user }
+            fragment Panic_user on User { name }",
+            SourceLocationKey::standalone("Panic.graphql"),
+        )
+        .unwrap();
+        let program = Program::from_definitions(
+            Arc::clone(&schema),
+            build(&schema, &ast.definitions).unwrap(),
+        );
+        let errors = time_transform(&NoopPerfLoggerEvent, "panic", &program, |program| {
+            program.par_fragments().for_each(|fragment| {
+                if fragment.name.item == "Panic_user".intern() {
+                    panic!("Unexpected fragment.");
+                }
+            });
+            Ok(())
+        })
+        .unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].message().to_string(),
+            "The `panic` transform failed unexpectedly: Unexpected fragment. This is a bug in the compiler, please report it with the documents that cause it."
+        );
+        assert_eq!(
+            errors[0].location(),
+            program
+                .fragment("Panic_user".intern())
+                .unwrap()
+                .name
+                .location
+        );
+    }
+}
@@ -6,15 +6,15 @@
  */
 
 use super::is_operation_preloadable;
-use crate::config::{Config, ProjectConfig};
+use super::typegen_cache::{fragment_shape_hash, operation_shape_hash};
+use crate::config::{Config, PersistConfig, ProjectConfig};
 use common::NamedItem;
 use graphql_ir::{Directive, FragmentDefinition, OperationDefinition};
-use relay_codegen::{build_request_params, Printer};
+use relay_codegen::{build_request_params, Printer, RequestParameters};
 use relay_transforms::{
     DATA_DRIVEN_DEPENDENCY_METADATA_KEY, INLINE_DATA_CONSTANTS,
     REACT_FLIGHT_LOCAL_COMPONENTS_METADATA_ARG_KEY, REACT_FLIGHT_LOCAL_COMPONENTS_METADATA_KEY,
 };
-use relay_typegen::generate_fragment_type;
 use schema::Schema;
 use signedsource::{sign_file, SIGNING_TOKEN};
 use std::fmt::{Result, Write};
@@ -28,6 +28,9 @@ pub enum ArtifactContent {
         source_hash: String,
         text: String,
         id_and_text_hash: Option<(String, String)>,
+        /// The id is the text hash, used in place of a persist id when
+        /// persisting isn't possible, see `persistOfflineFallback`.
+        is_local_id: bool,
     },
     Fragment {
         reader_fragment: Arc<FragmentDefinition>,
@@ -38,9 +41,21 @@ pub enum ArtifactContent {
         normalization_operation: Arc<OperationDefinition>,
         source_hash: String,
     },
+    OperationTypeDeclaration {
+        normalization_operation: Arc<OperationDefinition>,
+        typegen_operation: Arc<OperationDefinition>,
+    },
+    FragmentTypeDeclaration {
+        typegen_fragment: Arc<FragmentDefinition>,
+    },
     Generic {
         content: Vec<u8>,
     },
+    /// The operations, split operations and fragments of a project merged
+    /// into a single module, see `ProjectConfig::bundle`.
+    Bundle {
+        contents: Vec<ArtifactContent>,
+    },
 }
 
 impl ArtifactContent {
@@ -59,6 +74,7 @@ impl ArtifactContent {
                 source_hash,
                 text,
                 id_and_text_hash,
+                is_local_id,
             } => generate_operation(
                 config,
                 project_config,
@@ -70,6 +86,7 @@ impl ArtifactContent {
                 source_hash.into(),
                 text,
                 id_and_text_hash,
+                *is_local_id,
             ),
             ArtifactContent::SplitOperation {
                 normalization_operation,
@@ -94,11 +111,107 @@ impl ArtifactContent {
                 typegen_fragment,
                 source_hash,
             ),
+            ArtifactContent::OperationTypeDeclaration {
+                normalization_operation,
+                typegen_operation,
+            } => generate_type_declaration(
+                config,
+                &generate_operation_types(
+                    config,
+                    project_config,
+                    schema,
+                    typegen_operation,
+                    normalization_operation,
+                    true,
+                ),
+            ),
+            ArtifactContent::FragmentTypeDeclaration { typegen_fragment } => {
+                generate_type_declaration(
+                    config,
+                    &generate_fragment_types(
+                        config,
+                        project_config,
+                        schema,
+                        typegen_fragment,
+                        true,
+                    ),
+                )
+            }
             ArtifactContent::Generic { content } => content.clone(),
+            ArtifactContent::Bundle { contents } => {
+                generate_bundle(config, project_config, printer, schema, contents)
+            }
         }
     }
 }
 
+/// Generates the types of an operation, inline in the artifact or as the
+/// content of a declaration file, reusing the types of previous builds if
+/// the shape of the operation is unchanged.
+fn generate_operation_types(
+    config: &Config,
+    project_config: &ProjectConfig,
+    schema: &Schema,
+    typegen_operation: &OperationDefinition,
+    normalization_operation: &OperationDefinition,
+    is_declaration: bool,
+) -> String {
+    config.typegen_cache.get_or_generate(
+        project_config.name,
+        typegen_operation.name.item,
+        is_declaration,
+        operation_shape_hash(schema, typegen_operation, normalization_operation),
+        || {
+            if is_declaration {
+                relay_typegen::generate_operation_type_declaration(
+                    typegen_operation,
+                    normalization_operation,
+                    schema,
+                    &project_config.typegen_config,
+                )
+            } else {
+                relay_typegen::generate_operation_type(
+                    typegen_operation,
+                    normalization_operation,
+                    schema,
+                    &project_config.typegen_config,
+                )
+            }
+        },
+    )
+}
+
+/// Same as `generate_operation_types` for fragments.
+fn generate_fragment_types(
+    config: &Config,
+    project_config: &ProjectConfig,
+    schema: &Schema,
+    typegen_fragment: &FragmentDefinition,
+    is_declaration: bool,
+) -> String {
+    config.typegen_cache.get_or_generate(
+        project_config.name,
+        typegen_fragment.name.item,
+        is_declaration,
+        fragment_shape_hash(schema, typegen_fragment),
+        || {
+            if is_declaration {
+                relay_typegen::generate_fragment_type_declaration(
+                    typegen_fragment,
+                    schema,
+                    &project_config.typegen_config,
+                )
+            } else {
+                relay_typegen::generate_fragment_type(
+                    typegen_fragment,
+                    schema,
+                    &project_config.typegen_config,
+                )
+            }
+        },
+    )
+}
+
 fn write_data_driven_dependency_annotation(
     content: &mut String,
     data_driven_dependency_directive: &Directive,
@@ -146,27 +259,37 @@ fn write_flight_annotation(content: &mut String, flight_directive: &Directive) -
     Ok(())
 }
 
-#[allow(clippy::too_many_arguments)]
-fn generate_operation(
-    config: &Config,
+/// Returns the request parameters, the fragment for the reader AST and the
+/// hash of the text for persisted operations. The text is omitted from the
+/// parameters if the project writes it to a separate file, if the operation
+/// is persisted and its id isn't a local placeholder, or if the project is
+/// `persisted_only`.
+fn build_request(
     project_config: &ProjectConfig,
-    printer: &mut Printer,
-    schema: &Schema,
     normalization_operation: &OperationDefinition,
     reader_operation: &OperationDefinition,
-    typegen_operation: &OperationDefinition,
-    source_hash: String,
     text: &str,
     id_and_text_hash: &Option<(String, String)>,
-) -> Vec<u8> {
-    let mut request_parameters = build_request_params(&normalization_operation);
-    let operation_hash: Option<String> = if let Some((id, text_hash)) = id_and_text_hash {
+    is_local_id: bool,
+) -> (RequestParameters, FragmentDefinition, Option<String>) {
+    let mut request_parameters = build_request_params(normalization_operation);
+    let operation_hash = id_and_text_hash.as_ref().map(|(id, text_hash)| {
         request_parameters.id = Some(id.clone());
-        Some(text_hash.clone())
-    } else {
+        text_hash.clone()
+    });
+    let is_persisted_only = matches!(
+        project_config.persist,
+        Some(PersistConfig {
+            persisted_only: true,
+            ..
+        })
+    );
+    if (operation_hash.is_none() || is_local_id)
+        && !project_config.operation_text_files
+        && !is_persisted_only
+    {
         request_parameters.text = Some(text.into());
-        None
-    };
+    }
     let operation_fragment = FragmentDefinition {
         name: reader_operation.name,
         variable_definitions: reader_operation.variable_definitions.clone(),
@@ -175,6 +298,31 @@ fn generate_operation(
         directives: reader_operation.directives.clone(),
         type_condition: reader_operation.type_,
     };
+    (request_parameters, operation_fragment, operation_hash)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn generate_operation(
+    config: &Config,
+    project_config: &ProjectConfig,
+    printer: &mut Printer,
+    schema: &Schema,
+    normalization_operation: &OperationDefinition,
+    reader_operation: &OperationDefinition,
+    typegen_operation: &OperationDefinition,
+    source_hash: String,
+    text: &str,
+    id_and_text_hash: &Option<(String, String)>,
+    is_local_id: bool,
+) -> Vec<u8> {
+    let (request_parameters, operation_fragment, operation_hash) = build_request(
+        project_config,
+        normalization_operation,
+        reader_operation,
+        text,
+        id_and_text_hash,
+        is_local_id,
+    );
     let mut content = get_content_start(config);
     writeln!(content, " * {}", SIGNING_TOKEN).unwrap();
     if let Some(operation_hash) = operation_hash {
@@ -190,7 +338,13 @@ fn generate_operation(
     writeln!(content, "/* eslint-disable */\n").unwrap();
     writeln!(content, "'use strict';\n").unwrap();
     if let Some(id) = &request_parameters.id {
-        writeln!(content, "// @relayRequestID {}", id).unwrap();
+        // A local id isn't picked up as a persist id by the next build, so
+        // the operation is persisted once that's possible again.
+        if is_local_id {
+            writeln!(content, "// @relayLocalRequestID {}", id).unwrap();
+        } else {
+            writeln!(content, "// @relayRequestID {}", id).unwrap();
+        }
     }
     if project_config.variable_names_comment {
         write!(content, "// @relayVariables").unwrap();
@@ -220,11 +374,13 @@ fn generate_operation(
     writeln!(
         content,
         "/*::\nimport type {{ ConcreteRequest }} from 'relay-runtime';\n{}*/\n",
-        relay_typegen::generate_operation_type(
+        generate_operation_types(
+            config,
+            project_config,
+            schema,
             typegen_operation,
             normalization_operation,
-            schema,
-            &project_config.typegen_config,
+            false,
         )
     )
     .unwrap();
@@ -339,7 +495,7 @@ fn generate_fragment(
         content,
         "/*::\nimport type {{ {} }} from 'relay-runtime';\n{}*/\n",
         reader_node_flow_type,
-        generate_fragment_type(typegen_fragment, schema, &project_config.typegen_config)
+        generate_fragment_types(config, project_config, schema, typegen_fragment, false)
     )
     .unwrap();
     writeln!(
@@ -356,6 +512,126 @@ fn generate_fragment(
     sign_file(&content).into_bytes()
 }
 
+/// Generates a module exporting an object with the nodes of all contents,
+/// keyed by the name of their definition.
+fn generate_bundle(
+    config: &Config,
+    project_config: &ProjectConfig,
+    printer: &mut Printer,
+    schema: &Schema,
+    contents: &[ArtifactContent],
+) -> Vec<u8> {
+    let mut nodes = contents
+        .iter()
+        .map(|content| match content {
+            ArtifactContent::Operation {
+                normalization_operation,
+                reader_operation,
+                source_hash,
+                text,
+                id_and_text_hash,
+                is_local_id,
+                ..
+            } => {
+                let (request_parameters, operation_fragment, _) = build_request(
+                    project_config,
+                    normalization_operation,
+                    reader_operation,
+                    text,
+                    id_and_text_hash,
+                    *is_local_id,
+                );
+                (
+                    normalization_operation.name.item,
+                    printer.print_request(
+                        schema,
+                        normalization_operation,
+                        &operation_fragment,
+                        request_parameters,
+                    ),
+                    source_hash,
+                    is_operation_preloadable(normalization_operation),
+                )
+            }
+            ArtifactContent::SplitOperation {
+                normalization_operation,
+                source_hash,
+            } => (
+                normalization_operation.name.item,
+                printer.print_operation(schema, normalization_operation),
+                source_hash,
+                false,
+            ),
+            ArtifactContent::Fragment {
+                reader_fragment,
+                source_hash,
+                ..
+            } => (
+                reader_fragment.name.item,
+                printer.print_fragment(schema, reader_fragment),
+                source_hash,
+                false,
+            ),
+            _ => panic!("Expected only operations and fragments in a bundle."),
+        })
+        .collect::<Vec<_>>();
+    nodes.sort_by_key(|(name, ..)| name.lookup());
+
+    let mut content = get_content_start(config);
+    writeln!(content, " * {}", SIGNING_TOKEN).unwrap();
+    writeln!(content, " * @flow").unwrap();
+    writeln!(content, " * @lightSyntaxTransform").unwrap();
+    writeln!(content, " * @nogrep").unwrap();
+    if let Some(codegen_command) = &config.codegen_command {
+        writeln!(content, " * @codegen-command: {}", codegen_command).unwrap();
+    }
+    writeln!(content, " */\n").unwrap();
+    writeln!(content, "/* eslint-disable */\n").unwrap();
+    writeln!(content, "'use strict';\n").unwrap();
+    writeln!(
+        content,
+        "/*::\nimport type {{ ConcreteRequest, NormalizationSplitOperation, ReaderFragment, ReaderInlineDataFragment }} from 'relay-runtime';\n\n\
+        type Node = ConcreteRequest | NormalizationSplitOperation | ReaderFragment | ReaderInlineDataFragment;\n*/\n"
+    )
+    .unwrap();
+    writeln!(content, "var nodes/*: {{[name: string]: Node}}*/ = {{}};\n").unwrap();
+    for (name, node, source_hash, is_preloadable) in nodes {
+        writeln!(content, "nodes[\"{}\"] = {};\n", name, node).unwrap();
+        writeln!(content, "if (__DEV__) {{").unwrap();
+        writeln!(
+            content,
+            "  (nodes[\"{}\"]/*: any*/).hash = \"{}\";",
+            name, source_hash
+        )
+        .unwrap();
+        writeln!(content, "}}\n").unwrap();
+        if is_preloadable {
+            writeln!(
+                content,
+                "if (nodes[\"{0}\"].params.id != null) {{\n  require('relay-runtime').PreloadableQueryRegistry.set(nodes[\"{0}\"].params.id, nodes[\"{0}\"]);\n}}\n",
+                name
+            )
+            .unwrap();
+        }
+    }
+    writeln!(content, "module.exports = nodes;").unwrap();
+    sign_file(&content).into_bytes()
+}
+
+fn generate_type_declaration(config: &Config, types: &str) -> Vec<u8> {
+    let mut content = get_content_start(config);
+    writeln!(content, " * {}", SIGNING_TOKEN).unwrap();
+    writeln!(content, " * @lightSyntaxTransform").unwrap();
+    writeln!(content, " * @nogrep").unwrap();
+    if let Some(codegen_command) = &config.codegen_command {
+        writeln!(content, " * @codegen-command: {}", codegen_command).unwrap();
+    }
+    writeln!(content, " */\n").unwrap();
+    writeln!(content, "/* eslint-disable */\n").unwrap();
+    write!(content, "{}", types).unwrap();
+    sign_file(&content).into_bytes()
+}
+
 fn get_content_start(config: &Config) -> String {
     let mut content = String::new();
     writeln!(content, "/**").unwrap();
@@ -11,13 +11,23 @@ use serde::{Serialize, Serializer};
 use std::fs::{create_dir_all, File};
 use std::io;
 use std::io::prelude::*;
-use std::{path::PathBuf, sync::Mutex};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
 
 type BuildProjectResult = Result<(), BuildProjectError>;
 
+/// Destination of the generated artifacts. All reads, writes and deletions of
+/// artifacts by the compiler go through this trait, so artifacts can be kept
+/// somewhere else than on the file system, see `ArtifactMemoryWriter`.
 pub trait ArtifactWriter {
     fn write_if_changed(&self, path: PathBuf, content: Vec<u8>) -> BuildProjectResult;
     fn remove(&self, path: PathBuf) -> BuildProjectResult;
+    /// Returns the current content of an artifact, if it exists. Used to reuse
+    /// information from previously written artifacts, like persisted ids.
+    fn read(&self, path: &Path) -> Option<Vec<u8>>;
     fn finalize(&self) -> crate::errors::Result<()>;
 }
 
@@ -90,6 +100,10 @@ impl ArtifactWriter for ArtifactFileWriter {
         Ok(())
     }
 
+    fn read(&self, path: &Path) -> Option<Vec<u8>> {
+        std::fs::read(path).ok()
+    }
+
     fn finalize(&self) -> crate::errors::Result<()> {
         if let Some(source_control_fn) = self.source_control_fn {
             (source_control_fn)(&self.root_dir, &self.added, &self.removed)
@@ -179,6 +193,10 @@ impl ArtifactWriter for ArtifactDifferenceWriter {
         Ok(())
     }
 
+    fn read(&self, path: &Path) -> Option<Vec<u8>> {
+        std::fs::read(path).ok()
+    }
+
     fn finalize(&self) -> crate::errors::Result<()> {
         (|| {
             let mut file = File::create(&self.codegen_filepath)?;
@@ -191,6 +209,48 @@ impl ArtifactWriter for ArtifactDifferenceWriter {
     }
 }
 
+/// Keeps the artifacts in memory instead of writing them to disk, e.g. for
+/// builds in an environment without a file system.
+#[derive(Default)]
+pub struct ArtifactMemoryWriter {
+    files: Mutex<HashMap<PathBuf, Vec<u8>>>,
+}
+
+impl ArtifactMemoryWriter {
+    pub fn new(files: HashMap<PathBuf, Vec<u8>>) -> Self {
+        Self {
+            files: Mutex::new(files),
+        }
+    }
+
+    /// Returns a copy of all artifacts currently in memory.
+    pub fn files(&self) -> HashMap<PathBuf, Vec<u8>> {
+        self.files.lock().unwrap().clone()
+    }
+}
+
+impl ArtifactWriter for ArtifactMemoryWriter {
+    fn write_if_changed(&self, path: PathBuf, content: Vec<u8>) -> BuildProjectResult {
+        self.files.lock().unwrap().insert(path, content);
+        Ok(())
+    }
+
+    fn remove(&self, path: PathBuf) -> BuildProjectResult {
+        if self.files.lock().unwrap().remove(&path).is_none() {
+            info!("tried to delete already deleted file: {:?}", path);
+        }
+        Ok(())
+    }
+
+    fn read(&self, path: &Path) -> Option<Vec<u8>> {
+        self.files.lock().unwrap().get(path).cloned()
+    }
+
+    fn finalize(&self) -> crate::errors::Result<()> {
+        Ok(())
+    }
+}
+
 fn ensure_file_directory_exists(file_path: &PathBuf) -> io::Result<()> {
     if let Some(file_directory) = file_path.parent() {
         if !file_directory.exists() {
@@ -209,3 +269,27 @@ fn content_is_same(path: &PathBuf, content: &Vec<u8>) -> io::Result<bool> {
         Ok(false)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_writer() {
+        let writer = ArtifactMemoryWriter::default();
+        writer
+            .write_if_changed("a/Foo.graphql.js".into(), b"foo".to_vec())
+            .unwrap();
+        writer
+            .write_if_changed("a/Bar.graphql.js".into(), b"bar".to_vec())
+            .unwrap();
+        writer.remove("a/Bar.graphql.js".into()).unwrap();
+
+        assert_eq!(
+            writer.read(Path::new("a/Foo.graphql.js")),
+            Some(b"foo".to_vec())
+        );
+        assert_eq!(writer.read(Path::new("a/Bar.graphql.js")), None);
+        assert_eq!(writer.files().len(), 1);
+    }
+}
@@ -5,7 +5,7 @@
  * LICENSE file in the root directory of this source tree.
  */
 
-use crate::config::ProjectConfig;
+use crate::config::{Config, ProjectConfig};
 use crate::{compiler_state::SourceSetName, graphql_asts::GraphQLAsts};
 use common::Diagnostic;
 use dependency_analyzer::{get_reachable_ast, get_reachable_ir, ReachableAst};
@@ -42,6 +42,7 @@ impl SourceHashes {
 }
 
 pub fn build_ir(
+    config: &Config,
     project_config: &ProjectConfig,
     schema: &Schema,
     graphql_asts: &FnvHashMap<SourceSetName, GraphQLAsts>,
@@ -51,21 +52,20 @@ pub fn build_ir(
         .get(&project_config.name)
         .map(|asts| asts.asts.clone())
         .unwrap_or_default();
-    let (base_project_asts, base_definition_names) = match project_config.base {
-        Some(base_project_name) => {
-            let base_project_asts = graphql_asts
-                .get(&base_project_name)
+    let base_projects = config.base_projects(project_config.name);
+    let base_project_asts = base_projects
+        .iter()
+        .flat_map(|base_project_name| {
+            graphql_asts
+                .get(base_project_name)
                 .map(|asts| asts.asts.clone())
-                .unwrap_or_default();
-            let base_definition_names = base_project_asts
-                .iter()
-                // TODO(T64459085): Figure out what to do about unnamed (anonymous) operations
-                .filter_map(|definition| definition.name())
-                .collect::<FnvHashSet<_>>();
-            (base_project_asts, base_definition_names)
-        }
-        None => (Vec::new(), FnvHashSet::default()),
-    };
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+    let base_definition_names = base_project_asts
+        .iter()
+        .filter_map(|definition| definition.name())
+        .collect::<FnvHashSet<_>>();
 
     find_duplicates(&project_asts, &base_project_asts)?;
 
@@ -81,10 +81,10 @@ pub fn build_ir(
             .get(&project_config.name)
             .map(|asts| asts.pending_definition_names.clone())
             .unwrap_or_default();
-        if let Some(base_project_name) = project_config.base {
+        for base_project_name in &base_projects {
             reachable_names.extend(
                 graphql_asts
-                    .get(&base_project_name)
+                    .get(base_project_name)
                     .map(|asts| asts.pending_definition_names.clone())
                     .unwrap_or_default(),
             );
@@ -137,3 +137,155 @@ fn md5(data: &str) -> String {
     md5.input(data);
     hex::encode(md5.result())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::SourceLocationKey;
+    use graphql_ir::Program;
+    use graphql_syntax::parse_executable;
+    use interner::Intern;
+    use relay_test_schema::get_test_schema;
+    use relay_transforms::remove_base_fragments_with_report;
+    use std::sync::Arc;
+
+    fn asts(source: &str) -> GraphQLAsts {
+        GraphQLAsts {
+            asts: parse_executable(source, SourceLocationKey::standalone("test.graphql"))
+                .unwrap()
+                .definitions,
+            pending_definition_names: Default::default(),
+            removed_definition_names: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_build_ir_with_base_project_chain() {
+        let config = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src/a": "a",
+                        "src/b": "b",
+                        "src/c": "c"
+                    },
+                    "projects": {
+                        "a": {
+                            "schema": "schema.graphql"
+                        },
+                        "b": {
+                            "schema": "schema.graphql",
+                            "base": "a"
+                        },
+                        "c": {
+                            "schema": "schema.graphql",
+                            "base": "b"
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap();
+        let mut graphql_asts = FnvHashMap::default();
+        graphql_asts.insert("a".intern(), asts("fragment A_user on User { name }"));
+        graphql_asts.insert("b".intern(), asts("fragment B_user on User { ...A_user }"));
+        graphql_asts.insert("c".intern(), asts("query CQuery { me { ...B_user } }"));
+
+        let BuildIRResult {
+            ir,
+            base_fragment_names,
+            ..
+        } = build_ir(
+            &config,
+            &config.projects[&"c".intern()],
+            &get_test_schema(),
+            &graphql_asts,
+            false,
+        )
+        .unwrap();
+        let mut names = ir
+            .iter()
+            .map(|definition| match definition {
+                graphql_ir::ExecutableDefinition::Operation(operation) => operation.name.item,
+                graphql_ir::ExecutableDefinition::Fragment(fragment) => fragment.name.item,
+            })
+            .map(|name| name.lookup())
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        assert_eq!(names, vec!["A_user", "B_user", "CQuery"]);
+        assert_eq!(
+            base_fragment_names,
+            vec!["A_user".intern(), "B_user".intern()]
+                .into_iter()
+                .collect()
+        );
+    }
+
+    #[test]
+    fn test_remove_base_fragments_with_report() {
+        let config = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src/base": "base",
+                        "src/app": "app"
+                    },
+                    "projects": {
+                        "base": {
+                            "schema": "schema.graphql"
+                        },
+                        "app": {
+                            "schema": "schema.graphql",
+                            "base": "base"
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap();
+        let mut graphql_asts = FnvHashMap::default();
+        graphql_asts.insert(
+            "base".intern(),
+            asts(
+                "fragment Base_user on User { name ...Base_profile }
+                fragment Base_profile on User { id }
+                fragment Base_unused on User { id }",
+            ),
+        );
+        graphql_asts.insert(
+            "app".intern(),
+            asts(
+                "query AppQuery { me { ...App_user } }
+                fragment App_user on User { ...Base_user }",
+            ),
+        );
+
+        let BuildIRResult {
+            ir,
+            base_fragment_names,
+            ..
+        } = build_ir(
+            &config,
+            &config.projects[&"app".intern()],
+            &get_test_schema(),
+            &graphql_asts,
+            false,
+        )
+        .unwrap();
+        let program = Program::from_definitions(get_test_schema(), ir);
+        let (program, removed_fragment_names) =
+            remove_base_fragments_with_report(&program, Arc::new(base_fragment_names));
+
+        let mut removed_fragment_names = removed_fragment_names
+            .into_iter()
+            .map(|name| name.lookup())
+            .collect::<Vec<_>>();
+        removed_fragment_names.sort_unstable();
+        assert_eq!(removed_fragment_names, vec!["Base_profile", "Base_user"]);
+        let fragment_names = program
+            .fragments()
+            .map(|fragment| fragment.name.item.lookup())
+            .collect::<Vec<_>>();
+        assert_eq!(fragment_names, vec!["App_user"]);
+    }
+}
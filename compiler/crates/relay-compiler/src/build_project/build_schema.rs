@@ -6,18 +6,25 @@
  */
 
 use crate::compiler_state::CompilerState;
-use crate::config::ProjectConfig;
+use crate::config::{Config, ProjectConfig};
 use common::DiagnosticsResult;
 use schema::Schema;
 use std::sync::Arc;
 
 pub fn build_schema(
     compiler_state: &CompilerState,
+    config: &Config,
     project_config: &ProjectConfig,
 ) -> DiagnosticsResult<Arc<Schema>> {
+    let base_projects = config.base_projects(project_config.name);
     let schema = compiler_state.schema_cache.get(&project_config.name);
     match schema {
-        Some(schema) if !compiler_state.project_has_pending_schema_changes(project_config.name) => {
+        Some(schema)
+            if !compiler_state.project_has_pending_schema_changes(project_config.name)
+                && !base_projects.iter().any(|base_project_name| {
+                    compiler_state.project_has_pending_schema_changes(*base_project_name)
+                }) =>
+        {
             Ok(schema.clone())
         }
         _ => {
@@ -25,21 +32,20 @@ pub fn build_schema(
             if let Some(project_extensions) = compiler_state.extensions.get(&project_config.name) {
                 extensions.extend(project_extensions.get_sources());
             }
-            if let Some(base_project_name) = project_config.base {
+            for base_project_name in &base_projects {
                 if let Some(base_project_extensions) =
-                    compiler_state.extensions.get(&base_project_name)
+                    compiler_state.extensions.get(base_project_name)
                 {
                     extensions.extend(base_project_extensions.get_sources());
                 }
             }
-            let mut schema_sources = Vec::new();
-            schema_sources.extend(
-                compiler_state.schemas[&project_config.name]
-                    .get_sources()
-                    .into_iter()
-                    .map(String::as_str),
-            );
-            relay_schema::build_schema_with_extensions(&schema_sources, &extensions).map(Arc::new)
+            let schema_sources = compiler_state.schemas[&project_config.name].get_sources();
+            relay_schema::build_schema_with_extensions(
+                &schema_sources,
+                &extensions,
+                &project_config.schema_config,
+            )
+            .map(Arc::new)
         }
     }
 }
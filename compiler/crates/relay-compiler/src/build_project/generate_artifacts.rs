@@ -8,17 +8,20 @@
 use super::apply_transforms::Programs;
 pub use super::artifact_content::ArtifactContent;
 use super::build_ir::SourceHashes;
-use crate::config::ProjectConfig;
+use super::source_map::generate_source_map;
+use crate::config::{ArtifactPathStrategy, ProjectConfig};
 use crate::errors::BuildProjectError;
 use common::{NamedItem, SourceLocationKey};
+use fnv::FnvHashMap;
 use graphql_ir::{FragmentDefinition, OperationDefinition};
 use graphql_text_printer::print_full_operation;
 use interner::StringKey;
 use relay_transforms::{
     RefetchableDerivedFromMetadata, SplitOperationMetadata, DIRECTIVE_SPLIT_OPERATION,
 };
-use relay_typegen::TypegenLanguage;
-use std::path::PathBuf;
+use relay_typegen::{generate_mock_response, TypegenLanguage};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 /// Represents a generated output artifact.
@@ -110,9 +113,136 @@ pub fn generate_artifacts(
         ));
     }
 
+    separate_colliding_artifacts(project_config, &mut artifacts);
+
+    if project_config.typegen_config.declaration_files {
+        let declaration_artifacts: Vec<_> = artifacts
+            .iter()
+            .filter_map(generate_type_declaration_artifact)
+            .collect();
+        artifacts.extend(declaration_artifacts);
+    }
+
+    if project_config.typegen_config.mock_response_files {
+        let mock_response_artifacts: Vec<_> = artifacts
+            .iter()
+            .filter_map(|artifact| generate_mock_response_artifact(programs, artifact))
+            .collect();
+        artifacts.extend(mock_response_artifacts);
+    }
+
+    if project_config.operation_text_files {
+        let text_artifacts: Vec<_> = artifacts
+            .iter()
+            .filter_map(generate_operation_text_artifact)
+            .collect();
+        artifacts.extend(text_artifacts);
+    }
+
+    if project_config.source_maps {
+        let source_map_artifacts: Vec<_> = artifacts
+            .iter()
+            .filter_map(|artifact| generate_source_map_artifact(programs, artifact))
+            .collect();
+        artifacts.extend(source_map_artifacts);
+    }
+
     Ok(artifacts)
 }
 
+/// Merges the operation, split operation and fragment artifacts into a single
+/// artifact at the `bundle` path of the project. Other artifacts, like type
+/// declarations, are kept as separate files.
+pub fn bundle_artifacts(bundle_path: &Path, artifacts: Vec<Artifact>) -> Vec<Artifact> {
+    let mut source_definition_names = Vec::new();
+    let mut contents = Vec::new();
+    let mut other_artifacts = Vec::new();
+    for artifact in artifacts {
+        match artifact.content {
+            ArtifactContent::Operation { .. }
+            | ArtifactContent::SplitOperation { .. }
+            | ArtifactContent::Fragment { .. } => {
+                source_definition_names.extend(artifact.source_definition_names);
+                contents.push(artifact.content);
+            }
+            _ => other_artifacts.push(artifact),
+        }
+    }
+    source_definition_names.sort_by_key(|name| name.lookup());
+    source_definition_names.dedup();
+    other_artifacts.push(Artifact {
+        source_definition_names,
+        path: bundle_path.to_owned(),
+        content: ArtifactContent::Bundle { contents },
+        source_file: SourceLocationKey::Generated,
+    });
+    other_artifacts
+}
+
+/// Creates the artifact at `manifest_path` that maps the id of each persisted
+/// operation to its text, as JSON ordered by id. Operations without an id or
+/// with a local placeholder id aren't persisted and are left out.
+pub fn generate_persisted_queries_manifest(
+    manifest_path: &Path,
+    artifacts: &[Artifact],
+) -> Artifact {
+    let mut source_definition_names = Vec::new();
+    let mut manifest = BTreeMap::new();
+    for artifact in artifacts {
+        if let ArtifactContent::Operation {
+            text,
+            id_and_text_hash: Some((id, _)),
+            is_local_id: false,
+            ..
+        } = &artifact.content
+        {
+            source_definition_names.extend(artifact.source_definition_names.iter().copied());
+            manifest.insert(id.as_str(), text.as_str());
+        }
+    }
+    source_definition_names.sort_by_key(|name| name.lookup());
+    source_definition_names.dedup();
+    let mut content = serde_json::to_string_pretty(&manifest).unwrap();
+    content.push('\n');
+    Artifact {
+        source_definition_names,
+        path: manifest_path.to_owned(),
+        content: ArtifactContent::Generic {
+            content: content.into_bytes(),
+        },
+        source_file: SourceLocationKey::Generated,
+    }
+}
+
+/// With the centralized strategy, artifacts of different source files that
+/// would be written to the same path of the `output` directory are moved to a
+/// subdirectory mirroring their source directory, like with `shardOutput`.
+fn separate_colliding_artifacts(project_config: &ProjectConfig, artifacts: &mut [Artifact]) {
+    let output = match (
+        project_config.artifact_path_strategy,
+        &project_config.output,
+    ) {
+        (ArtifactPathStrategy::Centralized, Some(output)) if !project_config.shard_output => output,
+        _ => return,
+    };
+    let mut source_files_by_path: FnvHashMap<PathBuf, Vec<SourceLocationKey>> = Default::default();
+    for artifact in artifacts.iter() {
+        source_files_by_path
+            .entry(artifact.path.clone())
+            .or_default()
+            .push(artifact.source_file);
+    }
+    for artifact in artifacts.iter_mut() {
+        let is_colliding = source_files_by_path[&artifact.path]
+            .iter()
+            .any(|source_file| *source_file != artifact.source_file);
+        if is_colliding && artifact.path.parent() == Some(output) {
+            let file_name = artifact.path.file_name().unwrap().to_owned();
+            artifact.path = output.join(artifact.source_file.get_dir()).join(file_name);
+        }
+    }
+}
+
 fn generate_normalization_artifact<'a>(
     source_definition_name: StringKey,
     project_config: &ProjectConfig,
@@ -145,6 +275,7 @@ fn generate_normalization_artifact<'a>(
             source_hash,
             text,
             id_and_text_hash: None,
+            is_local_id: false,
         },
         source_file: normalization_operation.name.location.source_location(),
     })
@@ -177,6 +308,117 @@ fn generate_reader_artifact(
     }
 }
 
+/// Creates the `.d.ts` declaration artifact with the types of an operation or
+/// fragment artifact, next to that artifact.
+fn generate_type_declaration_artifact(artifact: &Artifact) -> Option<Artifact> {
+    let content = match &artifact.content {
+        ArtifactContent::Operation {
+            normalization_operation,
+            typegen_operation,
+            ..
+        } => ArtifactContent::OperationTypeDeclaration {
+            normalization_operation: Arc::clone(normalization_operation),
+            typegen_operation: Arc::clone(typegen_operation),
+        },
+        ArtifactContent::Fragment {
+            typegen_fragment, ..
+        } => ArtifactContent::FragmentTypeDeclaration {
+            typegen_fragment: Arc::clone(typegen_fragment),
+        },
+        _ => return None,
+    };
+    Some(Artifact {
+        source_definition_names: artifact.source_definition_names.clone(),
+        path: type_declaration_path(&artifact.path),
+        content,
+        source_file: artifact.source_file,
+    })
+}
+
+/// `Name.graphql.ts` -> `Name.graphql.types.d.ts`: a declaration file with
+/// the base name of the artifact would be shadowed by the artifact when
+/// resolving `./Name.graphql`.
+fn type_declaration_path(artifact_path: &Path) -> PathBuf {
+    artifact_path.with_extension("types.d.ts")
+}
+
+/// Creates the `.mock.json` file with an example response of an operation
+/// artifact, next to that artifact.
+fn generate_mock_response_artifact(programs: &Programs, artifact: &Artifact) -> Option<Artifact> {
+    if let ArtifactContent::Operation {
+        normalization_operation,
+        ..
+    } = &artifact.content
+    {
+        Some(Artifact {
+            source_definition_names: artifact.source_definition_names.clone(),
+            // `Name.graphql.js` -> `Name.graphql.mock.json`
+            path: artifact.path.with_extension("mock.json"),
+            content: ArtifactContent::Generic {
+                content: generate_mock_response(normalization_operation, &programs.source.schema)
+                    .into_bytes(),
+            },
+            source_file: artifact.source_file,
+        })
+    } else {
+        None
+    }
+}
+
+/// Creates the `.graphql` file with the text of an operation artifact, next to
+/// that artifact.
+fn generate_operation_text_artifact(artifact: &Artifact) -> Option<Artifact> {
+    if let ArtifactContent::Operation { text, .. } = &artifact.content {
+        Some(Artifact {
+            source_definition_names: artifact.source_definition_names.clone(),
+            // `Name.graphql.js` -> `Name.graphql`
+            path: artifact.path.with_extension(""),
+            content: ArtifactContent::Generic {
+                content: text.clone().into_bytes(),
+            },
+            source_file: artifact.source_file,
+        })
+    } else {
+        None
+    }
+}
+
+/// Creates the `.map` file with the source locations of an operation or
+/// fragment artifact, next to that artifact.
+fn generate_source_map_artifact(programs: &Programs, artifact: &Artifact) -> Option<Artifact> {
+    let schema = &programs.source.schema;
+    let source_map = match &artifact.content {
+        ArtifactContent::Operation {
+            reader_operation, ..
+        } => generate_source_map(
+            schema,
+            reader_operation.name.item,
+            reader_operation.name.location,
+            &reader_operation.selections,
+        ),
+        ArtifactContent::Fragment {
+            reader_fragment, ..
+        } => generate_source_map(
+            schema,
+            reader_fragment.name.item,
+            reader_fragment.name.location,
+            &reader_fragment.selections,
+        ),
+        _ => return None,
+    };
+    let mut path = artifact.path.clone().into_os_string();
+    path.push(".map");
+    Some(Artifact {
+        source_definition_names: artifact.source_definition_names.clone(),
+        // `Name.graphql.js` -> `Name.graphql.js.map`
+        path: path.into(),
+        content: ArtifactContent::Generic {
+            content: source_map.into_bytes(),
+        },
+        source_file: artifact.source_file,
+    })
+}
+
 /// This function will create a correct path for artifact based on the project configuration
 pub fn create_path_for_artifact(
     project_config: &ProjectConfig,
@@ -193,8 +435,11 @@ pub fn create_path_for_artifact(
     }
 
     // Otherwise, we will use default project output dif (and settings)
-    match &project_config.output {
-        Some(output) => {
+    match (
+        project_config.artifact_path_strategy,
+        &project_config.output,
+    ) {
+        (ArtifactPathStrategy::Centralized, Some(output)) => {
             if project_config.shard_output {
                 if let Some(ref regex) = project_config.shard_strip_regex {
                     let full_source_path = regex.replace_all(source_file.path(), "");
@@ -209,7 +454,7 @@ pub fn create_path_for_artifact(
                 output.join(artifact_file_name)
             }
         }
-        None => {
+        _ => {
             let path = source_file.get_dir();
             path.join(format!("__generated__/{}", artifact_file_name))
         }
@@ -231,3 +476,115 @@ fn path_for_artifact(
         false,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use interner::Intern;
+    use relay_typegen::TypegenConfig;
+
+    fn generic_artifact(path: PathBuf, source_file: &str) -> Artifact {
+        Artifact {
+            source_definition_names: vec![],
+            path,
+            content: ArtifactContent::Generic {
+                content: Vec::new(),
+            },
+            source_file: SourceLocationKey::standalone(source_file),
+        }
+    }
+
+    #[test]
+    fn test_co_located_artifact_path() {
+        let project_config = ProjectConfig {
+            output: Some("output".into()),
+            artifact_path_strategy: ArtifactPathStrategy::CoLocated,
+            ..Default::default()
+        };
+        assert_eq!(
+            path_for_artifact(
+                &project_config,
+                SourceLocationKey::standalone("src/a/Foo.js"),
+                "Foo_user".intern()
+            ),
+            PathBuf::from("src/a/__generated__/Foo_user.graphql.js")
+        );
+    }
+
+    #[test]
+    fn test_centralized_artifact_path() {
+        let project_config = ProjectConfig {
+            output: Some("output".into()),
+            artifact_path_strategy: ArtifactPathStrategy::Centralized,
+            ..Default::default()
+        };
+        assert_eq!(
+            path_for_artifact(
+                &project_config,
+                SourceLocationKey::standalone("src/a/Foo.js"),
+                "Foo_user".intern()
+            ),
+            PathBuf::from("output/Foo_user.graphql.js")
+        );
+
+        let sharded_project_config = ProjectConfig {
+            shard_output: true,
+            ..project_config
+        };
+        assert_eq!(
+            path_for_artifact(
+                &sharded_project_config,
+                SourceLocationKey::standalone("src/a/Foo.js"),
+                "Foo_user".intern()
+            ),
+            PathBuf::from("output/src/a/Foo_user.graphql.js")
+        );
+    }
+
+    #[test]
+    fn test_type_declaration_path() {
+        let project_config = ProjectConfig {
+            typegen_config: TypegenConfig {
+                language: TypegenLanguage::TypeScript,
+                declaration_files: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let artifact_path = path_for_artifact(
+            &project_config,
+            SourceLocationKey::standalone("src/a/Foo.js"),
+            "Foo_user".intern(),
+        );
+        assert_eq!(
+            type_declaration_path(&artifact_path),
+            PathBuf::from("src/a/__generated__/Foo_user.graphql.types.d.ts")
+        );
+    }
+
+    #[test]
+    fn test_centralized_artifact_path_collision() {
+        let project_config = ProjectConfig {
+            output: Some("output".into()),
+            artifact_path_strategy: ArtifactPathStrategy::Centralized,
+            ..Default::default()
+        };
+        let mut artifacts = vec![
+            generic_artifact("output/Foo.graphql.js".into(), "src/a/Foo.js"),
+            generic_artifact("output/Foo.graphql.js".into(), "src/b/Foo.js"),
+            generic_artifact("output/Bar.graphql.js".into(), "src/a/Bar.js"),
+        ];
+        separate_colliding_artifacts(&project_config, &mut artifacts);
+        assert_eq!(
+            artifacts
+                .iter()
+                .map(|artifact| artifact.path.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                PathBuf::from("output/src/a/Foo.graphql.js"),
+                PathBuf::from("output/src/b/Foo.graphql.js"),
+                PathBuf::from("output/Bar.graphql.js"),
+            ]
+        );
+    }
+}
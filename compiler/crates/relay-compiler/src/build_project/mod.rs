@@ -17,22 +17,26 @@ mod generate_artifacts;
 pub mod generate_extra_artifacts;
 mod is_operation_preloadable;
 mod persist_operations;
+mod schema_change_impact;
 mod source_control;
+mod source_map;
+mod typegen_cache;
 mod validate;
 
 use crate::compiler_state::{ArtifactMapKind, CompilerState, ProjectName, SourceSetName};
 use crate::config::{Config, ProjectConfig};
 use crate::errors::BuildProjectError;
 use crate::{artifact_map::ArtifactMap, graphql_asts::GraphQLAsts};
-pub use apply_transforms::apply_transforms;
 pub use apply_transforms::Programs;
+pub use apply_transforms::{apply_transforms, DISABLEABLE_TRANSFORMS, REQUIRED_TRANSFORMS};
 use build_ir::BuildIRResult;
 pub use build_ir::SourceHashes;
 pub use build_schema::build_schema;
-use common::{PerfLogEvent, PerfLogger};
+use common::{Diagnostic, PerfLogEvent, PerfLogger, Severity};
 use fnv::{FnvHashMap, FnvHashSet};
 pub use generate_artifacts::{
-    create_path_for_artifact, generate_artifacts, Artifact, ArtifactContent,
+    bundle_artifacts, create_path_for_artifact, generate_artifacts,
+    generate_persisted_queries_manifest, Artifact, ArtifactContent,
 };
 use generate_extra_artifacts::generate_extra_artifacts;
 use graphql_ir::Program;
@@ -41,9 +45,15 @@ pub use is_operation_preloadable::is_operation_preloadable;
 use log::info;
 use relay_codegen::Printer;
 use schema::Schema;
+pub use schema_change_impact::{check_schema_change, DefinitionSchemaChange, SchemaChangeSafety};
 pub use source_control::add_to_mercurial;
+pub use source_map::generate_source_map;
 use std::{collections::hash_map::Entry, path::PathBuf, sync::Arc};
-pub use validate::validate;
+pub use typegen_cache::TypegenCache;
+pub use validate::{
+    parse_and_validate, validate, ValidationContext, ValidationDiagnostics, ValidationRule,
+    ValidationSeverity, VALIDATION_RULES,
+};
 
 pub enum BuildProjectFailure {
     Error(BuildProjectError),
@@ -54,13 +64,14 @@ pub enum BuildProjectFailure {
 /// It's perfect for the LSP server: we have all the documents with
 /// their locations to provide information to go_to_definition, hover, etc.
 pub fn build_raw_program(
+    config: &Config,
     project_config: &ProjectConfig,
     graphql_asts: &FnvHashMap<SourceSetName, GraphQLAsts>,
     schema: Arc<Schema>,
     log_event: &impl PerfLogEvent,
 ) -> Result<Program, BuildProjectError> {
     let BuildIRResult { ir, .. } = log_event.time("build_ir_time", || {
-        build_ir::build_ir(project_config, &schema, graphql_asts, false)
+        build_ir::build_ir(config, project_config, &schema, graphql_asts, false)
             .map_err(|errors| BuildProjectError::ValidationErrors { errors })
     })?;
 
@@ -69,6 +80,25 @@ pub fn build_raw_program(
     }))
 }
 
+/// Moves the warnings among the diagnostics of a failed step to `warnings`
+/// and returns the errors. A step that failed with only warnings still
+/// failed, so its warnings are returned instead.
+fn partition_warnings(
+    diagnostics: Vec<Diagnostic>,
+    warnings: &mut Vec<Diagnostic>,
+) -> Vec<Diagnostic> {
+    let (step_warnings, errors): (Vec<_>, Vec<_>) = diagnostics
+        .into_iter()
+        .partition(|diagnostic| diagnostic.severity() == Severity::Warning);
+    if errors.is_empty() {
+        step_warnings
+    } else {
+        warnings.extend(step_warnings);
+        errors
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn build_programs(
     config: &Config,
     project_config: &ProjectConfig,
@@ -77,10 +107,14 @@ fn build_programs(
     schema: Arc<Schema>,
     log_event: &impl PerfLogEvent,
     perf_logger: Arc<impl PerfLogger + 'static>,
+    warnings: &mut Vec<Diagnostic>,
 ) -> Result<(Programs, Arc<SourceHashes>), BuildProjectFailure> {
-    let project_name = project_config.name;
-    let is_incremental_build =
-        compiler_state.has_processed_changes() && !compiler_state.has_breaking_schema_change();
+    // A bundle contains all definitions of the project and the persisted
+    // queries manifest all operations, so they always need a full build.
+    let is_incremental_build = compiler_state.has_processed_changes()
+        && !compiler_state.has_breaking_schema_change()
+        && project_config.bundle.is_none()
+        && project_config.persisted_queries_manifest.is_none();
 
     // Build a type aware IR.
     let BuildIRResult {
@@ -88,9 +122,18 @@ fn build_programs(
         base_fragment_names,
         source_hashes,
     } = log_event.time("build_ir_time", || {
-        build_ir::build_ir(project_config, &schema, graphql_asts, is_incremental_build).map_err(
-            |errors| BuildProjectFailure::Error(BuildProjectError::ValidationErrors { errors }),
+        build_ir::build_ir(
+            config,
+            project_config,
+            &schema,
+            graphql_asts,
+            is_incremental_build,
         )
+        .map_err(|diagnostics| {
+            BuildProjectFailure::Error(BuildProjectError::ValidationErrors {
+                errors: partition_warnings(diagnostics, warnings),
+            })
+        })
     })?;
 
     // Turn the IR into a base Program.
@@ -102,26 +145,49 @@ fn build_programs(
         return Err(BuildProjectFailure::Cancelled);
     }
 
+    let feature_flags = project_config.feature_flags.unwrap_or(config.feature_flags);
+
     // Call validation rules that go beyond type checking.
-    log_event.time("validate_time", || {
+    let ValidationDiagnostics {
+        errors,
+        warnings: validation_warnings,
+    } = log_event.time("validate_time", || {
         // TODO(T63482263): Pass connection interface from configuration
-        validate(&program, &config.connection_interface).map_err(|errors| {
-            BuildProjectFailure::Error(BuildProjectError::ValidationErrors { errors })
-        })
-    })?;
+        validate(
+            &ValidationContext {
+                program: &program,
+                connection_interface: &config.connection_interface,
+                feature_flags: &feature_flags,
+                base_fragment_names: &base_fragment_names,
+                max_spread_depth: project_config.max_spread_depth,
+                output_only_scalars: &project_config.output_only_scalars,
+                is_incremental_build,
+            },
+            &project_config.validation_rules,
+            &project_config.validation_rules_by_directory,
+        )
+    });
+    warnings.extend(validation_warnings);
+    if !errors.is_empty() {
+        return Err(BuildProjectFailure::Error(
+            BuildProjectError::ValidationErrors { errors },
+        ));
+    }
 
     // Apply various chains of transforms to create a set of output programs.
     let programs = log_event.time("apply_transforms_time", || {
         apply_transforms(
-            project_name,
+            project_config,
             Arc::new(program),
             Arc::new(base_fragment_names),
             &config.connection_interface,
-            Arc::new(project_config.feature_flags.unwrap_or(config.feature_flags)),
+            Arc::new(feature_flags),
             perf_logger,
         )
-        .map_err(|errors| {
-            BuildProjectFailure::Error(BuildProjectError::ValidationErrors { errors })
+        .map_err(|diagnostics| {
+            BuildProjectFailure::Error(BuildProjectError::ValidationErrors {
+                errors: partition_warnings(diagnostics, warnings),
+            })
         })
     })?;
 
@@ -134,6 +200,7 @@ pub fn build_project(
     compiler_state: &CompilerState,
     graphql_asts: &FnvHashMap<SourceSetName, GraphQLAsts>,
     perf_logger: Arc<impl PerfLogger + 'static>,
+    warnings: &mut Vec<Diagnostic>,
 ) -> Result<(ProjectName, Arc<Schema>, Programs, Vec<Artifact>), BuildProjectFailure> {
     let log_event = perf_logger.create_event("build_project");
     let build_time = log_event.start("build_project_time");
@@ -144,16 +211,23 @@ pub fn build_project(
     // Construct a schema instance including project specific extensions.
     let schema = log_event
         .time("build_schema_time", || {
-            Ok(build_schema(compiler_state, project_config)?)
+            Ok(build_schema(compiler_state, config, project_config)?)
         })
-        .map_err(|errors| {
-            BuildProjectFailure::Error(BuildProjectError::ValidationErrors { errors })
+        .map_err(|diagnostics| {
+            BuildProjectFailure::Error(BuildProjectError::ValidationErrors {
+                errors: partition_warnings(diagnostics, warnings),
+            })
         })?;
 
     if compiler_state.has_pending_file_source_changes() {
         return Err(BuildProjectFailure::Cancelled);
     }
 
+    // The cached types are only valid for the schema they were generated with.
+    if compiler_state.has_schema_changes() {
+        config.typegen_cache.clear_project(project_config.name);
+    }
+
     // Apply different transform pipelines to produce the `Programs`.
     let (programs, source_hashes) = build_programs(
         config,
@@ -163,6 +237,7 @@ pub fn build_project(
         Arc::clone(&schema),
         &log_event,
         Arc::clone(&perf_logger),
+        warnings,
     )?;
 
     if compiler_state.has_pending_file_source_changes() {
@@ -201,15 +276,22 @@ pub async fn commit_project(
     let log_event = perf_logger.create_event("commit_project");
     let commit_time = log_event.start("commit_project_time");
 
-    if let Some(ref operation_persister) = config.operation_persister {
-        if let Some(ref persist_config) = project_config.persist {
+    if let Some(ref persist_config) = project_config.persist {
+        // Without persisting, the operations would keep their text.
+        if persist_config.persisted_only && config.operation_persister.is_none() {
+            return Err(BuildProjectError::PersistedOnlyWithoutPersister {
+                project_name: project_config.name,
+            });
+        }
+        if config.operation_persister.is_some() || project_config.persist_offline_fallback {
             let persist_operations_timer = log_event.start("persist_operations_time");
             persist_operations::persist_operations(
                 &mut artifacts,
                 &config.root_dir,
-                &persist_config,
+                persist_config,
+                project_config.persist_offline_fallback,
                 config,
-                &operation_persister,
+                config.operation_persister.as_deref(),
                 &log_event,
             )
             .await?;
@@ -231,6 +313,19 @@ pub async fn commit_project(
         });
     }
 
+    if let Some(manifest_path) = &project_config.persisted_queries_manifest {
+        let manifest = log_event.time("persisted_queries_manifest_time", || {
+            generate_persisted_queries_manifest(manifest_path, &artifacts)
+        });
+        artifacts.push(manifest);
+    }
+
+    if let Some(bundle_path) = &project_config.bundle {
+        artifacts = log_event.time("bundle_artifacts_time", || {
+            bundle_artifacts(bundle_path, artifacts)
+        });
+    }
+
     // Write the generated artifacts to disk. This step is separate from
     // generating artifacts or persisting to avoid partial writes in case of
     // errors as much as possible.
@@ -11,32 +11,43 @@ use crate::{
     errors::BuildProjectError,
     Artifact, ArtifactContent,
 };
-use common::PerfLogEvent;
+use common::{NamedItem, PerfLogEvent};
+use graphql_ir::{ConstantValue, OperationDefinition, Value};
 use lazy_static::lazy_static;
-use log::debug;
+use log::{debug, warn};
 use md5::{Digest, Md5};
-use regex::Regex;
-use std::{fs, path::PathBuf};
+use regex::{Captures, Regex};
+use std::collections::HashMap;
+use std::path::PathBuf;
 
 lazy_static! {
     static ref RELAY_HASH_REGEX: Regex = Regex::new(r#"@relayHash (\w{32})\n"#).unwrap();
     static ref REQUEST_ID_REGEX: Regex = Regex::new(r#"@relayRequestID (\w+)\n"#).unwrap();
+    static ref PARAM_PLACEHOLDER_REGEX: Regex = Regex::new(r#"\{([^{}]+)\}"#).unwrap();
 }
 
+/// Persists the text of the operations that don't have a persist id for
+/// their current text yet. With `offline_fallback`, operations that can't be
+/// persisted because persisting failed or there is no `operation_persister`
+/// get the hash of their text as a local placeholder id instead of failing
+/// the build.
 pub async fn persist_operations(
     artifacts: &mut [Artifact],
     root_dir: &PathBuf,
     persist_config: &PersistConfig,
+    offline_fallback: bool,
     config: &Config,
-    operation_persister: &Box<dyn OperationPersister + Send + Sync>,
+    operation_persister: Option<&(dyn OperationPersister + Send + Sync)>,
     log_event: &impl PerfLogEvent,
 ) -> Result<(), BuildProjectError> {
     let handles = artifacts
         .iter_mut()
         .flat_map(|artifact| {
             if let ArtifactContent::Operation {
+                ref normalization_operation,
                 ref text,
                 ref mut id_and_text_hash,
+                ref mut is_local_id,
                 ..
             } = artifact.content
             {
@@ -45,21 +56,37 @@ pub async fn persist_operations(
                 let extracted_persist_id = if config.repersist_operations {
                     None
                 } else {
-                    extract_persist_id(&artifact_path, &text_hash)
+                    extract_persist_id(config, &artifact_path, &text_hash)
                 };
                 if let Some(id) = extracted_persist_id {
                     *id_and_text_hash = Some((id, text_hash));
                     None
-                } else {
+                } else if let Some(operation_persister) = operation_persister {
                     let text = text.clone();
+                    let params = operation_params(persist_config, normalization_operation);
+                    let name = normalization_operation.name.item;
                     Some(async move {
-                        operation_persister
-                            .persist_artifact(text, persist_config)
+                        match operation_persister
+                            .persist_artifact(text, persist_config, params)
                             .await
-                            .map(|id| {
+                        {
+                            Ok(id) => {
                                 *id_and_text_hash = Some((id, text_hash));
-                            })
+                                Ok(())
+                            }
+                            Err(error) if offline_fallback => {
+                                warn!("Using a local id for `{}`: {}", name, error);
+                                *id_and_text_hash = Some((text_hash.clone(), text_hash));
+                                *is_local_id = true;
+                                Ok(())
+                            }
+                            Err(error) => Err(error),
+                        }
                     })
+                } else {
+                    *id_and_text_hash = Some((text_hash.clone(), text_hash));
+                    *is_local_id = true;
+                    None
                 }
             } else {
                 None
@@ -67,7 +94,9 @@ pub async fn persist_operations(
         })
         .collect::<Vec<_>>();
     log_event.number("persist_documents", handles.len());
-    log_event.number("worker_count", operation_persister.worker_count());
+    if let Some(operation_persister) = operation_persister {
+        log_event.number("worker_count", operation_persister.worker_count());
+    }
     let results = futures::future::join_all(handles).await;
     debug!("done persisting");
     let errors = results
@@ -80,8 +109,61 @@ pub async fn persist_operations(
     Ok(())
 }
 
-fn extract_persist_id(path: &PathBuf, text_hash: &str) -> Option<String> {
-    let content = fs::read_to_string(path).ok()?;
+/// The POST parameters to persist the operation with: the static parameters
+/// and the `operation_params` templates that could be resolved.
+fn operation_params(
+    persist_config: &PersistConfig,
+    operation: &OperationDefinition,
+) -> HashMap<String, String> {
+    let mut params = persist_config.params.clone();
+    for (name, template) in &persist_config.operation_params {
+        let mut is_resolved = true;
+        let value = PARAM_PLACEHOLDER_REGEX.replace_all(template, |captures: &Captures<'_>| {
+            let placeholder = &captures[1];
+            let value = if placeholder == "operationName" {
+                Some(operation.name.item.lookup().to_owned())
+            } else {
+                placeholder
+                    .strip_prefix("metadata.")
+                    .and_then(|key| get_metadata_value(persist_config, operation, key))
+            };
+            value.unwrap_or_else(|| {
+                is_resolved = false;
+                String::new()
+            })
+        });
+        if is_resolved {
+            params.insert(name.clone(), value.into_owned());
+        }
+    }
+    params
+}
+
+/// Returns the value of the argument `key` of the configured metadata
+/// directive of the operation, if it's a scalar.
+fn get_metadata_value(
+    persist_config: &PersistConfig,
+    operation: &OperationDefinition,
+    key: &str,
+) -> Option<String> {
+    let metadata_directive = persist_config.metadata_directive?;
+    operation
+        .directives
+        .named(metadata_directive)?
+        .arguments
+        .iter()
+        .find(|argument| argument.name.item.lookup() == key)
+        .and_then(|argument| match &argument.value.item {
+            Value::Constant(ConstantValue::String(value))
+            | Value::Constant(ConstantValue::Enum(value)) => Some(value.lookup().to_owned()),
+            Value::Constant(ConstantValue::Int(value)) => Some(value.to_string()),
+            Value::Constant(ConstantValue::Boolean(value)) => Some(value.to_string()),
+            _ => None,
+        })
+}
+
+fn extract_persist_id(config: &Config, path: &PathBuf, text_hash: &str) -> Option<String> {
+    let content = String::from_utf8(config.artifact_writer.read(path)?).ok()?;
 
     // Looks like a merge conflict, let's not trust this file.
     if content.contains("<<<<") || content.contains(">>>>") {
@@ -115,3 +197,249 @@ fn md5(data: &str) -> String {
     md5.input(data);
     hex::encode(md5.result())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use common::{NoopPerfLoggerEvent, SourceLocationKey, WithLocation};
+    use graphql_ir::{build, Argument, Directive};
+    use graphql_syntax::parse_executable;
+    use interner::Intern;
+    use persist_query::PersistError;
+    use relay_test_schema::{get_test_schema, get_test_schema_with_extensions};
+    use std::sync::Arc;
+
+    fn build_operation() -> OperationDefinition {
+        let schema = get_test_schema();
+        let ast = parse_executable(
+            "query TeamQuery { me { id } }",
+            SourceLocationKey::standalone("Team.graphql"),
+        )
+        .unwrap();
+        match build(&schema, &ast.definitions).unwrap().pop() {
+            Some(graphql_ir::ExecutableDefinition::Operation(operation)) => operation,
+            _ => panic!("Expected an operation."),
+        }
+    }
+
+    fn persist_config() -> PersistConfig {
+        PersistConfig {
+            url: "https://example.com/persist".to_owned(),
+            params: vec![("app_id".to_owned(), "1".to_owned())]
+                .into_iter()
+                .collect(),
+            operation_params: Default::default(),
+            metadata_directive: Some("persistMetadata".intern()),
+            persisted_only: false,
+        }
+    }
+
+    fn test_operation_params(
+        metadata: &[(&str, &str)],
+        templates: &[(&str, &str)],
+    ) -> Vec<(String, String)> {
+        let mut operation = build_operation();
+        for (key, value) in metadata {
+            operation.directives.push(Directive {
+                name: WithLocation::generated("persistMetadata".intern()),
+                arguments: vec![Argument {
+                    name: WithLocation::generated(key.intern()),
+                    value: WithLocation::generated(Value::Constant(ConstantValue::String(
+                        value.intern(),
+                    ))),
+                }],
+            });
+        }
+        let persist_config = PersistConfig {
+            operation_params: templates
+                .iter()
+                .map(|(name, template)| (name.to_string(), template.to_string()))
+                .collect(),
+            ..persist_config()
+        };
+        let mut params = operation_params(&persist_config, &operation)
+            .into_iter()
+            .collect::<Vec<_>>();
+        params.sort();
+        params
+    }
+
+    fn param(name: &str, value: &str) -> (String, String) {
+        (name.to_owned(), value.to_owned())
+    }
+
+    #[test]
+    fn test_static_params() {
+        assert_eq!(test_operation_params(&[], &[]), vec![param("app_id", "1")]);
+    }
+
+    #[test]
+    fn test_operation_params_templates() {
+        assert_eq!(
+            test_operation_params(
+                &[("owner", "feed")],
+                &[
+                    ("app_id", "2"),
+                    ("name", "{operationName}"),
+                    ("owner", "team_{metadata.owner}"),
+                ]
+            ),
+            vec![
+                param("app_id", "2"),
+                param("name", "TeamQuery"),
+                param("owner", "team_feed"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unresolved_operation_params_are_skipped() {
+        assert_eq!(
+            test_operation_params(&[], &[("owner", "{metadata.owner}"), ("other", "{other}")]),
+            vec![param("app_id", "1")]
+        );
+    }
+
+    #[test]
+    fn test_operation_params_from_config() {
+        let config = Config::from_string_for_test(
+            r#"{
+                "sources": {"src": "a"},
+                "projects": {
+                    "a": {
+                        "schema": "schema.graphql",
+                        "persist": {
+                            "url": "https://example.com/persist",
+                            "params": {"app_id": "1"},
+                            "operation_params": {"owner": "team_{metadata.owner}"},
+                            "metadata_directive": "persistMetadata"
+                        }
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        let persist_config = config.projects[&"a".intern()].persist.as_ref().unwrap();
+        let schema =
+            get_test_schema_with_extensions("directive @persistMetadata(owner: String) on QUERY");
+        let ast = parse_executable(
+            r#"query TeamQuery @persistMetadata(owner: "feed") { me { id } }"#,
+            SourceLocationKey::standalone("Team.graphql"),
+        )
+        .unwrap();
+        let operation = match build(&schema, &ast.definitions).unwrap().pop() {
+            Some(graphql_ir::ExecutableDefinition::Operation(operation)) => operation,
+            _ => panic!("Expected an operation."),
+        };
+        let mut params = operation_params(persist_config, &operation)
+            .into_iter()
+            .collect::<Vec<_>>();
+        params.sort();
+        assert_eq!(
+            params,
+            vec![param("app_id", "1"), param("owner", "team_feed")]
+        );
+    }
+
+    struct TestPersister {
+        is_offline: bool,
+    }
+
+    #[async_trait]
+    impl OperationPersister for TestPersister {
+        async fn persist_artifact(
+            &self,
+            _artifact_text: String,
+            _project_config: &PersistConfig,
+            _params: HashMap<String, String>,
+        ) -> Result<String, PersistError> {
+            if self.is_offline {
+                Err(PersistError::ErrorResponse {
+                    message: "offline".to_owned(),
+                })
+            } else {
+                Ok("persisted".to_owned())
+            }
+        }
+
+        fn worker_count(&self) -> usize {
+            1
+        }
+    }
+
+    /// Persists a single operation, returning its id and whether it's local.
+    fn test_persist_operations(
+        operation_persister: Option<TestPersister>,
+        offline_fallback: bool,
+    ) -> Result<Option<(String, bool)>, BuildProjectError> {
+        let config = Config::from_string_for_test(
+            r#"{"sources": {"src": "a"}, "projects": {"a": {"schema": "schema.graphql"}}}"#,
+        )
+        .unwrap();
+        let operation = Arc::new(build_operation());
+        let mut artifacts = vec![Artifact {
+            source_definition_names: vec![operation.name.item],
+            path: "TeamQuery.graphql.js".into(),
+            content: ArtifactContent::Operation {
+                normalization_operation: Arc::clone(&operation),
+                reader_operation: Arc::clone(&operation),
+                typegen_operation: Arc::clone(&operation),
+                source_hash: String::new(),
+                text: "query TeamQuery { me { id } }".to_owned(),
+                id_and_text_hash: None,
+                is_local_id: false,
+            },
+            source_file: SourceLocationKey::standalone("Team.graphql"),
+        }];
+        let operation_persister = operation_persister.map(|operation_persister| {
+            Box::new(operation_persister) as Box<dyn OperationPersister + Send + Sync>
+        });
+        futures::executor::block_on(persist_operations(
+            &mut artifacts,
+            &config.root_dir,
+            &persist_config(),
+            offline_fallback,
+            &config,
+            operation_persister.as_deref(),
+            &NoopPerfLoggerEvent,
+        ))?;
+        match &artifacts[0].content {
+            ArtifactContent::Operation {
+                id_and_text_hash,
+                is_local_id,
+                ..
+            } => Ok(id_and_text_hash
+                .as_ref()
+                .map(|(id, _)| (id.clone(), *is_local_id))),
+            _ => panic!("Expected an operation."),
+        }
+    }
+
+    #[test]
+    fn test_offline_fallback() {
+        let text_hash = md5("query TeamQuery { me { id } }");
+        assert_eq!(
+            test_persist_operations(Some(TestPersister { is_offline: false }), true).unwrap(),
+            Some(("persisted".to_owned(), false))
+        );
+        assert!(test_persist_operations(Some(TestPersister { is_offline: true }), false).is_err());
+        assert_eq!(
+            test_persist_operations(Some(TestPersister { is_offline: true }), true).unwrap(),
+            Some((text_hash.clone(), true))
+        );
+        assert_eq!(
+            test_persist_operations(None, true).unwrap(),
+            Some((text_hash, true))
+        );
+    }
+
+    #[test]
+    fn test_local_ids_are_not_reused() {
+        assert_eq!(
+            extract_request_id("// @relayRequestID 1234\n"),
+            Some("1234".to_owned())
+        );
+        assert_eq!(extract_request_id("// @relayLocalRequestID 1234\n"), None);
+    }
+}
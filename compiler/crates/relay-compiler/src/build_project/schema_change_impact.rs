@@ -0,0 +1,225 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use crate::config::Config;
+use common::{Diagnostic, SourceLocationKey};
+use fnv::{FnvHashMap, FnvHashSet};
+use graphql_ir::{build, FragmentSpread, Program, Visitor};
+use graphql_syntax::parse_executable;
+use graphql_text_printer::{print_fragment, print_operation};
+use interner::StringKey;
+use rayon::prelude::*;
+use schema::Schema;
+
+/// Whether a definition still builds against the new schema.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SchemaChangeSafety {
+    Safe,
+    /// The definition, or a fragment it spreads, selects fields or uses
+    /// types and arguments that were removed or changed incompatibly. The
+    /// reasons are the build errors against the new schema.
+    Breaking(Vec<String>),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct DefinitionSchemaChange {
+    pub name: StringKey,
+    pub safety: SchemaChangeSafety,
+}
+
+/// Reports how a schema change affects the operations and fragments of a
+/// program built against the old schema: each definition is printed and
+/// built again against the old and the new schema, together with the
+/// fragments it spreads. Definitions that don't build against the old
+/// schema either are left out. The result is sorted by definition name.
+/// Definitions are checked in parallel within the thread pool of `config`.
+pub fn check_schema_change(
+    config: &Config,
+    old_schema: &Schema,
+    new_schema: &Schema,
+    program: &Program,
+) -> Vec<DefinitionSchemaChange> {
+    let texts = program
+        .operations()
+        .map(|operation| (operation.name.item, print_operation(old_schema, operation)))
+        .chain(
+            program
+                .fragments()
+                .map(|fragment| (fragment.name.item, print_fragment(old_schema, fragment))),
+        )
+        .collect::<FnvHashMap<_, _>>();
+
+    let mut changes = config.install(|| {
+        texts
+            .par_iter()
+            .filter_map(|(&name, _)| {
+                let mut documents = vec![name];
+                documents.extend(
+                    find_reachable_fragments(program, name)
+                        .into_iter()
+                        .filter(|fragment_name| *fragment_name != name),
+                );
+                let definitions = documents
+                    .iter()
+                    .flat_map(|document_name| {
+                        parse_executable(
+                            &texts[document_name],
+                            SourceLocationKey::standalone(document_name.lookup()),
+                        )
+                        .expect("Expected printed definitions to parse.")
+                        .definitions
+                    })
+                    .collect::<Vec<_>>();
+                if build(old_schema, &definitions).is_err() {
+                    return None;
+                }
+                let safety = match build(new_schema, &definitions) {
+                    Ok(_) => SchemaChangeSafety::Safe,
+                    Err(errors) => SchemaChangeSafety::Breaking(breaking_reasons(name, &errors)),
+                };
+                Some(DefinitionSchemaChange { name, safety })
+            })
+            .collect::<Vec<_>>()
+    });
+    changes.sort_by_key(|change| change.name.lookup());
+    changes
+}
+
+/// The errors in the definition itself, or the fragments that fail to build
+/// if the definition is only broken by the fragments it spreads.
+fn breaking_reasons(name: StringKey, errors: &[Diagnostic]) -> Vec<String> {
+    let source_location = SourceLocationKey::standalone(name.lookup());
+    let reasons = errors
+        .iter()
+        .filter(|error| error.location().source_location() == source_location)
+        .map(|error| error.message().to_string())
+        .collect::<Vec<_>>();
+    if !reasons.is_empty() {
+        return reasons;
+    }
+    let mut broken_fragments = errors
+        .iter()
+        .map(|error| error.location().source_location().path().to_string())
+        .collect::<Vec<_>>();
+    broken_fragments.sort();
+    broken_fragments.dedup();
+    broken_fragments
+        .into_iter()
+        .map(|fragment_name| format!("Spreads the breaking fragment `{}`.", fragment_name))
+        .collect()
+}
+
+fn find_reachable_fragments(program: &Program, name: StringKey) -> FnvHashSet<StringKey> {
+    let mut finder = ReachableFragmentFinder {
+        program,
+        fragments: Default::default(),
+    };
+    if let Some(operation) = program.operation(name) {
+        finder.visit_operation(operation);
+    } else if let Some(fragment) = program.fragment(name) {
+        finder.visit_fragment(fragment);
+    }
+    finder.fragments
+}
+
+struct ReachableFragmentFinder<'program> {
+    program: &'program Program,
+    fragments: FnvHashSet<StringKey>,
+}
+
+impl Visitor for ReachableFragmentFinder<'_> {
+    const NAME: &'static str = "ReachableFragmentFinder";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_fragment_spread(&mut self, spread: &FragmentSpread) {
+        if self.fragments.insert(spread.fragment.item) {
+            if let Some(fragment) = self.program.fragment(spread.fragment.item) {
+                self.visit_fragment(fragment);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_and_validate;
+    use interner::Intern;
+    use schema::SchemaConfig;
+
+    const OLD_SCHEMA: &str = "
+        type Query { me: User }
+        type User { id: ID name: String age: Int nickname: String }
+    ";
+
+    fn check(new_schema_sdl: &str, document: &str) -> Vec<DefinitionSchemaChange> {
+        let program = parse_and_validate(OLD_SCHEMA, document).unwrap();
+        let new_schema = relay_schema::build_schema_with_extensions::<_, &str>(
+            &[(new_schema_sdl, SourceLocationKey::generated())],
+            &[],
+            &SchemaConfig::default(),
+        )
+        .unwrap();
+        check_schema_change(&Config::default(), &program.schema, &new_schema, &program)
+    }
+
+    #[test]
+    fn test_check_schema_change() {
+        let changes = check(
+            "
+            type Query { me: User }
+            type User { id: ID name: Name age: Int }
+            type Name { first: String }
+            ",
+            "
+            query MeQuery { me { id ...Profile_user ...Age_user } }
+            fragment Profile_user on User { name nickname }
+            fragment Age_user on User { age }
+            ",
+        );
+        let mut reasons = match &changes[2].safety {
+            SchemaChangeSafety::Breaking(reasons) => reasons.clone(),
+            SchemaChangeSafety::Safe => panic!("Expected Profile_user to break."),
+        };
+        reasons.sort();
+        assert_eq!(
+            reasons,
+            vec![
+                "Expected selections on field `name` of type `User`".to_string(),
+                "The type `User` has no field `nickname`".to_string(),
+            ]
+        );
+        assert_eq!(
+            changes[..2],
+            [
+                DefinitionSchemaChange {
+                    name: "Age_user".intern(),
+                    safety: SchemaChangeSafety::Safe,
+                },
+                DefinitionSchemaChange {
+                    name: "MeQuery".intern(),
+                    safety: SchemaChangeSafety::Breaking(vec![
+                        "Spreads the breaking fragment `Profile_user`.".to_string()
+                    ]),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_unchanged_schema() {
+        let changes = check(OLD_SCHEMA, "query MeQuery { me { id name } }");
+        assert_eq!(
+            changes,
+            vec![DefinitionSchemaChange {
+                name: "MeQuery".intern(),
+                safety: SchemaChangeSafety::Safe,
+            }]
+        );
+    }
+}
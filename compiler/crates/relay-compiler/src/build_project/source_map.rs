@@ -0,0 +1,120 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::{Location, SourceLocationKey};
+use graphql_ir::Selection;
+use interner::StringKey;
+use schema::Schema;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Content of the `.map` file written next to an artifact, mapping the
+/// definition and the selections of the artifact back to their GraphQL source.
+/// Selections are keyed by their path in the data of the artifact, e.g.
+/// `UserQuery.node.name`, fragment spreads are keyed as `...FragmentName`.
+/// Selections added by the compiler (without source location) are omitted.
+#[derive(Serialize)]
+struct SourceMap {
+    version: u32,
+    definitions: BTreeMap<StringKey, SourceMapLocation>,
+    selections: BTreeMap<String, SourceMapLocation>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SourceMapLocation {
+    path: &'static str,
+    /// Index of the GraphQL tag within the source file, for GraphQL embedded
+    /// in JavaScript.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    embedded_index: Option<usize>,
+    /// Byte offsets of the start and end within the GraphQL source.
+    start: u32,
+    end: u32,
+}
+
+impl SourceMapLocation {
+    fn from_location(location: Location) -> Option<Self> {
+        let embedded_index = match location.source_location() {
+            SourceLocationKey::Embedded { index, .. } => Some(index),
+            SourceLocationKey::Standalone { .. } => None,
+            SourceLocationKey::Generated => return None,
+        };
+        Some(Self {
+            path: location.source_location().path(),
+            embedded_index,
+            start: location.span().start,
+            end: location.span().end,
+        })
+    }
+}
+
+pub fn generate_source_map(
+    schema: &Schema,
+    definition_name: StringKey,
+    definition_location: Location,
+    selections: &[Selection],
+) -> String {
+    let mut source_map = SourceMap {
+        version: 1,
+        definitions: Default::default(),
+        selections: Default::default(),
+    };
+    if let Some(location) = SourceMapLocation::from_location(definition_location) {
+        source_map.definitions.insert(definition_name, location);
+    }
+    collect_selections(
+        schema,
+        definition_name.lookup(),
+        selections,
+        &mut source_map.selections,
+    );
+    serde_json::to_string_pretty(&source_map).unwrap()
+}
+
+fn collect_selections(
+    schema: &Schema,
+    parent_path: &str,
+    selections: &[Selection],
+    locations: &mut BTreeMap<String, SourceMapLocation>,
+) {
+    for selection in selections {
+        match selection {
+            Selection::ScalarField(field) => {
+                let path = format!("{}.{}", parent_path, field.alias_or_name(schema));
+                insert_location(locations, path, field.alias_or_name_location());
+            }
+            Selection::LinkedField(field) => {
+                let path = format!("{}.{}", parent_path, field.alias_or_name(schema));
+                collect_selections(schema, &path, &field.selections, locations);
+                insert_location(locations, path, field.alias_or_name_location());
+            }
+            Selection::FragmentSpread(spread) => {
+                let path = format!("{}....{}", parent_path, spread.fragment.item);
+                insert_location(locations, path, spread.fragment.location);
+            }
+            Selection::InlineFragment(fragment) => {
+                collect_selections(schema, parent_path, &fragment.selections, locations);
+            }
+            Selection::Condition(condition) => {
+                collect_selections(schema, parent_path, &condition.selections, locations);
+            }
+        }
+    }
+}
+
+/// The same path can be selected multiple times, e.g. in different inline
+/// fragments, the first selection is used.
+fn insert_location(
+    locations: &mut BTreeMap<String, SourceMapLocation>,
+    path: String,
+    location: Location,
+) {
+    if let Some(location) = SourceMapLocation::from_location(location) {
+        locations.entry(path).or_insert(location);
+    }
+}
@@ -0,0 +1,174 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use crate::compiler_state::ProjectName;
+use common::NamedItem;
+use fnv::FnvHashMap;
+use graphql_ir::{FragmentDefinition, OperationDefinition};
+use graphql_text_printer::{print_fragment, print_operation};
+use interner::{Intern, StringKey};
+use lazy_static::lazy_static;
+use md5::{Digest, Md5};
+use schema::Schema;
+use std::sync::Mutex;
+
+lazy_static! {
+    static ref RAW_RESPONSE_TYPE_DIRECTIVE_NAME: StringKey = "raw_response_type".intern();
+}
+
+/// Keeps the generated types of the operations and fragments of previous
+/// builds, keyed by the shape of their typegen IR. In incremental builds,
+/// definitions spreading a changed fragment are rebuilt, but their types only
+/// change if the fragment is unmasked, so their types can be reused.
+///
+/// The shape doesn't include the schema, the cache of a project needs to be
+/// cleared when its schema changes.
+#[derive(Default)]
+pub struct TypegenCache {
+    entries: Mutex<FnvHashMap<TypegenCacheKey, (String, String)>>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct TypegenCacheKey {
+    project_name: ProjectName,
+    definition_name: StringKey,
+    is_declaration: bool,
+}
+
+impl TypegenCache {
+    /// Returns the cached types of the definition if its shape is unchanged,
+    /// otherwise generates and caches them.
+    pub fn get_or_generate(
+        &self,
+        project_name: ProjectName,
+        definition_name: StringKey,
+        is_declaration: bool,
+        shape_hash: String,
+        generate: impl FnOnce() -> String,
+    ) -> String {
+        let key = TypegenCacheKey {
+            project_name,
+            definition_name,
+            is_declaration,
+        };
+        if let Some((cached_hash, types)) = self.entries.lock().unwrap().get(&key) {
+            if *cached_hash == shape_hash {
+                return types.clone();
+            }
+        }
+        let types = generate();
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(key, (shape_hash, types.clone()));
+        types
+    }
+
+    pub fn clear_project(&self, project_name: ProjectName) {
+        self.entries
+            .lock()
+            .unwrap()
+            .retain(|key, _| key.project_name != project_name);
+    }
+}
+
+/// The types of a fragment only depend on its own selections: masked spreads
+/// are typed by the name of the spread fragment, and unmasked spreads are
+/// already inlined into the selections of the typegen IR.
+pub fn fragment_shape_hash(schema: &Schema, typegen_fragment: &FragmentDefinition) -> String {
+    md5(&print_fragment(schema, typegen_fragment))
+}
+
+/// Like `fragment_shape_hash`, but also includes the normalization IR for
+/// operations with a `@raw_response_type`, which is typed from the full
+/// response.
+pub fn operation_shape_hash(
+    schema: &Schema,
+    typegen_operation: &OperationDefinition,
+    normalization_operation: &OperationDefinition,
+) -> String {
+    let mut text = print_operation(schema, typegen_operation);
+    if normalization_operation
+        .directives
+        .named(*RAW_RESPONSE_TYPE_DIRECTIVE_NAME)
+        .is_some()
+    {
+        text.push_str(&print_operation(schema, normalization_operation));
+    }
+    md5(&text)
+}
+
+fn md5(data: &str) -> String {
+    let mut md5 = Md5::new();
+    md5.input(data);
+    hex::encode(md5.result())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::SourceLocationKey;
+    use graphql_ir::{build, Program};
+    use graphql_syntax::parse_executable;
+    use relay_test_schema::get_test_schema;
+    use std::sync::Arc;
+
+    fn build_program(text: &str) -> Program {
+        let schema = get_test_schema();
+        let ast = parse_executable(text, SourceLocationKey::standalone("test.graphql")).unwrap();
+        let ir = build(&schema, &ast.definitions).unwrap();
+        Program::from_definitions(Arc::clone(&schema), ir)
+    }
+
+    fn user_fragment_hash(spread_fragment_selections: &str) -> String {
+        let program = build_program(&format!(
+            "fragment UserFragment on User {{ name ...SpreadFragment }}
+            fragment SpreadFragment on User {{ {} }}",
+            spread_fragment_selections
+        ));
+        fragment_shape_hash(
+            &program.schema,
+            program.fragment("UserFragment".intern()).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_shape_hash_ignores_masked_spreads() {
+        assert_eq!(user_fragment_hash("id"), user_fragment_hash("id username"));
+    }
+
+    #[test]
+    fn test_get_or_generate() {
+        let cache = TypegenCache::default();
+        let project_name = "test".intern();
+        let name = "UserFragment".intern();
+        let generate = |types: &'static str| move || types.to_owned();
+        assert_eq!(
+            cache.get_or_generate(project_name, name, false, "a".to_owned(), generate("A")),
+            "A"
+        );
+        // Same shape: the cached types are reused.
+        assert_eq!(
+            cache.get_or_generate(project_name, name, false, "a".to_owned(), generate("B")),
+            "A"
+        );
+        // Declarations are cached separately.
+        assert_eq!(
+            cache.get_or_generate(project_name, name, true, "a".to_owned(), generate("C")),
+            "C"
+        );
+        assert_eq!(
+            cache.get_or_generate(project_name, name, false, "b".to_owned(), generate("D")),
+            "D"
+        );
+        cache.clear_project(project_name);
+        assert_eq!(
+            cache.get_or_generate(project_name, name, false, "b".to_owned(), generate("E")),
+            "E"
+        );
+    }
+}
@@ -5,27 +5,457 @@
  * LICENSE file in the root directory of this source tree.
  */
 
-use common::DiagnosticsResult;
-use errors::try_all;
-use graphql_ir::Program;
+use common::{Diagnostic, DiagnosticsResult, Severity, SourceLocationKey};
+use fnv::FnvHashSet;
+use graphql_ir::{build, Program};
+use graphql_syntax::parse_executable;
+use interner::StringKey;
 use relay_transforms::{
-    disallow_reserved_aliases, disallow_typename_on_root, validate_connections,
-    validate_module_names, validate_relay_directives, validate_unused_variables,
-    ConnectionInterface,
+    disallow_reserved_aliases, disallow_typename_on_root, validate_connection_keys,
+    validate_connection_pagination_fields, validate_connection_parents, validate_connections,
+    validate_dead_selections, validate_deprecated_fields, validate_fragment_directives,
+    validate_inline_fragment_alias, validate_module_names, validate_output_only_scalars,
+    validate_plural_connections, validate_relay_directives, validate_spread_depth,
+    validate_strict_fragment_arguments, validate_subscription_root_field,
+    validate_unused_fragments, validate_unused_variables, ConnectionInterface, FeatureFlags,
 };
+use schema::SchemaConfig;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
+/// How the diagnostics of a validation rule are reported. The severity of
+/// each rule can be changed per project with `validationRules` and per
+/// directory with `validationRulesByDirectory`.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ValidationSeverity {
+    /// Fail the build.
+    Error,
+    /// Log the diagnostics without failing the build.
+    Warning,
+    /// Don't run the rule.
+    Off,
+}
+
+/// The program and project options the validation rules run on.
+pub struct ValidationContext<'a> {
+    pub program: &'a Program,
+    pub connection_interface: &'a ConnectionInterface,
+    pub feature_flags: &'a FeatureFlags,
+    pub base_fragment_names: &'a FnvHashSet<StringKey>,
+    pub max_spread_depth: Option<usize>,
+    pub output_only_scalars: &'a [StringKey],
+    /// Incremental builds only contain the changed documents and the
+    /// documents they reach, rules that need the whole project are skipped.
+    pub is_incremental_build: bool,
+}
+
+pub struct ValidationRule {
+    pub name: &'static str,
+    /// The severity of the diagnostics of the rule unless configured with
+    /// `validationRules` or `validationRulesByDirectory`.
+    pub default_severity: ValidationSeverity,
+    run: fn(&ValidationContext<'_>) -> Vec<Diagnostic>,
+}
+
+/// Validation rules that go beyond type checking, run in this order.
+pub const VALIDATION_RULES: &[ValidationRule] = &[
+    ValidationRule {
+        name: "reserved-aliases",
+        default_severity: ValidationSeverity::Error,
+        run: |context| diagnostics(disallow_reserved_aliases(context.program)),
+    },
+    ValidationRule {
+        name: "unused-variables",
+        default_severity: ValidationSeverity::Error,
+        run: |context| diagnostics(validate_unused_variables(context.program)),
+    },
+    ValidationRule {
+        name: "connections",
+        default_severity: ValidationSeverity::Error,
+        run: |context| {
+            diagnostics(validate_connections(
+                context.program,
+                context.connection_interface,
+                context.feature_flags,
+            ))
+        },
+    },
+    ValidationRule {
+        name: "connection-keys",
+        default_severity: ValidationSeverity::Error,
+        run: |context| {
+            diagnostics(validate_connection_keys(
+                context.program,
+                context.base_fragment_names,
+            ))
+        },
+    },
+    ValidationRule {
+        name: "connection-parents",
+        default_severity: ValidationSeverity::Error,
+        run: |context| diagnostics(validate_connection_parents(context.program)),
+    },
+    ValidationRule {
+        name: "relay-directives",
+        default_severity: ValidationSeverity::Error,
+        run: |context| diagnostics(validate_relay_directives(context.program)),
+    },
+    ValidationRule {
+        name: "fragment-directives",
+        default_severity: ValidationSeverity::Error,
+        run: |context| diagnostics(validate_fragment_directives(context.program)),
+    },
+    ValidationRule {
+        name: "module-names",
+        default_severity: ValidationSeverity::Error,
+        run: |context| diagnostics(validate_module_names(context.program)),
+    },
+    ValidationRule {
+        name: "typename-on-root",
+        default_severity: ValidationSeverity::Error,
+        run: |context| diagnostics(disallow_typename_on_root(context.program)),
+    },
+    ValidationRule {
+        name: "subscription-root-field",
+        default_severity: ValidationSeverity::Error,
+        run: |context| diagnostics(validate_subscription_root_field(context.program)),
+    },
+    ValidationRule {
+        name: "inline-fragment-alias",
+        default_severity: ValidationSeverity::Error,
+        run: |context| diagnostics(validate_inline_fragment_alias(context.program)),
+    },
+    ValidationRule {
+        name: "spread-depth",
+        default_severity: ValidationSeverity::Error,
+        run: |context| match context.max_spread_depth {
+            Some(max_depth) => diagnostics(validate_spread_depth(context.program, max_depth)),
+            None => Vec::new(),
+        },
+    },
+    ValidationRule {
+        name: "output-only-scalars",
+        default_severity: ValidationSeverity::Error,
+        run: |context| {
+            diagnostics(validate_output_only_scalars(
+                context.program,
+                context.output_only_scalars,
+            ))
+        },
+    },
+    ValidationRule {
+        name: "strict-fragment-arguments",
+        default_severity: ValidationSeverity::Off,
+        run: |context| {
+            diagnostics(validate_strict_fragment_arguments(
+                context.program,
+                context.base_fragment_names,
+            ))
+        },
+    },
+    ValidationRule {
+        name: "plural-connections",
+        default_severity: ValidationSeverity::Warning,
+        run: |context| validate_plural_connections(context.program),
+    },
+    ValidationRule {
+        name: "connection-pagination-fields",
+        default_severity: ValidationSeverity::Warning,
+        run: |context| {
+            validate_connection_pagination_fields(context.program, context.connection_interface)
+        },
+    },
+    ValidationRule {
+        name: "dead-selections",
+        default_severity: ValidationSeverity::Warning,
+        run: |context| validate_dead_selections(context.program),
+    },
+    ValidationRule {
+        name: "unused-fragments",
+        default_severity: ValidationSeverity::Off,
+        run: |context| {
+            if context.is_incremental_build {
+                Vec::new()
+            } else {
+                validate_unused_fragments(context.program, context.base_fragment_names)
+            }
+        },
+    },
+    ValidationRule {
+        name: "deprecated-fields",
+        default_severity: ValidationSeverity::Off,
+        run: |context| validate_deprecated_fields(context.program),
+    },
+];
+
+fn diagnostics(result: DiagnosticsResult<()>) -> Vec<Diagnostic> {
+    result.err().unwrap_or_default()
+}
+
+#[derive(Debug, Default)]
+pub struct ValidationDiagnostics {
+    pub errors: Vec<Diagnostic>,
+    pub warnings: Vec<Diagnostic>,
+}
+
+/// Runs all validation rules that aren't turned off and collects the
+/// diagnostics of every rule. A severity in `severities_by_directory` applies
+/// to the diagnostics in documents under that directory, the most specific
+/// directory wins, then the severity in `severities`. A configured severity
+/// applies to all diagnostics of the rule. With the default severity of the
+/// rule, diagnostics that the rule reports as warnings stay warnings.
 pub fn validate(
-    program: &Program,
-    connection_interface: &ConnectionInterface,
-) -> DiagnosticsResult<()> {
-    try_all(vec![
-        disallow_reserved_aliases(program),
-        validate_unused_variables(&program),
-        validate_connections(program, connection_interface),
-        validate_relay_directives(program),
-        validate_module_names(program),
-        disallow_typename_on_root(program),
-    ])?;
-
-    Ok(())
+    context: &ValidationContext<'_>,
+    severities: &HashMap<String, ValidationSeverity>,
+    severities_by_directory: &HashMap<PathBuf, HashMap<String, ValidationSeverity>>,
+) -> ValidationDiagnostics {
+    let mut result = ValidationDiagnostics::default();
+    for rule in VALIDATION_RULES {
+        let configured_severity = severities.get(rule.name).copied();
+        let directory_severities = severities_by_directory
+            .iter()
+            .filter_map(|(directory, severities)| {
+                severities
+                    .get(rule.name)
+                    .map(|severity| (directory.as_path(), *severity))
+            })
+            .collect::<Vec<_>>();
+        if configured_severity.unwrap_or(rule.default_severity) == ValidationSeverity::Off
+            && directory_severities
+                .iter()
+                .all(|(_, severity)| *severity == ValidationSeverity::Off)
+        {
+            continue;
+        }
+        for diagnostic in (rule.run)(context) {
+            let path = Path::new(diagnostic.location().source_location().path());
+            let configured_severity = directory_severities
+                .iter()
+                .filter(|(directory, _)| path.starts_with(directory))
+                .max_by_key(|(directory, _)| directory.components().count())
+                .map(|(_, severity)| *severity)
+                .or(configured_severity);
+            let severity = match (configured_severity, rule.default_severity) {
+                (Some(ValidationSeverity::Off), _) | (None, ValidationSeverity::Off) => continue,
+                (Some(ValidationSeverity::Error), _) => Severity::Error,
+                (Some(ValidationSeverity::Warning), _) | (None, ValidationSeverity::Warning) => {
+                    Severity::Warning
+                }
+                (None, ValidationSeverity::Error) => diagnostic.severity(),
+            };
+            match severity {
+                Severity::Error => result.errors.push(diagnostic.with_severity(severity)),
+                Severity::Warning => result.warnings.push(diagnostic.with_severity(severity)),
+            }
+        }
+    }
+    result
+}
+
+/// Builds the program of a single document against a schema and runs the
+/// validation rules with their default severities, without a config or
+/// compiler state. Warnings are dropped, only the errors are returned. The
+/// document has no file name, so operation and fragment names aren't checked
+/// against one.
+pub fn parse_and_validate(schema_sdl: &str, document: &str) -> DiagnosticsResult<Program> {
+    let schema = Arc::new(relay_schema::build_schema_with_extensions::<_, &str>(
+        &[(schema_sdl, SourceLocationKey::generated())],
+        &[],
+        &SchemaConfig::default(),
+    )?);
+    let ast = parse_executable(document, SourceLocationKey::standalone("document.graphql"))?;
+    let program = Program::from_definitions(Arc::clone(&schema), build(&schema, &ast.definitions)?);
+    let ValidationDiagnostics { errors, .. } = validate(
+        &ValidationContext {
+            program: &program,
+            connection_interface: &Default::default(),
+            feature_flags: &Default::default(),
+            base_fragment_names: &Default::default(),
+            max_spread_depth: None,
+            output_only_scalars: &[],
+            is_incremental_build: false,
+        },
+        &std::iter::once(("module-names".to_string(), ValidationSeverity::Off)).collect(),
+        &Default::default(),
+    );
+    if errors.is_empty() {
+        Ok(program)
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use relay_test_schema::get_test_schema;
+
+    fn validate_with_severities(severities: &[(&str, ValidationSeverity)]) -> (usize, usize) {
+        validate_with_directory_severities(severities, &[])
+    }
+
+    fn validate_with_directory_severities(
+        severities: &[(&str, ValidationSeverity)],
+        severities_by_directory: &[(&str, &str, ValidationSeverity)],
+    ) -> (usize, usize) {
+        let schema = get_test_schema();
+        let ast = parse_executable(
+            "query ValidateQuery($unused: ID) { me { id } }",
+            SourceLocationKey::standalone("src/legacy/Validate.graphql"),
+        )
+        .unwrap();
+        let program = Program::from_definitions(
+            Arc::clone(&schema),
+            build(&schema, &ast.definitions).unwrap(),
+        );
+        let ValidationDiagnostics { errors, warnings } = validate(
+            &ValidationContext {
+                program: &program,
+                connection_interface: &Default::default(),
+                feature_flags: &Default::default(),
+                base_fragment_names: &Default::default(),
+                max_spread_depth: None,
+                output_only_scalars: &[],
+                is_incremental_build: false,
+            },
+            &severities
+                .iter()
+                .map(|(name, severity)| (name.to_string(), *severity))
+                .collect(),
+            &severities_by_directory.iter().fold(
+                HashMap::new(),
+                |mut severities_by_directory, (directory, name, severity)| {
+                    severities_by_directory
+                        .entry(PathBuf::from(directory))
+                        .or_insert_with(HashMap::new)
+                        .insert(name.to_string(), *severity);
+                    severities_by_directory
+                },
+            ),
+        );
+        (errors.len(), warnings.len())
+    }
+
+    #[test]
+    fn test_rule_names_are_unique() {
+        let mut names = VALIDATION_RULES
+            .iter()
+            .map(|rule| rule.name)
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), VALIDATION_RULES.len());
+    }
+
+    #[test]
+    fn test_severities() {
+        assert_eq!(validate_with_severities(&[]), (1, 0));
+        assert_eq!(
+            validate_with_severities(&[("unused-variables", ValidationSeverity::Warning)]),
+            (0, 1)
+        );
+        assert_eq!(
+            validate_with_severities(&[("unused-variables", ValidationSeverity::Off)]),
+            (0, 0)
+        );
+    }
+
+    #[test]
+    fn test_directory_severities() {
+        let warning_in_legacy = (
+            "src/legacy",
+            "unused-variables",
+            ValidationSeverity::Warning,
+        );
+        assert_eq!(
+            validate_with_directory_severities(&[], &[warning_in_legacy]),
+            (0, 1)
+        );
+        // Directories that don't contain the document don't apply.
+        assert_eq!(
+            validate_with_directory_severities(
+                &[],
+                &[("src/leg", "unused-variables", ValidationSeverity::Off)]
+            ),
+            (1, 0)
+        );
+        // The most specific directory wins.
+        assert_eq!(
+            validate_with_directory_severities(
+                &[],
+                &[
+                    warning_in_legacy,
+                    ("src", "unused-variables", ValidationSeverity::Off)
+                ]
+            ),
+            (0, 1)
+        );
+        // Rules that are off for the project still run for the directory.
+        assert_eq!(
+            validate_with_directory_severities(
+                &[("unused-variables", ValidationSeverity::Off)],
+                &[("src", "unused-variables", ValidationSeverity::Error)]
+            ),
+            (1, 0)
+        );
+    }
+
+    #[test]
+    fn test_severities_of_warnings() {
+        let schema = get_test_schema();
+        let ast = parse_executable(
+            "query ValidateQuery { me { id name @include(if: false) } }",
+            SourceLocationKey::standalone("src/Validate.graphql"),
+        )
+        .unwrap();
+        let program = Program::from_definitions(
+            Arc::clone(&schema),
+            build(&schema, &ast.definitions).unwrap(),
+        );
+        let context = ValidationContext {
+            program: &program,
+            connection_interface: &Default::default(),
+            feature_flags: &Default::default(),
+            base_fragment_names: &Default::default(),
+            max_spread_depth: None,
+            output_only_scalars: &[],
+            is_incremental_build: false,
+        };
+        let count = |severity: Option<ValidationSeverity>| {
+            let ValidationDiagnostics { errors, warnings } = validate(
+                &context,
+                &severity
+                    .map(|severity| ("dead-selections".to_string(), severity))
+                    .into_iter()
+                    .collect(),
+                &Default::default(),
+            );
+            (errors.len(), warnings.len())
+        };
+        // The rule reports warnings, a configured severity applies to them.
+        assert_eq!(count(None), (0, 1));
+        assert_eq!(count(Some(ValidationSeverity::Error)), (1, 0));
+        assert_eq!(count(Some(ValidationSeverity::Off)), (0, 0));
+    }
+
+    #[test]
+    fn test_parse_and_validate() {
+        let schema_sdl = "type Query { me: User } type User { id: ID name: String }";
+        let program = parse_and_validate(schema_sdl, "query MeQuery { me { id name } }").unwrap();
+        assert_eq!(program.operations().count(), 1);
+
+        // Schema, syntax, build and validation errors are all returned.
+        assert!(parse_and_validate("type Query { me: Unknown }", "{ me }").is_err());
+        assert!(parse_and_validate(schema_sdl, "query MeQuery { me {").is_err());
+        assert!(parse_and_validate(schema_sdl, "query MeQuery { me { unknown } }").is_err());
+        assert_eq!(
+            parse_and_validate(schema_sdl, "query MeQuery($unused: ID) { me { id } }")
+                .unwrap_err()
+                .len(),
+            1
+        );
+    }
 }
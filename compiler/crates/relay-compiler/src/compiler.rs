@@ -23,7 +23,8 @@ use graphql_ir::Program;
 use log::info;
 use rayon::prelude::*;
 use schema::Schema;
-use std::{collections::HashMap, collections::HashSet, sync::Arc};
+use schema_print::print_schema_json;
+use std::{collections::HashMap, collections::HashSet, fs, path::Path, sync::Arc};
 use tokio::{sync::Notify, task};
 
 pub struct Compiler<TPerfLogger>
@@ -56,6 +57,29 @@ impl<TPerfLogger: PerfLogger> Compiler<TPerfLogger> {
         Ok(compiler_state)
     }
 
+    /// Writes a JSON snapshot of the schema of each enabled project, including
+    /// its extensions, to `<output_dir>/<project>.graphql.json` without
+    /// compiling any documents.
+    pub async fn write_schema_snapshots(&self, output_dir: &Path) -> Result<()> {
+        let setup_event = self.perf_logger.create_event("compiler_setup");
+        let file_source = FileSource::connect(&self.config, &setup_event).await?;
+        let compiler_state = file_source
+            .query(&setup_event, self.perf_logger.as_ref())
+            .await?;
+        let (schemas, errors) = self.build_schemas(&compiler_state, &setup_event);
+        if !errors.is_empty() {
+            return Err(Error::DiagnosticsError { errors });
+        }
+        for (project_name, schema) in schemas {
+            let file = output_dir.join(format!("{}.graphql.json", project_name));
+            fs::create_dir_all(output_dir)
+                .and_then(|_| fs::write(&file, print_schema_json(&schema)))
+                .map_err(|source| Error::WriteFileError { file, source })?;
+        }
+        self.perf_logger.complete_event(setup_event);
+        Ok(())
+    }
+
     pub fn build_schemas(
         &self,
         compiler_state: &CompilerState,
@@ -65,7 +89,7 @@ impl<TPerfLogger: PerfLogger> Compiler<TPerfLogger> {
         let timer = setup_event.start("build_schemas");
         let mut schemas = HashMap::default();
         for project_config in self.config.enabled_projects() {
-            match build_schema(compiler_state, project_config) {
+            match build_schema(compiler_state, &self.config, project_config) {
                 Ok(schema) => {
                     schemas.insert(project_config.name, schema);
                 }
@@ -92,39 +116,42 @@ impl<TPerfLogger: PerfLogger> Compiler<TPerfLogger> {
             GraphQLAsts::from_graphql_sources_map(
                 &compiler_state.graphql_sources,
                 &compiler_state.get_dirty_definitions(&self.config),
+                self.config.name_anonymous_operations,
             )
         })?;
 
-        let programs: Vec<(ProjectName, _)> = self
-            .config
-            .par_enabled_projects()
-            .filter(|project_config| {
-                if let Some(affected_projects) = &affected_projects {
-                    affected_projects.contains(&project_config.name)
-                } else {
-                    true
-                }
-            })
-            .map(|project_config| {
-                let project_name = project_config.name;
-                if let Some(schema) = schemas.get(&project_name) {
-                    (
-                        project_config.name,
-                        build_raw_program(
-                            project_config,
-                            &graphql_asts,
-                            Arc::clone(schema),
-                            setup_event,
-                        ),
-                    )
-                } else {
-                    (
-                        project_name,
-                        Err(BuildProjectError::SchemaNotFoundForProject { project_name }),
-                    )
-                }
-            })
-            .collect();
+        let programs: Vec<(ProjectName, _)> = self.config.install(|| {
+            self.config
+                .par_enabled_projects()
+                .filter(|project_config| {
+                    if let Some(affected_projects) = &affected_projects {
+                        affected_projects.contains(&project_config.name)
+                    } else {
+                        true
+                    }
+                })
+                .map(|project_config| {
+                    let project_name = project_config.name;
+                    if let Some(schema) = schemas.get(&project_name) {
+                        (
+                            project_config.name,
+                            build_raw_program(
+                                &self.config,
+                                project_config,
+                                &graphql_asts,
+                                Arc::clone(schema),
+                                setup_event,
+                            ),
+                        )
+                    } else {
+                        (
+                            project_name,
+                            Err(BuildProjectError::SchemaNotFoundForProject { project_name }),
+                        )
+                    }
+                })
+                .collect()
+        });
 
         let mut errors: Vec<BuildProjectError> = vec![];
         let mut program_map: HashMap<ProjectName, Program> = Default::default();
@@ -165,6 +192,7 @@ impl<TPerfLogger: PerfLogger> Compiler<TPerfLogger> {
             info!("Compilation completed.");
         }
         self.perf_logger.complete_event(setup_event);
+        self.perf_logger.flush();
         info!("Waiting for changes...");
 
         let pending_file_source_changes = compiler_state.pending_file_source_changes.clone();
@@ -172,21 +200,22 @@ impl<TPerfLogger: PerfLogger> Compiler<TPerfLogger> {
         let notify_receiver = notify_sender.clone();
         task::spawn(async move {
             loop {
-                if let Some(file_source_changes) = subscription.next_change().await.unwrap() {
-                    pending_file_source_changes
-                        .write()
-                        .unwrap()
-                        .push(file_source_changes);
-                    notify_sender.notify();
-                }
+                // Single change to file sometimes produces 2 watchman change events for the same file
+                // and branch switches produce many, wait until there are no changes for 50ms
+                let file_source_changes = subscription
+                    .next_coalesced(std::time::Duration::from_millis(50))
+                    .await
+                    .unwrap();
+                pending_file_source_changes
+                    .write()
+                    .unwrap()
+                    .push(file_source_changes);
+                notify_sender.notify();
             }
         });
 
         loop {
             notify_receiver.notified().await;
-            // Single change to file sometimes produces 2 watchman change events for the same file
-            // wait for 50ms in case there is a subsequent request
-            tokio::time::delay_for(std::time::Duration::from_millis(50)).await;
             if compiler_state.has_pending_file_source_changes() {
                 let incremental_build_event =
                     self.perf_logger.create_event("incremental_build_event");
@@ -232,11 +261,13 @@ impl<TPerfLogger: PerfLogger> Compiler<TPerfLogger> {
         setup_event: &impl PerfLogEvent,
     ) -> Result<()> {
         self.config.status_reporter.build_starts();
+        let mut warnings = Vec::new();
         let result = build_projects(
             Arc::clone(&self.config),
             Arc::clone(&self.perf_logger),
             setup_event,
             compiler_state,
+            &mut warnings,
         )
         .await;
         let result = match result {
@@ -256,7 +287,9 @@ impl<TPerfLogger: PerfLogger> Compiler<TPerfLogger> {
             }
             Err(error) => Err(error),
         };
-        self.config.status_reporter.build_finishes(&result);
+        self.config
+            .status_reporter
+            .build_finishes(&result, &warnings);
         result
     }
 }
@@ -266,11 +299,13 @@ async fn build_projects<TPerfLogger: PerfLogger + 'static>(
     perf_logger: Arc<TPerfLogger>,
     setup_event: &impl PerfLogEvent,
     compiler_state: &mut CompilerState,
+    warnings: &mut Vec<Diagnostic>,
 ) -> Result<()> {
     let mut graphql_asts = setup_event.time("parse_sources_time", || {
         GraphQLAsts::from_graphql_sources_map(
             &compiler_state.graphql_sources,
             &compiler_state.get_dirty_definitions(&config),
+            config.name_anonymous_operations,
         )
     })?;
 
@@ -278,29 +313,34 @@ async fn build_projects<TPerfLogger: PerfLogger + 'static>(
         return Err(Error::Cancelled);
     }
 
-    let build_results: Vec<_> = config
-        .par_enabled_projects()
-        .filter(|project_config| {
-            if let Some(base) = project_config.base {
-                if compiler_state.project_has_pending_changes(base) {
-                    return true;
-                }
-            }
-            compiler_state.project_has_pending_changes(project_config.name)
-        })
-        .map(|project_config| {
-            build_project(
-                &config,
-                project_config,
-                compiler_state,
-                &graphql_asts,
-                Arc::clone(&perf_logger),
-            )
-        })
-        .collect();
+    let build_results: Vec<_> = config.install(|| {
+        config
+            .par_enabled_projects()
+            .filter(|project_config| {
+                compiler_state.project_has_pending_changes(project_config.name)
+                    || config
+                        .base_projects(project_config.name)
+                        .into_iter()
+                        .any(|base| compiler_state.project_has_pending_changes(base))
+            })
+            .map(|project_config| {
+                let mut project_warnings = Vec::new();
+                let result = build_project(
+                    &config,
+                    project_config,
+                    compiler_state,
+                    &graphql_asts,
+                    Arc::clone(&perf_logger),
+                    &mut project_warnings,
+                );
+                (result, project_warnings)
+            })
+            .collect()
+    });
     let mut results = Vec::new();
     let mut errors = Vec::new();
-    for result in build_results {
+    for (result, project_warnings) in build_results {
+        warnings.extend(project_warnings);
         match result {
             Ok(result) => results.push(result),
             Err(error) => match error {
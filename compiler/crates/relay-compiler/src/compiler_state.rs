@@ -12,7 +12,7 @@ use crate::watchman::{
     categorize_files, extract_graphql_strings_from_file, read_to_string, Clock, FileGroup,
     FileSourceResult, WatchmanFile,
 };
-use common::{PerfLogEvent, PerfLogger};
+use common::{PerfLogEvent, PerfLogger, SourceLocationKey};
 use fnv::{FnvHashMap, FnvHashSet};
 use graphql_syntax::GraphQLSource;
 use interner::StringKey;
@@ -43,6 +43,13 @@ pub enum ProjectSet {
     ProjectNames(Vec<ProjectName>),
 }
 impl ProjectSet {
+    pub fn contains(&self, project_name: ProjectName) -> bool {
+        match self {
+            ProjectSet::ProjectName(name) => *name == project_name,
+            ProjectSet::ProjectNames(names) => names.contains(&project_name),
+        }
+    }
+
     /// Inserts a new project name into this set.
     pub fn insert(&mut self, project_name: ProjectName) {
         match self {
@@ -145,7 +152,9 @@ impl Source for String {
 }
 
 impl SchemaSources {
-    pub fn get_sources(&self) -> Vec<&String> {
+    /// Returns the sources sorted by path, together with the location key of
+    /// their file to report errors.
+    pub fn get_sources(&self) -> Vec<(&String, SourceLocationKey)> {
         let mut sources: Vec<_>;
         if self.pending.is_empty() {
             sources = self.processed.iter().collect();
@@ -158,7 +167,15 @@ impl SchemaSources {
             }
         }
         sources.sort_by_key(|file_content| file_content.0);
-        sources.iter().map(|file_content| file_content.1).collect()
+        sources
+            .into_iter()
+            .map(|(path, content)| {
+                (
+                    content,
+                    SourceLocationKey::standalone(&path.to_string_lossy()),
+                )
+            })
+            .collect()
     }
 }
 #[derive(Serialize, Deserialize, Debug)]
@@ -216,22 +233,24 @@ impl CompilerState {
                     let log_event = perf_logger.create_event("categorize");
                     log_event.string("source_set_name", source_set.to_string());
                     let extract_timer = log_event.start("extract_graphql_strings_from_file_time");
-                    let sources = files
-                        .par_iter()
-                        .filter(|file| *file.exists)
-                        .filter_map(|file| {
-                            match extract_graphql_strings_from_file(
-                                &file_source_changes.resolved_root,
-                                &file,
-                            ) {
-                                Ok(graphql_strings) if graphql_strings.is_empty() => None,
-                                Ok(graphql_strings) => {
-                                    Some(Ok(((*file.name).to_owned(), graphql_strings)))
+                    let sources = config.install(|| {
+                        files
+                            .par_iter()
+                            .filter(|file| *file.exists)
+                            .filter_map(|file| {
+                                match extract_graphql_strings_from_file(
+                                    &file_source_changes.resolved_root,
+                                    &file,
+                                ) {
+                                    Ok(graphql_strings) if graphql_strings.is_empty() => None,
+                                    Ok(graphql_strings) => {
+                                        Some(Ok(((*file.name).to_owned(), graphql_strings)))
+                                    }
+                                    Err(err) => Some(Err(err)),
                                 }
-                                Err(err) => Some(Err(err)),
-                            }
-                        })
-                        .collect::<Result<_>>()?;
+                            })
+                            .collect::<Result<_>>()
+                    })?;
                     log_event.stop(extract_timer);
                     match source_set {
                         SourceSet::SourceSetName(source_set_name) => {
@@ -352,20 +371,22 @@ impl CompilerState {
                         log_event.string("source_set_name", source_set.to_string());
                         let extract_timer =
                             log_event.start("extract_graphql_strings_from_file_time");
-                        let sources = files
-                            .par_iter()
-                            .map(|file| {
-                                let graphql_strings = if *file.exists {
-                                    extract_graphql_strings_from_file(
-                                        &file_source_changes.resolved_root,
-                                        &file,
-                                    )?
-                                } else {
-                                    Vec::new()
-                                };
-                                Ok(((*file.name).to_owned(), graphql_strings))
-                            })
-                            .collect::<Result<_>>()?;
+                        let sources = config.install(|| {
+                            files
+                                .par_iter()
+                                .map(|file| {
+                                    let graphql_strings = if *file.exists {
+                                        extract_graphql_strings_from_file(
+                                            &file_source_changes.resolved_root,
+                                            &file,
+                                        )?
+                                    } else {
+                                        Vec::new()
+                                    };
+                                    Ok(((*file.name).to_owned(), graphql_strings))
+                                })
+                                .collect::<Result<_>>()
+                        })?;
                         log_event.stop(extract_timer);
                         match source_set {
                             SourceSet::SourceSetName(source_set_name) => {
@@ -7,23 +7,32 @@
 
 use crate::build_project::artifact_writer::{ArtifactFileWriter, ArtifactWriter};
 use crate::build_project::generate_extra_artifacts::GenerateExtraArtifactsFn;
+use crate::build_project::{
+    TypegenCache, ValidationSeverity, DISABLEABLE_TRANSFORMS, REQUIRED_TRANSFORMS, VALIDATION_RULES,
+};
 use crate::compiler_state::{ProjectName, SourceSet};
 use crate::errors::{ConfigValidationError, Error, Result};
 use crate::rollout::Rollout;
 use crate::saved_state::SavedStateLoader;
 use crate::status_reporter::{ConsoleStatusReporter, StatusReporter};
 use async_trait::async_trait;
+use interner::{Intern, StringKey};
+use lazy_static::lazy_static;
 use persist_query::PersistError;
 use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
 use regex::Regex;
 use relay_transforms::{ConnectionInterface, FeatureFlags};
-use relay_typegen::TypegenConfig;
+use relay_typegen::{TypegenConfig, TypegenLanguage};
+use schema::SchemaConfig;
 use serde::Deserialize;
 use sha1::{Digest, Sha1};
 use std::{
     collections::{HashMap, HashSet},
     fmt,
-    path::PathBuf,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
 };
 use watchman_client::pdu::ScmAwareClockData;
 
@@ -44,12 +53,18 @@ pub struct Config {
     pub projects: HashMap<ProjectName, ProjectConfig>,
     pub header: Vec<String>,
     pub codegen_command: Option<String>,
+    /// Name anonymous operations in files with a single operation after the
+    /// module name of the file, e.g. `FooQuery` for `query { ... }` in
+    /// `Foo.js`. Otherwise, anonymous operations are an error.
+    pub name_anonymous_operations: bool,
     /// If set, tries to initialize the compiler from the saved state file.
     pub load_saved_state_file: Option<PathBuf>,
     /// Function to generate extra
     pub generate_extra_operation_artifacts: Option<GenerateExtraArtifactsFn>,
     /// Path to which to write the output of the compilation
     pub artifact_writer: Box<dyn ArtifactWriter + Send + Sync>,
+    /// Types generated by previous builds, reused in incremental builds.
+    pub typegen_cache: TypegenCache,
 
     /// Compile all files. Persist ids are still re-used unless
     /// `Config::repersist_operations` is also set.
@@ -61,6 +76,10 @@ pub struct Config {
     pub connection_interface: ConnectionInterface,
     pub feature_flags: FeatureFlags,
 
+    /// Maximum number of threads used to extract the sources and build the
+    /// projects, including the transforms. Uses a thread per CPU if not set.
+    pub max_threads: Option<NonZeroUsize>,
+
     pub saved_state_config: Option<ScmAwareClockData>,
     pub saved_state_loader: Option<Box<dyn SavedStateLoader + Send + Sync>>,
     pub saved_state_version: String,
@@ -87,6 +106,37 @@ impl Config {
             .filter(|project_config| project_config.enabled)
     }
 
+    /// The base projects of a project, following `base` transitively with the
+    /// nearest base first. Stops at missing projects and at a base cycle.
+    pub fn base_projects(&self, project_name: ProjectName) -> Vec<ProjectName> {
+        let mut base_projects = Vec::new();
+        let mut next_base = self
+            .projects
+            .get(&project_name)
+            .and_then(|project_config| project_config.base);
+        while let Some(base_name) = next_base {
+            if base_name == project_name || base_projects.contains(&base_name) {
+                break;
+            }
+            base_projects.push(base_name);
+            next_base = self
+                .projects
+                .get(&base_name)
+                .and_then(|project_config| project_config.base);
+        }
+        base_projects
+    }
+
+    /// Runs `op` in a thread pool with `max_threads` threads, so that the
+    /// parallel iterators and joins in `op` use at most that many threads.
+    /// Without `max_threads`, runs `op` in the global thread pool.
+    pub fn install<R: Send>(&self, op: impl FnOnce() -> R + Send) -> R {
+        match self.max_threads {
+            Some(max_threads) => thread_pool(max_threads).install(op),
+            None => op(),
+        }
+    }
+
     /// Rayon parallel iterator over projects that are enabled.
     pub fn par_enabled_projects(&self) -> impl ParallelIterator<Item = &ProjectConfig> {
         self.projects
@@ -155,19 +205,81 @@ impl Config {
                     },
                 }?;
 
+                let extension_globs = config_file_project
+                    .extension_globs
+                    .into_iter()
+                    .map(|pattern| {
+                        ExtensionGlob::new(pattern.clone()).ok_or_else(|| {
+                            Error::ConfigFileValidation {
+                                config_path: config_path.clone(),
+                                validation_errors: vec![
+                                    ConfigValidationError::ExtensionGlobWithoutRoot {
+                                        project_name,
+                                        pattern,
+                                    },
+                                ],
+                            }
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                // The older options turn on the rules, `validationRules` wins.
+                let mut validation_rules = HashMap::new();
+                if config_file_project.warn_unused_fragments {
+                    validation_rules
+                        .insert("unused-fragments".to_owned(), ValidationSeverity::Warning);
+                }
+                if config_file_project.strict_fragment_arguments {
+                    validation_rules.insert(
+                        "strict-fragment-arguments".to_owned(),
+                        ValidationSeverity::Error,
+                    );
+                }
+                validation_rules.extend(config_file_project.validation_rules);
+
+                let artifact_path_strategy = match config_file_project.artifact_path_strategy {
+                    Some(artifact_path_strategy) => artifact_path_strategy,
+                    None if config_file_project.output.is_some() => {
+                        ArtifactPathStrategy::Centralized
+                    }
+                    None => ArtifactPathStrategy::CoLocated,
+                };
+
                 let project_config = ProjectConfig {
                     name: project_name,
                     base: config_file_project.base,
                     enabled: true,
                     extensions: config_file_project.extensions,
+                    extension_globs,
                     output: config_file_project.output,
+                    artifact_path_strategy,
                     extra_artifacts_output: config_file_project.extra_artifacts_output,
                     shard_output: config_file_project.shard_output,
                     shard_strip_regex,
                     schema_location,
+                    schema_config: config_file_project.schema_config,
                     typegen_config: config_file_project.typegen_config,
                     persist: config_file_project.persist,
+                    persist_offline_fallback: config_file_project.persist_offline_fallback,
+                    persisted_queries_manifest: config_file_project.persisted_queries_manifest,
                     variable_names_comment: config_file_project.variable_names_comment,
+                    source_maps: config_file_project.source_maps,
+                    max_spread_depth: config_file_project.max_spread_depth,
+                    output_only_scalars: config_file_project.output_only_scalars,
+                    bundle: config_file_project.bundle,
+                    operation_text_files: config_file_project.operation_text_files,
+                    operation_text_excluded_directives: config_file_project
+                        .operation_text_excluded_directives,
+                    field_metadata_directives: config_file_project.field_metadata_directives,
+                    validation_rules,
+                    validation_rules_by_directory: config_file_project
+                        .validation_rules_by_directory,
+                    disabled_transforms: config_file_project.disabled_transforms,
+                    skip_selected_typename: config_file_project.skip_selected_typename,
+                    skip_redundant_nodes_preserved_directives: config_file_project
+                        .skip_redundant_nodes_preserved_directives,
+                    keep_original_handle_fields: config_file_project.keep_original_handle_fields,
+                    standalone_graphql_files: config_file_project.standalone_graphql_files,
                     extra: config_file_project.extra,
                     feature_flags: config_file_project.feature_flags,
                     rollout: config_file_project.rollout,
@@ -190,12 +302,14 @@ impl Config {
             name: config_file.name,
             artifact_writer: Box::new(ArtifactFileWriter::new(None, root_dir.clone())),
             status_reporter: Box::new(ConsoleStatusReporter::new(root_dir.clone())),
+            typegen_cache: Default::default(),
             root_dir,
             sources: config_file.sources,
             excludes: config_file.excludes,
             projects,
             header: config_file.header,
             codegen_command: config_file.codegen_command,
+            name_anonymous_operations: config_file.name_anonymous_operations,
             load_saved_state_file: None,
             generate_extra_operation_artifacts: None,
             saved_state_config: config_file.saved_state_config,
@@ -203,6 +317,7 @@ impl Config {
             saved_state_version: hex::encode(hash.result()),
             connection_interface: config_file.connection_interface,
             feature_flags: config_file.feature_flags,
+            max_threads: config_file.max_threads,
             operation_persister: None,
             compile_everything: false,
             repersist_operations: false,
@@ -226,6 +341,10 @@ impl Config {
 
     /// Validated internal consistency of the config.
     fn validate_consistency(&self, errors: &mut Vec<ConfigValidationError>) {
+        if let Err(error) = self.connection_interface.validate() {
+            errors.push(ConfigValidationError::InvalidConnectionInterface { error });
+        }
+
         let mut source_set_names: HashSet<_> = Default::default();
         for value in self.sources.values() {
             match value {
@@ -255,6 +374,156 @@ impl Config {
                     })
                 }
             }
+
+            // Following the bases shouldn't lead back to the project
+            let base_projects = self.base_projects(project_name);
+            if base_projects
+                .last()
+                .and_then(|last_base| self.projects[last_base].base)
+                == Some(project_name)
+            {
+                errors.push(ConfigValidationError::ProjectBaseCycle {
+                    project_name,
+                    base_project_names: base_projects,
+                })
+            }
+
+            // The manifest only contains operations with persist ids
+            if project_config.persisted_queries_manifest.is_some()
+                && project_config.persist.is_none()
+            {
+                errors.push(
+                    ConfigValidationError::PersistedQueriesManifestRequiresPersist { project_name },
+                );
+            }
+
+            // With `persisted_only`, nothing may write the operation text
+            if matches!(
+                project_config.persist,
+                Some(PersistConfig {
+                    persisted_only: true,
+                    ..
+                })
+            ) {
+                let text_options = [
+                    ("operationTextFiles", project_config.operation_text_files),
+                    (
+                        "persistOfflineFallback",
+                        project_config.persist_offline_fallback,
+                    ),
+                    (
+                        "persistedQueriesManifest",
+                        project_config.persisted_queries_manifest.is_some(),
+                    ),
+                ];
+                for (option_name, is_set) in text_options.iter() {
+                    if *is_set {
+                        errors.push(ConfigValidationError::PersistedOnlyWritesText {
+                            project_name,
+                            option_name,
+                        });
+                    }
+                }
+            }
+
+            // A central directory is needed to write all artifacts to
+            if project_config.artifact_path_strategy == ArtifactPathStrategy::Centralized
+                && project_config.output.is_none()
+            {
+                errors.push(ConfigValidationError::CentralizedArtifactsRequireOutput {
+                    project_name,
+                });
+            }
+
+            // Rules are referenced by name, catch typos
+            let mut unknown_rule_names = project_config
+                .validation_rules
+                .keys()
+                .chain(
+                    project_config
+                        .validation_rules_by_directory
+                        .values()
+                        .flat_map(|validation_rules| validation_rules.keys()),
+                )
+                .filter(|rule_name| {
+                    !VALIDATION_RULES
+                        .iter()
+                        .any(|rule| rule.name == rule_name.as_str())
+                })
+                .collect::<Vec<_>>();
+            unknown_rule_names.sort();
+            unknown_rule_names.dedup();
+            for rule_name in unknown_rule_names {
+                errors.push(ConfigValidationError::UnknownValidationRule {
+                    project_name,
+                    rule_name: rule_name.clone(),
+                });
+            }
+
+            // Only transforms that nothing depends on can be skipped
+            for transform_name in &project_config.disabled_transforms {
+                if REQUIRED_TRANSFORMS.contains(&transform_name.as_str()) {
+                    errors.push(ConfigValidationError::RequiredTransformDisabled {
+                        project_name,
+                        transform_name: transform_name.clone(),
+                    });
+                } else if !DISABLEABLE_TRANSFORMS.contains(&transform_name.as_str()) {
+                    errors.push(ConfigValidationError::UnknownTransform {
+                        project_name,
+                        transform_name: transform_name.clone(),
+                    });
+                }
+            }
+
+            // `.d.ts` declaration files only exist for TypeScript
+            if project_config.typegen_config.declaration_files
+                && !matches!(
+                    project_config.typegen_config.language,
+                    TypegenLanguage::TypeScript
+                )
+            {
+                errors.push(ConfigValidationError::DeclarationFilesRequireTypeScript {
+                    project_name,
+                });
+            }
+
+            // Enum objects are only generated for TypeScript, in place of the
+            // enum modules
+            if project_config.typegen_config.enum_const_objects {
+                if !matches!(
+                    project_config.typegen_config.language,
+                    TypegenLanguage::TypeScript
+                ) {
+                    errors.push(ConfigValidationError::EnumConstObjectsRequireTypeScript {
+                        project_name,
+                    });
+                }
+                if project_config.typegen_config.enum_module_suffix.is_some() {
+                    errors.push(
+                        ConfigValidationError::EnumConstObjectsWithEnumModuleSuffix {
+                            project_name,
+                        },
+                    );
+                }
+            }
+        }
+
+        // Definition names are only unique within a project
+        let mut bundle_paths: HashMap<&PathBuf, ProjectName> = Default::default();
+        let mut project_names = self.projects.keys().collect::<Vec<_>>();
+        project_names.sort_by_key(|project_name| project_name.lookup());
+        for &project_name in project_names {
+            if let Some(bundle_path) = &self.projects[&project_name].bundle {
+                if let Some(&other_project_name) = bundle_paths.get(bundle_path) {
+                    errors.push(ConfigValidationError::SharedBundle {
+                        project_name,
+                        other_project_name,
+                        bundle_path: bundle_path.clone(),
+                    });
+                } else {
+                    bundle_paths.insert(bundle_path, project_name);
+                }
+            }
         }
     }
 
@@ -317,11 +586,43 @@ impl Config {
     }
 }
 
+/// A config without projects or sources, rooted at the current directory.
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            name: None,
+            artifact_writer: Box::new(ArtifactFileWriter::default()),
+            status_reporter: Box::new(ConsoleStatusReporter::new(Default::default())),
+            typegen_cache: Default::default(),
+            root_dir: Default::default(),
+            sources: Default::default(),
+            excludes: vec![],
+            projects: Default::default(),
+            header: vec![],
+            codegen_command: None,
+            name_anonymous_operations: false,
+            load_saved_state_file: None,
+            generate_extra_operation_artifacts: None,
+            saved_state_config: None,
+            saved_state_loader: None,
+            saved_state_version: "0".to_owned(),
+            connection_interface: Default::default(),
+            feature_flags: Default::default(),
+            max_threads: None,
+            operation_persister: None,
+            compile_everything: false,
+            repersist_operations: false,
+            post_artifacts_write: None,
+        }
+    }
+}
+
 impl fmt::Debug for Config {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let Config {
             name,
             artifact_writer: _,
+            typegen_cache: _,
             root_dir,
             sources,
             excludes,
@@ -330,6 +631,7 @@ impl fmt::Debug for Config {
             projects,
             header,
             codegen_command,
+            name_anonymous_operations,
             load_saved_state_file,
             generate_extra_operation_artifacts,
             saved_state_config,
@@ -356,6 +658,7 @@ impl fmt::Debug for Config {
             .field("projects", projects)
             .field("header", header)
             .field("codegen_command", codegen_command)
+            .field("name_anonymous_operations", name_anonymous_operations)
             .field("load_saved_state_file", load_saved_state_file)
             .field("saved_state_config", saved_state_config)
             .field(
@@ -386,26 +689,192 @@ pub struct ProjectConfig {
     pub name: ProjectName,
     pub base: Option<ProjectName>,
     pub output: Option<PathBuf>,
+    pub artifact_path_strategy: ArtifactPathStrategy,
     pub extra_artifacts_output: Option<PathBuf>,
     pub shard_output: bool,
     pub shard_strip_regex: Option<Regex>,
     pub extensions: Vec<PathBuf>,
+    pub extension_globs: Vec<ExtensionGlob>,
     pub enabled: bool,
     pub schema_location: SchemaLocation,
+    pub schema_config: SchemaConfig,
     pub typegen_config: TypegenConfig,
     pub persist: Option<PersistConfig>,
+    pub persist_offline_fallback: bool,
+    pub persisted_queries_manifest: Option<PathBuf>,
     pub variable_names_comment: bool,
+    pub source_maps: bool,
+    pub max_spread_depth: Option<usize>,
+    pub output_only_scalars: Vec<StringKey>,
+    pub bundle: Option<PathBuf>,
+    pub operation_text_files: bool,
+    pub operation_text_excluded_directives: Vec<StringKey>,
+    pub field_metadata_directives: Vec<StringKey>,
+    /// Severities of the validation rules by rule name, overriding the
+    /// default severity of the rule.
+    pub validation_rules: HashMap<String, ValidationSeverity>,
+    /// Severities of the validation rules for the documents under a
+    /// directory, relative to the root directory, overriding
+    /// `validation_rules`.
+    pub validation_rules_by_directory: HashMap<PathBuf, HashMap<String, ValidationSeverity>>,
+    pub disabled_transforms: Vec<String>,
+    pub skip_selected_typename: bool,
+    pub skip_redundant_nodes_preserved_directives: Vec<StringKey>,
+    pub keep_original_handle_fields: bool,
+    pub standalone_graphql_files: bool,
     pub extra: Option<HashMap<String, String>>,
     pub feature_flags: Option<FeatureFlags>,
     pub rollout: Rollout,
 }
 
+/// A project named `default` with the default options, e.g. to build a
+/// project config in tests with `..Default::default()`.
+impl Default for ProjectConfig {
+    fn default() -> Self {
+        Self {
+            name: "default".intern(),
+            base: None,
+            output: None,
+            artifact_path_strategy: ArtifactPathStrategy::CoLocated,
+            extra_artifacts_output: None,
+            shard_output: false,
+            shard_strip_regex: None,
+            extensions: vec![],
+            extension_globs: vec![],
+            enabled: true,
+            schema_location: SchemaLocation::File(Default::default()),
+            schema_config: Default::default(),
+            typegen_config: Default::default(),
+            persist: None,
+            persist_offline_fallback: false,
+            persisted_queries_manifest: None,
+            variable_names_comment: false,
+            source_maps: false,
+            max_spread_depth: None,
+            output_only_scalars: vec![],
+            bundle: None,
+            operation_text_files: false,
+            operation_text_excluded_directives: vec![],
+            field_metadata_directives: vec![],
+            validation_rules: Default::default(),
+            validation_rules_by_directory: Default::default(),
+            disabled_transforms: vec![],
+            skip_selected_typename: false,
+            skip_redundant_nodes_preserved_directives: vec![],
+            keep_original_handle_fields: false,
+            standalone_graphql_files: false,
+            extra: None,
+            feature_flags: None,
+            rollout: Default::default(),
+        }
+    }
+}
+
+/// A glob pattern matching *.graphql files with schema extensions, relative
+/// to the root directory. Supports `*` and `?` within a path component and
+/// `**` for any number of directories, all other characters match literally.
+#[derive(Clone, Debug)]
+pub struct ExtensionGlob {
+    pub pattern: String,
+    /// The directory before the first wildcard of the pattern, all matching
+    /// files are contained in this directory.
+    pub root: PathBuf,
+    regex: Regex,
+}
+
+impl ExtensionGlob {
+    /// Returns `None` if the pattern doesn't start with a directory without
+    /// wildcards.
+    pub fn new(pattern: String) -> Option<Self> {
+        let path = Path::new(&pattern);
+        let literal_components = path
+            .components()
+            .take_while(|component| {
+                !component
+                    .as_os_str()
+                    .to_string_lossy()
+                    .contains(['*', '?'])
+            })
+            .count();
+        // The last component is the file name for a pattern without wildcards.
+        let root_components = literal_components.min(path.components().count().saturating_sub(1));
+        let root = path.components().take(root_components).collect::<PathBuf>();
+        if root.as_os_str().is_empty() {
+            return None;
+        }
+
+        let mut regex = String::from("^");
+        let mut rest = pattern.as_str();
+        while let Some(c) = rest.chars().next() {
+            if rest.starts_with("**/") {
+                regex.push_str("(?:.*/)?");
+                rest = &rest[3..];
+            } else if rest.starts_with("**") {
+                regex.push_str(".*");
+                rest = &rest[2..];
+            } else {
+                match c {
+                    '*' => regex.push_str("[^/]*"),
+                    '?' => regex.push_str("[^/]"),
+                    _ => regex.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+                }
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+        regex.push('$');
+
+        Some(Self {
+            root,
+            regex: Regex::new(&regex).unwrap(),
+            pattern,
+        })
+    }
+
+    pub fn is_match(&self, path: &Path) -> bool {
+        path.to_str().is_some_and(|path| self.regex.is_match(path))
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum SchemaLocation {
     File(PathBuf),
     Directory(PathBuf),
 }
 
+/// Where the artifacts generated for a project are written.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ArtifactPathStrategy {
+    /// Write the artifacts to a `__generated__` directory next to the source
+    /// file containing the definition.
+    CoLocated,
+    /// Write all artifacts of the project to its `output` directory. Set
+    /// `shardOutput` to mirror the source directories in there. Otherwise only
+    /// the artifacts of different source files that have the same name are
+    /// written to subdirectories mirroring their source directories.
+    Centralized,
+}
+
+lazy_static! {
+    static ref THREAD_POOLS: Mutex<HashMap<NonZeroUsize, Arc<ThreadPool>>> = Default::default();
+}
+
+/// The thread pool with the given number of threads. Pools are shared by the
+/// configs with the same `max_threads`, e.g. of the compilers the language
+/// server creates for each build, instead of starting new threads each time.
+fn thread_pool(num_threads: NonZeroUsize) -> Arc<ThreadPool> {
+    let mut thread_pools = THREAD_POOLS.lock().unwrap();
+    let thread_pool = thread_pools.entry(num_threads).or_insert_with(|| {
+        Arc::new(
+            ThreadPoolBuilder::new()
+                .num_threads(num_threads.get())
+                .build()
+                .expect("Failed to start the threads of the compiler."),
+        )
+    });
+    Arc::clone(thread_pool)
+}
+
 /// Schema of the compiler configuration JSON file.
 #[derive(Debug, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
@@ -425,6 +894,11 @@ struct ConfigFile {
     #[serde(default)]
     codegen_command: Option<String>,
 
+    /// Name anonymous operations in files with a single operation after the
+    /// module name of the file instead of reporting them as errors.
+    #[serde(default)]
+    name_anonymous_operations: bool,
+
     /// A mapping from directory paths (relative to the root) to a source set.
     /// If a path is a subdirectory of another path, the more specific path
     /// wins.
@@ -444,6 +918,12 @@ struct ConfigFile {
     #[serde(default)]
     feature_flags: FeatureFlags,
 
+    /// Maximum number of threads used to extract the sources and build the
+    /// projects, e.g. to leave CPUs to other processes on shared build
+    /// machines. Defaults to a thread per CPU.
+    #[serde(default)]
+    max_threads: Option<NonZeroUsize>,
+
     /// Watchman saved state config.
     saved_state_config: Option<ScmAwareClockData>,
 }
@@ -455,6 +935,7 @@ struct ConfigFileProject {
     /// referenced, but won't produce output artifacts.
     /// Extensions from the base project will be added as well and the schema
     /// of the base project should be a subset of the schema of this project.
+    /// The bases of the base project are included the same way.
     #[serde(default)]
     base: Option<ProjectName>,
 
@@ -465,6 +946,11 @@ struct ConfigFileProject {
     #[serde(default)]
     output: Option<PathBuf>,
 
+    /// Either `"coLocated"` or `"centralized"`. Defaults to `"centralized"`
+    /// if an `output` directory is set and to `"coLocated"` otherwise.
+    #[serde(default)]
+    artifact_path_strategy: Option<ArtifactPathStrategy>,
+
     /// Some projects may need to generate extra artifacts. For those, we may
     /// need to provide an additional directory to put them.
     /// By default the will use `output` *if available
@@ -483,16 +969,41 @@ struct ConfigFileProject {
     #[serde(default)]
     extensions: Vec<PathBuf>,
 
+    /// Glob patterns (relative to the root) of *.graphql files with schema
+    /// extensions, e.g. `"graphql/**/*.extension.graphql"`. A file matched by
+    /// the extensions of multiple projects is an extension of all of them.
+    #[serde(default)]
+    extension_globs: Vec<String>,
+
     /// Path to the schema.graphql or a directory containing a schema broken up
     /// in multiple *.graphql files.
     /// Exactly 1 of these options needs to be defined.
     schema: Option<PathBuf>,
     schema_dir: Option<PathBuf>,
 
+    /// Names of the root types if the schema doesn't declare them with a
+    /// `schema` definition, e.g. `{"queryTypeName": "QueryRoot"}`. Defaults
+    /// to `Query`, `Mutation` and `Subscription`.
+    #[serde(default)]
+    schema_config: SchemaConfig,
+
     /// If this option is set, the compiler will persist queries using this
     /// config.
     persist: Option<PersistConfig>,
 
+    /// Don't fail the build if persisting fails or the compiler runs without
+    /// an operation persister, e.g. offline. The operations that couldn't be
+    /// persisted keep their text and use its hash as a local id, marked with
+    /// `@relayLocalRequestID` so that the next build persists them.
+    #[serde(default)]
+    persist_offline_fallback: bool,
+
+    /// Writes a JSON file (relative to the root) mapping the id of every
+    /// persisted operation of the project to its text, e.g. to load the
+    /// operations into a server's registry. Requires `persist`, the project
+    /// is always fully rebuilt.
+    persisted_queries_manifest: Option<PathBuf>,
+
     #[serde(flatten)]
     typegen_config: TypegenConfig,
 
@@ -504,6 +1015,105 @@ struct ConfigFileProject {
     #[serde(default)]
     variable_names_comment: bool,
 
+    /// Warn about fragments that are not reachable from any operation or
+    /// `@refetchable` fragment of the project.
+    #[serde(default)]
+    warn_unused_fragments: bool,
+
+    /// Writes a `.map` file next to each operation and fragment artifact,
+    /// mapping the definition and its selections to their GraphQL source.
+    #[serde(default)]
+    source_maps: bool,
+
+    /// Fail the build if an operation or fragment spreads fragments nested
+    /// deeper than this number of levels.
+    #[serde(default)]
+    max_spread_depth: Option<usize>,
+
+    /// Custom scalars that are only returned by the server and must not be
+    /// used in the type of a variable or of an input object field used by one.
+    #[serde(default)]
+    output_only_scalars: Vec<StringKey>,
+
+    /// Instead of a file per definition, write all operations and fragments
+    /// of the project to this single file (relative to the root), exporting
+    /// an object keyed by definition name. Projects need distinct bundles and
+    /// are always fully rebuilt.
+    #[serde(default)]
+    bundle: Option<PathBuf>,
+
+    /// Writes the text of each operation to an `OperationName.graphql` file
+    /// next to its artifact, e.g. to upload it to a query registry, and
+    /// omits the text from the artifact itself.
+    #[serde(default)]
+    operation_text_files: bool,
+
+    /// Directives that are removed from the text sent to (or persisted for)
+    /// the server, e.g. directives only used by tooling. Only the text
+    /// changes, the reader and normalization transforms still see the
+    /// directives, e.g. to emit them with `fieldMetadataDirectives`.
+    #[serde(default)]
+    operation_text_excluded_directives: Vec<StringKey>,
+
+    /// Directives on fields whose literal arguments are emitted into the
+    /// `metadata` of the field in the reader and normalization artifacts,
+    /// e.g. to let a runtime extension read them.
+    #[serde(default)]
+    field_metadata_directives: Vec<StringKey>,
+
+    /// Fail the build if a fragment references a variable that isn't defined
+    /// in its `@argumentDefinitions`, i.e. a global variable of the operation.
+    #[serde(default)]
+    strict_fragment_arguments: bool,
+
+    /// Changes the severity of validation rules by name to `"error"`,
+    /// `"warning"` or `"off"`, e.g. `{"plural-connections": "error"}`.
+    #[serde(default)]
+    validation_rules: HashMap<String, ValidationSeverity>,
+
+    /// Changes the severity of validation rules for the documents under a
+    /// directory relative to the root directory, e.g.
+    /// `{"src/legacy": {"deprecated-fields": "warning"}}`. The most specific
+    /// directory wins over the others and over `validationRules`.
+    #[serde(default)]
+    validation_rules_by_directory: HashMap<PathBuf, HashMap<String, ValidationSeverity>>,
+
+    /// Names of transforms to skip, e.g. `["generate_typename"]`, to inspect the
+    /// artifacts without them when debugging the compiler. Transforms that
+    /// others depend on can't be disabled.
+    #[serde(default)]
+    disabled_transforms: Vec<String>,
+
+    /// Don't add `__typename` to the normalization and operation text
+    /// selections of abstract fields that already select it, directly or in
+    /// an inline fragment without directives on the type of the field. This
+    /// changes the order of the selections in existing artifacts.
+    #[serde(default)]
+    skip_selected_typename: bool,
+
+    /// Directives, e.g. `["required"]`, that keep a field or fragment spread
+    /// in the artifacts even if an ancestor selection already fetches it,
+    /// because the directive affects the selection in its own scope. These are
+    /// preserved in addition to the directives of Relay's custom inline
+    /// fragments, e.g. `@module`, which are always preserved.
+    #[serde(default)]
+    skip_redundant_nodes_preserved_directives: Vec<StringKey>,
+
+    /// Keep fields with a `@__clientField` handle in the reader artifacts
+    /// next to the handle field, aliased to `__<response key>_original`, to
+    /// read the value of the server field as well as the handled value. The
+    /// generated types include the aliased field. The normalization artifacts
+    /// don't change, they already write the server value under the storage
+    /// key of the field next to the handle.
+    #[serde(default)]
+    keep_original_handle_fields: bool,
+
+    /// Compile the `*.graphql` and `*.gql` documents in the source
+    /// directories of this project, next to the `graphql` tags in the
+    /// JavaScript files.
+    #[serde(default)]
+    standalone_graphql_files: bool,
+
     extra: Option<HashMap<String, String>>,
 
     #[serde(default)]
@@ -523,17 +1133,406 @@ pub struct PersistConfig {
     /// The document will be in a POST parameter `text`. This map can contain
     /// additional parameters to send.
     pub params: HashMap<String, String>,
+    /// Additional parameters computed per operation, overriding `params`.
+    /// The values are templates: `{operationName}` is replaced with the name
+    /// of the operation and `{metadata.<key>}` with the `<key>` argument of
+    /// the `metadata_directive` of the operation. A parameter referencing
+    /// metadata the operation doesn't have is not sent.
+    #[serde(default)]
+    pub operation_params: HashMap<String, String>,
+    /// Name of the operation directive holding the metadata used by
+    /// `operation_params`, e.g. `persistMetadata` for
+    /// `query Q @persistMetadata(owner: "feed")`. Define it in the schema
+    /// extensions to leave it out of the persisted text.
+    #[serde(default)]
+    pub metadata_directive: Option<StringKey>,
+    /// Never write the text of operations to disk, the artifacts only contain
+    /// the persist id. Options that write the text (`operationTextFiles`,
+    /// `persistOfflineFallback` and `persistedQueriesManifest`) can't be used
+    /// and the build fails if an operation can't be persisted.
+    #[serde(default)]
+    pub persisted_only: bool,
 }
 
 type PersistId = String;
 
 #[async_trait]
 pub trait OperationPersister {
+    /// Persists the text of an operation. `params` are the POST parameters
+    /// for this operation: the static `params` of the persist config merged
+    /// with its `operation_params`.
     async fn persist_artifact(
         &self,
         artifact_text: String,
         project_config: &PersistConfig,
+        params: HashMap<String, String>,
     ) -> std::result::Result<PersistId, PersistError>;
 
     fn worker_count(&self) -> usize;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use interner::Intern;
+
+    #[test]
+    fn test_base_projects() {
+        let config = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src/a": "a",
+                        "src/b": "b",
+                        "src/c": "c"
+                    },
+                    "projects": {
+                        "a": {
+                            "schema": "schema.graphql"
+                        },
+                        "b": {
+                            "schema": "schema.graphql",
+                            "base": "a"
+                        },
+                        "c": {
+                            "schema": "schema.graphql",
+                            "base": "b"
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.base_projects("a".intern()), vec![]);
+        assert_eq!(config.base_projects("b".intern()), vec!["a".intern()]);
+        assert_eq!(
+            config.base_projects("c".intern()),
+            vec!["b".intern(), "a".intern()]
+        );
+    }
+
+    #[test]
+    fn test_base_project_cycle() {
+        let error = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src/a": "a",
+                        "src/b": "b",
+                        "src/c": "c"
+                    },
+                    "projects": {
+                        "a": {
+                            "schema": "schema.graphql",
+                            "base": "c"
+                        },
+                        "b": {
+                            "schema": "schema.graphql",
+                            "base": "a"
+                        },
+                        "c": {
+                            "schema": "schema.graphql",
+                            "base": "b"
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("The base projects of `c` form a cycle: `c` -> `b` -> `a` -> `c`."));
+    }
+
+    #[test]
+    fn test_disabled_transforms() {
+        let error = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src": "a"
+                    },
+                    "projects": {
+                        "a": {
+                            "schema": "schema.graphql",
+                            "disabledTransforms": ["generate_typename", "flatten", "flaten"]
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(!error.contains("`generate_typename`"));
+        assert!(error.contains(
+            "The project `a` disables the transform `flatten` in `disabledTransforms`, but other transforms or the code generation depend on it."
+        ));
+        assert!(error.contains(
+            "The project `a` disables the transform `flaten` in `disabledTransforms`, but no such transform exists."
+        ));
+    }
+
+    #[test]
+    fn test_validation_rules_by_directory() {
+        let error = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src": "a"
+                    },
+                    "projects": {
+                        "a": {
+                            "schema": "schema.graphql",
+                            "validationRules": {"deprecated-fields": "error"},
+                            "validationRulesByDirectory": {
+                                "src/legacy": {"deprecated-fields": "warning"},
+                                "src/old": {"deprecated-field": "off"}
+                            }
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(!error.contains("`deprecated-fields`"));
+        assert!(error.contains(
+            "The project `a` configures the validation rule `deprecated-field` in `validationRules` or `validationRulesByDirectory`, but no such rule exists."
+        ));
+    }
+
+    #[test]
+    fn test_validation_rule_severities() {
+        let config = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src": "a"
+                    },
+                    "projects": {
+                        "a": {
+                            "schema": "schema.graphql",
+                            "validationRules": {
+                                "deprecated-fields": "warning",
+                                "dead-selections": "off"
+                            },
+                            "validationRulesByDirectory": {
+                                "src/legacy": {"plural-connections": "error"}
+                            }
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap();
+        let project_config = &config.projects[&"a".intern()];
+        assert_eq!(
+            project_config.validation_rules["deprecated-fields"],
+            ValidationSeverity::Warning
+        );
+        assert_eq!(
+            project_config.validation_rules["dead-selections"],
+            ValidationSeverity::Off
+        );
+        assert_eq!(
+            project_config.validation_rules_by_directory[&PathBuf::from("src/legacy")]
+                ["plural-connections"],
+            ValidationSeverity::Error
+        );
+
+        let error = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src": "a"
+                    },
+                    "projects": {
+                        "a": {
+                            "schema": "schema.graphql",
+                            "validationRules": {"deprecated-fields": "fatal"}
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(error.contains("unknown variant `fatal`"));
+    }
+
+    #[test]
+    fn test_persisted_only_disallows_writing_text() {
+        let error = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src": "a"
+                    },
+                    "projects": {
+                        "a": {
+                            "schema": "schema.graphql",
+                            "persist": {
+                                "url": "https://example.com/persist",
+                                "params": {},
+                                "persisted_only": true
+                            },
+                            "operationTextFiles": true,
+                            "persistOfflineFallback": true
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(error.contains(
+            "The project `a` sets `operationTextFiles`, which writes the operation text and can't be used with the `persisted_only` persist config."
+        ));
+        assert!(error.contains("The project `a` sets `persistOfflineFallback`"));
+        assert!(!error.contains("`persistedQueriesManifest`"));
+    }
+
+    #[test]
+    fn test_schema_config() {
+        let config = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src": "a"
+                    },
+                    "projects": {
+                        "a": {
+                            "schema": "schema.graphql",
+                            "schemaConfig": {
+                                "queryTypeName": "QueryRoot"
+                            }
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap();
+        let schema_config = config.projects[&"a".intern()].schema_config;
+        assert_eq!(schema_config.query_type_name, "QueryRoot".intern());
+        assert_eq!(schema_config.mutation_type_name, "Mutation".intern());
+    }
+
+    #[test]
+    fn test_persisted_queries_manifest_requires_persist() {
+        let error = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src": "a"
+                    },
+                    "projects": {
+                        "a": {
+                            "schema": "schema.graphql",
+                            "persistedQueriesManifest": "persisted_queries.json"
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(error.contains(
+            "The project `a` sets `persistedQueriesManifest`, which requires a `persist` config."
+        ));
+    }
+
+    #[test]
+    fn test_enum_const_objects() {
+        let config_with_typegen = |typegen_config: &str| {
+            Config::from_string_for_test(&format!(
+                r#"
+                    {{
+                        "sources": {{
+                            "src": "a"
+                        }},
+                        "projects": {{
+                            "a": {{
+                                "schema": "schema.graphql",
+                                "enumConstObjects": true,
+                                {}
+                            }}
+                        }}
+                    }}
+                "#,
+                typegen_config
+            ))
+        };
+        assert!(config_with_typegen(r#""language": "typescript""#).is_ok());
+        let error = config_with_typegen(r#""language": "flow""#)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains(
+            "The project `a` sets `enumConstObjects`, which is only supported with `\"language\": \"typescript\"`."
+        ));
+        let error =
+            config_with_typegen(r#""language": "typescript", "enumModuleSuffix": ".graphql""#)
+                .unwrap_err()
+                .to_string();
+        assert!(error.contains(
+            "The project `a` sets `enumConstObjects` and `enumModuleSuffix`, enums can't be both imported and generated."
+        ));
+    }
+
+    #[test]
+    fn test_connection_interface() {
+        let error = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src": "a"
+                    },
+                    "projects": {
+                        "a": {
+                            "schema": "schema.graphql"
+                        }
+                    },
+                    "connectionInterface": {
+                        "cursor": "cursor",
+                        "edges": "edges",
+                        "endCursor": "cursor",
+                        "hasNextPage": "hasNextPage",
+                        "hasPreviousPage": "hasPreviousPage",
+                        "node": "node",
+                        "pageInfo": "pageInfo",
+                        "startCursor": "startCursor"
+                    }
+                }
+            "#,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(error.contains(
+            "The `connectionInterface` is invalid: The connection interface fields `cursor` and `endCursor` both use the field name `cursor`."
+        ));
+    }
+
+    #[test]
+    fn test_max_threads() {
+        let config = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src": "a"
+                    },
+                    "projects": {
+                        "a": {
+                            "schema": "schema.graphql"
+                        }
+                    },
+                    "maxThreads": 2
+                }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.install(rayon::current_num_threads), 2);
+        assert_eq!(
+            config.install(|| (0..100).into_par_iter().map(|_| 1).sum::<usize>()),
+            100
+        );
+    }
+}
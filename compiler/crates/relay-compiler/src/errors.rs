@@ -8,6 +8,7 @@
 use crate::compiler_state::ProjectName;
 use common::Diagnostic;
 use persist_query::PersistError;
+use relay_transforms::ConnectionInterfaceError;
 use serde_json::error::Error as SerdeError;
 use std::io;
 use std::path::PathBuf;
@@ -137,6 +138,19 @@ pub enum ConfigValidationError {
         base_project_name: ProjectName,
     },
 
+    #[error(
+        "The base projects of `{project_name}` form a cycle: `{project_name}`{} -> `{project_name}`.",
+        base_project_names
+            .iter()
+            .map(|name| format!(" -> `{}`", name))
+            .collect::<Vec<_>>()
+            .join("")
+    )]
+    ProjectBaseCycle {
+        project_name: ProjectName,
+        base_project_names: Vec<ProjectName>,
+    },
+
     #[error("Project `{project_name}` needs to define exactly one of `schema` or `schema_dir`.")]
     ProjectNeedsSchemaXorSchemaDir { project_name: ProjectName },
 
@@ -172,6 +186,83 @@ pub enum ConfigValidationError {
         schema_dir: PathBuf,
     },
 
+    #[error(
+        "The project `{project_name}` uses the `\"centralized\"` `artifactPathStrategy`, which requires an `output` directory."
+    )]
+    CentralizedArtifactsRequireOutput { project_name: ProjectName },
+
+    #[error(
+        "The project `{project_name}` sets `persistedQueriesManifest`, which requires a `persist` config."
+    )]
+    PersistedQueriesManifestRequiresPersist { project_name: ProjectName },
+
+    #[error(
+        "The project `{project_name}` sets `{option_name}`, which writes the operation text and can't be used with the `persisted_only` persist config."
+    )]
+    PersistedOnlyWritesText {
+        project_name: ProjectName,
+        option_name: &'static str,
+    },
+
+    #[error(
+        "The project `{project_name}` sets `declarationFiles`, which is only supported with `\"language\": \"typescript\"`."
+    )]
+    DeclarationFilesRequireTypeScript { project_name: ProjectName },
+
+    #[error(
+        "The project `{project_name}` sets `enumConstObjects`, which is only supported with `\"language\": \"typescript\"`."
+    )]
+    EnumConstObjectsRequireTypeScript { project_name: ProjectName },
+
+    #[error(
+        "The project `{project_name}` sets `enumConstObjects` and `enumModuleSuffix`, enums can't be both imported and generated."
+    )]
+    EnumConstObjectsWithEnumModuleSuffix { project_name: ProjectName },
+
+    #[error(
+        "The projects `{other_project_name}` and `{project_name}` use the same `bundle` `{bundle_path}`, definitions of different projects can't be bundled together."
+    )]
+    SharedBundle {
+        project_name: ProjectName,
+        other_project_name: ProjectName,
+        bundle_path: PathBuf,
+    },
+
+    #[error(
+        "The extension glob `{pattern}` of project `{project_name}` needs to start with a directory without wildcards, e.g. `graphql/**/*.graphql`."
+    )]
+    ExtensionGlobWithoutRoot {
+        project_name: ProjectName,
+        pattern: String,
+    },
+
+    #[error(
+        "The project `{project_name}` configures the validation rule `{rule_name}` in `validationRules` or `validationRulesByDirectory`, but no such rule exists."
+    )]
+    UnknownValidationRule {
+        project_name: ProjectName,
+        rule_name: String,
+    },
+
+    #[error("The `connectionInterface` is invalid: {error}")]
+    InvalidConnectionInterface { error: ConnectionInterfaceError },
+
+    #[error(
+        "The project `{project_name}` disables the transform `{transform_name}` in `disabledTransforms`, but other transforms or the code generation depend on it."
+    )]
+    RequiredTransformDisabled {
+        project_name: ProjectName,
+        transform_name: String,
+    },
+
+    #[error(
+        "The project `{project_name}` disables the transform `{transform_name}` in `disabledTransforms`, but no such transform exists."
+    )]
+    UnknownTransform {
+        project_name: ProjectName,
+        transform_name: String,
+    },
+
     #[error("The Regex in `shardPathStrip` for project `{project_name}` is invalid.\n {error}.")]
     InvalidShardPathStripRegex {
         project_name: ProjectName,
@@ -200,6 +291,11 @@ pub enum BuildProjectError {
     )]
     PersistErrors { errors: Vec<PersistError> },
 
+    #[error(
+        "The project `{project_name}` is `persisted_only`, but the compiler has no operation persister to persist its operations with."
+    )]
+    PersistedOnlyWithoutPersister { project_name: ProjectName },
+
     #[error("Failed to write file `{file}`: {source}")]
     WriteFileError { file: PathBuf, source: io::Error },
 
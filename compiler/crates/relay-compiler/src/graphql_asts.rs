@@ -7,10 +7,14 @@
 
 use crate::compiler_state::{GraphQLSources, SourceSetName};
 use crate::errors::{Error, Result};
-use common::SourceLocationKey;
+use crate::watchman::is_standalone_graphql_file;
+use common::{Diagnostic, SourceLocationKey, Span};
 use fnv::{FnvHashMap, FnvHashSet};
-use graphql_syntax::ExecutableDefinition;
-use interner::StringKey;
+use graphql_ir::ValidationMessage;
+use graphql_syntax::{ExecutableDefinition, Identifier, OperationKind, Token, TokenKind};
+use interner::{Intern, StringKey};
+use relay_transforms::extract_module_name;
+use std::path::Path;
 
 #[derive(Debug)]
 pub struct GraphQLAsts {
@@ -25,6 +29,7 @@ impl GraphQLAsts {
     pub fn from_graphql_sources_map(
         graphql_sources_map: &FnvHashMap<SourceSetName, GraphQLSources>,
         dirty_definitions_map: &FnvHashMap<SourceSetName, Vec<StringKey>>,
+        name_anonymous_operations: bool,
     ) -> Result<FnvHashMap<SourceSetName, GraphQLAsts>> {
         graphql_sources_map
             .iter()
@@ -32,6 +37,7 @@ impl GraphQLAsts {
                 let asts = GraphQLAsts::from_graphql_sources(
                     sources,
                     dirty_definitions_map.get(&source_set_name),
+                    name_anonymous_operations,
                 )?;
                 Ok((source_set_name, asts))
             })
@@ -44,6 +50,7 @@ impl GraphQLAsts {
     pub fn from_graphql_sources(
         graphql_sources: &GraphQLSources,
         dirty_definitions: Option<&Vec<StringKey>>,
+        name_anonymous_operations: bool,
     ) -> Result<Self> {
         let mut syntax_errors = Vec::new();
 
@@ -61,37 +68,45 @@ impl GraphQLAsts {
         for (file_name, pending_graphql_sources) in graphql_sources.pending.iter() {
             let mut definitions_for_file = Vec::new();
             for (index, graphql_source) in pending_graphql_sources.iter().enumerate() {
-                let source_location =
-                    SourceLocationKey::embedded(&file_name.to_string_lossy(), index);
+                let source_location = source_location_for_file(file_name, index);
                 match graphql_syntax::parse_executable(&graphql_source.text, source_location) {
                     Ok(document) => {
-                        for def in &document.definitions {
-                            let name = def.name().expect("Expected operation name to exist.");
-                            pending_definition_names.insert(name);
-                        }
                         definitions_for_file.extend(document.definitions);
                     }
                     Err(errors) => syntax_errors.extend(errors),
                 }
             }
+            syntax_errors.extend(name_operations(
+                file_name,
+                &mut definitions_for_file,
+                name_anonymous_operations,
+            ));
+            pending_definition_names
+                .extend(definitions_for_file.iter().filter_map(|def| def.name()));
             // Parse the processed source to get all processed definition names
             // and collect definition names that are removed from that file.
             // (A definition moved to another file is considered as a deletion and a new source)
             if let Some(processed_graphql_sources) = graphql_sources.processed.get(file_name) {
+                let mut processed_definitions = Vec::new();
                 for (index, graphql_source) in processed_graphql_sources.iter().enumerate() {
                     // TODO: parse name instead of the whole graphql text
-                    let source_location =
-                        SourceLocationKey::embedded(&file_name.to_string_lossy(), index);
+                    let source_location = source_location_for_file(file_name, index);
                     if let Ok(document) =
                         graphql_syntax::parse_executable(&graphql_source.text, source_location)
                     {
-                        for def in document.definitions {
-                            let name = def.name();
-                            if let Some(def_name) = name {
-                                if !definitions_for_file.iter().any(|def| def.name() == name) {
-                                    removed_definition_names.push(def_name);
-                                }
-                            }
+                        processed_definitions.extend(document.definitions);
+                    }
+                }
+                name_operations(
+                    file_name,
+                    &mut processed_definitions,
+                    name_anonymous_operations,
+                );
+                for def in processed_definitions {
+                    let name = def.name();
+                    if let Some(def_name) = name {
+                        if !definitions_for_file.iter().any(|def| def.name() == name) {
+                            removed_definition_names.push(def_name);
                         }
                     }
                 }
@@ -109,8 +124,7 @@ impl GraphQLAsts {
 
             let mut definitions_for_file = Vec::new();
             for (index, graphql_source) in processed_graphql_sources.iter().enumerate() {
-                let source_location =
-                    SourceLocationKey::embedded(&file_name.to_string_lossy(), index);
+                let source_location = source_location_for_file(file_name, index);
                 match graphql_syntax::parse_executable(&graphql_source.text, source_location) {
                     Ok(document) => {
                         definitions_for_file.extend(document.definitions);
@@ -118,6 +132,11 @@ impl GraphQLAsts {
                     Err(errors) => syntax_errors.extend(errors),
                 }
             }
+            syntax_errors.extend(name_operations(
+                file_name,
+                &mut definitions_for_file,
+                name_anonymous_operations,
+            ));
             asts.extend(definitions_for_file);
         }
 
@@ -134,3 +153,188 @@ impl GraphQLAsts {
         }
     }
 }
+
+/// Standalone GraphQL files are a single source, the sources of other files
+/// are the `graphql` tagged literals embedded in them.
+fn source_location_for_file(file_name: &Path, index: usize) -> SourceLocationKey {
+    if is_standalone_graphql_file(file_name) {
+        SourceLocationKey::standalone(&file_name.to_string_lossy())
+    } else {
+        SourceLocationKey::embedded(&file_name.to_string_lossy(), index)
+    }
+}
+
+/// Anonymous operations are named after the module name of the file if
+/// `name_anonymous_operations` is set and the file has a single operation, so
+/// that the name stays stable across builds. Otherwise, every anonymous
+/// operation is reported, for both project and base project sources.
+fn name_operations(
+    file_name: &Path,
+    definitions: &mut [ExecutableDefinition],
+    name_anonymous_operations: bool,
+) -> Vec<Diagnostic> {
+    let operation_count = definitions
+        .iter()
+        .filter(|definition| matches!(definition, ExecutableDefinition::Operation(_)))
+        .count();
+    let module_name = if name_anonymous_operations && operation_count == 1 {
+        extract_module_name(&file_name.to_string_lossy())
+    } else {
+        None
+    };
+    let mut errors = Vec::new();
+    for definition in definitions {
+        if let ExecutableDefinition::Operation(operation) = definition {
+            if operation.name.is_some() {
+                continue;
+            }
+            match &module_name {
+                Some(module_name) => {
+                    let kind = operation
+                        .operation
+                        .as_ref()
+                        .map_or(OperationKind::Query, |(_, kind)| *kind);
+                    let suffix = match kind {
+                        OperationKind::Query => "Query",
+                        OperationKind::Mutation => "Mutation",
+                        OperationKind::Subscription => "Subscription",
+                    };
+                    // Point the name at the operation keyword, or at the start
+                    // of the selections of a query shorthand.
+                    let span = match &operation.operation {
+                        Some((token, _)) => token.span,
+                        None => {
+                            let start = operation.location.span().start;
+                            Span::new(start, start)
+                        }
+                    };
+                    operation.name = Some(Identifier {
+                        span,
+                        token: Token {
+                            span,
+                            kind: TokenKind::Identifier,
+                        },
+                        value: format!("{}{}", module_name, suffix).intern(),
+                    });
+                }
+                None => errors.push(Diagnostic::error(
+                    ValidationMessage::ExpectedOperationName(),
+                    operation.location,
+                )),
+            }
+        }
+    }
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::watchman::{source_for_location, SourceReader};
+    use graphql_syntax::GraphQLSource;
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn parse(file_name: &str, text: &str) -> Vec<ExecutableDefinition> {
+        graphql_syntax::parse_executable(text, SourceLocationKey::embedded(file_name, 0))
+            .unwrap()
+            .definitions
+    }
+
+    #[test]
+    fn test_name_anonymous_operation() {
+        let file_name = Path::new("src/Foo.react.js");
+        let mut definitions = parse(
+            "src/Foo.react.js",
+            "mutation { commentCreate(input: {}) { __typename } }
+            fragment Foo_user on User { id }",
+        );
+        assert!(name_operations(file_name, &mut definitions, true).is_empty());
+        assert_eq!(definitions[0].name(), Some("FooMutation".intern()));
+        assert_eq!(definitions[1].name(), Some("Foo_user".intern()));
+
+        let mut definitions = parse("src/Foo.react.js", "{ me { id } }");
+        assert!(name_operations(file_name, &mut definitions, true).is_empty());
+        assert_eq!(definitions[0].name(), Some("FooQuery".intern()));
+    }
+
+    #[test]
+    fn test_anonymous_operation_errors() {
+        let file_name = Path::new("src/Foo.react.js");
+        let text = "query { me { id } } query FooQuery { me { id } }";
+        let mut definitions = parse("src/Foo.react.js", text);
+        assert_eq!(name_operations(file_name, &mut definitions, true).len(), 1);
+        assert_eq!(definitions[0].name(), None);
+
+        let mut definitions = parse("src/Foo.react.js", "query { me { id } }");
+        assert_eq!(name_operations(file_name, &mut definitions, false).len(), 1);
+    }
+
+    struct TestSourceReader(HashMap<PathBuf, &'static str>);
+
+    impl SourceReader for TestSourceReader {
+        fn read_to_string(&self, path: &PathBuf) -> std::io::Result<String> {
+            Ok(self.0[path].to_string())
+        }
+    }
+
+    #[test]
+    fn test_standalone_graphql_files() {
+        let js_contents = "graphql`fragment Foo_user on User { id }`;";
+        let graphql_contents = "query BarQuery { me { ...Foo_user } }";
+        let mut sources = GraphQLSources::default();
+        sources.pending.insert(
+            "src/Foo.react.js".into(),
+            vec![GraphQLSource::new("fragment Foo_user on User { id }", 0, 8)],
+        );
+        sources.pending.insert(
+            "src/Bar.graphql".into(),
+            vec![GraphQLSource::new(graphql_contents, 0, 0)],
+        );
+        let asts = GraphQLAsts::from_graphql_sources(&sources, None, false).unwrap();
+
+        let mut locations = asts
+            .asts
+            .iter()
+            .map(|definition| {
+                (
+                    definition.name().unwrap().lookup(),
+                    definition.location().source_location(),
+                )
+            })
+            .collect::<Vec<_>>();
+        locations.sort();
+        assert_eq!(
+            locations,
+            vec![
+                ("BarQuery", SourceLocationKey::standalone("src/Bar.graphql")),
+                (
+                    "Foo_user",
+                    SourceLocationKey::embedded("src/Foo.react.js", 0)
+                ),
+            ]
+        );
+
+        let reader = TestSourceReader(
+            vec![
+                (PathBuf::from("/root/src/Foo.react.js"), js_contents),
+                (PathBuf::from("/root/src/Bar.graphql"), graphql_contents),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        let root_dir = Path::new("/root");
+        assert_eq!(
+            source_for_location(root_dir, locations[0].1, &reader)
+                .unwrap()
+                .text,
+            graphql_contents
+        );
+        assert_eq!(
+            source_for_location(root_dir, locations[1].1, &reader)
+                .unwrap()
+                .text,
+            "fragment Foo_user on User { id }"
+        );
+    }
+}
@@ -24,10 +24,16 @@ mod watchman;
 
 pub use build_project::{
     add_to_mercurial, apply_transforms,
-    artifact_writer::{ArtifactDifferenceWriter, ArtifactFileWriter, ArtifactWriter},
-    build_schema, create_path_for_artifact, generate_artifacts,
+    artifact_writer::{
+        ArtifactDifferenceWriter, ArtifactFileWriter, ArtifactMemoryWriter, ArtifactWriter,
+    },
+    build_schema, bundle_artifacts, check_schema_change, create_path_for_artifact,
+    generate_artifacts,
     generate_extra_artifacts::{GenerateExtraArtifactArgs, GenerateExtraArtifactsFn},
-    is_operation_preloadable, validate, Artifact, ArtifactContent, Programs, SourceHashes,
+    generate_persisted_queries_manifest, generate_source_map, is_operation_preloadable,
+    parse_and_validate, validate, Artifact, ArtifactContent, DefinitionSchemaChange, Programs,
+    SchemaChangeSafety, SourceHashes, ValidationContext, ValidationDiagnostics, ValidationRule,
+    ValidationSeverity, DISABLEABLE_TRANSFORMS, REQUIRED_TRANSFORMS, VALIDATION_RULES,
 };
 pub use config::{OperationPersister, PersistConfig};
 pub use graphql_asts::GraphQLAsts;
@@ -5,9 +5,10 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use common::{NoopPerfLogger, PerfLogger, ProfileLogger};
 use env_logger::Env;
 use log::{error, info};
-use relay_compiler::{compiler::Compiler, config::Config};
+use relay_compiler::{compiler::Compiler, config::Config, status_reporter::JsonStatusReporter};
 use std::{path::PathBuf, sync::Arc};
 use structopt::StructOpt;
 
@@ -21,17 +22,47 @@ struct Opt {
     #[structopt(long, short)]
     watch: bool,
 
+    /// Compile all files and persist all operations, ignoring persist ids
+    /// saved in existing artifacts
+    #[structopt(long)]
+    repersist: bool,
+
+    /// Write a JSON snapshot of the schema of each project to
+    /// `<DIR>/<project>.graphql.json` instead of compiling
+    #[structopt(long, value_name = "DIR")]
+    schema_snapshot: Option<PathBuf>,
+
+    /// Print the duration of each compiler phase and transform per project,
+    /// sorted by duration, after the compilation
+    #[structopt(long)]
+    profile: bool,
+
+    /// Print errors to stdout as JSON, one object per line with the message
+    /// and the file path and byte range of each location
+    #[structopt(long)]
+    json_diagnostics: bool,
+
+    /// Log debug output of the compiler, e.g. the base project fragments
+    /// removed from the output of each project
+    #[structopt(long)]
+    verbose: bool,
+
     /// Path to the compiler config file
     config: PathBuf,
 }
 
 #[tokio::main]
 async fn main() {
-    env_logger::from_env(Env::default().default_filter_or("info")).init();
-
     let opt = Opt::from_args();
 
-    let config = match Config::load(opt.config) {
+    let default_filter = if opt.verbose {
+        "info,relay_compiler=debug"
+    } else {
+        "info"
+    };
+    env_logger::from_env(Env::default().default_filter_or(default_filter)).init();
+
+    let mut config = match Config::load(opt.config.clone()) {
         Ok(config) => config,
         Err(err) => {
             error!("{}", err);
@@ -39,15 +70,49 @@ async fn main() {
         }
     };
 
-    let compiler = Compiler::new(Arc::new(config), Arc::new(common::NoopPerfLogger));
+    if opt.repersist {
+        config.compile_everything = true;
+        config.repersist_operations = true;
+    }
+
+    if opt.json_diagnostics {
+        config.status_reporter = Box::new(JsonStatusReporter::new(config.root_dir.clone()));
+    }
+
+    if opt.profile {
+        run(&opt, config, ProfileLogger::default()).await
+    } else {
+        run(&opt, config, NoopPerfLogger).await
+    }
+}
+
+async fn run<TPerfLogger: PerfLogger + 'static>(
+    opt: &Opt,
+    config: Config,
+    perf_logger: TPerfLogger,
+) {
+    let perf_logger = Arc::new(perf_logger);
+    let compiler = Compiler::new(Arc::new(config), Arc::clone(&perf_logger));
 
-    if opt.watch {
+    if let Some(output_dir) = &opt.schema_snapshot {
+        match compiler.write_schema_snapshots(output_dir).await {
+            Ok(()) => {
+                info!("Done");
+            }
+            Err(err) => {
+                error!("{}", err);
+                std::process::exit(1);
+            }
+        }
+    } else if opt.watch {
         if let Err(err) = compiler.watch().await {
             error!("{}", err);
             std::process::exit(1);
         }
     } else {
-        match compiler.compile().await {
+        let result = compiler.compile().await;
+        perf_logger.flush();
+        match result {
             Ok(_compiler_state) => {
                 info!("Done");
             }
@@ -9,14 +9,18 @@ use crate::{
     errors::{BuildProjectError, Error, Result},
     source_for_location, FsSourceReader, SourceReader,
 };
-use common::Diagnostic;
-use graphql_cli::DiagnosticPrinter;
-use log::{error, info};
+use common::{Diagnostic, Location, Severity, SourceLocationKey};
+use graphql_cli::{DiagnosticPrinter, Sources};
+use graphql_syntax::GraphQLSource;
+use log::{error, info, warn};
+use serde::Serialize;
 use std::path::PathBuf;
 
 pub trait StatusReporter {
     fn build_starts(&self);
-    fn build_finishes(&self, result: &Result<()>);
+    /// Called with the result of a build and the warnings of all projects
+    /// that were built, which are reported whether the build failed or not.
+    fn build_finishes(&self, result: &Result<()>, warnings: &[Diagnostic]);
 }
 
 pub struct ConsoleStatusReporter {
@@ -74,18 +78,30 @@ impl ConsoleStatusReporter {
     }
 
     fn print_diagnostic(&self, diagnostic: &Diagnostic) {
-        let printer = DiagnosticPrinter::new(|source_location| {
-            source_for_location(&self.root_dir, source_location, self.source_reader.as_ref())
-                .map(|source| source.text)
-        });
-        error!("{}", printer.diagnostic_to_string(diagnostic));
+        match diagnostic.severity() {
+            Severity::Error => error!("{}", self.diagnostic_to_string(diagnostic)),
+            Severity::Warning => warn!("{}", self.diagnostic_to_string(diagnostic)),
+        }
+    }
+
+    fn diagnostic_to_string(&self, diagnostic: &Diagnostic) -> String {
+        DiagnosticPrinter::new(self).diagnostic_to_string(diagnostic)
+    }
+}
+
+impl Sources for &ConsoleStatusReporter {
+    fn get(&self, source_location: SourceLocationKey) -> Option<GraphQLSource> {
+        source_for_location(&self.root_dir, source_location, self.source_reader.as_ref())
     }
 }
 
 impl StatusReporter for ConsoleStatusReporter {
     fn build_starts(&self) {}
 
-    fn build_finishes(&self, result: &Result<()>) {
+    fn build_finishes(&self, result: &Result<()>, warnings: &[Diagnostic]) {
+        for warning in warnings {
+            self.print_diagnostic(warning);
+        }
         if let Err(error) = result {
             self.print_error(error);
             if !matches!(error, Error::Cancelled) {
@@ -94,3 +110,244 @@ impl StatusReporter for ConsoleStatusReporter {
         }
     }
 }
+
+/// Prints the warnings and errors of a build to stdout as JSON, one object
+/// per line, for editors and CI integrations.
+pub struct JsonStatusReporter {
+    source_reader: Box<dyn SourceReader + Send + Sync>,
+    root_dir: PathBuf,
+}
+
+impl JsonStatusReporter {
+    pub fn new(root_dir: PathBuf) -> Self {
+        Self {
+            root_dir,
+            source_reader: Box::new(FsSourceReader),
+        }
+    }
+}
+
+impl Sources for &JsonStatusReporter {
+    fn get(&self, source_location: SourceLocationKey) -> Option<GraphQLSource> {
+        source_for_location(&self.root_dir, source_location, self.source_reader.as_ref())
+    }
+}
+
+impl StatusReporter for JsonStatusReporter {
+    fn build_starts(&self) {}
+
+    fn build_finishes(&self, result: &Result<()>, warnings: &[Diagnostic]) {
+        let mut diagnostics = warnings
+            .iter()
+            .map(|warning| JsonDiagnostic::from_diagnostic(warning, &self))
+            .collect::<Vec<_>>();
+        if let Err(error) = result {
+            diagnostics.extend(JsonDiagnostic::from_error(error, &self));
+        }
+        for diagnostic in diagnostics {
+            println!("{}", serde_json::to_string(&diagnostic).unwrap());
+        }
+    }
+}
+
+/// A warning or error of the build. Errors that aren't about the sources,
+/// e.g. failing to write artifacts, don't have any `locations`.
+#[derive(Debug, Serialize)]
+pub struct JsonDiagnostic {
+    /// `"error"` or `"warning"`.
+    pub severity: String,
+    pub message: String,
+    /// The location of the diagnostic, followed by the related locations.
+    pub locations: Vec<JsonLocation>,
+}
+
+/// A range in a source file, relative to the root directory. `start` and
+/// `end` are byte offsets into the GraphQL source, i.e. into the `graphql`
+/// literal for embedded sources. The one-based lines and columns are
+/// positions in the file, with columns counted in UTF-16 code units as in
+/// LSP, and are left out if the file can't be read.
+#[derive(Debug, Serialize)]
+pub struct JsonLocation {
+    pub path: &'static str,
+    pub start: u32,
+    pub end: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_line: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_column: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<u64>,
+}
+
+impl JsonDiagnostic {
+    pub fn from_error(error: &Error, sources: &impl Sources) -> Vec<Self> {
+        match error {
+            Error::DiagnosticsError { errors } => errors
+                .iter()
+                .map(|diagnostic| Self::from_diagnostic(diagnostic, sources))
+                .collect(),
+            Error::BuildProjectsErrors { errors } => errors
+                .iter()
+                .flat_map(|error| match error {
+                    BuildProjectError::ValidationErrors { errors } => errors
+                        .iter()
+                        .map(|diagnostic| Self::from_diagnostic(diagnostic, sources))
+                        .collect(),
+                    BuildProjectError::PersistErrors { errors } => errors
+                        .iter()
+                        .map(|error| Self::without_location(error.to_string()))
+                        .collect(),
+                    error => vec![Self::without_location(error.to_string())],
+                })
+                .collect(),
+            Error::Cancelled => vec![],
+            error => vec![Self::without_location(error.to_string())],
+        }
+    }
+
+    pub fn from_diagnostic(diagnostic: &Diagnostic, sources: &impl Sources) -> Self {
+        Self {
+            severity: diagnostic.severity().to_string(),
+            message: diagnostic.message().to_string(),
+            locations: std::iter::once(diagnostic.location())
+                .chain(
+                    diagnostic
+                        .related_information()
+                        .iter()
+                        .map(|related| related.location),
+                )
+                .filter_map(|location| JsonLocation::from_location(location, sources))
+                .collect(),
+        }
+    }
+
+    fn without_location(message: String) -> Self {
+        Self {
+            severity: Severity::Error.to_string(),
+            message,
+            locations: vec![],
+        }
+    }
+}
+
+impl JsonLocation {
+    fn from_location(location: Location, sources: &impl Sources) -> Option<Self> {
+        if let SourceLocationKey::Generated = location.source_location() {
+            return None;
+        }
+        let range = sources.get(location.source_location()).map(|source| {
+            location
+                .span()
+                .to_range(&source.text, source.line_index, source.column_index)
+        });
+        Some(Self {
+            path: location.source_location().path(),
+            start: location.span().start,
+            end: location.span().end,
+            start_line: range.map(|range| range.start.line + 1),
+            start_column: range.map(|range| range.start.character + 1),
+            end_line: range.map(|range| range.end.line + 1),
+            end_column: range.map(|range| range.end.character + 1),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Span;
+    use graphql_ir::ValidationMessage;
+    use interner::Intern;
+    use std::collections::HashMap;
+
+    /// The literal in `src/Foo.react.js` starts on the third line of the
+    /// file, other files can't be read.
+    struct TestSources;
+
+    impl Sources for TestSources {
+        fn get(&self, source_location: SourceLocationKey) -> Option<GraphQLSource> {
+            match source_location {
+                SourceLocationKey::Embedded { .. } => Some(GraphQLSource::new(
+                    "\n  fragment Foo on Usr {\n    id\n  }\n",
+                    2,
+                    26,
+                )),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_json_diagnostic() {
+        let error = Error::DiagnosticsError {
+            errors: vec![Diagnostic::error(
+                ValidationMessage::UnknownType("Usr".intern()),
+                Location::new(
+                    SourceLocationKey::embedded("src/Foo.react.js", 0),
+                    Span::new(19, 22),
+                ),
+            )
+            .annotate(
+                "related location",
+                Location::new(
+                    SourceLocationKey::standalone("src/Bar.graphql"),
+                    Span::new(5, 8),
+                ),
+            )],
+        };
+        let lines = JsonDiagnostic::from_error(&error, &TestSources)
+            .iter()
+            .map(|diagnostic| serde_json::to_string(diagnostic).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            vec![
+                r#"{"severity":"error","message":"Unknown type 'Usr'","locations":[{"path":"src/Foo.react.js","start":19,"end":22,"start_line":4,"start_column":19,"end_line":4,"end_column":22},{"path":"src/Bar.graphql","start":5,"end":8}]}"#
+            ]
+        );
+    }
+
+    struct TestSourceReader(HashMap<PathBuf, String>);
+
+    impl SourceReader for TestSourceReader {
+        fn read_to_string(&self, path: &PathBuf) -> std::io::Result<String> {
+            Ok(self.0[path].clone())
+        }
+    }
+
+    #[test]
+    fn test_print_embedded_diagnostic() {
+        // The literal starts on line 40 of the file.
+        let js_contents = format!(
+            "{}const fragment = graphql`\n  fragment Foo_user on User {{\n    id(\n  }}\n`;\n",
+            "// padding\n".repeat(39)
+        );
+        let reporter = ConsoleStatusReporter {
+            source_reader: Box::new(TestSourceReader(
+                vec![(PathBuf::from("/root/src/Foo.react.js"), js_contents.clone())]
+                    .into_iter()
+                    .collect(),
+            )),
+            root_dir: PathBuf::from("/root"),
+        };
+        let source_location = SourceLocationKey::embedded("src/Foo.react.js", 0);
+        let source = source_for_location(
+            &reporter.root_dir,
+            source_location,
+            reporter.source_reader.as_ref(),
+        )
+        .unwrap();
+        assert_eq!(source.line_index, 39);
+        let diagnostics =
+            graphql_syntax::parse_executable(&source.text, source_location).unwrap_err();
+
+        let printed = reporter.diagnostic_to_string(&diagnostics[0]);
+        assert!(printed.contains("src/Foo.react.js"));
+        // The unexpected `}` is on the fourth line of the literal, the source
+        // excerpt is numbered the same way.
+        assert!(printed.contains(":43:3"));
+        assert!(printed.contains("  43 \u{2502} "));
+    }
+}
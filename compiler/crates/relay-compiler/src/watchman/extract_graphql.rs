@@ -29,13 +29,31 @@ impl SourceReader for FsSourceReader {
     }
 }
 
-/// Reads and extracts `graphql` tagged literals from a file.
+/// Reads and extracts `graphql` tagged literals from a file. The contents of
+/// standalone `.graphql` and `.gql` files are a single source.
 pub fn extract_graphql_strings_from_file(
     resolved_root: &ResolvedRoot,
     file: &WatchmanFile,
 ) -> Result<Vec<GraphQLSource>> {
     let contents = read_to_string(resolved_root, file)?;
-    extract_graphql_strings_from_string(&contents)
+    if is_standalone_graphql_file(&file.name) {
+        Ok(vec![GraphQLSource {
+            text: contents,
+            line_index: 0,
+            column_index: 0,
+        }])
+    } else {
+        extract_graphql_strings_from_string(&contents)
+    }
+}
+
+/// Whether the source file is a GraphQL document rather than JavaScript with
+/// embedded `graphql` tagged literals.
+pub fn is_standalone_graphql_file(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|extension| extension.to_str()),
+        Some("graphql") | Some("gql")
+    )
 }
 
 pub fn source_for_location(
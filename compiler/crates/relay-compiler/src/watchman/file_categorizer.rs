@@ -8,7 +8,7 @@
 use super::FileGroup;
 use super::WatchmanFile;
 use crate::compiler_state::{ProjectName, ProjectSet, SourceSet};
-use crate::config::{Config, SchemaLocation};
+use crate::config::{Config, ExtensionGlob, SchemaLocation};
 use fnv::FnvHashSet;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
@@ -31,10 +31,8 @@ pub fn categorize_files(
     let mut relevant_projects = FnvHashSet::default();
     for (project_name, project_config) in &config.projects {
         if project_config.enabled {
-            relevant_projects.insert(project_name);
-            if let Some(base_project) = &project_config.base {
-                relevant_projects.insert(base_project);
-            }
+            relevant_projects.insert(*project_name);
+            relevant_projects.extend(config.base_projects(*project_name));
         } else {
             has_disabled = true;
         }
@@ -79,9 +77,11 @@ pub fn categorize_files(
 /// specific source file group or generated files from some project.
 pub struct FileCategorizer {
     extensions_mapping: PathMapping<ProjectSet>,
+    extension_globs: Vec<(ProjectName, ExtensionGlob)>,
     default_generated_dir: &'static OsStr,
     generated_dir_mapping: PathMapping<ProjectName>,
     source_mapping: PathMapping<SourceSet>,
+    standalone_source_mapping: PathMapping<SourceSet>,
     schema_file_mapping: HashMap<PathBuf, ProjectSet>,
     schema_dir_mapping: PathMapping<ProjectSet>,
 }
@@ -93,6 +93,26 @@ impl FileCategorizer {
             source_mapping.push((path.clone(), source_set.clone()));
         }
 
+        // The source directories, restricted to the projects compiling
+        // standalone *.graphql and *.gql documents.
+        let mut standalone_source_mapping = vec![];
+        for (path, source_set) in &config.sources {
+            let names = match source_set {
+                SourceSet::SourceSetName(name) => vec![*name],
+                SourceSet::SourceSetNames(names) => names.clone(),
+            };
+            let mut names = names
+                .into_iter()
+                .filter(|name| config.projects[name].standalone_graphql_files)
+                .collect::<Vec<_>>();
+            let standalone_source_set = match names.len() {
+                0 => continue,
+                1 => SourceSet::SourceSetName(names.pop().unwrap()),
+                _ => SourceSet::SourceSetNames(names),
+            };
+            standalone_source_mapping.push((path.clone(), standalone_source_set));
+        }
+
         let mut extensions_map: HashMap<PathBuf, ProjectSet> = Default::default();
         for (&project_name, project_config) in &config.projects {
             for extension_dir in &project_config.extensions {
@@ -107,6 +127,20 @@ impl FileCategorizer {
             }
         }
 
+        // Sorted by project name, so files matched by the globs of multiple
+        // projects get a deterministic project set.
+        let mut extension_globs = config
+            .projects
+            .iter()
+            .flat_map(|(&project_name, project_config)| {
+                project_config
+                    .extension_globs
+                    .iter()
+                    .map(move |glob| (project_name, glob.clone()))
+            })
+            .collect::<Vec<_>>();
+        extension_globs.sort_by_key(|(project_name, _)| project_name.lookup());
+
         let mut schema_file_mapping: HashMap<PathBuf, ProjectSet> = Default::default();
         for (&project_name, project_config) in &config.projects {
             if let SchemaLocation::File(schema_file) = &project_config.schema_location {
@@ -152,11 +186,13 @@ impl FileCategorizer {
 
         Self {
             extensions_mapping: PathMapping::new(extensions_map.into_iter().collect()),
+            extension_globs,
             default_generated_dir: OsStr::new("__generated__"),
             generated_dir_mapping: PathMapping::new(generated_dir_mapping),
             schema_file_mapping,
             schema_dir_mapping: PathMapping::new(schema_dir_mapping),
             source_mapping: PathMapping::new(source_mapping),
+            standalone_source_mapping: PathMapping::new(standalone_source_mapping),
         }
     }
 
@@ -167,13 +203,16 @@ impl FileCategorizer {
         if let Some(project_name) = self.generated_dir_mapping.find(path) {
             return FileGroup::Generated { project_name };
         }
-        let extension = path
-            .extension()
-            .unwrap_or_else(|| panic!("Got unexpected path without extension: `{:?}`.", path));
-        if extension == "js" {
-            let source_set = self.source_mapping.get(path);
-            if self.in_relative_generated_dir(path) {
-                if let SourceSet::SourceSetName(source_set_name) = source_set {
+        if let Some(project_set) = self.schema_file_mapping.get(path) {
+            return FileGroup::Schema {
+                project_set: project_set.clone(),
+            };
+        }
+        // Artifacts of any kind, e.g. the operation text files, in the
+        // relative generated directories of the sources.
+        if self.in_relative_generated_dir(path) {
+            if let Some(source_set) = self.source_mapping.find(path) {
+                return if let SourceSet::SourceSetName(source_set_name) = source_set {
                     FileGroup::Generated {
                         project_name: source_set_name,
                     }
@@ -183,22 +222,27 @@ impl FileCategorizer {
                         directories. Got `{:?}` in a relative generated directory with source set {:?}",
                         path, source_set
                     );
-                }
-            } else {
-                FileGroup::Source { source_set }
+                };
             }
-        } else if extension == "graphql" {
-            if let Some(project_set) = self.schema_file_mapping.get(path) {
-                FileGroup::Schema {
-                    project_set: project_set.clone(),
-                }
-            } else if let Some(project_set) = self.extensions_mapping.find(path) {
+        }
+        let extension = path
+            .extension()
+            .unwrap_or_else(|| panic!("Got unexpected path without extension: `{:?}`.", path));
+        if extension == "js" || extension == "jsx" || extension == "ts" || extension == "tsx" {
+            FileGroup::Source {
+                source_set: self.source_mapping.get(path),
+            }
+        } else if extension == "graphql" || extension == "gql" {
+            if let Some(project_set) = self.find_extension_project_set(path) {
                 FileGroup::Extension { project_set }
             } else if let Some(project_set) = self.schema_dir_mapping.find(path) {
                 FileGroup::Schema { project_set }
+            } else if let Some(source_set) = self.standalone_source_mapping.find(path) {
+                // Standalone documents in the source directories
+                FileGroup::Source { source_set }
             } else {
                 panic!(
-                    "Expected *.graphql file `{:?}` to be either a schema or extension.",
+                    "Expected *.graphql file `{:?}` to be either a schema, extension or source.",
                     path
                 )
             }
@@ -210,6 +254,25 @@ impl FileCategorizer {
         }
     }
 
+    /// Combines the projects with an extension directory containing the path
+    /// and the projects with an extension glob matching the path.
+    fn find_extension_project_set(&self, path: &PathBuf) -> Option<ProjectSet> {
+        let mut project_set = self.extensions_mapping.find(path);
+        for (project_name, glob) in &self.extension_globs {
+            if glob.is_match(path) {
+                match &mut project_set {
+                    Some(project_set) => {
+                        if !project_set.contains(*project_name) {
+                            project_set.insert(*project_name);
+                        }
+                    }
+                    None => project_set = Some(ProjectSet::ProjectName(*project_name)),
+                }
+            }
+        }
+        project_set
+    }
+
     fn in_relative_generated_dir(&self, path: &PathBuf) -> bool {
         path.components().any(|comp| match comp {
             Component::Normal(comp) => comp == self.default_generated_dir,
@@ -267,10 +330,12 @@ mod tests {
                     },
                     "projects": {
                         "public": {
-                            "schema": "graphql/public.graphql"
+                            "schema": "graphql/public.graphql",
+                            "standaloneGraphqlFiles": true
                         },
                         "internal": {
-                            "schema": "graphql/__generated__/internal.graphql"
+                            "schema": "graphql/__generated__/internal.graphql",
+                            "standaloneGraphqlFiles": true
                         },
                         "with_custom_generated_dir": {
                             "schema": "graphql/__generated__/custom.graphql",
@@ -323,6 +388,24 @@ mod tests {
                 project_name: "with_custom_generated_dir".intern()
             },
         );
+        assert_eq!(
+            categorizer.categorize(&"src/js/queries/d.graphql".into()),
+            FileGroup::Source {
+                source_set: SourceSet::SourceSetName("public".intern()),
+            },
+        );
+        assert_eq!(
+            categorizer.categorize(&"src/js/internal/e.gql".into()),
+            FileGroup::Source {
+                source_set: SourceSet::SourceSetName("internal".intern()),
+            },
+        );
+        assert_eq!(
+            categorizer.categorize(&"src/js/internal/nested/__generated__/f.ts".into()),
+            FileGroup::Generated {
+                project_name: "internal".intern()
+            },
+        );
         assert_eq!(
             categorizer.categorize(&"graphql/public.graphql".into()),
             FileGroup::Schema {
@@ -336,4 +419,170 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_categorize_standalone_graphql_files() {
+        let config = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src/js": ["public", "internal"],
+                        "src/vendor": "internal"
+                    },
+                    "projects": {
+                        "public": {
+                            "schema": "graphql/public.graphql",
+                            "standaloneGraphqlFiles": true
+                        },
+                        "internal": {
+                            "schema": "graphql/internal.graphql"
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap();
+        let categorizer = FileCategorizer::from_config(&config);
+
+        assert_eq!(
+            categorizer.categorize(&"src/js/a.graphql".into()),
+            FileGroup::Source {
+                source_set: SourceSet::SourceSetName("public".intern()),
+            },
+        );
+        assert_eq!(
+            categorizer.categorize(&"src/js/b.js".into()),
+            FileGroup::Source {
+                source_set: SourceSet::SourceSetNames(vec!["public".intern(), "internal".intern()]),
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "to be either a schema, extension or source")]
+    fn test_categorize_disabled_standalone_graphql_files() {
+        let config = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src/js": "public"
+                    },
+                    "projects": {
+                        "public": {
+                            "schema": "graphql/public.graphql"
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap();
+        let categorizer = FileCategorizer::from_config(&config);
+        categorizer.categorize(&"src/js/a.graphql".into());
+    }
+
+    #[test]
+    fn test_categorize_operation_text_files() {
+        let config = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src/js": "public",
+                        "src/custom": "with_custom_generated_dir"
+                    },
+                    "projects": {
+                        "public": {
+                            "schema": "graphql/public.graphql",
+                            "operationTextFiles": true,
+                            "standaloneGraphqlFiles": true
+                        },
+                        "with_custom_generated_dir": {
+                            "schema": "graphql/custom.graphql",
+                            "output": "graphql/custom-generated",
+                            "operationTextFiles": true,
+                            "standaloneGraphqlFiles": true
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap();
+        let categorizer = FileCategorizer::from_config(&config);
+
+        // The emitted operation text files are artifacts, not new documents.
+        assert_eq!(
+            categorizer.categorize(&"src/js/nested/__generated__/FooQuery.graphql".into()),
+            FileGroup::Generated {
+                project_name: "public".intern()
+            },
+        );
+        assert_eq!(
+            categorizer.categorize(&"graphql/custom-generated/BarQuery.graphql".into()),
+            FileGroup::Generated {
+                project_name: "with_custom_generated_dir".intern()
+            },
+        );
+        assert_eq!(
+            categorizer.categorize(&"src/js/nested/FooQuery.graphql".into()),
+            FileGroup::Source {
+                source_set: SourceSet::SourceSetName("public".intern()),
+            },
+        );
+    }
+
+    #[test]
+    fn test_categorize_extension_globs() {
+        let config = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src/js": ["public", "internal"]
+                    },
+                    "projects": {
+                        "public": {
+                            "schema": "graphql/public.graphql",
+                            "extensionGlobs": ["src/js/**/*.public.graphql", "src/js/shared/*.graphql"]
+                        },
+                        "internal": {
+                            "schema": "graphql/internal.graphql",
+                            "extensions": ["src/js/internal"],
+                            "extensionGlobs": ["src/js/shared/?.graphql"]
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap();
+        let categorizer = FileCategorizer::from_config(&config);
+
+        assert_eq!(
+            categorizer.categorize(&"src/js/a.public.graphql".into()),
+            FileGroup::Extension {
+                project_set: ProjectSet::ProjectName("public".intern()),
+            },
+        );
+        assert_eq!(
+            categorizer.categorize(&"src/js/nested/b.public.graphql".into()),
+            FileGroup::Extension {
+                project_set: ProjectSet::ProjectName("public".intern()),
+            },
+        );
+        assert_eq!(
+            categorizer.categorize(&"src/js/shared/c.graphql".into()),
+            FileGroup::Extension {
+                project_set: ProjectSet::ProjectNames(vec!["internal".intern(), "public".intern()]),
+            },
+        );
+        assert_eq!(
+            categorizer.categorize(&"src/js/shared/long.graphql".into()),
+            FileGroup::Extension {
+                project_set: ProjectSet::ProjectName("public".intern()),
+            },
+        );
+        assert_eq!(
+            categorizer.categorize(&"src/js/internal/d.public.graphql".into()),
+            FileGroup::Extension {
+                project_set: ProjectSet::ProjectNames(vec!["internal".intern(), "public".intern()]),
+            },
+        );
+    }
 }
@@ -9,9 +9,13 @@ use super::query_builder::{get_all_roots, get_watchman_expr};
 use super::{Clock, WatchmanFile};
 use crate::errors::{Error, Result};
 use crate::{compiler_state::CompilerState, config::Config, saved_state::SavedStateLoader};
+use async_trait::async_trait;
 use common::{PerfLogEvent, PerfLogger};
+use fnv::FnvHashMap;
 use log::{info, warn};
 use serde_bser::value::Value;
+use std::path::PathBuf;
+use std::time::Duration;
 use watchman_client::prelude::*;
 use watchman_client::{Subscription as WatchmanSubscription, SubscriptionData};
 
@@ -271,17 +275,156 @@ impl FileSourceSubscription {
     /// Awaits changes from Watchman and provides the next set of changes
     /// if there were any changes to files
     pub async fn next_change(&mut self) -> Result<Option<FileSourceResult>> {
-        let update = self.subscription.next().await?;
-        if let SubscriptionData::FilesChanged(changes) = update {
+        Ok(self
+            .subscription
+            .next_changed_files()
+            .await?
+            .map(|changes| self.to_file_source_result(changes)))
+    }
+
+    /// Awaits changes from Watchman until no further changes arrive for
+    /// `debounce` and returns all of them as a single set of changes. A file
+    /// that changed more than once is only included with its latest state.
+    pub async fn next_coalesced(&mut self, debounce: Duration) -> Result<FileSourceResult> {
+        let changes = coalesce_changes(&mut self.subscription, debounce).await?;
+        Ok(self.to_file_source_result(changes))
+    }
+
+    fn to_file_source_result(&self, changes: FileChanges) -> FileSourceResult {
+        FileSourceResult {
+            files: changes.files,
+            resolved_root: self.resolved_root.clone(),
+            clock: changes.clock,
+            saved_state_info: None,
+        }
+    }
+}
+
+/// A batch of changed files and the clock after the changes.
+struct FileChanges {
+    files: Vec<WatchmanFile>,
+    clock: Clock,
+}
+
+#[async_trait]
+trait ChangedFilesSource {
+    async fn next_changed_files(&mut self) -> Result<Option<FileChanges>>;
+}
+
+#[async_trait]
+impl ChangedFilesSource for WatchmanSubscription<WatchmanFile> {
+    async fn next_changed_files(&mut self) -> Result<Option<FileChanges>> {
+        if let SubscriptionData::FilesChanged(changes) = self.next().await? {
             if let Some(files) = changes.files {
-                return Ok(Some(FileSourceResult {
+                return Ok(Some(FileChanges {
                     files,
-                    resolved_root: self.resolved_root.clone(),
                     clock: changes.clock,
-                    saved_state_info: None,
                 }));
             }
         }
         Ok(None)
     }
 }
+
+/// Waits for the first batch of changed files, then merges the batches that
+/// follow until none arrives within `debounce`.
+async fn coalesce_changes(
+    source: &mut (impl ChangedFilesSource + Send),
+    debounce: Duration,
+) -> Result<FileChanges> {
+    let mut coalesced = loop {
+        if let Some(changes) = source.next_changed_files().await? {
+            break changes;
+        }
+    };
+    let mut file_indices: FnvHashMap<PathBuf, usize> = coalesced
+        .files
+        .iter()
+        .enumerate()
+        .map(|(index, file)| ((*file.name).clone(), index))
+        .collect();
+    while let Ok(next) = tokio::time::timeout(debounce, source.next_changed_files()).await {
+        if let Some(changes) = next? {
+            for file in changes.files {
+                if let Some(&index) = file_indices.get(&*file.name) {
+                    coalesced.files[index] = file;
+                } else {
+                    file_indices.insert((*file.name).clone(), coalesced.files.len());
+                    coalesced.files.push(file);
+                }
+            }
+            coalesced.clock = changes.clock;
+        }
+    }
+    Ok(coalesced)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    /// Returns each batch after its delay, then never returns again.
+    struct DelayedBatches {
+        batches: VecDeque<(Duration, FileChanges)>,
+    }
+
+    #[async_trait]
+    impl ChangedFilesSource for DelayedBatches {
+        async fn next_changed_files(&mut self) -> Result<Option<FileChanges>> {
+            match self.batches.front() {
+                Some((delay, _)) => {
+                    tokio::time::delay_for(*delay).await;
+                    Ok(self.batches.pop_front().map(|(_, changes)| changes))
+                }
+                None => futures::future::pending().await,
+            }
+        }
+    }
+
+    fn file(name: &str, exists: bool) -> WatchmanFile {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "exists": exists,
+            "content.sha1hex": "da39a3ee5e6b4b0d3255bfef95601890afd80709",
+        }))
+        .unwrap()
+    }
+
+    fn changes(files: Vec<WatchmanFile>, clock: &str) -> FileChanges {
+        FileChanges {
+            files,
+            clock: Clock::Spec(ClockSpec::StringClock(clock.to_string())),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_coalesce_changes() {
+        let rapid = Duration::from_millis(1);
+        let mut source = DelayedBatches {
+            batches: vec![
+                (
+                    rapid,
+                    changes(vec![file("a.js", true), file("b.js", true)], "c:1"),
+                ),
+                (rapid, changes(vec![file("c.js", true)], "c:2")),
+                (rapid, changes(vec![file("a.js", false)], "c:3")),
+            ]
+            .into_iter()
+            .collect(),
+        };
+        let coalesced = coalesce_changes(&mut source, Duration::from_millis(100))
+            .await
+            .unwrap();
+        let files = coalesced
+            .files
+            .iter()
+            .map(|file| ((*file.name).to_str().unwrap(), *file.exists))
+            .collect::<Vec<_>>();
+        assert_eq!(files, vec![("a.js", false), ("b.js", true), ("c.js", true)]);
+        match coalesced.clock {
+            Clock::Spec(ClockSpec::StringClock(clock)) => assert_eq!(clock, "c:3"),
+            clock => panic!("Unexpected clock {:?}", clock),
+        }
+    }
+}
@@ -13,7 +13,8 @@ mod query_builder;
 mod watchman_file;
 
 pub use self::extract_graphql::{
-    extract_graphql_strings_from_file, source_for_location, FsSourceReader, SourceReader,
+    extract_graphql_strings_from_file, is_standalone_graphql_file, source_for_location,
+    FsSourceReader, SourceReader,
 };
 pub use file_categorizer::{categorize_files, FileCategorizer};
 pub use file_group::FileGroup;
@@ -25,12 +25,22 @@ pub fn get_watchman_expr(config: &Config) -> Expr {
             })
             .map(|(path, project)| {
                 Expr::All(vec![
-                    // Ending in *.js(x) or *.ts(x) depending on the project language.
-                    Expr::Suffix(match &project.typegen_config.language {
-                        TypegenLanguage::Flow => vec![PathBuf::from("js"), PathBuf::from("jsx")],
-                        TypegenLanguage::TypeScript => {
-                            vec![PathBuf::from("ts"), PathBuf::from("tsx")]
+                    // Ending in *.js(x) or *.ts(x) depending on the project language,
+                    // or standalone *.graphql and *.gql documents if enabled.
+                    Expr::Suffix({
+                        let mut suffixes = match &project.typegen_config.language {
+                            TypegenLanguage::Flow => {
+                                vec![PathBuf::from("js"), PathBuf::from("jsx")]
+                            }
+                            TypegenLanguage::TypeScript => {
+                                vec![PathBuf::from("ts"), PathBuf::from("tsx")]
+                            }
+                        };
+                        if project.standalone_graphql_files {
+                            suffixes.push(PathBuf::from("graphql"));
+                            suffixes.push(PathBuf::from("gql"));
                         }
+                        suffixes
                     }),
                     // In the related source root.
                     Expr::DirName(DirNameTerm {
@@ -88,6 +98,28 @@ pub fn get_watchman_expr(config: &Config) -> Expr {
         expressions.push(extensions_expr);
     }
 
+    let extension_globs = get_extension_glob_patterns(config);
+    if !extension_globs.is_empty() {
+        let extension_globs_expr = Expr::All(vec![
+            // ending in *.graphql
+            Expr::Suffix(vec!["graphql".into()]),
+            // matching one of the extension globs
+            expr_any(
+                extension_globs
+                    .into_iter()
+                    .map(|glob| {
+                        Expr::Match(MatchTerm {
+                            glob,
+                            wholename: true,
+                            ..Default::default()
+                        })
+                    })
+                    .collect(),
+            ),
+        ]);
+        expressions.push(extension_globs_expr);
+    }
+
     Expr::All(vec![
         // we generally only care about regular files
         Expr::FileType(FileType::Regular),
@@ -101,6 +133,7 @@ pub fn get_all_roots(config: &Config) -> Vec<PathBuf> {
     let source_roots = get_source_roots(config);
     let output_roots = get_output_dir_paths(config);
     let extension_roots = get_extension_roots(config);
+    let extension_glob_roots = get_extension_glob_roots(config);
     let schema_file_roots = get_schema_file_roots(config);
     let schema_dir_roots = get_schema_dir_paths(config);
     unify_roots(
@@ -108,6 +141,7 @@ pub fn get_all_roots(config: &Config) -> Vec<PathBuf> {
             .into_iter()
             .chain(output_roots)
             .chain(extension_roots)
+            .chain(extension_glob_roots)
             .chain(schema_file_roots)
             .chain(schema_dir_roots)
             .collect(),
@@ -129,6 +163,35 @@ fn get_extension_roots(config: &Config) -> Vec<PathBuf> {
         .collect()
 }
 
+/// Returns the root directories of all GraphQL schema extension globs for the
+/// config.
+fn get_extension_glob_roots(config: &Config) -> Vec<PathBuf> {
+    config
+        .projects
+        .values()
+        .flat_map(|project_config| {
+            project_config
+                .extension_globs
+                .iter()
+                .map(|glob| glob.root.clone())
+        })
+        .collect()
+}
+
+/// Returns all glob patterns of GraphQL schema extension files for the config.
+fn get_extension_glob_patterns(config: &Config) -> Vec<String> {
+    config
+        .projects
+        .values()
+        .flat_map(|project_config| {
+            project_config
+                .extension_globs
+                .iter()
+                .map(|glob| glob.pattern.clone())
+        })
+        .collect()
+}
+
 /// Returns all output and extra artifact output directories for the config.
 fn get_output_dir_paths(config: &Config) -> Vec<PathBuf> {
     let output_dirs = config
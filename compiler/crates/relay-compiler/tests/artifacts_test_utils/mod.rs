@@ -0,0 +1,106 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+// Shared by the artifact fixture tests, each of them uses only some of it.
+#![allow(dead_code)]
+
+use common::{ConsoleLogger, SourceLocationKey};
+use fixture_tests::Fixture;
+use graphql_ir::{build, Program};
+use graphql_syntax::parse_executable;
+use graphql_test_helpers::diagnostics_to_sorted_string;
+use relay_codegen::Printer;
+use relay_compiler::{
+    apply_transforms,
+    config::{Config, ProjectConfig},
+    generate_artifacts, Artifact, Programs, SourceHashes,
+};
+use relay_test_schema::get_test_schema;
+use relay_transforms::{ConnectionInterface, FeatureFlags};
+use std::sync::Arc;
+
+/// Builds the documents of the fixture against the test schema and generates
+/// their artifacts for the project.
+pub fn generate_fixture_artifacts(
+    fixture: &Fixture<'_>,
+    project_config: &ProjectConfig,
+) -> Result<(Programs, Vec<Artifact>), String> {
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let schema = get_test_schema();
+    let ast = parse_executable(fixture.content, source_location)
+        .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
+    let ir = build(&schema, &ast.definitions)
+        .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
+    let source_hashes = SourceHashes::from_definitions(&ast.definitions);
+    let program = Program::from_definitions(Arc::clone(&schema), ir);
+
+    let programs = apply_transforms(
+        project_config,
+        Arc::new(program),
+        Default::default(),
+        &ConnectionInterface::default(),
+        Arc::new(FeatureFlags::default()),
+        Arc::new(ConsoleLogger),
+    )
+    .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
+
+    let artifacts = generate_artifacts(project_config, &programs, Arc::new(source_hashes))
+        .map_err(|error| error.to_string())?;
+    Ok((programs, artifacts))
+}
+
+/// The content of each artifact as written by the compiler.
+pub fn artifact_contents<'a>(
+    project_config: &ProjectConfig,
+    programs: &Programs,
+    artifacts: &'a [Artifact],
+) -> Vec<(&'a Artifact, String)> {
+    let config = Config::default();
+    let mut printer = Printer::with_dedupe();
+    artifacts
+        .iter()
+        .map(|artifact| {
+            let content = artifact.content.as_bytes(
+                &config,
+                project_config,
+                &mut printer,
+                &programs.normalization.schema,
+            );
+            (artifact, String::from_utf8(content).unwrap())
+        })
+        .collect()
+}
+
+/// Prints the content of an artifact after its path and the names of the
+/// definitions it's generated from.
+pub fn print_artifact(artifact: &Artifact, content: &str) -> String {
+    format!(
+        "// {} ({})\n{}",
+        artifact.path.to_string_lossy(),
+        artifact
+            .source_definition_names
+            .iter()
+            .map(|name| name.lookup())
+            .collect::<Vec<_>>()
+            .join(", "),
+        content
+    )
+}
+
+/// Prints all artifacts, sorted by path.
+pub fn print_artifacts(
+    project_config: &ProjectConfig,
+    programs: &Programs,
+    artifacts: &[Artifact],
+) -> String {
+    let mut result = artifact_contents(project_config, programs, artifacts)
+        .into_iter()
+        .map(|(artifact, content)| print_artifact(artifact, &content))
+        .collect::<Vec<_>>();
+    result.sort();
+    result.join("\n\n")
+}
@@ -0,0 +1,20 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<64f95af7deb7490ff0643dfaef7cc77e>>
+ */
+
+mod bundle_artifacts;
+
+use bundle_artifacts::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn bundle() {
+    let input = include_str!("bundle_artifacts/fixtures/bundle.graphql");
+    let expected = include_str!("bundle_artifacts/fixtures/bundle.expected");
+    test_fixture(transform_fixture, "bundle.graphql", "bundle_artifacts/fixtures/bundle.expected", input, expected);
+}
@@ -13,9 +13,12 @@ use graphql_test_helpers::diagnostics_to_sorted_string;
 use graphql_text_printer::print_full_operation;
 use interner::Intern;
 use relay_codegen::{build_request_params, print_fragment, print_operation, print_request};
-use relay_compiler::{apply_transforms, validate};
+use relay_compiler::{
+    apply_transforms, config::ProjectConfig, validate, ValidationContext, ValidationDiagnostics,
+};
 use relay_test_schema::{get_test_schema, get_test_schema_with_extensions};
 use relay_transforms::{ConnectionInterface, FeatureFlags, DIRECTIVE_SPLIT_OPERATION};
+use relay_typegen::{generate_fragment_type, TypegenConfig, TypegenLanguage};
 use std::sync::Arc;
 
 pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
@@ -43,17 +46,74 @@ pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
 
     let connection_interface = ConnectionInterface::default();
 
-    validate(&program, &connection_interface)
-        .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
-
     let feature_flags = FeatureFlags {
         enable_flight_transform: true,
         enable_required_transform_for_prefix: Some("".intern()),
+        preserve_reader_inline_fragment_depth: if fixture
+            .content
+            .contains("%preserve_reader_inline_fragments%")
+        {
+            1
+        } else {
+            0
+        },
+        actor_change_directive: Some("fb_actor_change".intern()),
+        ..Default::default()
+    };
+
+    let ValidationDiagnostics { errors, .. } = validate(
+        &ValidationContext {
+            program: &program,
+            connection_interface: &connection_interface,
+            feature_flags: &feature_flags,
+            base_fragment_names: &Default::default(),
+            max_spread_depth: None,
+            output_only_scalars: &[],
+            is_incremental_build: false,
+        },
+        &Default::default(),
+        &Default::default(),
+    );
+    if !errors.is_empty() {
+        return Err(diagnostics_to_sorted_string(fixture.content, &errors));
+    }
+
+    let project_config = ProjectConfig {
+        name: "test".intern(),
+        operation_text_excluded_directives: if fixture
+            .content
+            .contains("%exclude_custom_directive%")
+        {
+            vec!["customDirective".intern()]
+        } else {
+            Vec::new()
+        },
+        field_metadata_directives: if fixture.content.contains("%field_metadata_directives%") {
+            vec!["customDirective".intern()]
+        } else {
+            Vec::new()
+        },
+        disabled_transforms: if fixture.content.contains("%disable_generate_typename%") {
+            vec!["generate_typename".to_string()]
+        } else {
+            Vec::new()
+        },
+        skip_selected_typename: fixture.content.contains("%skip_selected_typename%"),
+        keep_original_handle_fields: fixture.content.contains("%keep_original_handle_fields%"),
+        skip_redundant_nodes_preserved_directives: if fixture
+            .content
+            .contains("%preserve_required%")
+        {
+            vec!["required".intern()]
+        } else {
+            Vec::new()
+        },
+        ..Default::default()
     };
 
     // TODO pass base fragment names
     let programs = apply_transforms(
-        "test".intern(),
+        &project_config,
         Arc::new(program),
         Default::default(),
         &connection_interface,
@@ -62,6 +122,11 @@ pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
     )
     .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
 
+    let typegen_config = TypegenConfig {
+        language: TypegenLanguage::Flow,
+        haste: true,
+        ..Default::default()
+    };
     let mut operations: Vec<&std::sync::Arc<OperationDefinition>> =
         programs.normalization.operations().collect();
     operations.sort_by(|a, b| a.name.item.lookup().cmp(&b.name.item.lookup()));
@@ -110,6 +175,22 @@ pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
                 .into_iter()
                 .map(|fragment| print_fragment(&schema, fragment))
         })
+        .chain({
+            // Print the types next to the reader ASTs to compare the keys
+            let mut fragments: Vec<&std::sync::Arc<FragmentDefinition>> =
+                if fixture.content.contains("%typegen%") {
+                    programs.typegen.fragments().collect()
+                } else {
+                    vec![]
+                };
+            fragments.sort_by(|a, b| a.name.item.lookup().cmp(b.name.item.lookup()));
+            fragments.into_iter().map(|fragment| {
+                format!(
+                    "TYPES:\n\n{}",
+                    generate_fragment_type(fragment, &schema, &typegen_config)
+                )
+            })
+        })
         .collect::<Vec<_>>();
     Ok(result.join("\n\n"))
 }
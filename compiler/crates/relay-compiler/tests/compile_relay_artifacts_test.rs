@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<95b79a4986f3411203abd6b9984ce4c3>>
+ * @generated SignedSource<<15e2b6cc6d4777fe0bf3ef90a49d83ab>>
  */
 
 mod compile_relay_artifacts;
@@ -103,6 +103,20 @@ fn abstract_type_refinement_no_unnecessary_type_discriminator_under_condition_in
     test_fixture(transform_fixture, "abstract-type-refinement-no-unnecessary-type-discriminator-under-condition_incorrect.graphql", "compile_relay_artifacts/fixtures/abstract-type-refinement-no-unnecessary-type-discriminator-under-condition_incorrect.expected", input, expected);
 }
 
+#[test]
+fn actor_change() {
+    let input = include_str!("compile_relay_artifacts/fixtures/actor-change.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/actor-change.expected");
+    test_fixture(transform_fixture, "actor-change.graphql", "compile_relay_artifacts/fixtures/actor-change.expected", input, expected);
+}
+
+#[test]
+fn actor_change_with_multiple_selections_invalid() {
+    let input = include_str!("compile_relay_artifacts/fixtures/actor-change-with-multiple-selections.invalid.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/actor-change-with-multiple-selections.invalid.expected");
+    test_fixture(transform_fixture, "actor-change-with-multiple-selections.invalid.graphql", "compile_relay_artifacts/fixtures/actor-change-with-multiple-selections.invalid.expected", input, expected);
+}
+
 #[test]
 fn alias_same_as_name() {
     let input = include_str!("compile_relay_artifacts/fixtures/alias-same-as-name.graphql");
@@ -145,6 +159,13 @@ fn auto_filled_argument_on_match() {
     test_fixture(transform_fixture, "auto-filled-argument-on-match.graphql", "compile_relay_artifacts/fixtures/auto-filled-argument-on-match.expected", input, expected);
 }
 
+#[test]
+fn catch_on_fragment_invalid() {
+    let input = include_str!("compile_relay_artifacts/fixtures/catch-on-fragment.invalid.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/catch-on-fragment.invalid.expected");
+    test_fixture(transform_fixture, "catch-on-fragment.invalid.graphql", "compile_relay_artifacts/fixtures/catch-on-fragment.invalid.expected", input, expected);
+}
+
 #[test]
 fn client_conditions() {
     let input = include_str!("compile_relay_artifacts/fixtures/client-conditions.graphql");
@@ -201,6 +222,13 @@ fn client_inline_fragments_in_query() {
     test_fixture(transform_fixture, "client-inline-fragments-in-query.graphql", "compile_relay_artifacts/fixtures/client-inline-fragments-in-query.expected", input, expected);
 }
 
+#[test]
+fn client_interface_type_discriminator() {
+    let input = include_str!("compile_relay_artifacts/fixtures/client-interface-type-discriminator.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/client-interface-type-discriminator.expected");
+    test_fixture(transform_fixture, "client-interface-type-discriminator.graphql", "compile_relay_artifacts/fixtures/client-interface-type-discriminator.expected", input, expected);
+}
+
 #[test]
 fn client_linked_fields() {
     let input = include_str!("compile_relay_artifacts/fixtures/client-linked-fields.graphql");
@@ -243,6 +271,34 @@ fn connection() {
     test_fixture(transform_fixture, "connection.graphql", "compile_relay_artifacts/fixtures/connection.expected", input, expected);
 }
 
+#[test]
+fn connection_custom_handler_module() {
+    let input = include_str!("compile_relay_artifacts/fixtures/connection-custom-handler-module.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/connection-custom-handler-module.expected");
+    test_fixture(transform_fixture, "connection-custom-handler-module.graphql", "compile_relay_artifacts/fixtures/connection-custom-handler-module.expected", input, expected);
+}
+
+#[test]
+fn connection_in_aliased_inline_fragment() {
+    let input = include_str!("compile_relay_artifacts/fixtures/connection-in-aliased-inline-fragment.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/connection-in-aliased-inline-fragment.expected");
+    test_fixture(transform_fixture, "connection-in-aliased-inline-fragment.graphql", "compile_relay_artifacts/fixtures/connection-in-aliased-inline-fragment.expected", input, expected);
+}
+
+#[test]
+fn connection_nested_under_non_node_field() {
+    let input = include_str!("compile_relay_artifacts/fixtures/connection-nested-under-non-node-field.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/connection-nested-under-non-node-field.expected");
+    test_fixture(transform_fixture, "connection-nested-under-non-node-field.graphql", "compile_relay_artifacts/fixtures/connection-nested-under-non-node-field.expected", input, expected);
+}
+
+#[test]
+fn connection_prefetchable() {
+    let input = include_str!("compile_relay_artifacts/fixtures/connection-prefetchable.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/connection-prefetchable.expected");
+    test_fixture(transform_fixture, "connection-prefetchable.graphql", "compile_relay_artifacts/fixtures/connection-prefetchable.expected", input, expected);
+}
+
 #[test]
 fn connection_with_aliased_edges_page_info() {
     let input = include_str!("compile_relay_artifacts/fixtures/connection-with-aliased-edges-page_info.graphql");
@@ -264,6 +320,13 @@ fn connection_with_dynamic_key_missing_variable_definition_invalid() {
     test_fixture(transform_fixture, "connection-with-dynamic-key-missing-variable-definition.invalid.graphql", "compile_relay_artifacts/fixtures/connection-with-dynamic-key-missing-variable-definition.invalid.expected", input, expected);
 }
 
+#[test]
+fn connection_with_mixed_filters() {
+    let input = include_str!("compile_relay_artifacts/fixtures/connection-with-mixed-filters.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/connection-with-mixed-filters.expected");
+    test_fixture(transform_fixture, "connection-with-mixed-filters.graphql", "compile_relay_artifacts/fixtures/connection-with-mixed-filters.expected", input, expected);
+}
+
 #[test]
 fn constant_variable_matches_constant_value() {
     let input = include_str!("compile_relay_artifacts/fixtures/constant_variable_matches_constant_value.graphql");
@@ -271,6 +334,20 @@ fn constant_variable_matches_constant_value() {
     test_fixture(transform_fixture, "constant_variable_matches_constant_value.graphql", "compile_relay_artifacts/fixtures/constant_variable_matches_constant_value.expected", input, expected);
 }
 
+#[test]
+fn dead_selections() {
+    let input = include_str!("compile_relay_artifacts/fixtures/dead-selections.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/dead-selections.expected");
+    test_fixture(transform_fixture, "dead-selections.graphql", "compile_relay_artifacts/fixtures/dead-selections.expected", input, expected);
+}
+
+#[test]
+fn defer_and_stream_with_if_conditions() {
+    let input = include_str!("compile_relay_artifacts/fixtures/defer-and-stream-with-if-conditions.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/defer-and-stream-with-if-conditions.expected");
+    test_fixture(transform_fixture, "defer-and-stream-with-if-conditions.graphql", "compile_relay_artifacts/fixtures/defer-and-stream-with-if-conditions.expected", input, expected);
+}
+
 #[test]
 fn defer_multiple_fragments_same_parent() {
     let input = include_str!("compile_relay_artifacts/fixtures/defer-multiple-fragments-same-parent.graphql");
@@ -299,6 +376,13 @@ fn directive_with_conditions() {
     test_fixture(transform_fixture, "directive_with_conditions.graphql", "compile_relay_artifacts/fixtures/directive_with_conditions.expected", input, expected);
 }
 
+#[test]
+fn disabled_transform() {
+    let input = include_str!("compile_relay_artifacts/fixtures/disabled-transform.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/disabled-transform.expected");
+    test_fixture(transform_fixture, "disabled-transform.graphql", "compile_relay_artifacts/fixtures/disabled-transform.expected", input, expected);
+}
+
 #[test]
 fn duplicate_directive_invalid() {
     let input = include_str!("compile_relay_artifacts/fixtures/duplicate-directive.invalid.graphql");
@@ -327,6 +411,13 @@ fn false_positive_circular_fragment_reference_regression() {
     test_fixture(transform_fixture, "false-positive-circular-fragment-reference-regression.graphql", "compile_relay_artifacts/fixtures/false-positive-circular-fragment-reference-regression.expected", input, expected);
 }
 
+#[test]
+fn field_metadata_directives() {
+    let input = include_str!("compile_relay_artifacts/fixtures/field-metadata-directives.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/field-metadata-directives.expected");
+    test_fixture(transform_fixture, "field-metadata-directives.graphql", "compile_relay_artifacts/fixtures/field-metadata-directives.expected", input, expected);
+}
+
 #[test]
 fn fields_with_null_arugment_values() {
     let input = include_str!("compile_relay_artifacts/fixtures/fields-with-null-arugment-values.graphql");
@@ -355,6 +446,13 @@ fn fragment_on_non_node_fetchable_type() {
     test_fixture(transform_fixture, "fragment-on-non-node-fetchable-type.graphql", "compile_relay_artifacts/fixtures/fragment-on-non-node-fetchable-type.expected", input, expected);
 }
 
+#[test]
+fn fragment_on_non_node_fetchable_type_with_token() {
+    let input = include_str!("compile_relay_artifacts/fixtures/fragment-on-non-node-fetchable-type-with-token.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/fragment-on-non-node-fetchable-type-with-token.expected");
+    test_fixture(transform_fixture, "fragment-on-non-node-fetchable-type-with-token.graphql", "compile_relay_artifacts/fixtures/fragment-on-non-node-fetchable-type-with-token.expected", input, expected);
+}
+
 #[test]
 fn fragment_on_object_implementing_node_interface() {
     let input = include_str!("compile_relay_artifacts/fixtures/fragment-on-object-implementing-node-interface.graphql");
@@ -460,6 +558,20 @@ fn inline_data_fragment_local_args() {
     test_fixture(transform_fixture, "inline-data-fragment-local-args.graphql", "compile_relay_artifacts/fixtures/inline-data-fragment-local-args.expected", input, expected);
 }
 
+#[test]
+fn inline_fragment_alias() {
+    let input = include_str!("compile_relay_artifacts/fixtures/inline-fragment-alias.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/inline-fragment-alias.expected");
+    test_fixture(transform_fixture, "inline-fragment-alias.graphql", "compile_relay_artifacts/fixtures/inline-fragment-alias.expected", input, expected);
+}
+
+#[test]
+fn keep_original_handle_fields() {
+    let input = include_str!("compile_relay_artifacts/fixtures/keep-original-handle-fields.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/keep-original-handle-fields.expected");
+    test_fixture(transform_fixture, "keep-original-handle-fields.graphql", "compile_relay_artifacts/fixtures/keep-original-handle-fields.expected", input, expected);
+}
+
 #[test]
 fn kitchen_sink() {
     let input = include_str!("compile_relay_artifacts/fixtures/kitchen-sink.graphql");
@@ -509,6 +621,13 @@ fn missing_argument_on_field_invalid() {
     test_fixture(transform_fixture, "missing-argument-on-field.invalid.graphql", "compile_relay_artifacts/fixtures/missing-argument-on-field.invalid.expected", input, expected);
 }
 
+#[test]
+fn module_at_multiple_paths_invalid() {
+    let input = include_str!("compile_relay_artifacts/fixtures/module-at-multiple-paths.invalid.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/module-at-multiple-paths.invalid.expected");
+    test_fixture(transform_fixture, "module-at-multiple-paths.invalid.graphql", "compile_relay_artifacts/fixtures/module-at-multiple-paths.invalid.expected", input, expected);
+}
+
 #[test]
 fn module_deduping() {
     let input = include_str!("compile_relay_artifacts/fixtures/module-deduping.graphql");
@@ -593,6 +712,13 @@ fn nested_conditions() {
     test_fixture(transform_fixture, "nested_conditions.graphql", "compile_relay_artifacts/fixtures/nested_conditions.expected", input, expected);
 }
 
+#[test]
+fn operation_text_excluded_directives() {
+    let input = include_str!("compile_relay_artifacts/fixtures/operation-text-excluded-directives.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/operation-text-excluded-directives.expected");
+    test_fixture(transform_fixture, "operation-text-excluded-directives.graphql", "compile_relay_artifacts/fixtures/operation-text-excluded-directives.expected", input, expected);
+}
+
 #[test]
 fn original_client_fields_test() {
     let input = include_str!("compile_relay_artifacts/fixtures/original-client-fields-test.graphql");
@@ -614,6 +740,27 @@ fn prepend_node() {
     test_fixture(transform_fixture, "prepend-node.graphql", "compile_relay_artifacts/fixtures/prepend-node.expected", input, expected);
 }
 
+#[test]
+fn preserve_reader_inline_fragments() {
+    let input = include_str!("compile_relay_artifacts/fixtures/preserve-reader-inline-fragments.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/preserve-reader-inline-fragments.expected");
+    test_fixture(transform_fixture, "preserve-reader-inline-fragments.graphql", "compile_relay_artifacts/fixtures/preserve-reader-inline-fragments.expected", input, expected);
+}
+
+#[test]
+fn preserve_required_duplicated_field() {
+    let input = include_str!("compile_relay_artifacts/fixtures/preserve-required-duplicated-field.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/preserve-required-duplicated-field.expected");
+    test_fixture(transform_fixture, "preserve-required-duplicated-field.graphql", "compile_relay_artifacts/fixtures/preserve-required-duplicated-field.expected", input, expected);
+}
+
+#[test]
+fn query_with_catch() {
+    let input = include_str!("compile_relay_artifacts/fixtures/query-with-catch.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/query-with-catch.expected");
+    test_fixture(transform_fixture, "query-with-catch.graphql", "compile_relay_artifacts/fixtures/query-with-catch.expected", input, expected);
+}
+
 #[test]
 fn query_with_conditional_module() {
     let input = include_str!("compile_relay_artifacts/fixtures/query-with-conditional-module.graphql");
@@ -726,6 +873,13 @@ fn refetchable_fragment_with_connection() {
     test_fixture(transform_fixture, "refetchable-fragment-with-connection.graphql", "compile_relay_artifacts/fixtures/refetchable-fragment-with-connection.expected", input, expected);
 }
 
+#[test]
+fn refetchable_fragment_with_connection_backward() {
+    let input = include_str!("compile_relay_artifacts/fixtures/refetchable-fragment-with-connection-backward.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/refetchable-fragment-with-connection-backward.expected");
+    test_fixture(transform_fixture, "refetchable-fragment-with-connection-backward.graphql", "compile_relay_artifacts/fixtures/refetchable-fragment-with-connection-backward.expected", input, expected);
+}
+
 #[test]
 fn refetchable_fragment_with_connection_bidirectional() {
     let input = include_str!("compile_relay_artifacts/fixtures/refetchable-fragment-with-connection-bidirectional.graphql");
@@ -761,6 +915,13 @@ fn relay_client_id_field() {
     test_fixture(transform_fixture, "relay-client-id-field.graphql", "compile_relay_artifacts/fixtures/relay-client-id-field.expected", input, expected);
 }
 
+#[test]
+fn required_aliased_fragment_spread() {
+    let input = include_str!("compile_relay_artifacts/fixtures/required-aliased-fragment-spread.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/required-aliased-fragment-spread.expected");
+    test_fixture(transform_fixture, "required-aliased-fragment-spread.graphql", "compile_relay_artifacts/fixtures/required-aliased-fragment-spread.expected", input, expected);
+}
+
 #[test]
 fn required_argument_not_passed_default_value() {
     let input = include_str!("compile_relay_artifacts/fixtures/required_argument_not_passed_default_value.graphql");
@@ -817,6 +978,13 @@ fn sibling_client_selections() {
     test_fixture(transform_fixture, "sibling-client-selections.graphql", "compile_relay_artifacts/fixtures/sibling-client-selections.expected", input, expected);
 }
 
+#[test]
+fn skip_selected_typename() {
+    let input = include_str!("compile_relay_artifacts/fixtures/skip-selected-typename.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/skip-selected-typename.expected");
+    test_fixture(transform_fixture, "skip-selected-typename.graphql", "compile_relay_artifacts/fixtures/skip-selected-typename.expected", input, expected);
+}
+
 #[test]
 fn stable_literals() {
     let input = include_str!("compile_relay_artifacts/fixtures/stable-literals.graphql");
@@ -838,6 +1006,13 @@ fn stream_connection_conditional() {
     test_fixture(transform_fixture, "stream-connection-conditional.graphql", "compile_relay_artifacts/fixtures/stream-connection-conditional.expected", input, expected);
 }
 
+#[test]
+fn subscription_with_connection_defer_and_stream() {
+    let input = include_str!("compile_relay_artifacts/fixtures/subscription-with-connection-defer-and-stream.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/subscription-with-connection-defer-and-stream.expected");
+    test_fixture(transform_fixture, "subscription-with-connection-defer-and-stream.graphql", "compile_relay_artifacts/fixtures/subscription-with-connection-defer-and-stream.expected", input, expected);
+}
+
 #[test]
 fn unions() {
     let input = include_str!("compile_relay_artifacts/fixtures/unions.graphql");
@@ -0,0 +1,24 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+#[path = "../artifacts_test_utils/mod.rs"]
+mod artifacts_test_utils;
+
+use artifacts_test_utils::{generate_fixture_artifacts, print_artifacts};
+use fixture_tests::Fixture;
+use interner::Intern;
+use relay_compiler::config::ProjectConfig;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let project_config = ProjectConfig {
+        name: "test".intern(),
+        operation_text_files: true,
+        ..Default::default()
+    };
+    let (programs, artifacts) = generate_fixture_artifacts(fixture, &project_config)?;
+    Ok(print_artifacts(&project_config, &programs, &artifacts))
+}
@@ -0,0 +1,20 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<8e7caa3a62763d9696662ff2310b5d06>>
+ */
+
+mod operation_text_files;
+
+use operation_text_files::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn operation_text_files() {
+    let input = include_str!("operation_text_files/fixtures/operation-text-files.graphql");
+    let expected = include_str!("operation_text_files/fixtures/operation-text-files.expected");
+    test_fixture(transform_fixture, "operation-text-files.graphql", "operation_text_files/fixtures/operation-text-files.expected", input, expected);
+}
@@ -0,0 +1,65 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+#[path = "../artifacts_test_utils/mod.rs"]
+mod artifacts_test_utils;
+
+use artifacts_test_utils::{artifact_contents, generate_fixture_artifacts, print_artifact};
+use fixture_tests::Fixture;
+use interner::Intern;
+use relay_compiler::{
+    config::{PersistConfig, ProjectConfig},
+    ArtifactContent,
+};
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let project_config = ProjectConfig {
+        name: "test".intern(),
+        persist: Some(PersistConfig {
+            url: "https://example.com/persist".to_owned(),
+            params: Default::default(),
+            operation_params: Default::default(),
+            metadata_directive: None,
+            persisted_only: true,
+        }),
+        ..Default::default()
+    };
+    let (programs, mut artifacts) = generate_fixture_artifacts(fixture, &project_config)?;
+
+    // Fake the persisting, the text of each operation is kept to check that
+    // it doesn't end up in any artifact.
+    let mut texts = Vec::new();
+    for artifact in &mut artifacts {
+        if let ArtifactContent::Operation {
+            normalization_operation,
+            text,
+            id_and_text_hash,
+            ..
+        } = &mut artifact.content
+        {
+            let name = normalization_operation.name.item.lookup();
+            *id_and_text_hash = Some((format!("{}_id", name), format!("{}_hash", name)));
+            texts.push(text.clone());
+        }
+    }
+
+    let mut result = artifact_contents(&project_config, &programs, &artifacts)
+        .into_iter()
+        .map(|(artifact, content)| {
+            if let Some(text) = texts.iter().find(|text| content.contains(text.as_str())) {
+                return Err(format!(
+                    "The artifact `{}` contains the operation text:\n{}",
+                    artifact.path.to_string_lossy(),
+                    text
+                ));
+            }
+            Ok(print_artifact(artifact, &content))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    result.sort();
+    Ok(result.join("\n\n"))
+}
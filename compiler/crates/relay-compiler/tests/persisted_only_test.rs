@@ -0,0 +1,20 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<ca729dbc1072f5c1b1c3dbb293b60104>>
+ */
+
+mod persisted_only;
+
+use persisted_only::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn persisted_only() {
+    let input = include_str!("persisted_only/fixtures/persisted-only.graphql");
+    let expected = include_str!("persisted_only/fixtures/persisted-only.expected");
+    test_fixture(transform_fixture, "persisted-only.graphql", "persisted_only/fixtures/persisted-only.expected", input, expected);
+}
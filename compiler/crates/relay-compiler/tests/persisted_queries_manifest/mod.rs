@@ -0,0 +1,51 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+#[path = "../artifacts_test_utils/mod.rs"]
+mod artifacts_test_utils;
+
+use artifacts_test_utils::{generate_fixture_artifacts, print_artifact};
+use fixture_tests::Fixture;
+use interner::Intern;
+use relay_compiler::{config::ProjectConfig, generate_persisted_queries_manifest, ArtifactContent};
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let project_config = ProjectConfig {
+        name: "test".intern(),
+        persisted_queries_manifest: Some("__generated__/persisted_queries.json".into()),
+        ..Default::default()
+    };
+    let (_, mut artifacts) = generate_fixture_artifacts(fixture, &project_config)?;
+
+    // Fake the persisting: operations named `*LocalQuery` get a local id as if
+    // persisting them failed.
+    for artifact in &mut artifacts {
+        if let ArtifactContent::Operation {
+            normalization_operation,
+            id_and_text_hash,
+            is_local_id,
+            ..
+        } = &mut artifact.content
+        {
+            let name = normalization_operation.name.item.lookup();
+            *id_and_text_hash = Some((format!("{}_id", name), String::new()));
+            *is_local_id = name.ends_with("LocalQuery");
+        }
+    }
+
+    let manifest = generate_persisted_queries_manifest(
+        project_config.persisted_queries_manifest.as_ref().unwrap(),
+        &artifacts,
+    );
+    match manifest.content {
+        ArtifactContent::Generic { ref content } => Ok(print_artifact(
+            &manifest,
+            &String::from_utf8(content.clone()).unwrap(),
+        )),
+        _ => panic!("Expected the manifest to be a generic artifact."),
+    }
+}
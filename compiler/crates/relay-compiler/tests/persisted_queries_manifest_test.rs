@@ -0,0 +1,20 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<c0bffd5a52527d735bccdf009f160e3f>>
+ */
+
+mod persisted_queries_manifest;
+
+use persisted_queries_manifest::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn persisted_queries_manifest() {
+    let input = include_str!("persisted_queries_manifest/fixtures/persisted-queries-manifest.graphql");
+    let expected = include_str!("persisted_queries_manifest/fixtures/persisted-queries-manifest.expected");
+    test_fixture(transform_fixture, "persisted-queries-manifest.graphql", "persisted_queries_manifest/fixtures/persisted-queries-manifest.expected", input, expected);
+}
@@ -0,0 +1,20 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<2261fa0f07934e3862475b92d57f3e6b>>
+ */
+
+mod source_map;
+
+use source_map::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn source_map() {
+    let input = include_str!("source_map/fixtures/source-map.graphql");
+    let expected = include_str!("source_map/fixtures/source-map.expected");
+    test_fixture(transform_fixture, "source-map.graphql", "source_map/fixtures/source-map.expected", input, expected);
+}
@@ -25,8 +25,8 @@ use lazy_static::lazy_static;
 use log::info;
 use lsp_types::request::{Completion, Request};
 use schema::{
-    Argument as SchemaArgument, Directive as SchemaDirective, Schema, Type, TypeReference,
-    TypeWithFields,
+    Argument as SchemaArgument, Directive as SchemaDirective, Field, FieldID, Schema, Type,
+    TypeReference, TypeWithFields,
 };
 use std::{
     collections::{HashMap, HashSet},
@@ -538,21 +538,19 @@ fn completion_items_for_request(
         }
         CompletionKind::FieldName {
             existing_linked_field,
-        } => match request.type_path.resolve_leaf_type(schema)? {
-            Type::Interface(interface_id) => {
-                let interface = schema.interface(interface_id);
-                let items =
-                    resolve_completion_items_from_fields(interface, schema, existing_linked_field);
-                Some(items)
-            }
-            Type::Object(object_id) => {
-                let object = schema.object(object_id);
-                let items =
-                    resolve_completion_items_from_fields(object, schema, existing_linked_field);
-                Some(items)
+        } => {
+            let type_ = request.type_path.resolve_leaf_type(schema)?;
+            let fields = resolve_selectable_fields(&TypeReference::Named(type_), schema);
+            if fields.is_empty() {
+                None
+            } else {
+                Some(resolve_completion_items_from_fields(
+                    fields,
+                    schema,
+                    existing_linked_field,
+                ))
             }
-            Type::Enum(_) | Type::InputObject(_) | Type::Scalar(_) | Type::Union(_) => None,
-        },
+        }
         CompletionKind::DirectiveName { location } => {
             let directives = schema.directives_for_location(location);
             let items = directives
@@ -789,29 +787,63 @@ fn resolve_completion_items_for_argument_value(
     }
 }
 
-fn resolve_completion_items_from_fields<T: TypeWithFields>(
-    type_: &T,
+/// A field that can be selected on a type.
+pub(crate) struct SelectableField<'schema> {
+    pub field: &'schema Field,
+    /// The type of the field as written in the schema, e.g. `[User!]`.
+    pub type_string: String,
+    pub is_deprecated: bool,
+    pub deprecation_reason: Option<StringKey>,
+}
+
+/// Returns the fields that can be selected on the type, including
+/// `__typename`, which is the only field selectable on a union. Leaf types
+/// don't have selectable fields.
+pub(crate) fn resolve_selectable_fields<'schema>(
+    type_reference: &TypeReference,
+    schema: &'schema Schema,
+) -> Vec<SelectableField<'schema>> {
+    let field_ids: &[FieldID] = match type_reference.inner() {
+        Type::Object(object_id) => schema.object(object_id).fields(),
+        Type::Interface(interface_id) => schema.interface(interface_id).fields(),
+        Type::Union(_) => &[],
+        Type::Enum(_) | Type::InputObject(_) | Type::Scalar(_) => return vec![],
+    };
+    field_ids
+        .iter()
+        .copied()
+        .chain(once(schema.typename_field()))
+        .map(|field_id| {
+            let field = schema.field(field_id);
+            let deprecated_directive = field.directives.named(*DEPRECATED_DIRECTIVE);
+            let deprecation_reason = deprecated_directive
+                .and_then(|directive| directive.arguments.first())
+                .and_then(|arg| match &arg.value {
+                    ConstantValue::String(reason) => Some(reason.value),
+                    _ => None,
+                });
+            SelectableField {
+                field,
+                type_string: schema.get_type_string(&field.type_),
+                is_deprecated: deprecated_directive.is_some(),
+                deprecation_reason,
+            }
+        })
+        .collect()
+}
+
+/// The `detail` of each item is the deprecation reason of the field, or the
+/// type of the field if it isn't deprecated.
+fn resolve_completion_items_from_fields(
+    fields: Vec<SelectableField<'_>>,
     schema: &Schema,
     existing_linked_field: bool,
 ) -> Vec<CompletionItem> {
-    type_
-        .fields()
-        .iter()
-        .map(|field_id| {
-            let field = schema.field(*field_id);
+    fields
+        .into_iter()
+        .map(|selectable_field| {
+            let field = selectable_field.field;
             let name = field.name.to_string();
-            let deprecated_directive = field.directives.named(*DEPRECATED_DIRECTIVE);
-            let deprecated_reason = if let Some(deprecated_directive) = deprecated_directive {
-                if let Some(ConstantValue::String(reason)) =
-                    deprecated_directive.arguments.get(0).map(|arg| &arg.value)
-                {
-                    Some(reason.value.lookup().to_string())
-                } else {
-                    None
-                }
-            } else {
-                None
-            };
             let args = create_arguments_snippets(field.arguments.iter(), schema);
             let insert_text = match (
                 existing_linked_field
@@ -843,9 +875,12 @@ fn resolve_completion_items_from_fields<T: TypeWithFields>(
             CompletionItem {
                 label: name,
                 kind: None,
-                detail: deprecated_reason,
+                detail: Some(match selectable_field.deprecation_reason {
+                    Some(reason) => reason.lookup().to_string(),
+                    None => selectable_field.type_string,
+                }),
                 documentation: None,
-                deprecated: Some(deprecated_directive.is_some()),
+                deprecated: Some(selectable_field.is_deprecated),
                 preselect: None,
                 sort_text: None,
                 filter_text: None,
@@ -1008,3 +1043,73 @@ pub(crate) fn on_completion<TPerfLogger: PerfLogger + 'static>(
         Err(LSPRuntimeError::ExpectedError)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::SourceLocationKey;
+    use graphql_syntax::parse_executable_with_error_recovery;
+    use relay_test_schema::get_test_schema_with_extensions;
+
+    /// Returns the completion items at the `|` in the source.
+    fn completion_items(source: &str) -> Vec<CompletionItem> {
+        let position = source.find('|').unwrap();
+        let document = parse_executable_with_error_recovery(
+            &source.replace('|', ""),
+            SourceLocationKey::standalone("/test/file"),
+        )
+        .item;
+        // Completion requests are made for the character before the cursor
+        let position_span = Span::from_usize(position - 1, position - 1);
+        let request = CompletionRequestBuilder::new("test_project".intern())
+            .create_completion_request(document, position_span)
+            .unwrap();
+        let schema = get_test_schema_with_extensions(
+            r#"
+            directive @deprecated(reason: String) on FIELD_DEFINITION
+            extend type User {
+                oldName: String @deprecated(reason: "Use `name`.")
+            }
+            "#,
+        );
+        completion_items_for_request(request, &schema, &Arc::new(RwLock::new(HashMap::new())))
+            .unwrap()
+    }
+
+    fn find_item<'a>(items: &'a [CompletionItem], label: &str) -> &'a CompletionItem {
+        items.iter().find(|item| item.label == label).unwrap()
+    }
+
+    #[test]
+    fn field_name_on_object() {
+        let items = completion_items("fragment Test on User { na| }");
+        let name = find_item(&items, "name");
+        assert_eq!(name.detail, Some("String".to_string()));
+        assert_eq!(name.deprecated, Some(false));
+        let old_name = find_item(&items, "oldName");
+        assert_eq!(old_name.detail, Some("Use `name`.".to_string()));
+        assert_eq!(old_name.deprecated, Some(true));
+        let typename = find_item(&items, "__typename");
+        assert_eq!(typename.detail, Some("String!".to_string()));
+    }
+
+    #[test]
+    fn field_name_on_interface() {
+        let items = completion_items("fragment Test on Actor { na| }");
+        assert_eq!(find_item(&items, "name").detail, Some("String".to_string()));
+        assert_eq!(
+            find_item(&items, "friends").detail,
+            Some("FriendsConnection".to_string())
+        );
+        find_item(&items, "__typename");
+    }
+
+    #[test]
+    fn field_name_on_union() {
+        let items = completion_items("fragment Test on MaybeNode { __| }");
+        assert_eq!(
+            items.iter().map(|item| &item.label).collect::<Vec<_>>(),
+            vec!["__typename"]
+        );
+    }
+}
@@ -8,7 +8,7 @@
 //! Utilities for providing the goto definition feature
 
 use crate::{
-    location::to_lsp_location_of_graphql_literal,
+    location::to_lsp_location,
     lsp::GotoDefinitionResponse,
     lsp_runtime_error::{LSPRuntimeError, LSPRuntimeResult},
     node_resolution_info::NodeKind,
@@ -23,6 +23,7 @@ use lsp_types::{
     request::{GotoDefinition, Request},
     Url,
 };
+use relay_compiler::{FsSourceReader, SourceReader};
 use std::{
     collections::HashMap,
     path::PathBuf,
@@ -38,26 +39,15 @@ fn get_goto_definition_response(
     #[allow(clippy::borrowed_box)] extra_data_provider: &Box<dyn LSPExtraDataProvider + 'static>,
 ) -> LSPRuntimeResult<GotoDefinitionResponse> {
     match node_resolution_info.kind {
-        NodeKind::FragmentSpread(fragment_name) => {
-            let project_name = node_resolution_info.project_name;
-            if let Some(source_program) = source_programs.read().unwrap().get(&project_name) {
-                let fragment = source_program.fragment(fragment_name).ok_or_else(|| {
-                    LSPRuntimeError::UnexpectedError(format!(
-                        "Could not find fragment with name {}",
-                        fragment_name
-                    ))
-                })?;
-
-                Ok(GotoDefinitionResponse::Scalar(
-                    to_lsp_location_of_graphql_literal(fragment.name.location, root_dir)?,
-                ))
-            } else {
-                Err(LSPRuntimeError::UnexpectedError(format!(
-                    "Project name {} not found",
-                    project_name
-                )))
-            }
-        }
+        NodeKind::FragmentSpread(fragment_name) => Ok(GotoDefinitionResponse::Scalar(
+            get_fragment_definition_location(
+                fragment_name,
+                node_resolution_info.project_name,
+                &source_programs.read().unwrap(),
+                root_dir,
+                &FsSourceReader,
+            )?,
+        )),
         NodeKind::FieldName => {
             let project_name = node_resolution_info.project_name;
             let programs = source_programs.read().unwrap();
@@ -112,6 +102,26 @@ fn get_goto_definition_response(
     }
 }
 
+/// Returns the location of the name of the fragment definition, which may be in
+/// any file of the project.
+fn get_fragment_definition_location(
+    fragment_name: StringKey,
+    project_name: StringKey,
+    source_programs: &HashMap<StringKey, Program>,
+    root_dir: &PathBuf,
+    source_reader: &dyn SourceReader,
+) -> LSPRuntimeResult<lsp_types::Location> {
+    let source_program = source_programs.get(&project_name).ok_or_else(|| {
+        LSPRuntimeError::UnexpectedError(format!("Project name {} not found", project_name))
+    })?;
+    // The fragment may not be defined yet, e.g. while the spread is being typed.
+    let fragment = source_program
+        .fragment(fragment_name)
+        .ok_or(LSPRuntimeError::ExpectedError)?;
+
+    to_lsp_location(fragment.name.location, root_dir, source_reader)
+}
+
 pub(crate) fn on_goto_definition<TPerfLogger: PerfLogger + 'static>(
     state: &mut LSPState<TPerfLogger>,
     params: <GotoDefinition as Request>::Params,
@@ -138,3 +148,77 @@ fn get_location(path: &str, line: u64) -> Result<lsp_types::Location, LSPRuntime
 
     Ok(lsp_types::Location { uri, range })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::SourceLocationKey;
+    use graphql_syntax::parse_executable;
+    use interner::Intern;
+    use relay_test_schema::get_test_schema;
+
+    struct MockSourceReader(HashMap<PathBuf, String>);
+
+    impl SourceReader for MockSourceReader {
+        fn read_to_string(&self, path: &PathBuf) -> std::io::Result<String> {
+            Ok(self.0[path].to_string())
+        }
+    }
+
+    #[test]
+    fn fragment_definition_in_another_file_test() {
+        let foo_contents = "graphql`query FooQuery { me { ...Bar_user } }`;";
+        let bar_contents =
+            "// Bar\n\nconst fragment = graphql`\n  fragment Bar_user on User { id }\n`;";
+        let mut definitions = Vec::new();
+        for (path, text) in &[
+            ("src/Foo.react.js", "query FooQuery { me { ...Bar_user } }"),
+            ("src/Bar.react.js", "\n  fragment Bar_user on User { id }\n"),
+        ] {
+            definitions.extend(
+                parse_executable(text, SourceLocationKey::embedded(path, 0))
+                    .unwrap()
+                    .definitions,
+            );
+        }
+        let schema = get_test_schema();
+        let ir = graphql_ir::build(&schema, &definitions).unwrap();
+        let mut source_programs = HashMap::new();
+        source_programs.insert("test".intern(), Program::from_definitions(schema, ir));
+        let root_dir = PathBuf::from("/root");
+        let source_reader = MockSourceReader(
+            vec![
+                ("/root/src/Foo.react.js".into(), foo_contents.to_string()),
+                ("/root/src/Bar.react.js".into(), bar_contents.to_string()),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        let location = get_fragment_definition_location(
+            "Bar_user".intern(),
+            "test".intern(),
+            &source_programs,
+            &root_dir,
+            &source_reader,
+        )
+        .unwrap();
+        assert_eq!(location.uri.as_str(), "file:///root/src/Bar.react.js");
+        assert_eq!(
+            location.range,
+            lsp_types::Range::new(
+                lsp_types::Position::new(3, 11),
+                lsp_types::Position::new(3, 19)
+            )
+        );
+
+        let result = get_fragment_definition_location(
+            "Baz_user".intern(),
+            "test".intern(),
+            &source_programs,
+            &root_dir,
+            &source_reader,
+        );
+        assert!(matches!(result, Err(LSPRuntimeError::ExpectedError)));
+    }
+}
@@ -8,6 +8,7 @@
 //! Utilities for providing the hover feature
 use crate::server::LSPExtraDataProvider;
 use crate::{
+    completion::resolve_selectable_fields,
     lsp::{HoverContents, LanguageString, MarkedString},
     lsp_runtime_error::{LSPRuntimeError, LSPRuntimeResult},
     node_resolution_info::{NodeKind, NodeResolutionInfo},
@@ -18,7 +19,7 @@ use graphql_ir::{Program, Value};
 use graphql_text_printer::print_value;
 use interner::StringKey;
 use lsp_types::{request::HoverRequest, request::Request, Hover};
-use schema::Schema;
+use schema::{Schema, TypeReference};
 use schema_print::print_directive;
 use std::{
     collections::HashMap,
@@ -104,14 +105,18 @@ fn get_hover_response_contents(
             }
         }
         NodeKind::FieldName => {
-            let field = node_resolution_info
+            let (parent_type, field_name) = node_resolution_info
                 .type_path
-                .resolve_current_field(schema)?;
+                .resolve_current_field_parent_type(schema)?;
+            let selectable_field =
+                resolve_selectable_fields(&TypeReference::Named(parent_type), schema)
+                    .into_iter()
+                    .find(|selectable_field| selectable_field.field.name == field_name)?;
+            let field = selectable_field.field;
 
-            let type_name = schema.get_type_string(&field.type_);
             let mut hover_contents: Vec<MarkedString> = vec![graphql_marked_string(format!(
                 "{}: {}",
-                field.name, type_name
+                field.name, selectable_field.type_string
             ))];
 
             if !field.arguments.is_empty() {
@@ -141,19 +146,16 @@ fn get_hover_response_contents(
                 .type_path
                 .resolve_current_type_reference(schema)?;
 
-            if type_ref.inner().is_object() || type_ref.inner().is_interface() {
-                let field_id = schema.named_field(type_ref.inner(), field_name)?;
-                let field = schema.field(field_id);
-                let argument = field.arguments.named(argument_name)?;
-                let content = format!(
-                    "{}: {}",
-                    argument_name,
-                    schema.get_type_string(&argument.type_)
-                );
-                Some(hover_content_wrapper(content))
-            } else {
-                None
-            }
+            let selectable_field = resolve_selectable_fields(&type_ref, schema)
+                .into_iter()
+                .find(|selectable_field| selectable_field.field.name == field_name)?;
+            let argument = selectable_field.field.arguments.named(argument_name)?;
+            let content = format!(
+                "{}: {}",
+                argument_name,
+                schema.get_type_string(&argument.type_)
+            );
+            Some(hover_content_wrapper(content))
         }
         NodeKind::FragmentSpread(fragment_name) => {
             let project_name = node_resolution_info.project_name;
@@ -294,3 +296,98 @@ pub(crate) fn on_hover<TPerfLogger: PerfLogger + 'static>(
         Err(LSPRuntimeError::ExpectedError)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node_resolution_info::create_node_resolution_info;
+    use common::{SourceLocationKey, Span};
+    use graphql_syntax::parse_executable;
+    use interner::Intern;
+    use relay_test_schema::get_test_schema;
+    use std::path::PathBuf;
+
+    struct NoExtraData;
+
+    impl LSPExtraDataProvider for NoExtraData {
+        fn fetch_query_stats(&self, _search_token: String) -> Vec<String> {
+            vec![]
+        }
+
+        fn resolve_field_definition(
+            &self,
+            _project_name: String,
+            _root_dir: &PathBuf,
+            _parent_type: String,
+            _field_name: Option<String>,
+        ) -> Option<Result<(String, u64), String>> {
+            None
+        }
+    }
+
+    /// Returns the hover contents at the `|` in the source.
+    fn hover_contents(source: &str) -> Option<HoverContents> {
+        let position = source.find('|').unwrap();
+        let document = parse_executable(
+            &source.replace('|', ""),
+            SourceLocationKey::standalone("/test/file"),
+        )
+        .unwrap();
+        let node_resolution_info = create_node_resolution_info(
+            document,
+            Span::from_usize(position, position),
+            "test_project".intern(),
+        )
+        .unwrap();
+        let extra_data_provider: Box<dyn LSPExtraDataProvider> = Box::new(NoExtraData);
+        get_hover_response_contents(
+            node_resolution_info,
+            &get_test_schema(),
+            &Arc::new(RwLock::new(HashMap::new())),
+            &extra_data_provider,
+        )
+    }
+
+    fn field_hover_title(source: &str) -> String {
+        match hover_contents(source) {
+            Some(HoverContents::Array(contents)) => match &contents[0] {
+                MarkedString::LanguageString(title) => title.value.clone(),
+                other => panic!("Expected a GraphQL title, got {:?}", other),
+            },
+            other => panic!("Expected hover contents for a field, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn field_on_object() {
+        assert_eq!(
+            field_hover_title("fragment Test on User { |name }"),
+            "name: String"
+        );
+        assert_eq!(
+            field_hover_title("fragment Test on User { |__typename }"),
+            "__typename: String!"
+        );
+    }
+
+    #[test]
+    fn field_on_interface() {
+        assert_eq!(
+            field_hover_title("fragment Test on Actor { |friends { count } }"),
+            "friends: FriendsConnection"
+        );
+        assert_eq!(
+            hover_contents("fragment Test on Actor { firstName(|if: true) }"),
+            Some(hover_content_wrapper("if: Boolean".to_string()))
+        );
+    }
+
+    #[test]
+    fn field_on_union() {
+        assert_eq!(
+            field_hover_title("fragment Test on MaybeNode { |__typename }"),
+            "__typename: String!"
+        );
+        assert_eq!(hover_contents("fragment Test on MaybeNode { |name }"), None);
+    }
+}
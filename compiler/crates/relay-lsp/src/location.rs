@@ -9,14 +9,35 @@ use std::path::PathBuf;
 
 use common::{Location, SourceLocationKey};
 use lsp_types::Url;
+use relay_compiler::{source_for_location, SourceReader};
 
 use crate::lsp_runtime_error::{LSPRuntimeError, LSPRuntimeResult};
 
-pub fn to_lsp_location_of_graphql_literal(
+/// Converts a location in a graphql literal or file to the range of its span
+/// in the file that contains it.
+pub fn to_lsp_location(
     location: Location,
     root_dir: &PathBuf,
+    source_reader: &dyn SourceReader,
 ) -> LSPRuntimeResult<lsp_types::Location> {
-    Ok(to_contents_and_lsp_location_of_graphql_literal(location, root_dir)?.1)
+    let path = match location.source_location() {
+        SourceLocationKey::Embedded { path, .. } | SourceLocationKey::Standalone { path } => path,
+        SourceLocationKey::Generated => {
+            return Err(LSPRuntimeError::UnexpectedError(
+                "Cannot get location of a generated artifact".to_string(),
+            ));
+        }
+    };
+    let source = source_for_location(root_dir, location.source_location(), source_reader)
+        .ok_or_else(|| {
+            LSPRuntimeError::UnexpectedError(format!("Could not read the source of {}", path))
+        })?;
+    let uri = get_uri(&root_dir.join(PathBuf::from(path.lookup())))?;
+    let range = location
+        .span()
+        .to_range(&source.text, source.line_index, source.column_index);
+
+    Ok(lsp_types::Location { uri, range })
 }
 
 pub fn to_contents_and_lsp_location_of_graphql_literal(
@@ -72,6 +93,13 @@ fn read_file_and_get_range(
         ))
     })?;
 
+    // LSP positions count characters in UTF-16 code units, and the first line
+    // of the literal starts at its column.
+    let mut last_line_character = last_line.encode_utf16().count();
+    if line_count == 0 {
+        last_line_character += source.column_index;
+    }
+
     Ok((
         source.text.to_string(),
         lsp_types::Range {
@@ -81,7 +109,7 @@ fn read_file_and_get_range(
             },
             end: lsp_types::Position {
                 line: (source.line_index + line_count) as u64,
-                character: last_line.len() as u64,
+                character: last_line_character as u64,
             },
         },
     ))
@@ -93,7 +93,7 @@ fn type_condition_at_position(
     Some(NodeKind::TypeCondition(type_condition.type_.value))
 }
 
-fn create_node_resolution_info(
+pub(crate) fn create_node_resolution_info(
     document: ExecutableDocument,
     position_span: Span,
     project_name: StringKey,
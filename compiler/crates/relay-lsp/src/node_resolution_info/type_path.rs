@@ -127,6 +127,15 @@ impl TypePath {
 
     /// Returns the leaf is it is a field
     pub fn resolve_current_field(self, schema: &Schema) -> Option<&Field> {
+        let (parent_type, field_name) = self.resolve_current_field_parent_type(schema)?;
+        schema
+            .named_field(parent_type, field_name)
+            .map(|field_id| schema.field(field_id))
+    }
+
+    /// Returns the type the leaf field is selected on and the name of the
+    /// field, if the leaf is a field
+    pub fn resolve_current_field_parent_type(self, schema: &Schema) -> Option<(Type, StringKey)> {
         let mut type_path = self.0;
         type_path.reverse();
         let mut type_ =
@@ -134,12 +143,9 @@ impl TypePath {
         while let Some(path_item) = type_path.pop() {
             if type_path.is_empty() {
                 return match path_item {
-                    TypePathItem::LinkedField { name } => schema
-                        .named_field(type_, name)
-                        .map(|field_id| schema.field(field_id)),
-                    TypePathItem::ScalarField { name } => schema
-                        .named_field(type_, name)
-                        .map(|field_id| schema.field(field_id)),
+                    TypePathItem::LinkedField { name } | TypePathItem::ScalarField { name } => {
+                        Some((type_, name))
+                    }
                     _ => None,
                 };
             } else {
@@ -13,15 +13,11 @@ use crate::{
     node_resolution_info::NodeKind,
     node_resolution_info::NodeResolutionInfo,
     server::LSPState,
-    utils::span_to_range_offset,
 };
 use common::{Location, PerfLogger};
 use graphql_ir::{FragmentSpread, Program, Visitor};
 use interner::StringKey;
-use lsp_types::{
-    request::{References, Request},
-    Range,
-};
+use lsp_types::request::{References, Request};
 use std::{
     collections::HashMap,
     path::PathBuf,
@@ -68,14 +64,11 @@ fn transform_reference_locations_to_lsp_locations(
     let (contents, mut lsp_location) =
         to_contents_and_lsp_location_of_graphql_literal(location, root_dir)?;
 
-    let range_offset =
-        span_to_range_offset(*location.span(), &contents).ok_or(LSPRuntimeError::ExpectedError)?;
-    log::info!("range offset {:?}", range_offset);
-
-    lsp_location.range = Range {
-        start: lsp_location.range.start + range_offset.start,
-        end: lsp_location.range.start + range_offset.end,
-    };
+    lsp_location.range = location.to_lsp_range(
+        &contents,
+        lsp_location.range.start.line as usize,
+        lsp_location.range.start.character as usize,
+    );
     Ok(lsp_location)
 }
 
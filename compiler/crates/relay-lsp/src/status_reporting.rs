@@ -11,7 +11,7 @@ use crate::lsp::{
     DiagnosticSeverity, Position, PublishDiagnosticsParams, Range, Url,
 };
 use crate::server::LSPStateError;
-use common::{Diagnostic as CompilerDiagnostic, Location};
+use common::{Diagnostic as CompilerDiagnostic, Location, Severity};
 use crossbeam::crossbeam_channel::Sender;
 use lsp_server::Message;
 use relay_compiler::{
@@ -115,7 +115,10 @@ impl LSPStatusReporter {
             message,
             range,
             related_information: None,
-            severity: Some(DiagnosticSeverity::Error),
+            severity: Some(match diagnostic.severity() {
+                Severity::Error => DiagnosticSeverity::Error,
+                Severity::Warning => DiagnosticSeverity::Warning,
+            }),
             source: None,
             tags: None,
         };
@@ -182,7 +185,7 @@ impl StatusReporter for LSPStatusReporter {
         set_running_status(&self.sender);
     }
 
-    fn build_finishes(&self, result: &Result<()>) {
+    fn build_finishes(&self, result: &Result<()>, warnings: &[CompilerDiagnostic]) {
         set_actual_server_status(&self.sender, &self.lsp_state_errors);
 
         {
@@ -194,6 +197,9 @@ impl StatusReporter for LSPStatusReporter {
                 diagnostics.clear();
             }
         }
+        for warning in warnings {
+            self.report_diagnostic(warning);
+        }
         if let Err(error) = result {
             self.report_error(error);
         }
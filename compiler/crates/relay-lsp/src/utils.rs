@@ -104,138 +104,78 @@ pub fn extract_executable_document_from_text(
 }
 
 /// Maps the LSP `Position` type back to a relative span, so we can find out which syntax node(s)
-/// this request came from
+/// this request came from. LSP positions count characters in UTF-16 code units, while spans
+/// are byte offsets into the source.
 pub(crate) fn position_to_span(
     position: Position,
     source: &GraphQLSource,
     index_offset: usize,
 ) -> Option<Span> {
-    let mut index_of_first_character_of_current_line = 0;
-    let mut line_index = source.line_index as u64;
-
-    let mut chars = source.text.chars().enumerate().peekable();
-
-    while let Some((index, chr)) = chars.next() {
-        let is_newline = match chr {
+    // Zero-indexed line offset in the document
+    let mut line = source.line_index as u64;
+    // Zero-indexed character offset on the line, in UTF-16 code units
+    let mut character = source.column_index as u64;
+    let mut chars = source.text.char_indices().peekable();
+
+    loop {
+        let next = chars.next();
+        let index = next.map_or(source.text.len(), |(index, _)| index);
+        let is_newline = match next {
             // Line terminators: https://www.ecma-international.org/ecma-262/#sec-line-terminators
-            '\u{000A}' | '\u{000D}' | '\u{2028}' | '\u{2029}' => {
+            Some((_, chr @ '\u{000A}'))
+            | Some((_, chr @ '\u{000D}'))
+            | Some((_, chr @ '\u{2028}'))
+            | Some((_, chr @ '\u{2029}')) => {
                 !matches!((chr, chars.peek()), ('\u{000D}', Some((_, '\u{000D}'))))
             }
             _ => false,
         };
 
-        if is_newline {
-            line_index += 1;
-            // Add index_offset to account for different position index between hover and autocomplete
-            index_of_first_character_of_current_line = (index + index_offset) as u64;
-        }
-
-        if line_index == position.line {
-            let start_offset =
-                (index_of_first_character_of_current_line + position.character) as u32;
+        // Stop at the position, or at the end of its line if the position is past it.
+        if line == position.line && (character >= position.character || is_newline) {
+            // The byte offset of the cursor, minus one for the character
+            // before the cursor, plus `index_offset` to account for the
+            // different position index between hover and autocomplete
+            let start_offset = (index + index_offset).saturating_sub(1) as u32;
             return Some(Span::new(start_offset, start_offset));
         }
-    }
-    None
-}
-
-#[derive(Debug)]
-pub(crate) struct SameLineOffset {
-    character_offset: u64,
-}
-
-#[derive(Debug)]
-pub(crate) struct DifferentLineOffset {
-    line_offset: u64,
-    character: u64,
-}
 
-/// Represents the offset from a given position to another position.
-/// The SameLineOffset variant represents moving to a later character
-/// position on the same line. The NewPositionOffset represents moving to
-/// a later line, and an arbitrary character position.
-#[derive(Debug)]
-pub(crate) enum PositionOffset {
-    SameLineOffset(SameLineOffset),
-    DifferentLineOffset(DifferentLineOffset),
-}
-
-impl std::ops::Add<PositionOffset> for Position {
-    type Output = Self;
-
-    fn add(self, offset: PositionOffset) -> Self::Output {
-        match offset {
-            PositionOffset::SameLineOffset(SameLineOffset { character_offset }) => Position {
-                line: self.line,
-                character: self.character + character_offset,
-            },
-            PositionOffset::DifferentLineOffset(DifferentLineOffset {
-                line_offset,
-                character,
-            }) => Position {
-                line: self.line + line_offset,
-                character,
-            },
+        let (_, chr) = next?;
+        if is_newline {
+            line += 1;
+            character = 0;
+        } else {
+            character += chr.len_utf16() as u64;
         }
     }
 }
 
-#[derive(Debug)]
-pub(crate) struct RangeOffset {
-    pub start: PositionOffset,
-    pub end: PositionOffset,
-}
-
-/// Returns a RangeOffset that represents the offset from the start
-/// of the source to the contents of the span.
-pub(crate) fn span_to_range_offset(span: Span, text: &str) -> Option<RangeOffset> {
-    if text.len() < span.end as usize {
-        return None;
+#[cfg(test)]
+mod tests {
+    use super::position_to_span;
+    use common::Span;
+    use graphql_syntax::GraphQLSource;
+    use lsp_types::Position;
+
+    #[test]
+    fn position_to_span_counts_utf16_code_units() {
+        let source = GraphQLSource::new("\n  # 😀\n  field\n", 3, 10);
+        // `f` of `field`, on the line after the emoji
+        assert_eq!(
+            position_to_span(Position::new(5, 2), &source, 1),
+            Some(Span::new(12, 12))
+        );
+        // Right after the emoji, which is two UTF-16 code units but four bytes
+        assert_eq!(
+            position_to_span(Position::new(4, 6), &source, 0),
+            Some(Span::new(8, 8))
+        );
+        // On the first line, the characters start at the column of the source
+        let source = GraphQLSource::new("fragment 😀 on User", 3, 10);
+        assert_eq!(
+            position_to_span(Position::new(3, 22), &source, 1),
+            Some(Span::new(14, 14))
+        );
+        assert_eq!(position_to_span(Position::new(7, 0), &source, 1), None);
     }
-
-    let mut start_position_offset = None;
-    let mut end_position_offset = None;
-    let Span { start, end } = span;
-    let span_start = start as u64;
-    let span_end = end as u64;
-    let mut characters_iterated: u64 = 0;
-
-    // For each line, determine whether the start and end of the span
-    // occur on that line.
-    for (line_index, line) in text.lines().enumerate() {
-        let line_length = line.len() as u64;
-        if start_position_offset.is_none() && characters_iterated + line_length >= span_start {
-            start_position_offset = Some(if line_index == 0 {
-                PositionOffset::SameLineOffset(SameLineOffset {
-                    character_offset: span_start,
-                })
-            } else {
-                PositionOffset::DifferentLineOffset(DifferentLineOffset {
-                    line_offset: line_index as u64,
-                    character: span_start - characters_iterated,
-                })
-            });
-        }
-        if end_position_offset.is_none() && characters_iterated + line_length >= span_end {
-            end_position_offset = Some(if line_index == 0 {
-                PositionOffset::SameLineOffset(SameLineOffset {
-                    character_offset: span_end,
-                })
-            } else {
-                PositionOffset::DifferentLineOffset(DifferentLineOffset {
-                    line_offset: line_index as u64,
-                    character: span_end - characters_iterated,
-                })
-            });
-            break;
-        }
-        characters_iterated += line_length;
-        // we also need to advance characters_iterated by 1 to account for the line break
-        characters_iterated += 1;
-    }
-
-    Some(RangeOffset {
-        start: start_position_offset?,
-        end: end_position_offset?,
-    })
 }
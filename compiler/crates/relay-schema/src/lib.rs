@@ -11,10 +11,10 @@
 #![deny(rust_2018_idioms)]
 #![deny(clippy::all)]
 
-use common::DiagnosticsResult;
+use common::{DiagnosticsResult, SourceLocationKey};
 use interner::{Intern, StringKey};
 use lazy_static::lazy_static;
-use schema::{ArgumentDefinitions, Schema, TypeReference};
+use schema::{ArgumentDefinitions, Schema, SchemaConfig, TypeReference};
 use std::iter::once;
 
 const RELAY_EXTENSIONS: &str = include_str!("./relay-extensions.graphql");
@@ -26,13 +26,19 @@ lazy_static! {
 }
 
 pub fn build_schema_with_extensions<T: AsRef<str>, U: AsRef<str>>(
-    server_sdls: &[T],
-    extension_sdls: &[U],
+    server_sdls: &[(T, SourceLocationKey)],
+    extension_sdls: &[(U, SourceLocationKey)],
+    schema_config: &SchemaConfig,
 ) -> DiagnosticsResult<Schema> {
-    let extensions: Vec<&str> = once(RELAY_EXTENSIONS)
-        .chain(extension_sdls.iter().map(|sdl| sdl.as_ref()))
-        .collect();
-    let mut schema = schema::build_schema_with_extensions(server_sdls, &extensions)?;
+    let extensions: Vec<(&str, SourceLocationKey)> =
+        once((RELAY_EXTENSIONS, SourceLocationKey::generated()))
+            .chain(
+                extension_sdls
+                    .iter()
+                    .map(|(sdl, source_location)| (sdl.as_ref(), *source_location)),
+            )
+            .collect();
+    let mut schema = schema::build_schema_with_extensions(server_sdls, &extensions, schema_config)?;
 
     // Remove label arg from @defer and @stream directives since the compiler
     // adds these arguments.
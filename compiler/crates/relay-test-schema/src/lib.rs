@@ -9,17 +9,22 @@
 #![deny(rust_2018_idioms)]
 #![deny(clippy::all)]
 
+use common::SourceLocationKey;
 use lazy_static::lazy_static;
 use relay_schema::build_schema_with_extensions;
-use schema::Schema;
+use schema::{Schema, SchemaConfig};
 use std::sync::Arc;
 
 const TEST_SCHEMA_DATA: &str = include_str!("testschema.graphql");
 
 lazy_static! {
     pub static ref TEST_SCHEMA: Arc<Schema> = Arc::new(
-        build_schema_with_extensions::<_, &str>(&[TEST_SCHEMA_DATA], &[])
-            .expect("Expected test schema to be valid")
+        build_schema_with_extensions::<_, &str>(
+            &[(TEST_SCHEMA_DATA, SourceLocationKey::generated())],
+            &[],
+            &SchemaConfig::default(),
+        )
+        .expect("Expected test schema to be valid")
     );
 }
 
@@ -29,7 +34,11 @@ pub fn get_test_schema() -> Arc<Schema> {
 
 pub fn get_test_schema_with_extensions(extensions_sdl: &str) -> Arc<Schema> {
     Arc::new(
-        build_schema_with_extensions(&[TEST_SCHEMA_DATA], &[extensions_sdl])
-            .expect("Expected test schema (and extensions) to be valid"),
+        build_schema_with_extensions(
+            &[(TEST_SCHEMA_DATA, SourceLocationKey::generated())],
+            &[(extensions_sdl, SourceLocationKey::generated())],
+            &SchemaConfig::default(),
+        )
+        .expect("Expected test schema (and extensions) to be valid"),
     )
 }
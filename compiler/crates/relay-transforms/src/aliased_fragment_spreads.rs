@@ -0,0 +1,59 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use crate::{INLINE_FRAGMENT_ALIAS_DIRECTIVE_NAME, REQUIRED_METADATA_KEY};
+use common::NamedItem;
+use graphql_ir::{FragmentSpread, InlineFragment, Program, Selection, Transformed, Transformer};
+use std::sync::Arc;
+
+/// Wraps the fragment spreads annotated with `@alias` in an inline fragment on
+/// the type of the fragment, and moves the `@alias` and the `@required`
+/// metadata of the spread to it, so that the reader asserts `@required` on
+/// the aliased selection like for an aliased inline fragment.
+pub fn wrap_aliased_fragment_spreads(program: &Program) -> Program {
+    let mut transform = WrapAliasedFragmentSpreads { program };
+    transform
+        .transform_program(program)
+        .replace_or_else(|| program.clone())
+}
+
+struct WrapAliasedFragmentSpreads<'s> {
+    program: &'s Program,
+}
+
+impl<'s> Transformer for WrapAliasedFragmentSpreads<'s> {
+    const NAME: &'static str = "WrapAliasedFragmentSpreads";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn transform_fragment_spread(&mut self, spread: &FragmentSpread) -> Transformed<Selection> {
+        if spread
+            .directives
+            .named(*INLINE_FRAGMENT_ALIAS_DIRECTIVE_NAME)
+            .is_none()
+        {
+            return Transformed::Keep;
+        }
+        let fragment = match self.program.fragment(spread.fragment.item) {
+            Some(fragment) => fragment,
+            None => return Transformed::Keep,
+        };
+        let (wrapper_directives, spread_directives) =
+            spread.directives.iter().cloned().partition(|directive| {
+                directive.name.item == *INLINE_FRAGMENT_ALIAS_DIRECTIVE_NAME
+                    || directive.name.item == *REQUIRED_METADATA_KEY
+            });
+        Transformed::Replace(Selection::InlineFragment(Arc::new(InlineFragment {
+            type_condition: Some(fragment.type_condition),
+            directives: wrapper_directives,
+            selections: vec![Selection::FragmentSpread(Arc::new(FragmentSpread {
+                directives: spread_directives,
+                ..spread.clone()
+            }))],
+        })))
+    }
+}
@@ -0,0 +1,96 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::WithLocation;
+use fnv::FnvHashMap;
+use graphql_ir::{Argument, FragmentSpread, Program, Selection, Transformed, Transformer, Value};
+use interner::StringKey;
+use std::sync::Arc;
+
+/// Passes the default value of every `@argumentDefinitions` argument that a
+/// fragment spread doesn't pass as an explicit argument of the spread, so
+/// later transforms don't have to look up the defaults of the fragment.
+/// Arguments without a default value are left out. The default values were
+/// already checked against their types when building the fragment signatures.
+pub fn apply_fragment_argument_defaults(program: &Program) -> Program {
+    let mut transform = ApplyFragmentArgumentDefaults::new(program);
+    transform
+        .transform_program(program)
+        .replace_or_else(|| program.clone())
+}
+
+struct ApplyFragmentArgumentDefaults<'program> {
+    program: &'program Program,
+    default_arguments: FnvHashMap<StringKey, Arc<Vec<Argument>>>,
+}
+
+impl<'program> ApplyFragmentArgumentDefaults<'program> {
+    fn new(program: &'program Program) -> Self {
+        Self {
+            program,
+            default_arguments: Default::default(),
+        }
+    }
+
+    fn default_arguments(&mut self, fragment_name: StringKey) -> Option<Arc<Vec<Argument>>> {
+        if let Some(default_arguments) = self.default_arguments.get(&fragment_name) {
+            return Some(Arc::clone(default_arguments));
+        }
+        let fragment = self.program.fragment(fragment_name)?;
+        let default_arguments = Arc::new(
+            fragment
+                .variable_definitions
+                .iter()
+                .filter_map(|variable_definition| {
+                    variable_definition
+                        .default_value
+                        .as_ref()
+                        .map(|default_value| Argument {
+                            name: WithLocation::generated(variable_definition.name.item),
+                            value: WithLocation::generated(Value::Constant(default_value.clone())),
+                        })
+                })
+                .collect::<Vec<_>>(),
+        );
+        self.default_arguments
+            .insert(fragment_name, Arc::clone(&default_arguments));
+        Some(default_arguments)
+    }
+}
+
+impl Transformer for ApplyFragmentArgumentDefaults<'_> {
+    const NAME: &'static str = "ApplyFragmentArgumentDefaultsTransform";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn transform_fragment_spread(&mut self, spread: &FragmentSpread) -> Transformed<Selection> {
+        let default_arguments = match self.default_arguments(spread.fragment.item) {
+            Some(default_arguments) => default_arguments,
+            None => return Transformed::Keep,
+        };
+        let missing_arguments = default_arguments
+            .iter()
+            .filter(|default_argument| {
+                !spread
+                    .arguments
+                    .iter()
+                    .any(|argument| argument.name.item == default_argument.name.item)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        if missing_arguments.is_empty() {
+            Transformed::Keep
+        } else {
+            let mut arguments = spread.arguments.clone();
+            arguments.extend(missing_arguments);
+            Transformed::Replace(Selection::FragmentSpread(Arc::new(FragmentSpread {
+                arguments,
+                ..spread.clone()
+            })))
+        }
+    }
+}
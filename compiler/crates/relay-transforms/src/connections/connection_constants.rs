@@ -25,6 +25,10 @@ pub struct ConnectionConstants {
     pub last_arg_name: StringKey,
     pub find_arg_name: StringKey,
     pub surrounds_arg_name: StringKey,
+
+    pub prefetchable_arg_name: StringKey,
+    pub generate_next_edges_arg_name: StringKey,
+    pub next_edges_handle_arg_name: StringKey,
 }
 
 impl ConnectionConstants {
@@ -57,6 +61,10 @@ impl Default for ConnectionConstants {
             last_arg_name: "last".intern(),
             find_arg_name: "find".intern(),
             surrounds_arg_name: "surrounds".intern(),
+
+            prefetchable_arg_name: "prefetchable".intern(),
+            generate_next_edges_arg_name: "generateNextEdges".intern(),
+            next_edges_handle_arg_name: "__connection_next_edges".intern(),
         }
     }
 }
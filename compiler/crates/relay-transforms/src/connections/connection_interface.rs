@@ -6,8 +6,8 @@
  */
 
 use interner::{Intern, StringKey};
-
 use serde::Deserialize;
+use std::fmt;
 
 /// Configuration where Relay should expect some fields in the schema.
 #[derive(Debug, Deserialize)]
@@ -37,3 +37,78 @@ impl Default for ConnectionInterface {
         }
     }
 }
+
+impl ConnectionInterface {
+    /// Checks that no two roles use the same field name.
+    pub fn validate(&self) -> Result<(), ConnectionInterfaceError> {
+        let roles = [
+            ("cursor", self.cursor),
+            ("edges", self.edges),
+            ("endCursor", self.end_cursor),
+            ("hasNextPage", self.has_next_page),
+            ("hasPreviousPage", self.has_previous_page),
+            ("node", self.node),
+            ("pageInfo", self.page_info),
+            ("startCursor", self.start_cursor),
+        ];
+        for (index, &(role, field_name)) in roles.iter().enumerate() {
+            if let Some(&(other_role, _)) = roles[..index]
+                .iter()
+                .find(|(_, other_field_name)| *other_field_name == field_name)
+            {
+                return Err(ConnectionInterfaceError::DuplicateFieldName {
+                    field_name,
+                    roles: (other_role, role),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ConnectionInterfaceError {
+    DuplicateFieldName {
+        field_name: StringKey,
+        roles: (&'static str, &'static str),
+    },
+}
+
+impl fmt::Display for ConnectionInterfaceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConnectionInterfaceError::DuplicateFieldName { field_name, roles } => write!(
+                f,
+                "The connection interface fields `{}` and `{}` both use the field name `{}`.",
+                roles.0, roles.1, field_name
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        let connection_interface = ConnectionInterface {
+            page_info: "page_info".intern(),
+            end_cursor: "end_cursor".intern(),
+            ..Default::default()
+        };
+        assert_eq!(connection_interface.validate(), Ok(()));
+
+        let connection_interface = ConnectionInterface {
+            page_info: "edges".intern(),
+            ..Default::default()
+        };
+        assert_eq!(
+            connection_interface.validate().unwrap_err(),
+            ConnectionInterfaceError::DuplicateFieldName {
+                field_name: "edges".intern(),
+                roles: ("edges", "pageInfo"),
+            }
+        );
+    }
+}
@@ -9,7 +9,8 @@ use crate::connections::{ConnectionConstants, ConnectionInterface};
 use crate::util::extract_variable_name;
 use common::{NamedItem, WithLocation};
 use graphql_ir::{
-    Argument, ConstantValue, Directive, InlineFragment, LinkedField, ScalarField, Selection, Value,
+    Argument, ConstantArgument, ConstantValue, Directive, InlineFragment, LinkedField, Program,
+    ScalarField, Selection, Value,
 };
 use interner::StringKey;
 use schema::{Schema, Type};
@@ -49,6 +50,38 @@ pub fn assert_connection_selections<'s>(
     }
 }
 
+/// Finds the `edges` selection of a connection field, either selected
+/// directly or within inline fragments and conditions, e.g. when the
+/// connection field has an interface type. Returns the `edges` field and the
+/// type it is selected on.
+pub fn find_edges_selection<'s>(
+    schema: &Schema,
+    selections: &'s [Selection],
+    parent_type: Type,
+    connection_interface: &ConnectionInterface,
+) -> Option<(&'s LinkedField, Type)> {
+    selections.iter().find_map(|selection| match selection {
+        Selection::LinkedField(field)
+            if schema.field(field.definition.item).name == connection_interface.edges =>
+        {
+            Some((field.as_ref(), parent_type))
+        }
+        Selection::InlineFragment(fragment) => find_edges_selection(
+            schema,
+            &fragment.selections,
+            fragment.type_condition.unwrap_or(parent_type),
+            connection_interface,
+        ),
+        Selection::Condition(condition) => find_edges_selection(
+            schema,
+            &condition.selections,
+            parent_type,
+            connection_interface,
+        ),
+        _ => None,
+    })
+}
+
 pub struct ConnectionMetadata {
     pub path: Option<Vec<StringKey>>,
     pub direction: StringKey,
@@ -57,6 +90,55 @@ pub struct ConnectionMetadata {
     pub before: Option<StringKey>,
     pub after: Option<StringKey>,
     pub is_stream_connection: bool,
+    /// Set with `@connection(prefetchable: true)`, the runtime may fetch the
+    /// next page before it is requested.
+    pub is_prefetchable: bool,
+}
+
+/// The direction a connection is paginated in: `forward` with a `first`
+/// argument, `backward` with a `last` argument and `bidirectional` with both.
+pub fn get_connection_direction(
+    connection_field: &LinkedField,
+    connection_constants: ConnectionConstants,
+) -> Option<StringKey> {
+    let first_arg = connection_field
+        .arguments
+        .named(connection_constants.first_arg_name);
+    let last_arg = connection_field
+        .arguments
+        .named(connection_constants.last_arg_name);
+    match (first_arg, last_arg) {
+        (Some(_), Some(_)) => Some(connection_constants.direction_bidirectional),
+        (Some(_), None) => Some(connection_constants.direction_forward),
+        (None, Some(_)) => Some(connection_constants.direction_backward),
+        (None, None) => None,
+    }
+}
+
+/// The page_info sub fields used to paginate a connection in the given
+/// direction.
+pub fn get_page_info_sub_field_names(
+    direction: StringKey,
+    connection_constants: ConnectionConstants,
+    connection_interface: &ConnectionInterface,
+) -> Vec<StringKey> {
+    let forward = [
+        connection_interface.end_cursor,
+        connection_interface.has_next_page,
+    ];
+    let backward = [
+        connection_interface.has_previous_page,
+        connection_interface.start_cursor,
+    ];
+    if direction == connection_constants.direction_forward {
+        forward.to_vec()
+    } else if direction == connection_constants.direction_backward {
+        backward.to_vec()
+    } else if direction == connection_constants.direction_bidirectional {
+        forward.iter().chain(backward.iter()).copied().collect()
+    } else {
+        unreachable!()
+    }
 }
 
 /// Builds the connection metadata that will be attached
@@ -65,7 +147,7 @@ pub fn build_connection_metadata(
     connection_field: &LinkedField,
     connection_constants: ConnectionConstants,
     path: &Option<Vec<StringKey>>,
-    is_stream_connection: bool,
+    connection_directive: &Directive,
 ) -> ConnectionMetadata {
     let first_arg = connection_field
         .arguments
@@ -74,14 +156,19 @@ pub fn build_connection_metadata(
         .arguments
         .named(connection_constants.last_arg_name);
 
-    let direction = match (first_arg, last_arg) {
-        (Some(_), Some(_)) => connection_constants.direction_bidirectional,
-        (Some(_), None) => connection_constants.direction_forward,
-        (None, Some(_)) => connection_constants.direction_backward,
-        (None, None) => unreachable!(
-            "Expected presence of first or last args on connection to have been previously validated."
-        ),
-    };
+    let is_stream_connection =
+        connection_directive.name.item == connection_constants.stream_connection_directive_name;
+    let is_prefetchable = matches!(
+        connection_directive
+            .arguments
+            .named(connection_constants.prefetchable_arg_name)
+            .map(|arg| &arg.value.item),
+        Some(Value::Constant(ConstantValue::Boolean(true)))
+    );
+
+    let direction = get_connection_direction(connection_field, connection_constants).expect(
+        "Expected presence of first or last args on connection to have been previously validated.",
+    );
 
     ConnectionMetadata {
         first: extract_variable_name(first_arg),
@@ -103,6 +190,7 @@ pub fn build_connection_metadata(
         direction,
         path: path.clone(),
         is_stream_connection,
+        is_prefetchable,
     }
 }
 
@@ -157,6 +245,7 @@ fn build_connection_metadata_value(connection_metadata: &ConnectionMetadata) ->
             None => ConstantValue::Null(),
         },
         ConstantValue::Boolean(connection_metadata.is_stream_connection),
+        ConstantValue::Boolean(connection_metadata.is_prefetchable),
     ])
 }
 
@@ -196,8 +285,8 @@ pub fn extract_connection_metadata_from_directive(
                     };
 
                     debug_assert!(
-                        metadata_value.len() == 7,
-                        "Expected metadata value to be a list with 7 elements"
+                        metadata_value.len() == 8,
+                        "Expected metadata value to be a list with 8 elements"
                     );
 
                     let path = match &metadata_value[0] {
@@ -254,6 +343,12 @@ pub fn extract_connection_metadata_from_directive(
                             "Expected connection metadata is_stream_connection to be a boolean."
                         ),
                     };
+                    let is_prefetchable = match &metadata_value[7] {
+                        ConstantValue::Boolean(bool_val) => *bool_val,
+                        _ => unreachable!(
+                            "Expected connection metadata is_prefetchable to be a boolean."
+                        ),
+                    };
 
                     ConnectionMetadata {
                         path,
@@ -263,6 +358,7 @@ pub fn extract_connection_metadata_from_directive(
                         after,
                         before,
                         is_stream_connection,
+                        is_prefetchable,
                     }
                 })
                 .collect::<Vec<_>>();
@@ -322,8 +418,131 @@ pub fn build_edge_selections(
     }))
 }
 
+/// Builds a description of the selections of the edges of a connection, set
+/// with `@connection(generateNextEdges: true)`. Optimistic updaters use it to
+/// construct new edges with the same shape as the fetched ones.
+///
+/// The description maps the response key of each selected field to `true`
+/// for scalar fields, or to the description of its selections for linked
+/// fields, and includes the `__typename` of each concrete type. Selections
+/// within inline fragments, conditions and fragment spreads are merged into
+/// their parent selections.
+pub fn build_edge_selection_metadata(
+    program: &Program,
+    edges_field: &LinkedField,
+) -> ConstantValue {
+    build_selection_metadata(
+        program,
+        program
+            .schema
+            .field(edges_field.definition.item)
+            .type_
+            .inner(),
+        &edges_field.selections,
+    )
+}
+
+fn build_selection_metadata(
+    program: &Program,
+    parent_type: Type,
+    selections: &[Selection],
+) -> ConstantValue {
+    let schema = &program.schema;
+    let mut entries = Vec::new();
+    if parent_type.is_object() {
+        entries.push(ConstantArgument {
+            name: WithLocation::generated(schema.field(schema.typename_field()).name),
+            value: WithLocation::generated(ConstantValue::String(
+                schema.get_type_name(parent_type),
+            )),
+        });
+    }
+    collect_selection_metadata(program, selections, &mut entries);
+    ConstantValue::Object(entries)
+}
+
+fn collect_selection_metadata(
+    program: &Program,
+    selections: &[Selection],
+    entries: &mut Vec<ConstantArgument>,
+) {
+    let schema = &program.schema;
+    for selection in selections {
+        match selection {
+            Selection::ScalarField(field) => {
+                let response_key = field.alias_or_name(schema);
+                if !entries.iter().any(|entry| entry.name.item == response_key) {
+                    entries.push(ConstantArgument {
+                        name: WithLocation::generated(response_key),
+                        value: WithLocation::generated(ConstantValue::Boolean(true)),
+                    });
+                }
+            }
+            Selection::LinkedField(field) => {
+                let response_key = field.alias_or_name(schema);
+                let existing_entry = entries
+                    .iter_mut()
+                    .find(|entry| entry.name.item == response_key);
+                match existing_entry {
+                    Some(ConstantArgument {
+                        value:
+                            WithLocation {
+                                item: ConstantValue::Object(field_entries),
+                                ..
+                            },
+                        ..
+                    }) => collect_selection_metadata(program, &field.selections, field_entries),
+                    Some(_) => {}
+                    None => entries.push(ConstantArgument {
+                        name: WithLocation::generated(response_key),
+                        value: WithLocation::generated(build_selection_metadata(
+                            program,
+                            schema.field(field.definition.item).type_.inner(),
+                            &field.selections,
+                        )),
+                    }),
+                }
+            }
+            Selection::InlineFragment(fragment) => {
+                collect_selection_metadata(program, &fragment.selections, entries)
+            }
+            Selection::Condition(condition) => {
+                collect_selection_metadata(program, &condition.selections, entries)
+            }
+            Selection::FragmentSpread(spread) => {
+                if let Some(fragment) = program.fragment(spread.fragment.item) {
+                    collect_selection_metadata(program, &fragment.selections, entries)
+                }
+            }
+        }
+    }
+}
+
+/// Whether the page info type exposes the sub fields used to paginate in
+/// the direction of the connection.
+pub fn has_page_info_sub_fields(
+    schema: &Schema,
+    page_info_type: Type,
+    connection_metadata: &ConnectionMetadata,
+    connection_constants: ConnectionConstants,
+    connection_interface: &ConnectionInterface,
+) -> bool {
+    get_page_info_sub_field_names(
+        connection_metadata.direction,
+        connection_constants,
+        connection_interface,
+    )
+    .iter()
+    .all(|sub_field_name| {
+        schema
+            .named_field(page_info_type, *sub_field_name)
+            .is_some()
+    })
+}
+
 /// Builds the selections that will be added to the page_info selection
-/// by the connections transform
+/// by the connections transform, the sub fields used to paginate in the
+/// direction of the connection.
 pub fn build_page_info_selections(
     schema: &Schema,
     page_info_type: Type,
@@ -331,91 +550,34 @@ pub fn build_page_info_selections(
     connection_constants: ConnectionConstants,
     connection_interface: &ConnectionInterface,
 ) -> Selection {
-    let end_cursor_field_id = schema
-        .named_field(page_info_type, connection_interface.end_cursor)
-        .expect("Expected presence of end_cursor field to have been previously validated.");
-    let has_next_page_field_id = schema
-        .named_field(page_info_type, connection_interface.has_next_page)
-        .expect("Expected presence of has_next_page field to have been previously validated.");
-    let has_prev_page_field_id = schema
-        .named_field(page_info_type, connection_interface.has_previous_page)
-        .expect("Expected presence of has_previous_page field to have been previously validated.");
-    let start_cursor_field_id = schema
-        .named_field(page_info_type, connection_interface.start_cursor)
-        .expect("Expected presence of start_cursor field to have been previously validated.");
-
-    if connection_metadata.direction == connection_constants.direction_forward {
-        Selection::InlineFragment(From::from(InlineFragment {
-            type_condition: Some(page_info_type),
-            directives: Vec::new(),
-            selections: vec![
-                Selection::ScalarField(From::from(ScalarField {
-                    alias: None,
-                    definition: WithLocation::generated(end_cursor_field_id),
-                    arguments: Vec::new(),
-                    directives: Vec::new(),
-                })),
-                Selection::ScalarField(From::from(ScalarField {
-                    alias: None,
-                    definition: WithLocation::generated(has_next_page_field_id),
-                    arguments: Vec::new(),
-                    directives: Vec::new(),
-                })),
-            ],
-        }))
-    } else if connection_metadata.direction == connection_constants.direction_backward {
-        Selection::InlineFragment(From::from(InlineFragment {
-            type_condition: Some(page_info_type),
+    let selections = get_page_info_sub_field_names(
+        connection_metadata.direction,
+        connection_constants,
+        connection_interface,
+    )
+    .into_iter()
+    .map(|sub_field_name| {
+        let sub_field_id = schema
+            .named_field(page_info_type, sub_field_name)
+            .unwrap_or_else(|| {
+                panic!(
+                    "Expected presence of {} field to have been previously validated.",
+                    sub_field_name
+                )
+            });
+        Selection::ScalarField(From::from(ScalarField {
+            alias: None,
+            definition: WithLocation::generated(sub_field_id),
+            arguments: Vec::new(),
             directives: Vec::new(),
-            selections: vec![
-                Selection::ScalarField(From::from(ScalarField {
-                    alias: None,
-                    definition: WithLocation::generated(has_prev_page_field_id),
-                    arguments: Vec::new(),
-                    directives: Vec::new(),
-                })),
-                Selection::ScalarField(From::from(ScalarField {
-                    alias: None,
-                    definition: WithLocation::generated(start_cursor_field_id),
-                    arguments: Vec::new(),
-                    directives: Vec::new(),
-                })),
-            ],
         }))
-    } else if connection_metadata.direction == connection_constants.direction_bidirectional {
-        Selection::InlineFragment(From::from(InlineFragment {
-            type_condition: Some(page_info_type),
-            directives: Vec::new(),
-            selections: vec![
-                Selection::ScalarField(From::from(ScalarField {
-                    alias: None,
-                    definition: WithLocation::generated(end_cursor_field_id),
-                    arguments: Vec::new(),
-                    directives: Vec::new(),
-                })),
-                Selection::ScalarField(From::from(ScalarField {
-                    alias: None,
-                    definition: WithLocation::generated(has_next_page_field_id),
-                    arguments: Vec::new(),
-                    directives: Vec::new(),
-                })),
-                Selection::ScalarField(From::from(ScalarField {
-                    alias: None,
-                    definition: WithLocation::generated(has_prev_page_field_id),
-                    arguments: Vec::new(),
-                    directives: Vec::new(),
-                })),
-                Selection::ScalarField(From::from(ScalarField {
-                    alias: None,
-                    definition: WithLocation::generated(start_cursor_field_id),
-                    arguments: Vec::new(),
-                    directives: Vec::new(),
-                })),
-            ],
-        }))
-    } else {
-        unreachable!()
-    }
+    })
+    .collect();
+    Selection::InlineFragment(From::from(InlineFragment {
+        type_condition: Some(page_info_type),
+        directives: Vec::new(),
+        selections,
+    }))
 }
 
 /// Helper to extract the connection directive if present in the given list of
@@ -10,10 +10,11 @@ mod connection_interface;
 mod connection_util;
 
 pub use connection_constants::ConnectionConstants;
-pub use connection_interface::ConnectionInterface;
+pub use connection_interface::{ConnectionInterface, ConnectionInterfaceError};
 pub use connection_util::{
     assert_connection_selections, build_connection_metadata,
-    build_connection_metadata_as_directive, build_edge_selections, build_page_info_selections,
-    extract_connection_directive, extract_connection_metadata_from_directive, get_default_filters,
-    ConnectionMetadata,
+    build_connection_metadata_as_directive, build_edge_selection_metadata, build_edge_selections,
+    build_page_info_selections, extract_connection_directive,
+    extract_connection_metadata_from_directive, find_edges_selection, get_default_filters,
+    has_page_info_sub_fields, ConnectionMetadata,
 };
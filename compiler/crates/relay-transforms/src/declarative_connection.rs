@@ -112,6 +112,7 @@ impl<'s, 'c> Transformer for DeclarativeConnectionMutationTransform<'s, 'c> {
                     let handle_directive =
                         build_handle_field_directive(HandleFieldDirectiveValues {
                             handle: delete_directive.name.item,
+                            handler_module: None,
                             key: "".intern(),
                             dynamic_key: None,
                             filters: None,
@@ -124,7 +125,7 @@ impl<'s, 'c> Transformer for DeclarativeConnectionMutationTransform<'s, 'c> {
                     let mut next_directives: Vec<_> = field
                         .directives
                         .iter()
-                        .filter(|directive| directive.name.item != *DELETE_RECORD)
+                        .filter(|directive| directive != &delete_directive)
                         .cloned()
                         .collect();
                     next_directives.push(handle_directive);
@@ -139,7 +140,9 @@ impl<'s, 'c> Transformer for DeclarativeConnectionMutationTransform<'s, 'c> {
 
     fn transform_linked_field(&mut self, field: &LinkedField) -> Transformed<Selection> {
         let transformed_field = self.default_transform_linked_field(field);
-        let delete_directive = field.directives.named(*DELETE_RECORD);
+        let delete_directive = field.directives.iter().find(|directive| {
+            directive.name.item == *DELETE_RECORD || directive.name.item == *DELETE_EDGE
+        });
         if let Some(delete_directive) = delete_directive {
             self.errors.push(Diagnostic::error(
                 ValidationMessage::DeleteRecordDirectiveOnLinkedField {
@@ -199,6 +202,7 @@ impl<'s, 'c> Transformer for DeclarativeConnectionMutationTransform<'s, 'c> {
                             let handle_directive =
                                 build_handle_field_directive(HandleFieldDirectiveValues {
                                     handle: edge_directive.name.item,
+                                    handler_module: None,
                                     key: "".intern(),
                                     dynamic_key: None,
                                     filters: None,
@@ -257,6 +261,7 @@ impl<'s, 'c> Transformer for DeclarativeConnectionMutationTransform<'s, 'c> {
                                     let handle_directive =
                                         build_handle_field_directive(HandleFieldDirectiveValues {
                                             handle: node_directive.name.item,
+                                            handler_module: None,
                                             key: "".intern(),
                                             dynamic_key: None,
                                             filters: None,
@@ -18,6 +18,7 @@ use graphql_ir::{
 };
 use interner::{Intern, StringKey};
 use lazy_static::lazy_static;
+use schema::Schema;
 use std::{collections::HashMap, sync::Arc};
 
 pub struct DeferStreamConstants {
@@ -44,6 +45,7 @@ impl Default for DeferStreamConstants {
 
 lazy_static! {
     pub static ref DEFER_STREAM_CONSTANTS: DeferStreamConstants = Default::default();
+    static ref INT_TYPE_NAME: StringKey = "Int".intern();
 }
 
 pub fn transform_defer_stream(program: &Program) -> DiagnosticsResult<Program> {
@@ -188,11 +190,16 @@ impl DeferStreamTransform<'_> {
             )));
         }
 
-        if initial_count_arg.is_none() {
-            return Err(Diagnostic::error(
-                ValidationMessage::StreamInitialCountRequired,
-                stream.name.location,
-            ));
+        match initial_count_arg {
+            None => {
+                return Err(Diagnostic::error(
+                    ValidationMessage::StreamInitialCountRequired,
+                    stream.name.location,
+                ));
+            }
+            Some(initial_count_arg) => {
+                validate_initial_count_argument(&self.program.schema, initial_count_arg)?
+            }
         }
 
         let label_value = get_literal_string_argument(&stream, label_arg)?;
@@ -362,6 +369,28 @@ fn transform_label(
     format!("{}${}${}", parent_name, directive_name, label).intern()
 }
 
+/// The initial count must be a non-negative integer. Literals of other types
+/// can reach this point through directives that are not type checked.
+fn validate_initial_count_argument(schema: &Schema, arg: &Argument) -> Result<(), Diagnostic> {
+    let is_valid = match &arg.value.item {
+        Value::Constant(ConstantValue::Int(value)) => *value >= 0,
+        Value::Variable(variable) => {
+            schema.get_type(*INT_TYPE_NAME) == Some(variable.type_.inner())
+        }
+        _ => false,
+    };
+    if is_valid {
+        Ok(())
+    } else {
+        Err(Diagnostic::error(
+            ValidationMessage::InvalidStreamInitialCount {
+                arg_name: arg.name.item,
+            },
+            arg.value.location,
+        ))
+    }
+}
+
 fn get_literal_string_argument(
     directive: &Directive,
     argument: Option<&Argument>,
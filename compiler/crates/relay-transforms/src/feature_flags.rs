@@ -15,6 +15,35 @@ pub struct FeatureFlags {
     pub enable_flight_transform: bool,
 
     pub enable_required_transform_for_prefix: Option<StringKey>,
+
+    /// Keep the selections of reader artifacts in the order of the source,
+    /// e.g. to correlate them with the GraphQL literal while debugging.
+    /// Normalization artifacts are not affected.
+    #[serde(default)]
+    pub preserve_reader_selection_order: bool,
+
+    /// Keep the inline fragments of reader artifacts that are nested at most
+    /// this many levels deep in a selection set instead of flattening them,
+    /// to make debug artifacts easier to read. Normalization artifacts are
+    /// always fully flattened.
+    #[serde(default)]
+    pub preserve_reader_inline_fragment_depth: usize,
+
+    /// Generate the `key` of `@connection` fields that don't pass one from
+    /// the name of the document and the path to the field. Explicit keys are
+    /// preferred, as renaming a field or document changes the generated key.
+    #[serde(default)]
+    pub enable_connection_key_generation: bool,
+
+    /// Pass the default values of `@argumentDefinitions` as explicit
+    /// arguments of the fragment spreads that don't pass them.
+    #[serde(default)]
+    pub enable_fragment_argument_defaults: bool,
+
+    /// The directive that marks fields whose fragment spread is read from
+    /// the store of another actor, e.g. `fb_actor_change`. The directive has
+    /// to be defined in the schema extensions of the project.
+    pub actor_change_directive: Option<StringKey>,
 }
 
 impl Default for FeatureFlags {
@@ -22,6 +51,11 @@ impl Default for FeatureFlags {
         FeatureFlags {
             enable_flight_transform: false,
             enable_required_transform_for_prefix: None,
+            preserve_reader_selection_order: false,
+            preserve_reader_inline_fragment_depth: 0,
+            enable_connection_key_generation: false,
+            enable_fragment_argument_defaults: false,
+            actor_change_directive: None,
         }
     }
 }
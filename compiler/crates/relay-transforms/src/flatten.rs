@@ -26,7 +26,8 @@ use schema::Schema;
 use std::sync::Arc;
 
 type SeenLinkedFields = Arc<RwLock<FnvHashMap<PointerAddress, Arc<LinkedField>>>>;
-type SeenInlineFragments = Arc<RwLock<FnvHashMap<(PointerAddress, Type), Arc<InlineFragment>>>>;
+type SeenInlineFragments =
+    Arc<RwLock<FnvHashMap<(PointerAddress, Type, usize), Arc<InlineFragment>>>>;
 
 ///
 /// Transform that flattens inline fragments, fragment spreads, merges linked fields selections.
@@ -39,8 +40,22 @@ type SeenInlineFragments = Arc<RwLock<FnvHashMap<(PointerAddress, Type), Arc<Inl
 /// directives (@defer, @__clientExtensions).
 ///
 pub fn flatten(program: &Program, is_for_codegen: bool) -> DiagnosticsResult<Program> {
+    flatten_preserving_inline_fragments(program, is_for_codegen, 0)
+}
+
+/// Like `flatten`, but keeps the inline fragments with a type condition that
+/// are nested at most `preserved_depth` levels deep in a selection set instead
+/// of inlining them, to make the printed selections easier to read while
+/// debugging. Deeper inline fragments and anonymous inline fragments are still
+/// flattened, and the selections of the kept inline fragments are merged with
+/// the ones of identical siblings as usual.
+pub fn flatten_preserving_inline_fragments(
+    program: &Program,
+    is_for_codegen: bool,
+    preserved_depth: usize,
+) -> DiagnosticsResult<Program> {
     let next_program = Arc::new(RwLock::new(Program::new(Arc::clone(&program.schema))));
-    let transform = FlattenTransform::new(program, is_for_codegen);
+    let transform = FlattenTransform::new(program, is_for_codegen, preserved_depth);
     let errors = Arc::new(Mutex::new(Vec::new()));
 
     program.par_operations().for_each(|operation| {
@@ -78,15 +93,17 @@ pub fn flatten(program: &Program, is_for_codegen: bool) -> DiagnosticsResult<Pro
 struct FlattenTransform {
     schema: Arc<Schema>,
     is_for_codegen: bool,
+    preserved_depth: usize,
     seen_linked_fields: SeenLinkedFields,
     seen_inline_fragments: SeenInlineFragments,
 }
 
 impl FlattenTransform {
-    fn new(program: &'_ Program, is_for_codegen: bool) -> Self {
+    fn new(program: &'_ Program, is_for_codegen: bool, preserved_depth: usize) -> Self {
         Self {
             schema: Arc::clone(&program.schema),
             is_for_codegen,
+            preserved_depth,
             seen_linked_fields: Default::default(),
             seen_inline_fragments: Default::default(),
         }
@@ -102,7 +119,7 @@ impl FlattenTransform {
             type_: operation.type_,
             directives: operation.directives.clone(),
             variable_definitions: operation.variable_definitions.clone(),
-            selections: self.transform_selections(&operation.selections, operation.type_)?,
+            selections: self.transform_selections(&operation.selections, operation.type_, 0)?,
         })
     }
 
@@ -116,7 +133,11 @@ impl FlattenTransform {
             directives: fragment.directives.clone(),
             variable_definitions: fragment.variable_definitions.clone(),
             used_global_variables: fragment.used_global_variables.clone(),
-            selections: self.transform_selections(&fragment.selections, fragment.type_condition)?,
+            selections: self.transform_selections(
+                &fragment.selections,
+                fragment.type_condition,
+                0,
+            )?,
         })
     }
 
@@ -124,13 +145,19 @@ impl FlattenTransform {
         &self,
         selections: &[Selection],
         parent_type: Type,
+        depth: usize,
     ) -> DiagnosticsResult<Vec<Selection>> {
         let mut next_selections = Vec::with_capacity(selections.len());
         for selection in selections {
-            next_selections.push(self.transform_selection(selection, parent_type)?);
+            next_selections.push(self.transform_selection(selection, parent_type, depth)?);
         }
         let mut flattened_selections = Vec::with_capacity(next_selections.len());
-        self.flatten_selections(&mut flattened_selections, &next_selections, parent_type)?;
+        self.flatten_selections(
+            &mut flattened_selections,
+            &next_selections,
+            parent_type,
+            depth,
+        )?;
 
         Ok(flattened_selections)
     }
@@ -157,7 +184,7 @@ impl FlattenTransform {
             definition: linked_field.definition,
             arguments: linked_field.arguments.clone(),
             directives: linked_field.directives.clone(),
-            selections: self.transform_selections(&linked_field.selections, type_)?,
+            selections: self.transform_selections(&linked_field.selections, type_, 0)?,
         });
         if should_cache {
             let mut seen_linked_fields = self.seen_linked_fields.write();
@@ -174,9 +201,14 @@ impl FlattenTransform {
         &self,
         fragment: &Arc<InlineFragment>,
         parent_type: Type,
+        depth: usize,
     ) -> DiagnosticsResult<Arc<InlineFragment>> {
         let should_cache = Arc::strong_count(fragment) > 1;
-        let key = (PointerAddress::new(Arc::as_ref(fragment)), parent_type);
+        let key = (
+            PointerAddress::new(Arc::as_ref(fragment)),
+            parent_type,
+            depth,
+        );
         if should_cache {
             let seen_inline_fragments = self.seen_inline_fragments.read();
             if let Some(prev) = seen_inline_fragments.get(&key) {
@@ -190,7 +222,11 @@ impl FlattenTransform {
         let result = Arc::new(InlineFragment {
             type_condition: fragment.type_condition,
             directives: fragment.directives.clone(),
-            selections: self.transform_selections(&fragment.selections, next_parent_type)?,
+            selections: self.transform_selections(
+                &fragment.selections,
+                next_parent_type,
+                depth + 1,
+            )?,
         });
         if should_cache {
             let mut seen_inline_fragments = self.seen_inline_fragments.write();
@@ -207,18 +243,19 @@ impl FlattenTransform {
         &self,
         selection: &Selection,
         parent_type: Type,
+        depth: usize,
     ) -> DiagnosticsResult<Selection> {
         Ok(match selection {
-            Selection::InlineFragment(node) => {
-                Selection::InlineFragment(self.transform_inline_fragment(node, parent_type)?)
-            }
+            Selection::InlineFragment(node) => Selection::InlineFragment(
+                self.transform_inline_fragment(node, parent_type, depth)?,
+            ),
             Selection::LinkedField(node) => {
                 Selection::LinkedField(self.transform_linked_field(node)?)
             }
             Selection::Condition(node) => Selection::Condition(Arc::new(Condition {
                 value: node.value.clone(),
                 passing_value: node.passing_value,
-                selections: self.transform_selections(&node.selections, parent_type)?,
+                selections: self.transform_selections(&node.selections, parent_type, depth)?,
             })),
             Selection::FragmentSpread(node) => Selection::FragmentSpread(Arc::clone(node)),
             Selection::ScalarField(node) => Selection::ScalarField(Arc::clone(node)),
@@ -230,15 +267,18 @@ impl FlattenTransform {
         flattened_selections: &mut Vec<Selection>,
         selections: &[Selection],
         parent_type: Type,
+        depth: usize,
     ) -> DiagnosticsResult<()> {
         for selection in selections {
             if let Selection::InlineFragment(inline_fragment) = selection {
                 if should_flatten_inline_fragment(inline_fragment, parent_type, self.is_for_codegen)
+                    && !self.should_preserve_inline_fragment(inline_fragment, depth)
                 {
                     self.flatten_selections(
                         flattened_selections,
                         &inline_fragment.selections,
                         parent_type,
+                        depth,
                     )?;
                     continue;
                 }
@@ -300,6 +340,7 @@ impl FlattenTransform {
                                         &flattened_node.selections,
                                         &node_selections,
                                         type_condition,
+                                        depth + 1,
                                     )?,
                                 }));
                         }
@@ -345,6 +386,7 @@ impl FlattenTransform {
                                     &flattened_node.selections,
                                     &node.selections,
                                     type_,
+                                    0,
                                 )?,
                             }));
                         }
@@ -361,6 +403,7 @@ impl FlattenTransform {
                                     &flattened_node.selections,
                                     &node_selections,
                                     parent_type,
+                                    depth,
                                 )?,
                             }));
                         }
@@ -410,13 +453,24 @@ impl FlattenTransform {
         selections_a: &[Selection],
         selections_b: &[Selection],
         parent_type: Type,
+        depth: usize,
     ) -> DiagnosticsResult<Vec<Selection>> {
         let mut flattened_selections = Vec::with_capacity(selections_a.len());
-        self.flatten_selections(&mut flattened_selections, selections_a, parent_type)?;
-        self.flatten_selections(&mut flattened_selections, selections_b, parent_type)?;
+        self.flatten_selections(&mut flattened_selections, selections_a, parent_type, depth)?;
+        self.flatten_selections(&mut flattened_selections, selections_b, parent_type, depth)?;
         Ok(flattened_selections)
     }
 
+    /// Anonymous inline fragments are always inlined, codegen can't print
+    /// them without a type.
+    fn should_preserve_inline_fragment(
+        &self,
+        inline_fragment: &InlineFragment,
+        depth: usize,
+    ) -> bool {
+        depth < self.preserved_depth && inline_fragment.type_condition.is_some()
+    }
+
     fn create_conflicting_fields_error(
         &self,
         field_name: StringKey,
@@ -0,0 +1,66 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use crate::INTERNAL_METADATA_DIRECTIVE;
+use common::{NamedItem, WithLocation};
+use graphql_ir::{
+    Argument, ConstantValue, Directive, OperationDefinition, Program, Transformed, Transformer,
+    Value,
+};
+use interner::{Intern, StringKey};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    pub static ref CATCH_DIRECTIVE_NAME: StringKey = "catch".intern();
+    pub static ref CATCH_METADATA_KEY: StringKey = "catch".intern();
+}
+
+/// Adds `catch: true` to the request metadata of operations annotated with
+/// `@catch`, so the runtime returns errors of the operation, including those
+/// thrown by `@required(action: THROW)` fields, as part of the result instead
+/// of throwing them.
+pub fn generate_catch_metadata(program: &Program) -> Program {
+    let mut transformer = GenerateCatchMetadata;
+    transformer
+        .transform_program(program)
+        .replace_or_else(|| program.clone())
+}
+
+struct GenerateCatchMetadata;
+
+impl Transformer for GenerateCatchMetadata {
+    const NAME: &'static str = "GenerateCatchMetadata";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn transform_operation(
+        &mut self,
+        operation: &OperationDefinition,
+    ) -> Transformed<OperationDefinition> {
+        match operation.directives.named(*CATCH_DIRECTIVE_NAME) {
+            Some(catch_directive) => {
+                let location = catch_directive.name.location;
+                let mut next_directives = operation.directives.clone();
+                next_directives.push(Directive {
+                    name: WithLocation::new(location, *INTERNAL_METADATA_DIRECTIVE),
+                    arguments: vec![Argument {
+                        name: WithLocation::new(location, *CATCH_METADATA_KEY),
+                        value: WithLocation::new(
+                            location,
+                            Value::Constant(ConstantValue::Boolean(true)),
+                        ),
+                    }],
+                });
+                Transformed::Replace(OperationDefinition {
+                    directives: next_directives,
+                    ..operation.clone()
+                })
+            }
+            None => Transformed::Keep,
+        }
+    }
+}
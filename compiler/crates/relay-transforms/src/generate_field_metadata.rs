@@ -0,0 +1,109 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use crate::INTERNAL_METADATA_DIRECTIVE;
+use common::WithLocation;
+use graphql_ir::{
+    Argument, ConstantArgument, ConstantValue, Directive, LinkedField, Program, ScalarField,
+    Selection, Transformed, Transformer, Value,
+};
+use interner::StringKey;
+use std::sync::Arc;
+
+/// Adds the fields annotated with one of the given directives to the
+/// `metadata` of the field in the reader and normalization artifacts, e.g.
+/// `lazy: {priority: 1}` for `@lazy(priority: 1)`, for network layers that
+/// read these annotations from the artifacts. Only literal arguments of the
+/// directives are added. The directives themselves are kept and sent to the
+/// server.
+pub fn generate_field_metadata(program: &Program, metadata_directives: &[StringKey]) -> Program {
+    if metadata_directives.is_empty() {
+        return program.clone();
+    }
+    let mut transformer = GenerateFieldMetadata {
+        metadata_directives,
+    };
+    transformer
+        .transform_program(program)
+        .replace_or_else(|| program.clone())
+}
+
+struct GenerateFieldMetadata<'a> {
+    metadata_directives: &'a [StringKey],
+}
+
+impl GenerateFieldMetadata<'_> {
+    /// Returns the directives with a `@__metadata` directive added for each
+    /// metadata directive, or None if there are no metadata directives.
+    fn build_directives(&self, directives: &[Directive]) -> Option<Vec<Directive>> {
+        let metadata_directives = directives
+            .iter()
+            .filter(|directive| self.metadata_directives.contains(&directive.name.item))
+            .map(build_metadata_directive)
+            .collect::<Vec<_>>();
+        if metadata_directives.is_empty() {
+            None
+        } else {
+            let mut next_directives = directives.to_vec();
+            next_directives.extend(metadata_directives);
+            Some(next_directives)
+        }
+    }
+}
+
+impl Transformer for GenerateFieldMetadata<'_> {
+    const NAME: &'static str = "GenerateFieldMetadata";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn transform_scalar_field(&mut self, field: &ScalarField) -> Transformed<Selection> {
+        match self.build_directives(&field.directives) {
+            Some(directives) => {
+                Transformed::Replace(Selection::ScalarField(Arc::new(ScalarField {
+                    directives,
+                    ..field.clone()
+                })))
+            }
+            None => Transformed::Keep,
+        }
+    }
+
+    fn transform_linked_field(&mut self, field: &LinkedField) -> Transformed<Selection> {
+        let selections = self.transform_selections(&field.selections);
+        let directives = self.build_directives(&field.directives);
+        if selections.should_keep() && directives.is_none() {
+            return Transformed::Keep;
+        }
+        Transformed::Replace(Selection::LinkedField(Arc::new(LinkedField {
+            directives: directives.unwrap_or_else(|| field.directives.clone()),
+            selections: selections.replace_or_else(|| field.selections.clone()),
+            ..field.clone()
+        })))
+    }
+}
+
+fn build_metadata_directive(directive: &Directive) -> Directive {
+    let location = directive.name.location;
+    let arguments = directive
+        .arguments
+        .iter()
+        .filter_map(|argument| match &argument.value.item {
+            Value::Constant(value) => Some(ConstantArgument {
+                name: argument.name,
+                value: WithLocation::new(argument.value.location, value.clone()),
+            }),
+            _ => None,
+        })
+        .collect();
+    Directive {
+        name: WithLocation::new(location, *INTERNAL_METADATA_DIRECTIVE),
+        arguments: vec![Argument {
+            name: directive.name,
+            value: WithLocation::new(location, Value::Constant(ConstantValue::Object(arguments))),
+        }],
+    }
+}
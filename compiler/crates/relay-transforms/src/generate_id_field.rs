@@ -5,6 +5,7 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use crate::util::get_node_id_field_name;
 use common::{Location, WithLocation};
 use graphql_ir::{
     FragmentSpread, InlineFragment, LinkedField, Program, ScalarField, Selection, Transformed,
@@ -18,7 +19,8 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 /// A transform that adds an `id` field on any type that has an id field but
-/// where there is no unaliased `id` selection.
+/// where there is no unaliased `id` selection. The name of the id field is
+/// the name of the `ID` field of the `Node` interface.
 pub fn generate_id_field(program: &Program) -> Program {
     let mut transform = GenerateIDFieldTransform::new(program);
     transform
@@ -113,9 +115,9 @@ impl<'s> Transformer for GenerateIDFieldTransform<'s> {
 
 impl<'s> GenerateIDFieldTransform<'s> {
     fn new(program: &'s Program) -> Self {
-        let id_name = "id".intern();
-
         let schema = &program.schema;
+        let id_name = get_node_id_field_name(schema);
+
         let node_interface = match schema.get_type("Node".intern()) {
             Some(Type::Interface(node_interface_id)) => {
                 let node_interface = schema.interface(node_interface_id);
@@ -123,7 +125,9 @@ impl<'s> GenerateIDFieldTransform<'s> {
                     .fields
                     .iter()
                     .find(|&&id| schema.field(id).name == id_name)
-                    .expect("Expected `Node` to contain a field named `id`.");
+                    .unwrap_or_else(|| {
+                        panic!("Expected `Node` to contain a field named `{}`.", id_name)
+                    });
 
                 Some(NodeInterface {
                     id: node_interface_id,
@@ -5,6 +5,7 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use crate::node_identifier::NodeIdentifier;
 use crate::util::{
     generate_abstract_type_refinement_key, is_relay_custom_inline_fragment_directive,
     PointerAddress,
@@ -27,8 +28,18 @@ lazy_static! {
 
 /// Transform to add the `__typename` field to any LinkedField that both a) returns an
 /// abstract type and b) does not already directly query `__typename`.
-pub fn generate_typename(program: &Program, is_for_codegen: bool) -> Program {
-    let mut transform = GenerateTypenameTransform::new(program, is_for_codegen);
+///
+/// With `skip_selected_typename`, `__typename` is also not added to fields that
+/// select it in an inline fragment that `flatten` merges into the field, i.e.
+/// one without directives on the type of the field. `flatten` has to run after
+/// this transform then, otherwise the field doesn't query `__typename` directly.
+pub fn generate_typename(
+    program: &Program,
+    is_for_codegen: bool,
+    skip_selected_typename: bool,
+) -> Program {
+    let mut transform =
+        GenerateTypenameTransform::new(program, is_for_codegen, skip_selected_typename);
     transform
         .transform_program(program)
         .replace_or_else(|| program.clone())
@@ -43,18 +54,44 @@ struct GenerateTypenameTransform<'s> {
     program: &'s Program,
     seen: Seen,
     is_for_codegen: bool,
+    skip_selected_typename: bool,
     parent_type: Option<Type>,
 }
 
 impl<'s> GenerateTypenameTransform<'s> {
-    fn new(program: &'s Program, is_for_codegen: bool) -> Self {
+    fn new(program: &'s Program, is_for_codegen: bool, skip_selected_typename: bool) -> Self {
         Self {
             program,
             seen: Default::default(),
             is_for_codegen,
+            skip_selected_typename,
             parent_type: None,
         }
     }
+
+    fn selects_typename(&self, type_: Type, selections: &[Selection]) -> bool {
+        let schema = &self.program.schema;
+        if !self.skip_selected_typename {
+            return has_typename_field(schema, selections);
+        }
+        let typename = NodeIdentifier::from_selection(
+            schema,
+            &Selection::ScalarField(Arc::new(ScalarField {
+                alias: None,
+                definition: WithLocation::generated(schema.typename_field()),
+                arguments: Default::default(),
+                directives: Default::default(),
+            })),
+        );
+        selections.iter().any(|selection| match selection {
+            Selection::InlineFragment(fragment) => {
+                fragment.directives.is_empty()
+                    && fragment.type_condition.unwrap_or(type_) == type_
+                    && self.selects_typename(type_, &fragment.selections)
+            }
+            _ => NodeIdentifier::from_selection(schema, selection) == typename,
+        })
+    }
 }
 
 impl<'s> Transformer for GenerateTypenameTransform<'s> {
@@ -109,8 +146,10 @@ impl<'s> Transformer for GenerateTypenameTransform<'s> {
         self.parent_type = Some(field_definition.type_.inner());
         let selections = self.transform_selections(&field.selections);
         self.parent_type = parent_type;
-        let is_abstract = schema.is_abstract_type(field_definition.type_.inner());
-        let selections = if is_abstract && !has_typename_field(schema, &field.selections) {
+        let type_ = field_definition.type_.inner();
+        let selections = if schema.is_abstract_type(type_)
+            && !self.selects_typename(type_, &field.selections)
+        {
             let mut next_selections = Vec::with_capacity(field.selections.len() + 1);
             next_selections.push(Selection::ScalarField(Arc::new(ScalarField {
                 alias: None,
@@ -163,7 +202,9 @@ impl<'s> Transformer for GenerateTypenameTransform<'s> {
             .directives
             .iter()
             .any(is_relay_custom_inline_fragment_directive)
-            && !schema.is_extension_type(type_)
+            // Client abstract types are narrowed in normalization too, as part of their
+            // `ClientExtension` selections, but are never sent to the server.
+            && (self.is_for_codegen || !schema.is_extension_type(type_))
             && schema.is_abstract_type(type_)
         {
             let mut next_selections = Vec::with_capacity(fragment.selections.len() + 1);
@@ -5,29 +5,78 @@
  * LICENSE file in the root directory of this source tree.
  */
 
-use super::extract_values_from_handle_field_directive;
+use super::{extract_values_from_handle_field_directive, HANDLE_FIELD_DIRECTIVE_NAME};
 use crate::extract_handle_field_directives;
 use common::{Location, WithLocation};
 use graphql_ir::{
-    Argument, Directive, LinkedField, Program, ScalarField, Selection, Transformed, Transformer,
+    Argument, Directive, LinkedField, Program, ScalarField, Selection, Transformed,
+    TransformedMulti, TransformedValue, Transformer,
 };
-use interner::Intern;
+use interner::{Intern, StringKey};
+use schema::Schema;
 use std::sync::Arc;
 
 /// This transform applies field argument updates for client handle fields:
 /// - If filters is not set, existing arguments are removed.
 /// - If filters is set, only the arguments in the filter array are kept.
 /// - If dynamicKey is set, a new __dynamicKey argument is added.
-pub fn handle_field_transform(program: &Program) -> Program {
-    let mut transform = HandleFieldTransform;
+///
+/// With `keep_original_field`, the field is also kept with its arguments but
+/// without the handle directive, right before the handle field. The handle
+/// field keeps the response key, so the original field is aliased to
+/// `__<response key>_original`.
+pub fn handle_field_transform(program: &Program, keep_original_field: bool) -> Program {
+    let mut transform = HandleFieldTransform {
+        schema: &program.schema,
+        keep_original_field,
+    };
     transform
         .transform_program(program)
         .replace_or_else(|| program.clone())
 }
 
-struct HandleFieldTransform;
+struct HandleFieldTransform<'s> {
+    schema: &'s Schema,
+    keep_original_field: bool,
+}
+
+impl<'s> HandleFieldTransform<'s> {
+    fn transform_selection_multi(&mut self, selection: &Selection) -> TransformedMulti<Selection> {
+        let handle_selection = match self.transform_selection(selection) {
+            Transformed::Replace(handle_selection) => handle_selection,
+            transformed => return transformed.into(),
+        };
+        let original_selection = match (selection, &handle_selection) {
+            (Selection::ScalarField(field), Selection::ScalarField(_))
+                if has_handle_field_directive(&field.directives) =>
+            {
+                Selection::ScalarField(Arc::new(ScalarField {
+                    alias: Some(WithLocation::new(
+                        field.alias_or_name_location(),
+                        original_field_alias(field.alias_or_name(self.schema)),
+                    )),
+                    directives: remove_handle_field_directives(&field.directives),
+                    ..(**field).clone()
+                }))
+            }
+            (Selection::LinkedField(field), Selection::LinkedField(handle_field))
+                if has_handle_field_directive(&field.directives) =>
+            {
+                Selection::LinkedField(Arc::new(LinkedField {
+                    alias: Some(WithLocation::new(
+                        field.alias_or_name_location(),
+                        original_field_alias(field.alias_or_name(self.schema)),
+                    )),
+                    directives: remove_handle_field_directives(&field.directives),
+                    selections: handle_field.selections.clone(),
+                    ..(**field).clone()
+                }))
+            }
+            _ => return TransformedMulti::Replace(handle_selection),
+        };
+        TransformedMulti::ReplaceMultiple(vec![original_selection, handle_selection])
+    }
 
-impl<'s> HandleFieldTransform {
     fn update_arguments(
         &self,
         arguments: &mut Vec<Argument>,
@@ -49,11 +98,22 @@ impl<'s> HandleFieldTransform {
     }
 }
 
-impl<'s> Transformer for HandleFieldTransform {
+impl<'s> Transformer for HandleFieldTransform<'s> {
     const NAME: &'static str = "HandleFieldTransform";
     const VISIT_ARGUMENTS: bool = false;
     const VISIT_DIRECTIVES: bool = false;
 
+    fn transform_selections(
+        &mut self,
+        selections: &[Selection],
+    ) -> TransformedValue<Vec<Selection>> {
+        if self.keep_original_field {
+            self.transform_list_multi(selections, Self::transform_selection_multi)
+        } else {
+            self.transform_list(selections, Self::transform_selection)
+        }
+    }
+
     fn transform_scalar_field(&mut self, field: &ScalarField) -> Transformed<Selection> {
         let transformed_field = self.default_transform_scalar_field(field);
         let mut handle_field_directives = extract_handle_field_directives(&field.directives);
@@ -116,3 +176,19 @@ impl<'s> Transformer for HandleFieldTransform {
         }
     }
 }
+
+fn original_field_alias(response_key: StringKey) -> StringKey {
+    format!("__{}_original", response_key).intern()
+}
+
+fn has_handle_field_directive(directives: &[Directive]) -> bool {
+    extract_handle_field_directives(directives).next().is_some()
+}
+
+fn remove_handle_field_directives(directives: &[Directive]) -> Vec<Directive> {
+    directives
+        .iter()
+        .filter(|directive| directive.name.item != *HANDLE_FIELD_DIRECTIVE_NAME)
+        .cloned()
+        .collect()
+}
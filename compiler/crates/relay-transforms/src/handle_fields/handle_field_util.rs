@@ -12,9 +12,11 @@ use lazy_static::lazy_static;
 
 lazy_static! {
     pub static ref CONNECTION_HANDLER_ARG_NAME: StringKey = "handler".intern();
+    pub static ref HANDLER_MODULE_ARG_NAME: StringKey = "handlerModule".intern();
     pub static ref HANDLE_FIELD_DIRECTIVE_NAME: StringKey = "__clientField".intern();
     pub static ref HANDLER_ARG_NAME: StringKey = "handle".intern();
     pub static ref FILTERS_ARG_NAME: StringKey = "filters".intern();
+    pub static ref EXCLUDED_FILTERS_ARG_NAME: StringKey = "excludedFilters".intern();
     pub static ref KEY_ARG_NAME: StringKey = "key".intern();
     pub static ref DYNAMIC_KEY_ARG_NAME: StringKey = "dynamicKey_UNSTABLE".intern();
     pub static ref HANLDE_ARGS_NAME: StringKey = "handleArgs".intern();
@@ -22,14 +24,19 @@ lazy_static! {
 
 pub struct HandleFieldDirectiveArgs<'s> {
     pub handler_arg: Option<(&'s Argument, &'s ConstantValue)>,
+    pub handler_module_arg: Option<(&'s Argument, &'s ConstantValue)>,
     pub key_arg: Option<(&'s Argument, &'s ConstantValue)>,
     pub filters_arg: Option<(&'s Argument, &'s ConstantValue)>,
+    pub excluded_filters_arg: Option<(&'s Argument, &'s ConstantValue)>,
     pub dynamic_key_arg: Option<(&'s Argument, &'s Value)>,
     pub handle_args_arg: Option<&'s Argument>,
 }
 
 pub struct HandleFieldDirectiveValues {
     pub handle: StringKey,
+    /// Path of the module that exports the handler, for runtimes that
+    /// resolve handlers by module instead of by the `handle` name.
+    pub handler_module: Option<StringKey>,
     pub key: StringKey,
     pub filters: Option<Vec<StringKey>>,
     pub dynamic_key: Option<Value>,
@@ -70,23 +77,32 @@ pub fn extract_values_from_handle_field_directive(
 /// to be later used in codegen.
 /// This function will panic if the expected arguments aren't present on the input directive,
 /// with the assumption that the input directive has already been validated.
+/// The given `handle_args` are passed to the handler, in addition to the ones on the input directive.
 pub fn build_handle_field_directive_from_connection_directive(
     handle_field_directive: &Directive,
     default_handler: Option<StringKey>,
     default_filters: Option<Vec<StringKey>>,
+    handle_args: Vec<Argument>,
 ) -> Directive {
-    let values = extract_values_from_handle_field_directive_helper(
+    let mut values = extract_values_from_handle_field_directive_helper(
         handle_field_directive,
         *CONNECTION_HANDLER_ARG_NAME,
         default_handler,
         default_filters,
     );
+    if !handle_args.is_empty() {
+        values
+            .handle_args
+            .get_or_insert_with(Vec::new)
+            .extend(handle_args);
+    }
     build_handle_field_directive(values)
 }
 
 pub fn build_handle_field_directive(values: HandleFieldDirectiveValues) -> Directive {
     let HandleFieldDirectiveValues {
         handle,
+        handler_module,
         key,
         filters,
         dynamic_key,
@@ -121,6 +137,13 @@ pub fn build_handle_field_directive(values: HandleFieldDirectiveValues) -> Direc
         },
     ];
 
+    if let Some(handler_module) = handler_module {
+        directive_arguments.push(Argument {
+            name: WithLocation::generated(*HANDLER_MODULE_ARG_NAME),
+            value: WithLocation::generated(Value::Constant(ConstantValue::String(handler_module))),
+        });
+    }
+
     if let Some(handle_args) = handle_args {
         directive_arguments.push(Argument {
             name: WithLocation::generated(*HANLDE_ARGS_NAME),
@@ -149,8 +172,10 @@ fn extract_handle_field_directive_args_helper(
     handler_arg_name: StringKey,
 ) -> HandleFieldDirectiveArgs<'_> {
     let mut handler_arg = None;
+    let mut handler_module_arg = None;
     let mut key_arg = None;
     let mut filters_arg = None;
+    let mut excluded_filters_arg = None;
     let mut dynamic_key_arg = None;
     let mut handle_args_arg: Option<&Argument> = None;
 
@@ -159,6 +184,10 @@ fn extract_handle_field_directive_args_helper(
             if let Value::Constant(constant_val) = &arg.value.item {
                 handler_arg = Some((arg, constant_val));
             }
+        } else if arg.name.item == *HANDLER_MODULE_ARG_NAME {
+            if let Value::Constant(constant_val) = &arg.value.item {
+                handler_module_arg = Some((arg, constant_val));
+            }
         } else if arg.name.item == *KEY_ARG_NAME {
             if let Value::Constant(constant_val) = &arg.value.item {
                 key_arg = Some((arg, constant_val));
@@ -167,6 +196,10 @@ fn extract_handle_field_directive_args_helper(
             if let Value::Constant(constant_val) = &arg.value.item {
                 filters_arg = Some((arg, constant_val));
             }
+        } else if arg.name.item == *EXCLUDED_FILTERS_ARG_NAME {
+            if let Value::Constant(constant_val) = &arg.value.item {
+                excluded_filters_arg = Some((arg, constant_val));
+            }
         } else if arg.name.item == *DYNAMIC_KEY_ARG_NAME {
             if let Value::Variable(_) = arg.value.item {
                 dynamic_key_arg = Some((arg, &arg.value.item));
@@ -178,8 +211,10 @@ fn extract_handle_field_directive_args_helper(
 
     HandleFieldDirectiveArgs {
         handler_arg,
+        handler_module_arg,
         key_arg,
         filters_arg,
+        excluded_filters_arg,
         dynamic_key_arg,
         handle_args_arg,
     }
@@ -193,7 +228,9 @@ fn extract_values_from_handle_field_directive_helper(
 ) -> HandleFieldDirectiveValues {
     let HandleFieldDirectiveArgs {
         handler_arg,
+        handler_module_arg,
         filters_arg,
+        excluded_filters_arg,
         key_arg,
         dynamic_key_arg,
         handle_args_arg,
@@ -215,6 +252,13 @@ fn extract_values_from_handle_field_directive_helper(
          },
          None => default_handler.expect("Expected handler_arg to have been previously validated or a default to have been provided."),
      };
+    let handler_module = match handler_module_arg {
+        Some((_, value)) => match value {
+            ConstantValue::String(string_val) => Some(*string_val),
+            _ => unreachable!("Expected handler_module_arg to have been previously validated."),
+        },
+        None => None,
+    };
     let filters = match filters_arg {
         Some((_, value)) => match value {
             ConstantValue::List(list_val) => Some(
@@ -231,7 +275,23 @@ fn extract_values_from_handle_field_directive_helper(
             ConstantValue::Null() => None,
             _ => unreachable!("Expected filters_arg to have been previously validated.",),
         },
-        None => default_filters,
+        // Arguments listed in `excludedFilters` are removed from the default filters.
+        None => match excluded_filters_arg {
+            Some((_, ConstantValue::List(excluded_filters))) => default_filters
+                .map(|filters| {
+                    filters
+                        .into_iter()
+                        .filter(|filter| {
+                            !excluded_filters.contains(&ConstantValue::String(*filter))
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .filter(|filters| !filters.is_empty()),
+            Some(_) => {
+                unreachable!("Expected excluded_filters_arg to have been previously validated.")
+            }
+            None => default_filters,
+        },
     };
     let dynamic_key = match dynamic_key_arg {
         Some((_, value)) => match value {
@@ -250,6 +310,7 @@ fn extract_values_from_handle_field_directive_helper(
 
     HandleFieldDirectiveValues {
         handle,
+        handler_module,
         key,
         filters,
         dynamic_key,
@@ -13,6 +13,6 @@ pub use handle_field_util::{
     build_handle_field_directive, build_handle_field_directive_from_connection_directive,
     extract_handle_field_directive_args_for_connection, extract_handle_field_directives,
     extract_values_from_handle_field_directive, HandleFieldDirectiveValues,
-    CONNECTION_HANDLER_ARG_NAME, DYNAMIC_KEY_ARG_NAME, FILTERS_ARG_NAME, HANDLER_ARG_NAME,
-    HANDLE_FIELD_DIRECTIVE_NAME, KEY_ARG_NAME,
+    CONNECTION_HANDLER_ARG_NAME, DYNAMIC_KEY_ARG_NAME, EXCLUDED_FILTERS_ARG_NAME, FILTERS_ARG_NAME,
+    HANDLER_ARG_NAME, HANDLER_MODULE_ARG_NAME, HANDLE_FIELD_DIRECTIVE_NAME, KEY_ARG_NAME,
 };
@@ -11,7 +11,9 @@
 #![deny(clippy::clone_on_ref_ptr)]
 #![allow(clippy::comparison_chain)]
 
+mod aliased_fragment_spreads;
 mod applied_fragment_name;
+mod apply_fragment_argument_defaults;
 mod apply_fragment_arguments;
 mod client_extensions;
 mod connections;
@@ -20,7 +22,9 @@ mod dedupe_type_discriminator;
 mod defer_stream;
 mod feature_flags;
 mod flatten;
+mod generate_catch_metadata;
 mod generate_data_driven_dependency_metadata;
+mod generate_field_metadata;
 mod generate_id_field;
 mod generate_live_query_metadata;
 mod generate_subscription_name_metadata;
@@ -32,8 +36,10 @@ mod inline_fragments;
 mod mask;
 mod match_;
 mod node_identifier;
+mod normalize_conditions;
 mod react_flight;
 mod refetchable_fragment;
+mod relay_actor_change;
 mod relay_directive;
 mod relay_early_flush;
 mod remove_base_fragments;
@@ -59,12 +65,14 @@ lazy_static! {
     pub static ref INTERNAL_METADATA_DIRECTIVE: StringKey = "__metadata".intern();
 }
 
+pub use aliased_fragment_spreads::wrap_aliased_fragment_spreads;
 pub use applied_fragment_name::get_applied_fragment_name;
+pub use apply_fragment_argument_defaults::apply_fragment_argument_defaults;
 pub use apply_fragment_arguments::apply_fragment_arguments;
 pub use client_extensions::{client_extensions, CLIENT_EXTENSION_DIRECTIVE_NAME};
 pub use connections::{
     extract_connection_metadata_from_directive, ConnectionConstants, ConnectionInterface,
-    ConnectionMetadata,
+    ConnectionInterfaceError, ConnectionMetadata,
 };
 pub use declarative_connection::transform_declarative_connection;
 pub use dedupe_type_discriminator::dedupe_type_discriminator;
@@ -72,10 +80,14 @@ pub use defer_stream::{
     transform_defer_stream, DeferDirective, StreamDirective, DEFER_STREAM_CONSTANTS,
 };
 pub use feature_flags::FeatureFlags;
-pub use flatten::flatten;
+pub use flatten::{flatten, flatten_preserving_inline_fragments};
+pub use generate_catch_metadata::{
+    generate_catch_metadata, CATCH_DIRECTIVE_NAME, CATCH_METADATA_KEY,
+};
 pub use generate_data_driven_dependency_metadata::{
     generate_data_driven_dependency_metadata, DATA_DRIVEN_DEPENDENCY_METADATA_KEY,
 };
+pub use generate_field_metadata::generate_field_metadata;
 pub use generate_id_field::generate_id_field;
 pub use generate_live_query_metadata::generate_live_query_metadata;
 pub use generate_subscription_name_metadata::generate_subscription_name_metadata;
@@ -93,6 +105,7 @@ pub use match_::{
     MATCH_CONSTANTS,
 };
 pub use node_identifier::NodeIdentifier;
+pub use normalize_conditions::normalize_conditions;
 pub use react_flight::{
     react_flight, REACT_FLIGHT_LOCAL_COMPONENTS_METADATA_ARG_KEY,
     REACT_FLIGHT_LOCAL_COMPONENTS_METADATA_KEY, REACT_FLIGHT_SCALAR_FLIGHT_FIELD_METADATA_KEY,
@@ -101,22 +114,31 @@ pub use refetchable_fragment::{
     extract_refetch_metadata_from_directive, transform_refetchable_fragment,
     RefetchableDerivedFromMetadata, CONSTANTS as REFETCHABLE_CONSTANTS,
 };
+pub use relay_actor_change::{
+    relay_actor_change_transform, RELAY_ACTOR_CHANGE_DIRECTIVE_FOR_CODEGEN,
+};
 pub use relay_directive::RelayDirective;
 pub use relay_early_flush::relay_early_flush;
-pub use remove_base_fragments::remove_base_fragments;
+pub use remove_base_fragments::{remove_base_fragments, remove_base_fragments_with_report};
 pub use required_directive::{
     required_directive, RequiredAction, ACTION_ARGUMENT, CHILDREN_CAN_BUBBLE_METADATA_KEY,
     PATH_METADATA_ARGUMENT, REQUIRED_METADATA_KEY,
 };
-pub use skip_client_directives::skip_client_directives;
+pub use skip_client_directives::{skip_client_directives, skip_client_directives_for_server_text};
 pub use skip_client_extensions::skip_client_extensions;
-pub use skip_redundant_nodes::skip_redundant_nodes;
+pub use skip_redundant_nodes::{skip_redundant_nodes, skip_redundant_nodes_in_source_order};
 pub use skip_split_operation::skip_split_operation;
-pub use skip_unreachable_node::skip_unreachable_node;
+pub use skip_unreachable_node::{skip_unreachable_node, skip_unreachable_node_keeping_fragments};
 pub use skip_unused_variables::skip_unused_variables;
 pub use sort_selections::sort_selections;
-pub use test_operation_metadata::generate_test_operation_metadata;
+pub use test_operation_metadata::{
+    generate_test_operation_metadata, RelayTestOperationMetadata,
+    RelayTestOperationSelectionTypeInfo, TEST_OPERATION_DIRECTIVE,
+};
 pub use transform_connections::transform_connections;
 pub use unwrap_custom_directive_selection::unwrap_custom_directive_selection;
-pub use util::{extract_variable_name, generate_abstract_type_refinement_key, remove_directive};
+pub use util::{
+    extract_variable_name, generate_abstract_type_refinement_key, get_node_id_field_name,
+    remove_directive,
+};
 pub use validations::*;
@@ -50,7 +50,7 @@ impl Hash for Path {
 
 struct TypeMatch {
     fragment: WithLocation<StringKey>,
-    module: StringKey,
+    module: WithLocation<StringKey>,
 }
 struct Matches {
     key: StringKey,
@@ -314,18 +314,35 @@ impl<'program> MatchTransform<'program> {
 
             let previous_match_for_type = matches.types.get(&fragment.type_condition);
             if let Some(previous_match_for_type) = previous_match_for_type {
+                let type_name = self.program.schema.get_type_name(fragment.type_condition);
+                let alias_path = self
+                    .path
+                    .iter()
+                    .map(|with_loc| with_loc.item.lookup())
+                    .collect::<Vec<&str>>()
+                    .join(".");
+                // The same component for different fragments of a type would
+                // silently override the branch of the type in the metadata.
+                if previous_match_for_type.module.item == module_name
+                    && previous_match_for_type.fragment.item != spread.fragment.item
+                {
+                    return Err(Diagnostic::error(
+                        ValidationMessage::DuplicateModuleComponent {
+                            component: module_name,
+                            type_name,
+                            alias_path,
+                        },
+                        module_directive.name.location,
+                    )
+                    .annotate("related location", previous_match_for_type.module.location));
+                }
                 if previous_match_for_type.fragment.item != spread.fragment.item
-                    || previous_match_for_type.module != module_name
+                    || previous_match_for_type.module.item != module_name
                 {
                     return Err(Diagnostic::error(
                         ValidationMessage::InvalidModuleSelectionMultipleMatches {
-                            type_name: self.program.schema.get_type_name(fragment.type_condition),
-                            alias_path: self
-                                .path
-                                .iter()
-                                .map(|with_loc| with_loc.item.lookup())
-                                .collect::<Vec<&str>>()
-                                .join("."),
+                            type_name,
+                            alias_path,
                         },
                         spread.fragment.location,
                     )
@@ -339,7 +356,7 @@ impl<'program> MatchTransform<'program> {
                 fragment.type_condition,
                 TypeMatch {
                     fragment: spread.fragment,
-                    module: module_name,
+                    module: WithLocation::new(module_directive.name.location, module_name),
                 },
             );
 
@@ -0,0 +1,124 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use graphql_ir::{Condition, ConditionValue, Program, Selection, Transformed, Transformer};
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// Rewrites `@skip`/`@include` conditions into a canonical form, so that
+/// equivalent selections produce identical artifacts:
+///
+/// - Constant conditions are represented as `@include`, i.e.
+///   `@skip(if: false)` becomes `@include(if: true)`.
+/// - Nested conditions (directly or through inline fragments without type
+///   condition) are merged and nested like the conditions of a single
+///   selection, i.e. the constants innermost and then outwards by variable
+///   name, `@skip` inside of `@include`. Duplicates are removed, so
+///   `... @include(if: $b) { ... @skip(if: $a) { x } }` and
+///   `... @skip(if: $a) @include(if: $b) { x }` result in the same IR.
+pub fn normalize_conditions(program: &Program) -> Program {
+    let mut transform = NormalizeConditionsTransform;
+    transform
+        .transform_program(program)
+        .replace_or_else(|| program.clone())
+}
+
+struct NormalizeConditionsTransform;
+
+impl Transformer for NormalizeConditionsTransform {
+    const NAME: &'static str = "NormalizeConditionsTransform";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn transform_condition(&mut self, condition: &Condition) -> Transformed<Selection> {
+        let mut conditions = vec![(condition.value.clone(), condition.passing_value)];
+        let mut innermost = condition;
+        let mut has_inline_fragments = false;
+        while let Some((nested_condition, is_in_inline_fragment)) = get_nested_condition(innermost)
+        {
+            has_inline_fragments |= is_in_inline_fragment;
+            conditions.push((
+                nested_condition.value.clone(),
+                nested_condition.passing_value,
+            ));
+            innermost = nested_condition;
+        }
+        let selections = self.transform_selections(&innermost.selections);
+        // Like when building the IR, the first condition in order is the innermost.
+        conditions.reverse();
+
+        let mut normalized_conditions = conditions
+            .iter()
+            .map(|(value, passing_value)| normalize_condition(value, *passing_value))
+            .collect::<Vec<_>>();
+        normalized_conditions.sort_by(compare_conditions);
+        normalized_conditions.dedup_by(|a, b| compare_conditions(a, b) == Ordering::Equal);
+        if selections.should_keep() && !has_inline_fragments && normalized_conditions == conditions
+        {
+            return Transformed::Keep;
+        }
+
+        let mut selections = selections.replace_or_else(|| innermost.selections.clone());
+        for (value, passing_value) in normalized_conditions {
+            selections = vec![Selection::Condition(Arc::new(Condition {
+                selections,
+                value,
+                passing_value,
+            }))];
+        }
+        Transformed::Replace(selections.pop().unwrap())
+    }
+}
+
+/// Returns the only selection of a condition if it is a condition itself,
+/// looking through inline fragments without type condition and directives.
+/// Also returns whether such inline fragments were skipped.
+fn get_nested_condition(condition: &Condition) -> Option<(&Condition, bool)> {
+    let mut selections = &condition.selections;
+    let mut is_in_inline_fragment = false;
+    loop {
+        match selections.as_slice() {
+            [Selection::Condition(nested_condition)] => {
+                return Some((nested_condition, is_in_inline_fragment));
+            }
+            [Selection::InlineFragment(fragment)]
+                if fragment.type_condition.is_none() && fragment.directives.is_empty() =>
+            {
+                selections = &fragment.selections;
+                is_in_inline_fragment = true;
+            }
+            _ => return None,
+        }
+    }
+}
+
+/// Represents a constant condition with a passing value of `true`.
+fn normalize_condition(value: &ConditionValue, passing_value: bool) -> (ConditionValue, bool) {
+    match value {
+        ConditionValue::Constant(value) => {
+            (ConditionValue::Constant(*value == passing_value), true)
+        }
+        ConditionValue::Variable(_) => (value.clone(), passing_value),
+    }
+}
+
+fn compare_conditions(
+    (value_a, passing_a): &(ConditionValue, bool),
+    (value_b, passing_b): &(ConditionValue, bool),
+) -> Ordering {
+    match (value_a, value_b) {
+        (ConditionValue::Constant(a), ConditionValue::Constant(b)) => a.cmp(b),
+        (ConditionValue::Constant(_), ConditionValue::Variable(_)) => Ordering::Less,
+        (ConditionValue::Variable(_), ConditionValue::Constant(_)) => Ordering::Greater,
+        (ConditionValue::Variable(a), ConditionValue::Variable(b)) => a
+            .name
+            .item
+            .lookup()
+            .cmp(b.name.item.lookup())
+            .then(passing_a.cmp(passing_b)),
+    }
+}
@@ -28,7 +28,8 @@ fn build_refetch_operation(
     variables_map: &VariableMap,
 ) -> DiagnosticsResult<Option<RefetchRoot>> {
     if let Some(identifier_field_name) = get_fetchable_field_name(fragment, schema)? {
-        let identifier_field_id = get_identifier_field_id(fragment, schema, identifier_field_name)?;
+        let (identifier_field_id, identifier_type) =
+            get_identifier_field(fragment, schema, identifier_field_name)?;
 
         let query_type = schema.query_type().unwrap();
         let fetch_field_name = format!(
@@ -36,8 +37,13 @@ fn build_refetch_operation(
             schema.get_type_name(fragment.type_condition).lookup()
         )
         .intern();
-        let (fetch_field_id, id_arg) =
-            get_fetch_field_id_and_id_arg(fragment, schema, query_type, fetch_field_name)?;
+        let (fetch_field_id, id_arg) = get_fetch_field_id_and_id_arg(
+            fragment,
+            schema,
+            query_type,
+            fetch_field_name,
+            identifier_type,
+        )?;
 
         let fragment = Arc::new(FragmentDefinition {
             name: fragment.name,
@@ -134,16 +140,18 @@ fn get_fetchable_field_name<'schema>(
     Ok(None)
 }
 
-fn get_identifier_field_id(
+/// The identifying field can be any scalar, e.g. an `ID` or a `String` token,
+/// the fetch field is expected to accept a value of the same type.
+fn get_identifier_field(
     fragment: &FragmentDefinition,
     schema: &Schema,
     identifier_field_name: StringKey,
-) -> DiagnosticsResult<FieldID> {
+) -> DiagnosticsResult<(FieldID, Type)> {
     let identifier_field_id = schema.named_field(fragment.type_condition, identifier_field_name);
     if let Some(identifier_field_id) = identifier_field_id {
         let identifier_field = schema.field(identifier_field_id);
-        if schema.is_id(identifier_field.type_.inner()) {
-            return Ok(identifier_field_id);
+        if !identifier_field.type_.is_list() && identifier_field.type_.inner().is_scalar() {
+            return Ok((identifier_field_id, identifier_field.type_.inner()));
         }
     }
     Err(vec![Diagnostic::error(
@@ -161,6 +169,7 @@ fn get_fetch_field_id_and_id_arg<'s>(
     schema: &'s Schema,
     query_type: Type,
     fetch_field_name: StringKey,
+    identifier_type: Type,
 ) -> DiagnosticsResult<(FieldID, &'s ArgumentDef)> {
     let fetch_field_id = schema.named_field(query_type, fetch_field_name);
     if let Some(fetch_field_id) = fetch_field_id {
@@ -169,7 +178,7 @@ fn get_fetch_field_id_and_id_arg<'s>(
             if inner_type == fragment.type_condition {
                 let mut arg_iter = fetch_field.arguments.iter();
                 if let Some(id_arg) = arg_iter.next() {
-                    if !id_arg.type_.is_list() && schema.is_id(id_arg.type_.inner()) {
+                    if !id_arg.type_.is_list() && id_arg.type_.inner() == identifier_type {
                         return Ok((fetch_field_id, id_arg));
                     }
                 }
@@ -181,6 +190,7 @@ fn get_fetch_field_id_and_id_arg<'s>(
             fetch_field_name,
             fragment_name: fragment.name.item,
             type_name: schema.get_type_name(fragment.type_condition),
+            identifier_type_name: schema.get_type_name(identifier_type),
         },
         fragment.name.location,
     )])
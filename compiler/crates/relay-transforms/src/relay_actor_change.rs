@@ -0,0 +1,101 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use crate::feature_flags::FeatureFlags;
+use common::{Diagnostic, DiagnosticsResult, NamedItem, WithLocation};
+use graphql_ir::{
+    Directive, LinkedField, Program, Selection, Transformed, Transformer, ValidationMessage,
+};
+use interner::{Intern, StringKey};
+use lazy_static::lazy_static;
+use std::sync::Arc;
+
+lazy_static! {
+    /// Internal directive name for Relay Codegen
+    pub static ref RELAY_ACTOR_CHANGE_DIRECTIVE_FOR_CODEGEN: StringKey =
+        "__RelayActorChange".intern();
+}
+
+/// Marks the linked fields with the configured actor change directive for
+/// codegen, which emits `ActorChange` nodes for them: the runtime reads the
+/// fragment spread within the field from the store of another actor. The
+/// field must select exactly one fragment spread.
+pub fn relay_actor_change_transform(
+    program: &Program,
+    feature_flags: &FeatureFlags,
+) -> DiagnosticsResult<Program> {
+    let directive_name = match feature_flags.actor_change_directive {
+        Some(directive_name) => directive_name,
+        None => return Ok(program.clone()),
+    };
+    let mut transform = ActorChangeTransform::new(directive_name);
+    let next_program = transform
+        .transform_program(program)
+        .replace_or_else(|| program.clone());
+
+    if transform.errors.is_empty() {
+        Ok(next_program)
+    } else {
+        Err(transform.errors)
+    }
+}
+
+struct ActorChangeTransform {
+    directive_name: StringKey,
+    errors: Vec<Diagnostic>,
+}
+
+impl ActorChangeTransform {
+    fn new(directive_name: StringKey) -> Self {
+        Self {
+            directive_name,
+            errors: vec![],
+        }
+    }
+}
+
+impl Transformer for ActorChangeTransform {
+    const NAME: &'static str = "ActorChangeTransform";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn transform_linked_field(&mut self, field: &LinkedField) -> Transformed<Selection> {
+        let actor_change_directive = match field.directives.named(self.directive_name) {
+            Some(directive) => directive,
+            None => return self.default_transform_linked_field(field),
+        };
+        if !matches!(field.selections.as_slice(), [Selection::FragmentSpread(_)]) {
+            self.errors.push(Diagnostic::error(
+                ValidationMessage::ActorChangeExpectsSingleFragmentSpread {
+                    directive_name: self.directive_name,
+                },
+                actor_change_directive.name.location,
+            ));
+            return Transformed::Keep;
+        }
+        Transformed::Replace(Selection::LinkedField(Arc::new(LinkedField {
+            directives: field
+                .directives
+                .iter()
+                .map(|directive| {
+                    if directive.name.item == self.directive_name {
+                        Directive {
+                            name: WithLocation::new(
+                                directive.name.location,
+                                *RELAY_ACTOR_CHANGE_DIRECTIVE_FOR_CODEGEN,
+                            ),
+                            arguments: vec![],
+                        }
+                    } else {
+                        directive.clone()
+                    }
+                })
+                .collect(),
+            ..field.clone()
+        })))
+    }
+}
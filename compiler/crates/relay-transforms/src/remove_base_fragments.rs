@@ -20,20 +20,33 @@ pub fn remove_base_fragments(
     program: &Program,
     base_fragment_names: Arc<FnvHashSet<StringKey>>,
 ) -> Program {
+    remove_base_fragments_with_report(program, base_fragment_names).0
+}
+
+/// Same as `remove_base_fragments`, but also returns the names of the removed
+/// fragments in the order of the program, e.g. to debug why a fragment has no
+/// output file.
+pub fn remove_base_fragments_with_report(
+    program: &Program,
+    base_fragment_names: Arc<FnvHashSet<StringKey>>,
+) -> (Program, Vec<StringKey>) {
     if base_fragment_names.is_empty() {
         // Nothing to remove.
-        return program.clone();
+        return (program.clone(), Vec::new());
     }
     let mut transform = StripBaseFragmentsTransform {
         base_fragment_names: &base_fragment_names,
+        removed_fragment_names: Vec::new(),
     };
-    transform
+    let next_program = transform
         .transform_program(program)
-        .replace_or_else(|| program.clone())
+        .replace_or_else(|| program.clone());
+    (next_program, transform.removed_fragment_names)
 }
 
 struct StripBaseFragmentsTransform<'a> {
     base_fragment_names: &'a FnvHashSet<StringKey>,
+    removed_fragment_names: Vec<StringKey>,
 }
 
 impl<'a> Transformer for StripBaseFragmentsTransform<'a> {
@@ -53,6 +66,7 @@ impl<'a> Transformer for StripBaseFragmentsTransform<'a> {
         fragment: &FragmentDefinition,
     ) -> Transformed<FragmentDefinition> {
         if self.base_fragment_names.contains(&fragment.name.item) {
+            self.removed_fragment_names.push(fragment.name.item);
             Transformed::Delete
         } else {
             Transformed::Keep
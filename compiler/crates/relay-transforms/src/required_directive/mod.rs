@@ -9,11 +9,12 @@ use common::{Diagnostic, DiagnosticsResult, Location, NamedItem, WithLocation};
 mod requireable_field;
 
 use super::FeatureFlags;
+use crate::get_fragment_spread_alias;
 use fnv::FnvHashMap;
 use graphql_ir::{
-    Argument, ConstantValue, Directive, FragmentDefinition, InlineFragment, LinkedField,
-    OperationDefinition, Program, ScalarField, Selection, Transformed, TransformedValue,
-    Transformer, ValidationMessage, Value,
+    Argument, ConstantValue, Directive, FragmentDefinition, FragmentSpread, InlineFragment,
+    LinkedField, OperationDefinition, Program, ScalarField, Selection, Transformed,
+    TransformedValue, Transformer, ValidationMessage, Value,
 };
 use interner::Intern;
 use interner::StringKey;
@@ -431,6 +432,42 @@ impl<'s> Transformer for RequiredDirective<'s> {
         })))
     }
 
+    fn transform_fragment_spread(&mut self, spread: &FragmentSpread) -> Transformed<Selection> {
+        // Only an aliased spread has a key in the parent whose presence can
+        // be asserted, like a field.
+        let alias = match get_fragment_spread_alias(spread) {
+            Some(alias) => alias,
+            None => {
+                if let Some(directive) = spread.directives.named(*REQUIRED_DIRECTIVE_NAME) {
+                    self.errors.push(Diagnostic::error(
+                        ValidationMessage::RequiredFragmentSpreadWithoutAlias {
+                            fragment_name: spread.fragment.item,
+                        },
+                        directive.name.location,
+                    ));
+                }
+                return Transformed::Keep;
+            }
+        };
+        self.path.push(alias.lookup());
+        let path_name = self.path.join(".").intern();
+        self.path.pop();
+
+        match self.get_required_metadata(spread, path_name) {
+            None => Transformed::Keep,
+            Some(required_metadata) => {
+                Transformed::Replace(Selection::FragmentSpread(Arc::new(FragmentSpread {
+                    directives: add_metadata_directive(
+                        &spread.directives,
+                        path_name,
+                        required_metadata.action,
+                    ),
+                    ..spread.clone()
+                })))
+            }
+        }
+    }
+
     fn transform_inline_fragment(&mut self, fragment: &InlineFragment) -> Transformed<Selection> {
         let previous = self.within_abstract_inline_fragment;
 
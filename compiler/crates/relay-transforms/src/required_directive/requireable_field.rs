@@ -6,8 +6,11 @@
  */
 
 use super::{ACTION_ARGUMENT, REQUIRED_DIRECTIVE_NAME};
+use crate::get_fragment_spread_alias;
 use common::{Diagnostic, Location, NamedItem, WithLocation};
-use graphql_ir::{ConstantValue, Directive, LinkedField, ScalarField, ValidationMessage, Value};
+use graphql_ir::{
+    ConstantValue, Directive, FragmentSpread, LinkedField, ScalarField, ValidationMessage, Value,
+};
 use interner::StringKey;
 use schema::Schema;
 
@@ -53,6 +56,18 @@ impl RequireableField for LinkedField {
     }
 }
 
+impl RequireableField for FragmentSpread {
+    fn directives(&self) -> &Vec<Directive> {
+        &self.directives
+    }
+    fn name_with_location(&self, _schema: &Schema) -> WithLocation<StringKey> {
+        WithLocation::new(
+            self.fragment.location,
+            get_fragment_spread_alias(self).unwrap_or(self.fragment.item),
+        )
+    }
+}
+
 fn get_action_argument(
     required_directive: &Directive,
 ) -> Result<WithLocation<StringKey>, Diagnostic> {
@@ -6,9 +6,12 @@
  */
 
 use graphql_ir::{Directive, Program, Transformed, Transformer};
+use interner::StringKey;
 
-pub fn skip_client_directives(program: &Program) -> Program {
-    let mut transform = SkipClientDirectives::new(program);
+/// Removes the directives of client schema extensions and the
+/// `excluded_directives`, which the server doesn't understand.
+pub fn skip_client_directives(program: &Program, excluded_directives: &[StringKey]) -> Program {
+    let mut transform = SkipClientDirectives::new(program, excluded_directives);
     transform
         .transform_program(program)
         .replace_or_else(|| program.clone())
@@ -16,11 +19,15 @@ pub fn skip_client_directives(program: &Program) -> Program {
 
 pub struct SkipClientDirectives<'s> {
     program: &'s Program,
+    excluded_directives: &'s [StringKey],
 }
 
 impl<'s> SkipClientDirectives<'s> {
-    fn new(program: &'s Program) -> Self {
-        Self { program }
+    fn new(program: &'s Program, excluded_directives: &'s [StringKey]) -> Self {
+        Self {
+            program,
+            excluded_directives,
+        }
     }
 }
 
@@ -34,6 +41,7 @@ impl<'s> Transformer for SkipClientDirectives<'s> {
             .program
             .schema
             .is_extension_directive(directive.name.item)
+            || self.excluded_directives.contains(&directive.name.item)
         {
             Transformed::Delete
         } else {
@@ -41,3 +49,29 @@ impl<'s> Transformer for SkipClientDirectives<'s> {
         }
     }
 }
+
+/// Removes the internal metadata directives, whose names start with `__`
+/// (e.g. `@__dataDrivenDependencyMetadata` or `@__module`), so that they
+/// don't leak into the query text sent to the server.
+pub fn skip_client_directives_for_server_text(program: &Program) -> Program {
+    let mut transform = SkipInternalDirectives;
+    transform
+        .transform_program(program)
+        .replace_or_else(|| program.clone())
+}
+
+struct SkipInternalDirectives;
+
+impl Transformer for SkipInternalDirectives {
+    const NAME: &'static str = "SkipInternalDirectives";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = true;
+
+    fn transform_directive(&mut self, directive: &Directive) -> Transformed<Directive> {
+        if directive.name.item.lookup().starts_with("__") {
+            Transformed::Delete
+        } else {
+            Transformed::Keep
+        }
+    }
+}
@@ -6,15 +6,17 @@
  */
 
 use crate::node_identifier::NodeIdentifier;
+use crate::required_directive::{REQUIRED_DIRECTIVE_NAME, REQUIRED_METADATA_KEY};
 use crate::util::{is_relay_custom_inline_fragment_directive, PointerAddress};
 use crate::DEFER_STREAM_CONSTANTS;
 
 use common::NamedItem;
 use dashmap::DashMap;
 use graphql_ir::{
-    Condition, FragmentDefinition, InlineFragment, LinkedField, OperationDefinition, Program,
-    Selection, Transformed, TransformedValue,
+    Condition, Directive, FragmentDefinition, InlineFragment, LinkedField, OperationDefinition,
+    Program, Selection, Transformed, TransformedValue,
 };
+use interner::StringKey;
 use rayon::prelude::*;
 use schema::Schema;
 use std::iter::Iterator;
@@ -110,9 +112,31 @@ use std::sync::Arc;
  *
  *
  * 1 can be skipped because it is already fetched at the outer level.
+ *
+ * Fields and fragment spreads with a preserved directive are never considered
+ * redundant: the directive may have an effect on the selection in the child
+ * scope (e.g. `@required`), so both selections are kept. Inline fragments with
+ * one get their own scope. The directives of Relay's custom inline fragments,
+ * e.g. `@module`, are preserved by default, the `preserved_directives` are
+ * preserved in addition. Directives
+ * that an earlier transform replaced with metadata are matched by their
+ * source name, e.g. `required` also preserves selections with `__required`.
  */
-pub fn skip_redundant_nodes(program: &Program) -> Program {
-    let transform = SkipRedundantNodesTransform::new(program);
+pub fn skip_redundant_nodes(program: &Program, preserved_directives: &[StringKey]) -> Program {
+    let transform = SkipRedundantNodesTransform::new(program, false, preserved_directives);
+    transform
+        .transform_program(program)
+        .replace_or_else(|| program.clone())
+}
+
+/// Like `skip_redundant_nodes`, but keeps the remaining selections in the
+/// order of the source instead of moving fields before conditionals. Fields
+/// are still visited first, so the same selections are skipped.
+pub fn skip_redundant_nodes_in_source_order(
+    program: &Program,
+    preserved_directives: &[StringKey],
+) -> Program {
+    let transform = SkipRedundantNodesTransform::new(program, true, preserved_directives);
     transform
         .transform_program(program)
         .replace_or_else(|| program.clone())
@@ -126,16 +150,37 @@ type Cache = DashMap<PointerAddress, (Transformed<Selection>, SelectionMap)>;
 struct SkipRedundantNodesTransform {
     schema: Arc<Schema>,
     cache: Cache,
+    preserve_source_order: bool,
+    preserved_directives: Vec<StringKey>,
 }
 
-impl<'s> SkipRedundantNodesTransform {
-    fn new(program: &'_ Program) -> Self {
+impl SkipRedundantNodesTransform {
+    fn new(
+        program: &'_ Program,
+        preserve_source_order: bool,
+        preserved_directives: &[StringKey],
+    ) -> Self {
+        let mut all_preserved_directives = preserved_directives.to_vec();
+        if preserved_directives.contains(&REQUIRED_DIRECTIVE_NAME) {
+            all_preserved_directives.push(*REQUIRED_METADATA_KEY);
+        }
         Self {
             schema: Arc::clone(&program.schema),
             cache: DashMap::new(),
+            preserve_source_order,
+            preserved_directives: all_preserved_directives,
         }
     }
 
+    /// The directives of Relay's custom inline fragments, e.g. `@module`, are
+    /// always preserved, the `preserved_directives` are preserved in addition.
+    fn has_preserved_directive(&self, directives: &[Directive]) -> bool {
+        directives.iter().any(|directive| {
+            is_relay_custom_inline_fragment_directive(directive)
+                || self.preserved_directives.contains(&directive.name.item)
+        })
+    }
+
     fn transform_selection(
         &self,
         selection: &Selection,
@@ -148,11 +193,13 @@ impl<'s> SkipRedundantNodesTransform {
         let identifier = NodeIdentifier::from_selection(&self.schema, selection);
         match selection {
             Selection::ScalarField(_) | Selection::FragmentSpread(_) => {
-                if selection_map.0.contains_key(&identifier) {
-                    Transformed::Delete
-                } else {
+                if !selection_map.0.contains_key(&identifier) {
                     selection_map.0.insert(identifier, None);
                     Transformed::Keep
+                } else if self.has_preserved_directive(selection.directives()) {
+                    Transformed::Keep
+                } else {
+                    Transformed::Delete
                 }
             }
             Selection::LinkedField(selection) => {
@@ -221,11 +268,7 @@ impl<'s> SkipRedundantNodesTransform {
                 {
                     self.transform_inline_fragment(selection, existing_selection_map)
                         .map(Selection::InlineFragment)
-                } else if selection
-                    .directives
-                    .iter()
-                    .any(is_relay_custom_inline_fragment_directive)
-                {
+                } else if self.has_preserved_directive(&selection.directives) {
                     let mut linked_selection_map = Default::default();
                     let result = self
                         .transform_inline_fragment(selection, &mut linked_selection_map)
@@ -329,6 +372,9 @@ impl<'s> SkipRedundantNodesTransform {
         if selections.is_empty() {
             return TransformedValue::Keep;
         }
+        if self.preserve_source_order {
+            return self.transform_selections_in_source_order(selections, selection_map);
+        }
         let mut result: Vec<Selection> = Vec::new();
         let mut has_changes = false;
         let selections = get_partitioned_selections(selections);
@@ -369,6 +415,36 @@ impl<'s> SkipRedundantNodesTransform {
         }
     }
 
+    /// Visits the selections in the partitioned order, but returns the
+    /// remaining selections in their original order.
+    fn transform_selections_in_source_order(
+        &self,
+        selections: &[Selection],
+        selection_map: &mut SelectionMap,
+    ) -> TransformedValue<Vec<Selection>> {
+        let mut next_selections: Vec<Option<Selection>> = vec![None; selections.len()];
+        let mut has_changes = false;
+        for index in get_partitioned_indices(selections) {
+            let selection = &selections[index];
+            next_selections[index] = match self.transform_selection(selection, selection_map) {
+                Transformed::Keep => Some(selection.clone()),
+                Transformed::Delete => {
+                    has_changes = true;
+                    None
+                }
+                Transformed::Replace(next_selection) => {
+                    has_changes = true;
+                    Some(next_selection)
+                }
+            };
+        }
+        if has_changes {
+            TransformedValue::Replace(next_selections.into_iter().flatten().collect())
+        } else {
+            TransformedValue::Keep
+        }
+    }
+
     fn transform_operation(
         &self,
         operation: &OperationDefinition,
@@ -451,6 +527,14 @@ fn get_partitioned_selections(selections: &[Selection]) -> Vec<&Selection> {
     result
 }
 
+/// The indices of the selections in the order of `get_partitioned_selections`.
+fn get_partitioned_indices(selections: &[Selection]) -> Vec<usize> {
+    let (mut fields, conditionals): (Vec<_>, Vec<_>) =
+        (0..selections.len()).partition(|&index| is_selection_linked_or_scalar(&selections[index]));
+    fields.extend(conditionals);
+    fields
+}
+
 fn is_selection_linked_or_scalar(selection: &Selection) -> bool {
     match selection {
         Selection::LinkedField(field) => field
@@ -25,7 +25,23 @@ pub fn skip_unreachable_node(program: &Program) -> Program {
         })
         .collect();
 
-    let mut skip_unreachable_node_transform = SkipUnreachableNodeTransform::new(fragments);
+    let mut skip_unreachable_node_transform = SkipUnreachableNodeTransform::new(fragments, false);
+    skip_unreachable_node_transform
+        .transform_program(program)
+        .replace_or_else(|| program.clone())
+}
+
+/// Like `skip_unreachable_node`, but keeps all fragments, including the ones
+/// that aren't reachable from an operation or have no selections left, for
+/// programs where each fragment is an artifact of its own, like the reader
+/// program. Spreads of fragments are only removed with their condition.
+pub fn skip_unreachable_node_keeping_fragments(program: &Program) -> Program {
+    let fragments = program
+        .fragments()
+        .map(|fragment| (fragment.name.item, (Arc::clone(fragment), None)))
+        .collect();
+
+    let mut skip_unreachable_node_transform = SkipUnreachableNodeTransform::new(fragments, true);
     skip_unreachable_node_transform
         .transform_program(program)
         .replace_or_else(|| program.clone())
@@ -41,6 +57,7 @@ type VisitedFragments = FnvHashMap<
 
 pub struct SkipUnreachableNodeTransform {
     visited_fragments: VisitedFragments,
+    keep_fragments: bool,
 }
 
 impl Transformer for SkipUnreachableNodeTransform {
@@ -60,7 +77,7 @@ impl Transformer for SkipUnreachableNodeTransform {
         // @include(if: false)  => remove
         // @skip(if: true)      => remove
         // @include(if: true)   => replace with contents
-        // @include(if: false)  => replace with contents
+        // @skip(if: false)     => replace with contents
         //
         // Removal of a condition or spread can result in a FragmentDefinition being deleted.
 
@@ -78,6 +95,13 @@ impl Transformer for SkipUnreachableNodeTransform {
             }
         }
 
+        if self.keep_fragments {
+            let fragment_names = self.visited_fragments.keys().copied().collect::<Vec<_>>();
+            for fragment_name in fragment_names {
+                self.should_delete_fragment_definition(fragment_name);
+            }
+        }
+
         for fragment in self.visited_fragments.values() {
             match fragment {
                 (_, None) | (_, Some(Transformed::Delete)) => {
@@ -107,7 +131,7 @@ impl Transformer for SkipUnreachableNodeTransform {
         // Remove the fragment with empty selections
         let selections = self.transform_selections(&fragment.selections);
         if let TransformedValue::Replace(selections) = &selections {
-            if selections.is_empty() {
+            if selections.is_empty() && !self.keep_fragments {
                 return Transformed::Delete;
             }
         }
@@ -139,8 +163,11 @@ impl Transformer for SkipUnreachableNodeTransform {
 }
 
 impl SkipUnreachableNodeTransform {
-    pub fn new(visited_fragments: VisitedFragments) -> Self {
-        Self { visited_fragments }
+    pub fn new(visited_fragments: VisitedFragments, keep_fragments: bool) -> Self {
+        Self {
+            visited_fragments,
+            keep_fragments,
+        }
     }
 
     fn should_delete_fragment_definition(&mut self, key: StringKey) -> bool {
@@ -157,6 +184,8 @@ impl SkipUnreachableNodeTransform {
             Arc::clone(fragment)
         };
 
+        // Guard against cycles, these are reported by other transforms.
+        self.visited_fragments.get_mut(&key).unwrap().1 = Some(Transformed::Keep);
         let transformed = self.transform_fragment(&fragment);
         let should_delete = matches!(transformed, Transformed::Delete);
 
@@ -6,15 +6,18 @@
  */
 
 use crate::util::PointerAddress;
+use crate::DEFER_STREAM_CONSTANTS;
 use graphql_ir::{
-    FragmentDefinition, OperationDefinition, Program, Selection, Transformed, TransformedValue,
-    Transformer,
+    Directive, FragmentDefinition, OperationDefinition, Program, Selection, Transformed,
+    TransformedValue, Transformer,
 };
+use std::cmp::Ordering;
 use std::collections::HashMap;
 type Seen = HashMap<PointerAddress, Transformed<Selection>>;
 
 ///
-/// Sorts selections in the fragments and queries (and their selections)
+/// Sorts selections in the fragments and queries (and their selections), and
+/// the directives on each field, inline fragment and fragment spread.
 ///
 pub fn sort_selections(program: &Program) -> Program {
     let mut transform = SortSelectionsTransform::new(program);
@@ -124,8 +127,41 @@ impl<'s> Transformer for SortSelectionsTransform<'s> {
                 self.seen.insert(key, transformed.clone());
                 transformed
             }
-            Selection::ScalarField(_) => Transformed::Keep,
-            Selection::FragmentSpread(_) => Transformed::Keep,
+            Selection::ScalarField(selection) => self.transform_scalar_field(selection),
+            Selection::FragmentSpread(selection) => self.transform_fragment_spread(selection),
         }
     }
+
+    fn transform_directives(
+        &mut self,
+        directives: &[Directive],
+    ) -> TransformedValue<Vec<Directive>> {
+        if directives
+            .windows(2)
+            .all(|pair| compare_directives(&pair[0], &pair[1]) != Ordering::Greater)
+        {
+            return TransformedValue::Keep;
+        }
+        let mut next_directives = directives.to_vec();
+        // Stable sort: keeps multiple @defer or @stream directives in order
+        next_directives.sort_by(compare_directives);
+        TransformedValue::Replace(next_directives)
+    }
+}
+
+/// Orders directives by name, then by the names of their arguments.
+/// Directives named @defer or @stream are ordered by name only.
+fn compare_directives(a: &Directive, b: &Directive) -> Ordering {
+    a.name.item.cmp(&b.name.item).then_with(|| {
+        if a.name.item == DEFER_STREAM_CONSTANTS.defer_name
+            || a.name.item == DEFER_STREAM_CONSTANTS.stream_name
+        {
+            Ordering::Equal
+        } else {
+            a.arguments
+                .iter()
+                .map(|argument| argument.name.item)
+                .cmp(b.arguments.iter().map(|argument| argument.name.item))
+        }
+    })
 }
@@ -17,7 +17,7 @@ use lazy_static::lazy_static;
 use schema::{EnumValue, Field, Schema, Type};
 
 lazy_static! {
-    static ref TEST_OPERATION_DIRECTIVE: StringKey = "relay_test_operation".intern();
+    pub static ref TEST_OPERATION_DIRECTIVE: StringKey = "relay_test_operation".intern();
     static ref TEST_OPERATION_METADATA_KEY: StringKey = "relayTestingSelectionTypeInfo".intern();
     static ref ENUM_VALUES_KEY: StringKey = "enumValues".intern();
     static ref NULLABLE_KEY: StringKey = "nullable".intern();
@@ -7,70 +7,292 @@
 
 use crate::connections::{
     assert_connection_selections, build_connection_metadata,
-    build_connection_metadata_as_directive, build_edge_selections, build_page_info_selections,
-    extract_connection_directive, get_default_filters, ConnectionConstants, ConnectionInterface,
+    build_connection_metadata_as_directive, build_edge_selection_metadata, build_edge_selections,
+    build_page_info_selections, extract_connection_directive, find_edges_selection,
+    get_default_filters, has_page_info_sub_fields, ConnectionConstants, ConnectionInterface,
     ConnectionMetadata,
 };
 use crate::defer_stream::DEFER_STREAM_CONSTANTS;
+use crate::feature_flags::FeatureFlags;
 use crate::handle_fields::{build_handle_field_directive_from_connection_directive, KEY_ARG_NAME};
-use common::{NamedItem, WithLocation};
+use common::{Diagnostic, DiagnosticsResult, Location, NamedItem, WithLocation};
 use graphql_ir::{
-    Argument, ConstantValue, Directive, FragmentDefinition, InlineFragment, LinkedField,
-    OperationDefinition, Program, Selection, Transformed, Transformer, Value,
+    Argument, Condition, ConstantValue, Directive, FragmentDefinition, InlineFragment, LinkedField,
+    OperationDefinition, Program, Selection, Transformed, Transformer, ValidationMessage, Value,
 };
 use interner::{Intern, StringKey};
+use schema::{FieldID, Type};
 use std::sync::Arc;
 
 pub fn transform_connections(
     program: &Program,
     connection_interface: &ConnectionInterface,
-) -> Program {
-    let mut transform = ConnectionTransform::new(program, connection_interface);
-    transform
+    feature_flags: &FeatureFlags,
+) -> DiagnosticsResult<Program> {
+    let mut transform = ConnectionTransform::new(program, connection_interface, feature_flags);
+    let next_program = transform
         .transform_program(program)
-        .replace_or_else(|| program.clone())
+        .replace_or_else(|| program.clone());
+    transform.validate_connection_keys()?;
+    Ok(next_program)
+}
+
+/// The key of a connection, recorded to validate that keys are unique.
+struct ConnectionKey {
+    key: StringKey,
+    directive_name: StringKey,
+    document_name: StringKey,
+    /// The path to the parent field in the document and the type of the
+    /// selections containing the connection field.
+    parent: (Vec<StringKey>, Option<Type>),
+    field_name: StringKey,
+    location: Location,
+    is_generated: bool,
 }
 
 struct ConnectionTransform<'s> {
     connection_interface: &'s ConnectionInterface,
     connection_constants: ConnectionConstants,
     current_path: Option<Vec<StringKey>>,
+    /// The aliases or names of the fields to the current selection, including
+    /// plural fields, used to generate connection keys.
+    current_field_path: Vec<StringKey>,
+    current_parent_type: Option<Type>,
     current_connection_metadata: Vec<ConnectionMetadata>,
     current_document_name: StringKey,
+    enable_connection_key_generation: bool,
+    connection_keys: Vec<ConnectionKey>,
     program: &'s Program,
 }
 
 impl<'s> ConnectionTransform<'s> {
-    fn new(program: &'s Program, connection_interface: &'s ConnectionInterface) -> Self {
+    fn new(
+        program: &'s Program,
+        connection_interface: &'s ConnectionInterface,
+        feature_flags: &FeatureFlags,
+    ) -> Self {
         Self {
             connection_constants: ConnectionConstants::default(),
             connection_interface,
             current_path: None,
+            current_field_path: Vec::new(),
+            current_parent_type: None,
             current_document_name: connection_interface.cursor, // Set an arbitrary value to avoid Option
             current_connection_metadata: Vec::new(),
+            enable_connection_key_generation: feature_flags.enable_connection_key_generation,
+            connection_keys: Vec::new(),
             program,
         }
     }
 
+    /// Returns the connection directive with a `key` generated from the
+    /// document name and the path to the field if it doesn't pass one, and
+    /// records the key of the connection.
+    fn with_connection_key(
+        &mut self,
+        connection_field: &LinkedField,
+        connection_directive: &Directive,
+    ) -> Option<Directive> {
+        if let Some(key_arg) = connection_directive.arguments.named(*KEY_ARG_NAME) {
+            if let Value::Constant(ConstantValue::String(key)) = key_arg.value.item {
+                self.connection_keys.push(ConnectionKey {
+                    key,
+                    directive_name: connection_directive.name.item,
+                    document_name: self.current_document_name,
+                    parent: self.current_parent(),
+                    field_name: connection_field.alias_or_name(&self.program.schema),
+                    location: key_arg.value.location,
+                    is_generated: false,
+                });
+            }
+            return None;
+        }
+        if !self.enable_connection_key_generation {
+            return None;
+        }
+        let key = std::iter::once(self.current_document_name)
+            .chain(self.current_field_path.iter().copied())
+            .map(|part| part.lookup())
+            .collect::<Vec<_>>()
+            .join("_")
+            .intern();
+        self.connection_keys.push(ConnectionKey {
+            key,
+            directive_name: connection_directive.name.item,
+            document_name: self.current_document_name,
+            parent: self.current_parent(),
+            field_name: connection_field.alias_or_name(&self.program.schema),
+            location: connection_field.alias_or_name_location(),
+            is_generated: true,
+        });
+        let mut arguments = connection_directive.arguments.clone();
+        arguments.push(Argument {
+            name: WithLocation::generated(*KEY_ARG_NAME),
+            value: WithLocation::generated(Value::Constant(ConstantValue::String(key))),
+        });
+        Some(Directive {
+            name: connection_directive.name,
+            arguments,
+        })
+    }
+
+    /// The parent of the current connection field: the path to the field
+    /// containing it, without the connection field itself, and its type.
+    fn current_parent(&self) -> (Vec<StringKey>, Option<Type>) {
+        let parent_path_len = self.current_field_path.len().saturating_sub(1);
+        (
+            self.current_field_path[..parent_path_len].to_vec(),
+            self.current_parent_type,
+        )
+    }
+
+    /// Generated keys are derived from names that may collide, e.g. a field
+    /// aliased `user_friends` and the `friends` of a `user` field. Report
+    /// generated keys that are also used by another connection, and keys
+    /// passed to more than one connection on the same parent field and type
+    /// of a document, whose handle fields would be stored on the same record.
+    fn validate_connection_keys(&self) -> DiagnosticsResult<()> {
+        let mut errors = Vec::new();
+        for (index, connection_key) in self.connection_keys.iter().enumerate() {
+            if connection_key.is_generated {
+                if let Some(other) = self.connection_keys.iter().find(|other| {
+                    other.key == connection_key.key && other.location != connection_key.location
+                }) {
+                    errors.push(
+                        Diagnostic::error(
+                            ValidationMessage::DuplicateGeneratedConnectionKey {
+                                connection_directive_name: connection_key.directive_name,
+                                key_arg_name: *KEY_ARG_NAME,
+                                key_arg_value: connection_key.key,
+                            },
+                            connection_key.location,
+                        )
+                        .annotate("other connection with the same key", other.location),
+                    );
+                }
+            } else if let Some(original) = self.connection_keys[..index].iter().find(|other| {
+                !other.is_generated
+                    && other.key == connection_key.key
+                    && other.document_name == connection_key.document_name
+                    && other.parent == connection_key.parent
+            }) {
+                errors.push(
+                    Diagnostic::error(
+                        ValidationMessage::DuplicateConnectionKey {
+                            key: connection_key.key,
+                            connection_field_name: connection_key.field_name,
+                        },
+                        connection_key.location,
+                    )
+                    .annotate("key first used here", original.location),
+                );
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            errors.sort_by_key(|error| error.location());
+            Err(errors)
+        }
+    }
+
     fn transform_connection_selections(
         &mut self,
         connection_field: &LinkedField,
         connection_metadata: &ConnectionMetadata,
         connection_directive: &Directive,
     ) -> Vec<Selection> {
-        let is_stream_connection = connection_directive.name.item
-            == self.connection_constants.stream_connection_directive_name;
-        let schema = &self.program.schema;
         let transformed_selections = self
             .transform_selections(&connection_field.selections)
             .replace_or_else(|| connection_field.selections.clone());
+        let connection_field_type = self
+            .program
+            .schema
+            .field(connection_field.definition.item)
+            .type_
+            .inner();
+        self.transform_edges_and_page_info_selections(
+            &transformed_selections,
+            connection_field_type,
+            connection_metadata,
+            connection_directive,
+        )
+    }
 
+    /// Transforms the `edges` and `page_info` selections of a connection on
+    /// the given connection type. If the `edges` aren't selected directly,
+    /// transforms the inline fragments and conditions containing them.
+    fn transform_edges_and_page_info_selections(
+        &self,
+        transformed_selections: &[Selection],
+        connection_field_type: Type,
+        connection_metadata: &ConnectionMetadata,
+        connection_directive: &Directive,
+    ) -> Vec<Selection> {
+        let schema = &self.program.schema;
+        let has_edges_selection = transformed_selections.iter().any(|selection| {
+            matches!(selection, Selection::LinkedField(field)
+                if schema.field(field.definition.item).name == self.connection_interface.edges)
+        });
+        if !has_edges_selection {
+            return transformed_selections
+                .iter()
+                .map(|selection| match selection {
+                    Selection::InlineFragment(fragment) => {
+                        let type_ = fragment.type_condition.unwrap_or(connection_field_type);
+                        if find_edges_selection(
+                            schema,
+                            &fragment.selections,
+                            type_,
+                            self.connection_interface,
+                        )
+                        .is_none()
+                        {
+                            return selection.clone();
+                        }
+                        Selection::InlineFragment(Arc::new(InlineFragment {
+                            selections: self.transform_edges_and_page_info_selections(
+                                &fragment.selections,
+                                type_,
+                                connection_metadata,
+                                connection_directive,
+                            ),
+                            ..fragment.as_ref().clone()
+                        }))
+                    }
+                    Selection::Condition(condition) => {
+                        if find_edges_selection(
+                            schema,
+                            &condition.selections,
+                            connection_field_type,
+                            self.connection_interface,
+                        )
+                        .is_none()
+                        {
+                            return selection.clone();
+                        }
+                        Selection::Condition(Arc::new(Condition {
+                            selections: self.transform_edges_and_page_info_selections(
+                                &condition.selections,
+                                connection_field_type,
+                                connection_metadata,
+                                connection_directive,
+                            ),
+                            ..condition.as_ref().clone()
+                        }))
+                    }
+                    _ => selection.clone(),
+                })
+                .collect();
+        }
+
+        let is_stream_connection = connection_directive.name.item
+            == self.connection_constants.stream_connection_directive_name;
         let ((edges_ix, edges_field), page_info_selection) = assert_connection_selections(
             schema,
-            &transformed_selections,
+            transformed_selections,
             self.connection_interface,
         );
-        let connection_field_type = schema.field(connection_field.definition.item).type_.inner();
 
         // Construct edges selection
         let edges_schema_field_id = schema
@@ -136,18 +358,81 @@ impl<'s> ConnectionTransform<'s> {
             });
         }
 
-        // Construct page_info selection
-        let page_info_schema_field_id = schema
+        // Construct page_info selection, display-only connections may not
+        // expose a page_info field with the sub fields to paginate.
+        let transformed_page_info = schema
             .named_field(connection_field_type, self.connection_interface.page_info)
-            .expect("Expected presence of page_info field to have been previously validated.");
+            .filter(|page_info_schema_field_id| {
+                has_page_info_sub_fields(
+                    schema,
+                    schema.field(*page_info_schema_field_id).type_.inner(),
+                    connection_metadata,
+                    self.connection_constants,
+                    self.connection_interface,
+                )
+            })
+            .map(|page_info_schema_field_id| {
+                self.build_page_info_field_selection(
+                    page_info_schema_field_id,
+                    page_info_selection.map(|(_, page_info_field)| page_info_field),
+                    connection_metadata,
+                    connection_directive,
+                    is_stream_connection,
+                )
+            });
+        let page_info_ix = page_info_selection.map(|(ix, _)| ix);
+
+        // Copy the original selections, replacing edges/pageInfo (if present)
+        // with the generated locations. This is to maintain the original field
+        // ordering.
+        let mut next_selections = transformed_selections
+            .iter()
+            .enumerate()
+            .map(|(ix, selection)| {
+                if ix == edges_ix {
+                    if !is_aliased_edges {
+                        return Selection::LinkedField(From::from(transformed_edges_field.clone()));
+                    }
+                } else if Some(ix) == page_info_ix {
+                    if let Some((page_info_field_selection, false)) = &transformed_page_info {
+                        return page_info_field_selection.clone();
+                    }
+                }
+                selection.clone()
+            })
+            .collect::<Vec<_>>();
+
+        // If a page_info selection didn't exist, or the selections are aliased,
+        // append the generated version instead.
+        if is_aliased_edges {
+            next_selections.push(Selection::LinkedField(From::from(transformed_edges_field)));
+        }
+        if let Some((page_info_field_selection, is_aliased_page_info)) = transformed_page_info {
+            if page_info_selection.is_none() || is_aliased_page_info {
+                next_selections.push(page_info_field_selection);
+            }
+        }
+        next_selections
+    }
+
+    /// Builds the page_info selection of a connection, reusing the selected
+    /// page_info field if it isn't aliased. Returns the selection and
+    /// whether the selected page_info field is aliased.
+    fn build_page_info_field_selection(
+        &self,
+        page_info_schema_field_id: FieldID,
+        page_info_selection: Option<&LinkedField>,
+        connection_metadata: &ConnectionMetadata,
+        connection_directive: &Directive,
+        is_stream_connection: bool,
+    ) -> (Selection, bool) {
+        let schema = &self.program.schema;
         let page_info_schema_field = schema.field(page_info_schema_field_id);
         let page_info_field_name = page_info_schema_field.name;
         let page_info_type = page_info_schema_field.type_.inner();
-        let mut page_info_ix = None;
         let mut is_aliased_page_info = false;
         let mut transformed_page_info_field = match page_info_selection {
-            Some((ix, page_info_field)) => {
-                page_info_ix = Some(ix);
+            Some(page_info_field) => {
                 if let Some(alias) = page_info_field.alias {
                     // The page_info selection has to be generated as non-aliased field (since product
                     // code may be accessing the non-aliased response keys).
@@ -184,7 +469,7 @@ impl<'s> ConnectionTransform<'s> {
             .push(build_page_info_selections(
                 schema,
                 page_info_type,
-                &connection_metadata,
+                connection_metadata,
                 self.connection_constants,
                 self.connection_interface,
             ));
@@ -232,47 +517,24 @@ impl<'s> ConnectionTransform<'s> {
         } else {
             Selection::LinkedField(From::from(transformed_page_info_field))
         };
-
-        // Copy the original selections, replacing edges/pageInfo (if present)
-        // with the generated locations. This is to maintain the original field
-        // ordering.
-        let mut next_selections = transformed_selections
-            .iter()
-            .enumerate()
-            .map(|(ix, selection)| {
-                if ix == edges_ix {
-                    if !is_aliased_edges {
-                        return Selection::LinkedField(From::from(transformed_edges_field.clone()));
-                    }
-                } else if let Some(page_info_ix) = page_info_ix {
-                    if ix == page_info_ix && !is_aliased_page_info {
-                        return transformed_page_info_field_selection.clone();
-                    }
-                }
-                selection.clone()
-            })
-            .collect::<Vec<_>>();
-
-        // If a page_info selection didn't exist, or the selections are aliased,
-        // append the generated version instead.
-        if is_aliased_edges {
-            next_selections.push(Selection::LinkedField(From::from(transformed_edges_field)));
-        }
-        if page_info_selection.is_none() || is_aliased_page_info {
-            next_selections.push(transformed_page_info_field_selection);
-        }
-        next_selections
+        (transformed_page_info_field_selection, is_aliased_page_info)
     }
 
     fn transform_connection_directives(
         &mut self,
         connection_field: &LinkedField,
         connection_directive: &Directive,
+        connection_selections: &[Selection],
     ) -> Vec<Directive> {
         let connection_handle_directive = build_handle_field_directive_from_connection_directive(
             connection_directive,
             Some(self.connection_constants.connection_directive_name),
             get_default_filters(connection_field, self.connection_constants),
+            self.build_connection_handle_args(
+                connection_field,
+                connection_directive,
+                connection_selections,
+            ),
         );
         let mut next_directives = connection_field
             .directives
@@ -289,6 +551,43 @@ impl<'s> ConnectionTransform<'s> {
         next_directives
     }
 
+    /// Builds the additional arguments for the connection handler. With
+    /// `@connection(generateNextEdges: true)`, it describes the transformed
+    /// edges selection as `__connection_next_edges`, see
+    /// `build_edge_selection_metadata`.
+    fn build_connection_handle_args(
+        &self,
+        connection_field: &LinkedField,
+        connection_directive: &Directive,
+        connection_selections: &[Selection],
+    ) -> Vec<Argument> {
+        let generate_next_edges = matches!(
+            connection_directive
+                .arguments
+                .named(self.connection_constants.generate_next_edges_arg_name)
+                .map(|arg| &arg.value.item),
+            Some(Value::Constant(ConstantValue::Boolean(true)))
+        );
+        if !generate_next_edges {
+            return Vec::new();
+        }
+        let schema = &self.program.schema;
+        let (edges_field, _) = find_edges_selection(
+            schema,
+            connection_selections,
+            schema.field(connection_field.definition.item).type_.inner(),
+            self.connection_interface,
+        )
+        .expect("Expected presence of edges field to have been previously validated.");
+        vec![Argument {
+            name: WithLocation::generated(self.connection_constants.next_edges_handle_arg_name),
+            value: WithLocation::generated(Value::Constant(build_edge_selection_metadata(
+                self.program,
+                edges_field,
+            ))),
+        }]
+    }
+
     fn transform_connection_field(
         &mut self,
         connection_field: &LinkedField,
@@ -298,16 +597,18 @@ impl<'s> ConnectionTransform<'s> {
             &connection_field,
             self.connection_constants,
             &self.current_path,
-            connection_directive.name.item
-                == self.connection_constants.stream_connection_directive_name,
+            connection_directive,
         );
         let next_connection_selections = self.transform_connection_selections(
             &connection_field,
             &connection_metadata,
             &connection_directive,
         );
-        let next_connection_directives =
-            self.transform_connection_directives(&connection_field, &connection_directive);
+        let next_connection_directives = self.transform_connection_directives(
+            connection_field,
+            connection_directive,
+            &next_connection_selections,
+        );
 
         // Include the connection metadata from this linked field to
         // attach to the current root document (fragment or operation)
@@ -333,6 +634,8 @@ impl<'s> Transformer for ConnectionTransform<'s> {
         // TODO(T63626938): This assumes that each document is processed serially (not in parallel or concurrently)
         self.current_document_name = operation.name.item;
         self.current_path = Some(Vec::new());
+        self.current_field_path = Vec::new();
+        self.current_parent_type = Some(operation.type_);
         self.current_connection_metadata = Vec::new();
 
         let transformed = self.default_transform_operation(operation);
@@ -365,6 +668,8 @@ impl<'s> Transformer for ConnectionTransform<'s> {
         // TODO(T63626938): This assumes that each document is processed serially (not in parallel or concurrently)
         self.current_document_name = fragment.name.item;
         self.current_path = Some(Vec::new());
+        self.current_field_path = Vec::new();
+        self.current_parent_type = Some(fragment.type_condition);
         self.current_connection_metadata = Vec::new();
 
         let transformed = self.default_transform_fragment(fragment);
@@ -398,25 +703,53 @@ impl<'s> Transformer for ConnectionTransform<'s> {
         // supports passing state
         let current_path_at_field = self.current_path.clone();
 
+        let alias_or_name = if let Some(alias) = field.alias {
+            alias.item
+        } else {
+            connection_schema_field.name
+        };
         // Keep track of the current path as long as we don't encounter plural field.
         if connection_schema_field.type_.is_list() {
             self.current_path = None
         } else if let Some(path) = self.current_path.as_mut() {
-            path.push(if let Some(alias) = field.alias {
-                alias.item
-            } else {
-                connection_schema_field.name
-            })
+            path.push(alias_or_name)
         }
-
-        let res = if let Some(connection_directive) =
-            extract_connection_directive(&field.directives, self.connection_constants)
-        {
-            self.transform_connection_field(field, connection_directive)
-        } else {
-            self.default_transform_linked_field(field)
+        self.current_field_path.push(alias_or_name);
+
+        let connection_directive =
+            extract_connection_directive(&field.directives, self.connection_constants).map(
+                |connection_directive| {
+                    (
+                        connection_directive,
+                        self.with_connection_key(field, connection_directive),
+                    )
+                },
+            );
+        let parent_type_at_field = self
+            .current_parent_type
+            .replace(connection_schema_field.type_.inner());
+        let res = match connection_directive {
+            Some((_, Some(connection_directive))) => {
+                self.transform_connection_field(field, &connection_directive)
+            }
+            Some((connection_directive, None)) => {
+                self.transform_connection_field(field, connection_directive)
+            }
+            None => self.default_transform_linked_field(field),
         };
+        self.current_parent_type = parent_type_at_field;
         self.current_path = current_path_at_field;
+        self.current_field_path.pop();
+        res
+    }
+
+    fn transform_inline_fragment(&mut self, fragment: &InlineFragment) -> Transformed<Selection> {
+        let parent_type_at_fragment = self.current_parent_type;
+        if let Some(type_condition) = fragment.type_condition {
+            self.current_parent_type = Some(type_condition);
+        }
+        let res = self.default_transform_inline_fragment(fragment);
+        self.current_parent_type = parent_type_at_fragment;
         res
     }
 }
@@ -14,6 +14,7 @@ use crate::react_flight::{
     REACT_FLIGHT_LOCAL_COMPONENTS_METADATA_KEY, REACT_FLIGHT_SCALAR_FLIGHT_FIELD_METADATA_KEY,
 };
 use crate::refetchable_fragment::CONSTANTS as REFETCHABLE_CONSTANTS;
+use crate::relay_actor_change::RELAY_ACTOR_CHANGE_DIRECTIVE_FOR_CODEGEN;
 use crate::required_directive::{
     CHILDREN_CAN_BUBBLE_METADATA_KEY, REQUIRED_DIRECTIVE_NAME, REQUIRED_METADATA_KEY,
 };
@@ -103,6 +104,7 @@ impl CustomMetadataDirectives {
             || name == *REQUIRED_DIRECTIVE_NAME
             || name == *REQUIRED_METADATA_KEY
             || name == *CHILDREN_CAN_BUBBLE_METADATA_KEY
+            || name == *RELAY_ACTOR_CHANGE_DIRECTIVE_FOR_CODEGEN
     }
 
     pub fn should_skip_in_node_identifier(&self, name: StringKey) -> bool {
@@ -145,3 +147,26 @@ pub fn is_relay_custom_inline_fragment_directive(directive: &Directive) -> bool
 pub fn generate_abstract_type_refinement_key(schema: &Schema, type_: Type) -> StringKey {
     format!("__is{}", schema.get_type_name(type_).lookup()).intern()
 }
+
+/// The name of the field that identifies objects: the `ID` field of the
+/// `Node` interface, preferring a field named `id`. Defaults to `id` if the
+/// schema has no `Node` interface or it has no `ID` field.
+pub fn get_node_id_field_name(schema: &Schema) -> StringKey {
+    let id_name = "id".intern();
+    if let Some(Type::Interface(node_interface_id)) = schema.get_type("Node".intern()) {
+        let id_fields = schema
+            .interface(node_interface_id)
+            .fields
+            .iter()
+            .map(|&field_id| schema.field(field_id))
+            .filter(|field| schema.is_id(field.type_.inner()))
+            .map(|field| field.name)
+            .collect::<Vec<_>>();
+        if !id_fields.contains(&id_name) {
+            if let Some(&field_name) = id_fields.first() {
+                return field_name;
+            }
+        }
+    }
+    id_name
+}
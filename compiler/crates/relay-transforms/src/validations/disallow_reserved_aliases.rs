@@ -5,12 +5,18 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use crate::util::get_node_id_field_name;
 use common::{Diagnostic, DiagnosticsResult, WithLocation};
 use errors::validate;
 use graphql_ir::{LinkedField, Program, ScalarField, ValidationMessage, Validator};
 use interner::{Intern, StringKey};
+use lazy_static::lazy_static;
 use schema::{FieldID, Schema};
 
+lazy_static! {
+    static ref TYPENAME: StringKey = "__typename".intern();
+}
+
 pub fn disallow_reserved_aliases(program: &Program) -> DiagnosticsResult<()> {
     let mut validator = DisallowReservedAliases::new(program);
     validator.validate_program(program)
@@ -25,7 +31,11 @@ impl<'program> DisallowReservedAliases<'program> {
     fn new(program: &'program Program) -> Self {
         Self {
             program,
-            reserved_aliases: vec!["id".intern(), "__typename".intern(), "__id".intern()],
+            reserved_aliases: vec![
+                get_node_id_field_name(&program.schema),
+                "__typename".intern(),
+                "__id".intern(),
+            ],
         }
     }
 }
@@ -42,7 +52,7 @@ impl Validator for DisallowReservedAliases<'_> {
                     &self.program.schema,
                     &self.reserved_aliases,
                     &alias,
-                    field.definition.item,
+                    &field.definition,
                 )
             } else {
                 Ok(())
@@ -57,7 +67,7 @@ impl Validator for DisallowReservedAliases<'_> {
                 &self.program.schema,
                 &self.reserved_aliases,
                 &alias,
-                field.definition.item,
+                &field.definition,
             )
         } else {
             Ok(())
@@ -69,11 +79,28 @@ fn validate_field_alias(
     schema: &Schema,
     reserved_aliases: &[StringKey],
     alias: &WithLocation<StringKey>,
-    field: FieldID,
+    field: &WithLocation<FieldID>,
 ) -> DiagnosticsResult<()> {
+    let field_name = schema.field(field.item).name;
+    if field_name == *TYPENAME && alias.item != field_name && reserved_aliases.contains(&alias.item)
+    {
+        return Err(vec![Diagnostic::error(
+            ValidationMessage::DisallowAliasingTypename { alias: alias.item },
+            alias.location,
+        )
+        .annotate("aliased field", field.location)]);
+    }
+    if alias.item == *TYPENAME && field_name != *TYPENAME {
+        return Err(vec![Diagnostic::error(
+            ValidationMessage::DisallowTypenameAlias { field_name },
+            alias.location,
+        )
+        .annotate("aliased field", field.location)]);
+    }
+
     let mut validation_errors = vec![];
     for reserved_alias in reserved_aliases {
-        let result = validate_field_alias_once(schema, *reserved_alias, alias, field);
+        let result = validate_field_alias_once(schema, *reserved_alias, alias, field.item);
         if let Err(errors) = result {
             for err in errors {
                 validation_errors.push(err);
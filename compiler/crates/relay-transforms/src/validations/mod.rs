@@ -7,20 +7,49 @@
 
 mod disallow_reserved_aliases;
 mod disallow_typename_on_root;
+mod validate_connection_keys;
+mod validate_connection_pagination_fields;
+mod validate_connection_parents;
 mod validate_connections;
+mod validate_dead_selections;
+mod validate_deprecated_fields;
+mod validate_fragment_directives;
 mod validate_global_variables;
+mod validate_inline_fragment_alias;
 mod validate_module_names;
+mod validate_output_only_scalars;
+mod validate_plural_connections;
 mod validate_relay_directives;
 mod validate_required_arguments;
 mod validate_server_only_directives;
+mod validate_spread_depth;
+mod validate_strict_fragment_arguments;
+mod validate_subscription_root_field;
+mod validate_unused_fragments;
 mod validate_unused_variables;
 
 pub use disallow_reserved_aliases::disallow_reserved_aliases;
 pub use disallow_typename_on_root::disallow_typename_on_root;
+pub use validate_connection_keys::validate_connection_keys;
+pub use validate_connection_pagination_fields::validate_connection_pagination_fields;
+pub use validate_connection_parents::validate_connection_parents;
 pub use validate_connections::validate_connections;
+pub use validate_dead_selections::validate_dead_selections;
+pub use validate_deprecated_fields::validate_deprecated_fields;
+pub use validate_fragment_directives::validate_fragment_directives;
 pub use validate_global_variables::validate_global_variables;
-pub use validate_module_names::validate_module_names;
+pub use validate_inline_fragment_alias::{
+    get_fragment_spread_alias, get_inline_fragment_alias, validate_inline_fragment_alias,
+    INLINE_FRAGMENT_ALIAS_ARG_NAME, INLINE_FRAGMENT_ALIAS_DIRECTIVE_NAME,
+};
+pub use validate_module_names::{extract_module_name, validate_module_names};
+pub use validate_output_only_scalars::validate_output_only_scalars;
+pub use validate_plural_connections::validate_plural_connections;
 pub use validate_relay_directives::validate_relay_directives;
 pub use validate_required_arguments::validate_required_arguments;
 pub use validate_server_only_directives::validate_server_only_directives;
+pub use validate_spread_depth::validate_spread_depth;
+pub use validate_strict_fragment_arguments::validate_strict_fragment_arguments;
+pub use validate_subscription_root_field::validate_subscription_root_field;
+pub use validate_unused_fragments::validate_unused_fragments;
 pub use validate_unused_variables::validate_unused_variables;
@@ -0,0 +1,148 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use crate::connections::{extract_connection_directive, ConnectionConstants};
+use crate::handle_fields::extract_handle_field_directive_args_for_connection;
+use common::{Diagnostic, DiagnosticsResult, Location};
+use fnv::{FnvHashMap, FnvHashSet};
+use graphql_ir::{ConstantValue, Program, Selection, ValidationMessage};
+use interner::StringKey;
+
+/// Validates that the `@connection` keys of the project don't collide with
+/// the keys used by the base project fragments in the program, or by other
+/// definitions of the project: they are stored on the same records at
+/// runtime, so connections with the same key would overwrite each other's
+/// edges. Connections with the same key within a definition are reported by
+/// `transform_connections`.
+pub fn validate_connection_keys(
+    program: &Program,
+    base_fragment_names: &FnvHashSet<StringKey>,
+) -> DiagnosticsResult<()> {
+    let connection_constants = ConnectionConstants::default();
+
+    let mut base_keys = FnvHashMap::default();
+    let mut project_keys = Vec::new();
+    for fragment in program.fragments() {
+        if base_fragment_names.contains(&fragment.name.item) {
+            let mut keys = Vec::new();
+            collect_connection_keys(&fragment.selections, connection_constants, &mut keys);
+            for (key, directive_name, location) in keys {
+                let entry =
+                    base_keys
+                        .entry(key)
+                        .or_insert((fragment.name.item, directive_name, location));
+                // Report the first usage in the base project for stable output.
+                if location < entry.2 {
+                    *entry = (fragment.name.item, directive_name, location);
+                }
+            }
+        } else {
+            collect_definition_keys(
+                fragment.name.item,
+                &fragment.selections,
+                connection_constants,
+                &mut project_keys,
+            );
+        }
+    }
+    for operation in program.operations() {
+        collect_definition_keys(
+            operation.name.item,
+            &operation.selections,
+            connection_constants,
+            &mut project_keys,
+        );
+    }
+
+    // The first usage of a key in the project is reported as the other usage
+    // of the later ones, for stable output.
+    project_keys.sort_by_key(|(_, (_, _, location))| *location);
+    let mut first_project_keys = FnvHashMap::default();
+    let mut errors = Vec::new();
+    for (definition_name, (key, directive_name, location)) in project_keys {
+        if let Some((base_definition_name, _, base_location)) = base_keys.get(&key) {
+            errors.push(
+                Diagnostic::error(
+                    ValidationMessage::DuplicateBaseProjectConnectionKey {
+                        connection_directive_name: directive_name,
+                        key_arg_value: key,
+                        definition_name,
+                        base_definition_name: *base_definition_name,
+                    },
+                    location,
+                )
+                .annotate("key used in the base project", *base_location),
+            );
+            continue;
+        }
+        let (other_definition_name, other_location) = *first_project_keys
+            .entry(key)
+            .or_insert((definition_name, location));
+        if other_definition_name != definition_name {
+            errors.push(
+                Diagnostic::error(
+                    ValidationMessage::DuplicateDefinitionConnectionKey {
+                        connection_directive_name: directive_name,
+                        key_arg_value: key,
+                        definition_name,
+                        other_definition_name,
+                    },
+                    location,
+                )
+                .annotate("key used by the other definition", other_location),
+            );
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        errors.sort_by_key(|error| error.location());
+        Err(errors)
+    }
+}
+
+fn collect_definition_keys(
+    definition_name: StringKey,
+    selections: &[Selection],
+    connection_constants: ConnectionConstants,
+    result: &mut Vec<(StringKey, (StringKey, StringKey, Location))>,
+) {
+    let mut keys = Vec::new();
+    collect_connection_keys(selections, connection_constants, &mut keys);
+    result.extend(keys.into_iter().map(|key| (definition_name, key)));
+}
+
+/// Collects the constant `key` argument, the directive name and the location
+/// of the key of every connection in the selections.
+fn collect_connection_keys(
+    selections: &[Selection],
+    connection_constants: ConnectionConstants,
+    keys: &mut Vec<(StringKey, StringKey, Location)>,
+) {
+    for selection in selections {
+        match selection {
+            Selection::LinkedField(field) => {
+                if let Some(directive) =
+                    extract_connection_directive(&field.directives, connection_constants)
+                {
+                    let args = extract_handle_field_directive_args_for_connection(directive);
+                    if let Some((key_arg, ConstantValue::String(key))) = args.key_arg {
+                        keys.push((*key, directive.name.item, key_arg.value.location));
+                    }
+                }
+                collect_connection_keys(&field.selections, connection_constants, keys);
+            }
+            Selection::InlineFragment(fragment) => {
+                collect_connection_keys(&fragment.selections, connection_constants, keys)
+            }
+            Selection::Condition(condition) => {
+                collect_connection_keys(&condition.selections, connection_constants, keys)
+            }
+            Selection::FragmentSpread(_) | Selection::ScalarField(_) => {}
+        }
+    }
+}
@@ -0,0 +1,153 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use crate::connections::{extract_connection_directive, ConnectionConstants, ConnectionInterface};
+use common::{Diagnostic, Location};
+use graphql_ir::{LinkedField, Program, Selection, ValidationMessage, Visitor};
+use interner::StringKey;
+
+/// Lints manual selections of the pagination fields of `@connection` fields:
+/// the `pageInfo` of the connection and the `cursor` of its edges. These
+/// fields are added by the connection transform, selecting them manually can
+/// result in duplicate or conflicting selections.
+///
+/// The returned diagnostics are warnings and should not fail the build, some
+/// manual selections are intentional.
+pub fn validate_connection_pagination_fields(
+    program: &Program,
+    connection_interface: &ConnectionInterface,
+) -> Vec<Diagnostic> {
+    let mut validator = ConnectionPaginationFieldsValidation {
+        program,
+        connection_constants: ConnectionConstants::default(),
+        connection_interface,
+        warnings: Vec::new(),
+    };
+    validator.visit_program(program);
+    validator.warnings
+}
+
+struct ConnectionPaginationFieldsValidation<'a> {
+    program: &'a Program,
+    connection_constants: ConnectionConstants,
+    connection_interface: &'a ConnectionInterface,
+    warnings: Vec<Diagnostic>,
+}
+
+impl ConnectionPaginationFieldsValidation<'_> {
+    /// Reports the manually selected pagination fields in the selections of
+    /// the connection field.
+    fn validate_connection_selections(
+        &mut self,
+        connection_directive_name: StringKey,
+        connection_field_name: StringKey,
+        selections: &[Selection],
+    ) {
+        for selection in selections {
+            match selection {
+                Selection::LinkedField(field) => {
+                    let field_name = self.program.schema.field(field.definition.item).name;
+                    if field_name == self.connection_interface.page_info {
+                        self.warn(
+                            connection_directive_name,
+                            connection_field_name,
+                            field_name.lookup().to_owned(),
+                            field.alias_or_name_location(),
+                        );
+                    } else if field_name == self.connection_interface.edges {
+                        self.validate_edge_selections(
+                            connection_directive_name,
+                            connection_field_name,
+                            &field.selections,
+                        );
+                    }
+                }
+                Selection::InlineFragment(fragment) => self.validate_connection_selections(
+                    connection_directive_name,
+                    connection_field_name,
+                    &fragment.selections,
+                ),
+                Selection::Condition(condition) => self.validate_connection_selections(
+                    connection_directive_name,
+                    connection_field_name,
+                    &condition.selections,
+                ),
+                Selection::ScalarField(_) | Selection::FragmentSpread(_) => {}
+            }
+        }
+    }
+
+    fn validate_edge_selections(
+        &mut self,
+        connection_directive_name: StringKey,
+        connection_field_name: StringKey,
+        selections: &[Selection],
+    ) {
+        for selection in selections {
+            match selection {
+                Selection::ScalarField(field) => {
+                    let field_name = self.program.schema.field(field.definition.item).name;
+                    if field_name == self.connection_interface.cursor {
+                        self.warn(
+                            connection_directive_name,
+                            connection_field_name,
+                            format!("{}.{}", self.connection_interface.edges, field_name),
+                            field.alias_or_name_location(),
+                        );
+                    }
+                }
+                Selection::InlineFragment(fragment) => self.validate_edge_selections(
+                    connection_directive_name,
+                    connection_field_name,
+                    &fragment.selections,
+                ),
+                Selection::Condition(condition) => self.validate_edge_selections(
+                    connection_directive_name,
+                    connection_field_name,
+                    &condition.selections,
+                ),
+                Selection::LinkedField(_) | Selection::FragmentSpread(_) => {}
+            }
+        }
+    }
+
+    fn warn(
+        &mut self,
+        connection_directive_name: StringKey,
+        connection_field_name: StringKey,
+        field_path: String,
+        location: Location,
+    ) {
+        self.warnings.push(Diagnostic::warning(
+            ValidationMessage::ManualConnectionPaginationFieldSelection {
+                connection_directive_name,
+                connection_field_name,
+                field_path,
+            },
+            location,
+        ));
+    }
+}
+
+impl Visitor for ConnectionPaginationFieldsValidation<'_> {
+    const NAME: &'static str = "ConnectionPaginationFieldsValidation";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_linked_field(&mut self, field: &LinkedField) {
+        if let Some(directive) =
+            extract_connection_directive(&field.directives, self.connection_constants)
+        {
+            self.validate_connection_selections(
+                directive.name.item,
+                self.program.schema.field(field.definition.item).name,
+                &field.selections,
+            );
+        }
+        self.default_visit_linked_field(field);
+    }
+}
@@ -0,0 +1,148 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use crate::connections::{extract_connection_directive, ConnectionConstants};
+use crate::handle_fields::DYNAMIC_KEY_ARG_NAME;
+use crate::util::get_node_id_field_name;
+use common::{Diagnostic, DiagnosticsResult, NamedItem};
+use graphql_ir::{
+    FragmentDefinition, InlineFragment, LinkedField, OperationDefinition, Program,
+    ValidationMessage, Validator,
+};
+use interner::StringKey;
+use schema::{Schema, Type};
+
+/// Validates that `@connection` fields without a `dynamicKey_UNSTABLE` are
+/// selected on a record the store can identify: a root type, or a type with
+/// an `id` field. The runtime stores the connection on its parent record and
+/// can't find it again below other records.
+pub fn validate_connection_parents(program: &Program) -> DiagnosticsResult<()> {
+    ConnectionParentValidator::new(program).validate_program(program)
+}
+
+struct ConnectionParentValidator<'program> {
+    program: &'program Program,
+    connection_constants: ConnectionConstants,
+    id_field_name: StringKey,
+    parent_type: Option<Type>,
+}
+
+impl<'program> ConnectionParentValidator<'program> {
+    fn new(program: &'program Program) -> Self {
+        Self {
+            program,
+            connection_constants: ConnectionConstants::default(),
+            id_field_name: get_node_id_field_name(&program.schema),
+            parent_type: None,
+        }
+    }
+
+    fn is_identifiable(&self, type_: Type) -> bool {
+        let schema = &self.program.schema;
+        if is_root_type(schema, type_) {
+            return true;
+        }
+        match type_ {
+            Type::Object(_) => self.has_id_field(type_),
+            Type::Interface(id) => {
+                self.has_id_field(type_)
+                    || schema
+                        .interface(id)
+                        .implementing_objects
+                        .iter()
+                        .all(|&object_id| self.has_id_field(Type::Object(object_id)))
+            }
+            Type::Union(id) => schema
+                .union(id)
+                .members
+                .iter()
+                .all(|&object_id| self.has_id_field(Type::Object(object_id))),
+            _ => false,
+        }
+    }
+
+    fn has_id_field(&self, type_: Type) -> bool {
+        let schema = &self.program.schema;
+        match schema.named_field(type_, self.id_field_name) {
+            Some(field_id) => schema.is_id(schema.field(field_id).type_.inner()),
+            None => false,
+        }
+    }
+
+    fn validate_with_parent_type(
+        &mut self,
+        parent_type: Type,
+        validate: impl FnOnce(&mut Self) -> DiagnosticsResult<()>,
+    ) -> DiagnosticsResult<()> {
+        let previous_parent_type = self.parent_type.replace(parent_type);
+        let result = validate(self);
+        self.parent_type = previous_parent_type;
+        result
+    }
+}
+
+fn is_root_type(schema: &Schema, type_: Type) -> bool {
+    schema.query_type() == Some(type_)
+        || schema.mutation_type() == Some(type_)
+        || schema.subscription_type() == Some(type_)
+}
+
+impl Validator for ConnectionParentValidator<'_> {
+    const NAME: &'static str = "ConnectionParentValidator";
+    const VALIDATE_ARGUMENTS: bool = false;
+    const VALIDATE_DIRECTIVES: bool = false;
+
+    fn validate_operation(&mut self, operation: &OperationDefinition) -> DiagnosticsResult<()> {
+        self.validate_with_parent_type(operation.type_, |validator| {
+            validator.default_validate_operation(operation)
+        })
+    }
+
+    fn validate_fragment(&mut self, fragment: &FragmentDefinition) -> DiagnosticsResult<()> {
+        self.validate_with_parent_type(fragment.type_condition, |validator| {
+            validator.default_validate_fragment(fragment)
+        })
+    }
+
+    fn validate_inline_fragment(&mut self, fragment: &InlineFragment) -> DiagnosticsResult<()> {
+        match fragment.type_condition {
+            Some(type_condition) => self.validate_with_parent_type(type_condition, |validator| {
+                validator.default_validate_inline_fragment(fragment)
+            }),
+            None => self.default_validate_inline_fragment(fragment),
+        }
+    }
+
+    fn validate_linked_field(&mut self, field: &LinkedField) -> DiagnosticsResult<()> {
+        let schema = &self.program.schema;
+        if let Some(directive) =
+            extract_connection_directive(&field.directives, self.connection_constants)
+        {
+            let parent_type = self
+                .parent_type
+                .expect("Expected the parent type of a field to be set.");
+            if directive.arguments.named(*DYNAMIC_KEY_ARG_NAME).is_none()
+                && !self.is_identifiable(parent_type)
+            {
+                return Err(vec![Diagnostic::error(
+                    ValidationMessage::ConnectionParentNotIdentifiable {
+                        connection_directive_name: directive.name.item,
+                        connection_field_name: schema.field(field.definition.item).name,
+                        parent_type_name: schema.get_type_name(parent_type),
+                        id_field_name: self.id_field_name,
+                        dynamic_key_arg_name: *DYNAMIC_KEY_ARG_NAME,
+                    },
+                    field.alias_or_name_location(),
+                )]);
+            }
+        }
+        let field_type = schema.field(field.definition.item).type_.inner();
+        self.validate_with_parent_type(field_type, |validator| {
+            validator.default_validate_linked_field(field)
+        })
+    }
+}
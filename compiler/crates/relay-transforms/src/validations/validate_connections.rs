@@ -5,40 +5,126 @@
  * LICENSE file in the root directory of this source tree.
  */
 
-use crate::connections::{extract_connection_directive, ConnectionConstants, ConnectionInterface};
+use crate::connections::{
+    extract_connection_directive, find_edges_selection, ConnectionConstants, ConnectionInterface,
+};
+use crate::feature_flags::FeatureFlags;
 use crate::handle_fields::{
     extract_handle_field_directive_args_for_connection, CONNECTION_HANDLER_ARG_NAME,
-    DYNAMIC_KEY_ARG_NAME, FILTERS_ARG_NAME, KEY_ARG_NAME,
+    DYNAMIC_KEY_ARG_NAME, EXCLUDED_FILTERS_ARG_NAME, FILTERS_ARG_NAME, HANDLER_MODULE_ARG_NAME,
+    KEY_ARG_NAME,
 };
+use crate::refetchable_fragment::CONSTANTS as REFETCHABLE_CONSTANTS;
 use common::{Diagnostic, DiagnosticsResult, NamedItem};
 use errors::{validate, validate_map};
 use graphql_ir::{
-    Argument, ConstantValue, Directive, LinkedField, Program, Selection, ValidationMessage,
-    Validator, Value,
+    Argument, Condition, ConditionValue, ConstantValue, Directive, FragmentDefinition, LinkedField,
+    Program, Selection, ValidationMessage, Validator, Value,
 };
-use interner::StringKey;
+use interner::{Intern, StringKey};
 use schema::{Field, Type, TypeReference};
 
 pub fn validate_connections(
     program: &Program,
     connection_interface: &ConnectionInterface,
+    feature_flags: &FeatureFlags,
 ) -> DiagnosticsResult<()> {
-    let mut validator = ConnectionValidation::new(program, connection_interface);
+    let mut validator = ConnectionValidation::new(program, connection_interface, feature_flags);
     validator.validate_program(program)
 }
 struct ConnectionValidation<'s> {
     connection_constants: ConnectionConstants,
     connection_interface: &'s ConnectionInterface,
     program: &'s Program,
+    /// The innermost variable @include/@skip condition enclosing the current selection
+    /// within its parent linked field
+    current_condition: Option<(StringKey, StringKey)>,
+    /// Whether the current document is a @refetchable fragment
+    is_refetchable_fragment: bool,
+    /// Whether connections without a `key` get a generated one
+    enable_connection_key_generation: bool,
 }
 
 impl<'s> ConnectionValidation<'s> {
-    fn new(program: &'s Program, connection_interface: &'s ConnectionInterface) -> Self {
+    fn new(
+        program: &'s Program,
+        connection_interface: &'s ConnectionInterface,
+        feature_flags: &FeatureFlags,
+    ) -> Self {
         Self {
             connection_constants: ConnectionConstants::default(),
             connection_interface,
             program,
+            current_condition: None,
+            is_refetchable_fragment: false,
+            enable_connection_key_generation: feature_flags.enable_connection_key_generation,
+        }
+    }
+
+    /// Validates the selections of a linked field. A condition only applies to
+    /// the connections selected on the conditional record, not to the ones
+    /// nested in linked fields below it.
+    fn validate_linked_field_selections(&mut self, field: &LinkedField) -> DiagnosticsResult<()> {
+        let prev_condition = self.current_condition.take();
+        let result = self.default_validate_linked_field(field);
+        self.current_condition = prev_condition;
+        result
+    }
+
+    /// Whether the connection is set up for pagination: it's selected in a
+    /// @refetchable fragment (as used by `usePaginationFragment`), streamed,
+    /// or its count or cursor arguments are variables. Other connections are
+    /// display-only and don't need to select the `page_info`.
+    fn is_paginated_connection(
+        &self,
+        connection_field: &LinkedField,
+        connection_directive: &Directive,
+    ) -> bool {
+        self.is_refetchable_fragment
+            || connection_directive.name.item
+                == self.connection_constants.stream_connection_directive_name
+            || [
+                self.connection_constants.first_arg_name,
+                self.connection_constants.last_arg_name,
+                self.connection_constants.after_arg_name,
+                self.connection_constants.before_arg_name,
+            ]
+            .iter()
+            .any(|arg_name| {
+                matches!(
+                    connection_field
+                        .arguments
+                        .named(*arg_name)
+                        .map(|arg| &arg.value.item),
+                    Some(Value::Variable(_))
+                )
+            })
+    }
+
+    /// Validates that the connection field is not conditionally included: the
+    /// connection metadata is attached to the document and can't express the
+    /// condition, so the runtime would register a connection that may not exist.
+    fn validate_connection_condition(
+        &self,
+        connection_field: &LinkedField,
+        connection_schema_field: &Field,
+        connection_directive: &Directive,
+    ) -> DiagnosticsResult<()> {
+        if let Some((condition_directive_name, condition_variable_name)) = self.current_condition {
+            return Err(vec![
+                Diagnostic::error(
+                    ValidationMessage::ConnectionWithinCondition {
+                        connection_directive_name: connection_directive.name.item,
+                        connection_field_name: connection_schema_field.name,
+                        condition_directive_name,
+                        condition_variable_name,
+                    },
+                    connection_directive.name.location,
+                )
+                .annotate("on connection field", connection_field.definition.location),
+            ]);
         }
+        Ok(())
     }
 
     /// Validates that the connection field is a non-plural, object or interface type.
@@ -70,17 +156,19 @@ impl<'s> ConnectionValidation<'s> {
     ///   data access.
     /// - Has an `edges` selection, otherwise there is nothing to paginate.
     ///
-    /// TODO: This implementation requires the edges field to be a direct selection
-    /// and not contained within an inline fragment or fragment spread. It's
-    /// technically possible to remove this restriction if this pattern becomes
-    /// common/necessary.
+    /// The `edges` can be selected within inline fragments and conditions,
+    /// e.g. on a concrete type of an interface typed connection field. Returns
+    /// the `edges` field and the connection type it is selected on.
+    ///
+    /// TODO: This implementation requires the edges field to not be contained
+    /// within a fragment spread. It's technically possible to remove this
+    /// restriction if this pattern becomes common/necessary.
     fn validate_connection_selection(
         &self,
         connection_field: &'s LinkedField,
         connection_schema_field: &'s Field,
-    ) -> DiagnosticsResult<&'s LinkedField> {
-        let schema = &self.program.schema;
-
+        connection_field_type: Type,
+    ) -> DiagnosticsResult<(&'s LinkedField, Type)> {
         let first_arg = connection_field
             .arguments
             .named(self.connection_constants.first_arg_name);
@@ -98,30 +186,21 @@ impl<'s> ConnectionValidation<'s> {
             )]);
         }
 
-        let edges_selection_name = self.connection_interface.edges;
-        let edges_selection: Option<&Selection> =
-            connection_field.selections.iter().find(|sel| match sel {
-                Selection::LinkedField(field) => {
-                    schema.field(field.definition.item).name == edges_selection_name
-                }
-                _ => false,
-            });
-
-        if let Some(edges_selection) = edges_selection {
-            if let Selection::LinkedField(edges_field) = edges_selection {
-                Ok(edges_field.as_ref())
-            } else {
-                unreachable!("Expected selection for edges to be a linked field.")
-            }
-        } else {
-            Err(vec![Diagnostic::error(
+        find_edges_selection(
+            &self.program.schema,
+            &connection_field.selections,
+            connection_field_type,
+            self.connection_interface,
+        )
+        .ok_or_else(|| {
+            vec![Diagnostic::error(
                 ValidationMessage::ExpectedConnectionToHaveEdgesSelection {
                     connection_field_name: connection_schema_field.name,
-                    edges_selection_name,
+                    edges_selection_name: self.connection_interface.edges,
                 },
                 connection_field.definition.location,
-            )])
-        }
+            )]
+        })
     }
 
     /// Validates that the type satisfies the Connection specification:
@@ -129,7 +208,7 @@ impl<'s> ConnectionValidation<'s> {
     ///   - a scalar `cursor` field
     ///   - a object `node` field
     /// - The type has a page info field which is an object with the correct
-    ///   subfields.
+    ///   subfields, unless the connection is display-only.
     fn validate_connection_spec(
         &self,
         connection_field: &LinkedField,
@@ -146,12 +225,16 @@ impl<'s> ConnectionValidation<'s> {
                 connection_directive,
                 edges_field,
             ),
-            self.validate_page_info_spec(
-                connection_field,
-                connection_schema_field,
-                connection_field_type,
-                connection_directive,
-            )
+            if self.is_paginated_connection(connection_field, connection_directive) {
+                self.validate_page_info_spec(
+                    connection_field,
+                    connection_schema_field,
+                    connection_field_type,
+                    connection_directive,
+                )
+            } else {
+                Ok(())
+            }
         )
     }
 
@@ -349,8 +432,16 @@ impl<'s> ConnectionValidation<'s> {
                 connection_field,
                 connection_schema_field,
                 connection_directive,
+                *CONNECTION_HANDLER_ARG_NAME,
                 connection_directive_args.handler_arg,
             ),
+            self.validate_handler_arg(
+                connection_field,
+                connection_schema_field,
+                connection_directive,
+                *HANDLER_MODULE_ARG_NAME,
+                connection_directive_args.handler_module_arg,
+            ),
             self.validate_key_arg(
                 connection_field,
                 connection_schema_field,
@@ -358,16 +449,43 @@ impl<'s> ConnectionValidation<'s> {
                 connection_directive_args.key_arg,
             ),
             self.validate_filters_arg(
+                connection_field,
+                connection_schema_field,
+                connection_directive,
+                *FILTERS_ARG_NAME,
+                connection_directive_args.filters_arg,
+            ),
+            self.validate_filters_arg(
+                connection_field,
+                connection_schema_field,
+                connection_directive,
+                *EXCLUDED_FILTERS_ARG_NAME,
+                connection_directive_args.excluded_filters_arg,
+            ),
+            self.validate_excluded_filters_arg(
                 connection_field,
                 connection_schema_field,
                 connection_directive,
                 connection_directive_args.filters_arg,
+                connection_directive_args.excluded_filters_arg,
             ),
             self.validate_dynamic_key_arg(
                 connection_field,
                 connection_schema_field,
                 connection_directive,
                 connection_directive_args.dynamic_key_arg,
+            ),
+            self.validate_boolean_arg(
+                connection_field,
+                connection_schema_field,
+                connection_directive,
+                self.connection_constants.prefetchable_arg_name,
+            ),
+            self.validate_boolean_arg(
+                connection_field,
+                connection_schema_field,
+                connection_directive,
+                self.connection_constants.generate_next_edges_arg_name,
             )
         )
     }
@@ -377,6 +495,7 @@ impl<'s> ConnectionValidation<'s> {
         connection_field: &LinkedField,
         connection_schema_field: &Field,
         connection_directive: &Directive,
+        handler_arg_name: StringKey,
         constant_handler_arg: Option<(&Argument, &ConstantValue)>,
     ) -> DiagnosticsResult<()> {
         if let Some((arg, handler_val)) = constant_handler_arg {
@@ -388,7 +507,7 @@ impl<'s> ConnectionValidation<'s> {
                             ValidationMessage::InvalidConnectionHandlerArg {
                                 connection_directive_name: connection_directive.name.item,
                                 connection_field_name: connection_schema_field.name,
-                                handler_arg_name: *CONNECTION_HANDLER_ARG_NAME,
+                                handler_arg_name,
                             },
                             arg.value.location,
                         )
@@ -445,6 +564,11 @@ impl<'s> ConnectionValidation<'s> {
                     ]);
                 }
             },
+            // Generated, or reported as missing before
+            None if connection_directive
+                .arguments
+                .named(*KEY_ARG_NAME)
+                .is_none() => {}
             None => {
                 return Err(vec![Diagnostic::error(
                     ValidationMessage::InvalidConnectionKeyArg {
@@ -464,6 +588,7 @@ impl<'s> ConnectionValidation<'s> {
         connection_field: &LinkedField,
         connection_schema_field: &Field,
         connection_directive: &Directive,
+        filters_arg_name: StringKey,
         constant_filters_arg: Option<(&Argument, &ConstantValue)>,
     ) -> DiagnosticsResult<()> {
         if let Some((arg, filters_val)) = constant_filters_arg {
@@ -480,7 +605,7 @@ impl<'s> ConnectionValidation<'s> {
                                 ValidationMessage::InvalidConnectionFiltersArg {
                                     connection_directive_name: connection_directive.name.item,
                                     connection_field_name: connection_schema_field.name,
-                                    filters_arg_name: *FILTERS_ARG_NAME,
+                                    filters_arg_name,
                                 },
                                 arg.value.location,
                             )
@@ -494,7 +619,7 @@ impl<'s> ConnectionValidation<'s> {
                             ValidationMessage::InvalidConnectionFiltersArg {
                                 connection_directive_name: connection_directive.name.item,
                                 connection_field_name: connection_schema_field.name,
-                                filters_arg_name: *FILTERS_ARG_NAME,
+                                filters_arg_name,
                             },
                             arg.value.location,
                         )
@@ -506,6 +631,63 @@ impl<'s> ConnectionValidation<'s> {
         Ok(())
     }
 
+    /// `excludedFilters` only applies to the default filters, so it can't be
+    /// combined with `filters`, and should only name arguments of the field.
+    fn validate_excluded_filters_arg(
+        &self,
+        connection_field: &LinkedField,
+        connection_schema_field: &Field,
+        connection_directive: &Directive,
+        constant_filters_arg: Option<(&Argument, &ConstantValue)>,
+        constant_excluded_filters_arg: Option<(&Argument, &ConstantValue)>,
+    ) -> DiagnosticsResult<()> {
+        let (arg, excluded_filters) = match constant_excluded_filters_arg {
+            Some((arg, ConstantValue::List(excluded_filters))) => (arg, excluded_filters),
+            _ => return Ok(()),
+        };
+        if let Some((filters_arg, _)) = constant_filters_arg {
+            return Err(vec![
+                Diagnostic::error(
+                    ValidationMessage::ConflictingConnectionFiltersArgs {
+                        connection_directive_name: connection_directive.name.item,
+                        connection_field_name: connection_schema_field.name,
+                        filters_arg_name: *FILTERS_ARG_NAME,
+                        excluded_filters_arg_name: *EXCLUDED_FILTERS_ARG_NAME,
+                    },
+                    arg.name.location,
+                )
+                .annotate("conflicting argument", filters_arg.name.location),
+            ]);
+        }
+        let errors = excluded_filters
+            .iter()
+            .filter_map(|excluded_filter| match excluded_filter {
+                ConstantValue::String(filter_name)
+                    if !connection_schema_field.arguments.contains(*filter_name) =>
+                {
+                    Some(
+                        Diagnostic::error(
+                            ValidationMessage::UnknownConnectionExcludedFilter {
+                                connection_directive_name: connection_directive.name.item,
+                                connection_field_name: connection_schema_field.name,
+                                excluded_filters_arg_name: *EXCLUDED_FILTERS_ARG_NAME,
+                                filter_name: *filter_name,
+                            },
+                            arg.value.location,
+                        )
+                        .annotate("related location", connection_field.definition.location),
+                    )
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
     fn validate_dynamic_key_arg(
         &self,
         connection_field: &LinkedField,
@@ -534,6 +716,33 @@ impl<'s> ConnectionValidation<'s> {
         Ok(())
     }
 
+    fn validate_boolean_arg(
+        &self,
+        connection_field: &LinkedField,
+        connection_schema_field: &Field,
+        connection_directive: &Directive,
+        arg_name: StringKey,
+    ) -> DiagnosticsResult<()> {
+        let boolean_arg = connection_directive.arguments.named(arg_name);
+        if let Some(boolean_arg) = boolean_arg {
+            match boolean_arg.value.item {
+                Value::Constant(ConstantValue::Boolean(_)) => {}
+                _ => {
+                    return Err(vec![Diagnostic::error(
+                        ValidationMessage::InvalidConnectionBooleanArg {
+                            connection_directive_name: connection_directive.name.item,
+                            connection_field_name: connection_schema_field.name,
+                            arg_name,
+                        },
+                        boolean_arg.value.location,
+                    )
+                    .annotate("related location", connection_field.definition.location)]);
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn validate_stream_connection(
         &self,
         edges_field: &LinkedField,
@@ -583,10 +792,36 @@ impl<'s> Validator for ConnectionValidation<'s> {
     const VALIDATE_ARGUMENTS: bool = false;
     const VALIDATE_DIRECTIVES: bool = false;
 
+    fn validate_fragment(&mut self, fragment: &FragmentDefinition) -> DiagnosticsResult<()> {
+        self.is_refetchable_fragment = fragment
+            .directives
+            .named(REFETCHABLE_CONSTANTS.refetchable_name)
+            .is_some();
+        let result = self.default_validate_fragment(fragment);
+        self.is_refetchable_fragment = false;
+        result
+    }
+
     fn validate_linked_field(&mut self, field: &LinkedField) -> DiagnosticsResult<()> {
         if let Some(connection_directive) =
             extract_connection_directive(&field.directives, self.connection_constants)
         {
+            // The builder doesn't require the key, it's only optional if it
+            // can be generated
+            if !self.enable_connection_key_generation
+                && connection_directive
+                    .arguments
+                    .named(*KEY_ARG_NAME)
+                    .is_none()
+            {
+                return Err(vec![Diagnostic::error(
+                    ValidationMessage::MissingRequiredDirectiveArgument {
+                        directive: connection_directive.name.item,
+                        argument: *KEY_ARG_NAME,
+                    },
+                    connection_directive.name.location,
+                )]);
+            }
             let connection_schema_field = self.program.schema.field(field.definition.item);
 
             let connection_field_type = self.validate_connection_field_type(
@@ -594,7 +829,16 @@ impl<'s> Validator for ConnectionValidation<'s> {
                 connection_schema_field,
                 connection_directive,
             )?;
-            let edges_field = self.validate_connection_selection(field, connection_schema_field)?;
+            let (edges_field, connection_type) = self.validate_connection_selection(
+                field,
+                connection_schema_field,
+                connection_field_type,
+            )?;
+            self.validate_connection_condition(
+                field,
+                connection_schema_field,
+                connection_directive,
+            )?;
 
             if connection_directive.name.item
                 == self.connection_constants.stream_connection_directive_name
@@ -606,7 +850,7 @@ impl<'s> Validator for ConnectionValidation<'s> {
                 self.validate_connection_spec(
                     field,
                     connection_schema_field,
-                    connection_field_type,
+                    connection_type,
                     connection_directive,
                     edges_field,
                 ),
@@ -615,10 +859,29 @@ impl<'s> Validator for ConnectionValidation<'s> {
                     connection_schema_field,
                     connection_directive,
                 ),
-                self.default_validate_linked_field(field)
+                self.validate_linked_field_selections(field)
             )
         } else {
-            self.default_validate_linked_field(field)
+            self.validate_linked_field_selections(field)
+        }
+    }
+
+    fn validate_condition(&mut self, condition: &Condition) -> DiagnosticsResult<()> {
+        if let ConditionValue::Variable(variable) = &condition.value {
+            let condition_directive_name = if condition.passing_value {
+                "include"
+            } else {
+                "skip"
+            };
+            // Restore the enclosing condition after the conditional selections,
+            // so that their siblings are validated as unconditional again.
+            let prev_condition = self.current_condition;
+            self.current_condition = Some((condition_directive_name.intern(), variable.name.item));
+            let result = self.default_validate_condition(condition);
+            self.current_condition = prev_condition;
+            result
+        } else {
+            self.default_validate_condition(condition)
         }
     }
 }
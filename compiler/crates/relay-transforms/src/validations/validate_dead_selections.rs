@@ -0,0 +1,70 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::{Diagnostic, Location};
+use graphql_ir::{Condition, ConditionValue, Program, Selection, ValidationMessage, Visitor};
+use interner::Intern;
+
+/// Lints selections that are never fetched because of an `@include(if: false)`
+/// or `@skip(if: true)` with a literal value. These selections are removed
+/// from the artifacts by `skip_unreachable_node`, but are likely a mistake or
+/// leftover code.
+///
+/// The returned diagnostics are warnings and should not fail the build.
+pub fn validate_dead_selections(program: &Program) -> Vec<Diagnostic> {
+    let mut validator = DeadSelectionsValidation {
+        warnings: Vec::new(),
+    };
+    validator.visit_program(program);
+    validator.warnings
+}
+
+struct DeadSelectionsValidation {
+    warnings: Vec<Diagnostic>,
+}
+
+impl Visitor for DeadSelectionsValidation {
+    const NAME: &'static str = "DeadSelectionsValidation";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_condition(&mut self, condition: &Condition) {
+        if let ConditionValue::Constant(value) = condition.value {
+            if value != condition.passing_value {
+                let condition_directive_name = if condition.passing_value {
+                    "include"
+                } else {
+                    "skip"
+                };
+                if let Some(location) = get_first_location(&condition.selections) {
+                    self.warnings.push(Diagnostic::warning(
+                        ValidationMessage::DeadSelection {
+                            condition_directive_name: condition_directive_name.intern(),
+                            condition_value: value,
+                        },
+                        location,
+                    ));
+                }
+                // Nested conditions are dead too, only report the outermost.
+                return;
+            }
+        }
+        self.default_visit_condition(condition);
+    }
+}
+
+/// Conditions and inline fragments don't have a location, the dead selection
+/// is reported at the first field or fragment spread it contains.
+fn get_first_location(selections: &[Selection]) -> Option<Location> {
+    selections.iter().find_map(|selection| match selection {
+        Selection::ScalarField(field) => Some(field.alias_or_name_location()),
+        Selection::LinkedField(field) => Some(field.alias_or_name_location()),
+        Selection::FragmentSpread(spread) => Some(spread.fragment.location),
+        Selection::InlineFragment(fragment) => get_first_location(&fragment.selections),
+        Selection::Condition(condition) => get_first_location(&condition.selections),
+    })
+}
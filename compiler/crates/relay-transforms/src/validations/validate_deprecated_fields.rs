@@ -0,0 +1,83 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::{Diagnostic, WithLocation};
+use graphql_ir::{Argument, LinkedField, Program, ScalarField, ValidationMessage, Visitor};
+use schema::{FieldID, Schema};
+
+/// Reports the usages of fields and field arguments that are marked as
+/// `@deprecated` in the schema, with the reason of the deprecation.
+///
+/// The severity of the returned diagnostics is up to the caller, e.g. errors
+/// for new code and warnings for code that is still being migrated.
+pub fn validate_deprecated_fields(program: &Program) -> Vec<Diagnostic> {
+    let mut validator = DeprecatedFieldsValidation {
+        schema: &program.schema,
+        diagnostics: Vec::new(),
+    };
+    validator.visit_program(program);
+    validator.diagnostics
+}
+
+struct DeprecatedFieldsValidation<'s> {
+    schema: &'s Schema,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl DeprecatedFieldsValidation<'_> {
+    fn validate_field(&mut self, definition: &WithLocation<FieldID>, arguments: &[Argument]) {
+        let field = self.schema.field(definition.item);
+        // Only `__typename` and `__id` don't have a parent type, these can't
+        // be deprecated.
+        let parent_type = match field.parent_type {
+            Some(parent_type) => self.schema.get_type_name(parent_type),
+            None => return,
+        };
+        if let Some(deprecation) = field.deprecated() {
+            self.diagnostics.push(Diagnostic::error(
+                ValidationMessage::DeprecatedField {
+                    parent_type,
+                    field_name: field.name,
+                    reason: deprecation.reason,
+                },
+                definition.location,
+            ));
+        }
+        for argument in arguments {
+            let deprecation = field
+                .arguments
+                .named(argument.name.item)
+                .and_then(|argument_definition| argument_definition.deprecated());
+            if let Some(deprecation) = deprecation {
+                self.diagnostics.push(Diagnostic::error(
+                    ValidationMessage::DeprecatedArgument {
+                        parent_type,
+                        field_name: field.name,
+                        argument_name: argument.name.item,
+                        reason: deprecation.reason,
+                    },
+                    argument.name.location,
+                ));
+            }
+        }
+    }
+}
+
+impl Visitor for DeprecatedFieldsValidation<'_> {
+    const NAME: &'static str = "DeprecatedFieldsValidation";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_scalar_field(&mut self, field: &ScalarField) {
+        self.validate_field(&field.definition, &field.arguments);
+    }
+
+    fn visit_linked_field(&mut self, field: &LinkedField) {
+        self.validate_field(&field.definition, &field.arguments);
+        self.default_visit_linked_field(field);
+    }
+}
@@ -0,0 +1,91 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use crate::inline_data_fragment::INLINE_DATA_CONSTANTS;
+use crate::refetchable_fragment::CONSTANTS as REFETCHABLE_CONSTANTS;
+use crate::relay_directive::{MASK_ARG_NAME, RELAY_DIRECTIVE_NAME};
+use common::{Diagnostic, DiagnosticsResult, NamedItem};
+use graphql_ir::{ConstantValue, Directive, FragmentDefinition, Program, ValidationMessage, Value};
+
+/// A fragment-level directive usage, e.g. `@inline` or `@relay(mask: false)`.
+struct FragmentDirectiveUsage {
+    /// How the usage is printed in the error message.
+    display_name: &'static str,
+    find: fn(&FragmentDefinition) -> Option<&Directive>,
+}
+
+const INLINE: FragmentDirectiveUsage = FragmentDirectiveUsage {
+    display_name: "@inline",
+    find: find_inline_directive,
+};
+
+const REFETCHABLE: FragmentDirectiveUsage = FragmentDirectiveUsage {
+    display_name: "@refetchable",
+    find: find_refetchable_directive,
+};
+
+const UNMASKED: FragmentDirectiveUsage = FragmentDirectiveUsage {
+    display_name: "@relay(mask: false)",
+    find: find_unmasked_directive,
+};
+
+/// Pairs of fragment-level directives that can't be used on the same fragment.
+const INCOMPATIBLE_FRAGMENT_DIRECTIVES: &[(FragmentDirectiveUsage, FragmentDirectiveUsage)] =
+    &[(INLINE, REFETCHABLE), (INLINE, UNMASKED)];
+
+/// Reports fragments that use directives that can't be combined, e.g. an
+/// `@inline` fragment is read with `readInlineData` and can't be refetched.
+pub fn validate_fragment_directives(program: &Program) -> DiagnosticsResult<()> {
+    let mut errors = Vec::new();
+    for fragment in program.fragments() {
+        for (usage_a, usage_b) in INCOMPATIBLE_FRAGMENT_DIRECTIVES {
+            if (usage_a.find)(fragment).is_some() && (usage_b.find)(fragment).is_some() {
+                errors.push(Diagnostic::error(
+                    ValidationMessage::ConflictingFragmentDirectives {
+                        fragment_name: fragment.name.item,
+                        directive_a: usage_a.display_name,
+                        directive_b: usage_b.display_name,
+                    },
+                    fragment.name.location,
+                ));
+            }
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn find_inline_directive(fragment: &FragmentDefinition) -> Option<&Directive> {
+    fragment
+        .directives
+        .named(INLINE_DATA_CONSTANTS.directive_name)
+}
+
+fn find_refetchable_directive(fragment: &FragmentDefinition) -> Option<&Directive> {
+    fragment
+        .directives
+        .named(REFETCHABLE_CONSTANTS.refetchable_name)
+}
+
+/// Invalid `mask` values are reported by `validate_relay_directives`.
+fn find_unmasked_directive(fragment: &FragmentDefinition) -> Option<&Directive> {
+    fragment
+        .directives
+        .named(*RELAY_DIRECTIVE_NAME)
+        .filter(|directive| {
+            matches!(
+                directive.arguments.named(*MASK_ARG_NAME),
+                Some(argument) if matches!(
+                    argument.value.item,
+                    Value::Constant(ConstantValue::Boolean(false))
+                )
+            )
+        })
+}
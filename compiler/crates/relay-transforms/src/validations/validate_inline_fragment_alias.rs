@@ -0,0 +1,101 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::{Diagnostic, DiagnosticsResult, NamedItem};
+use graphql_ir::{
+    ConstantValue, Directive, FragmentSpread, InlineFragment, Program, ValidationMessage,
+    Validator, Value,
+};
+use interner::{Intern, StringKey};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    pub static ref INLINE_FRAGMENT_ALIAS_DIRECTIVE_NAME: StringKey = "alias".intern();
+    pub static ref INLINE_FRAGMENT_ALIAS_ARG_NAME: StringKey = "as".intern();
+}
+
+/// Validates the usages of `@alias` on inline fragments and fragment spreads:
+/// an aliased inline fragment has to narrow to a type and the alias has to be
+/// a literal string.
+pub fn validate_inline_fragment_alias(program: &Program) -> DiagnosticsResult<()> {
+    InlineFragmentAliasValidator.validate_program(program)
+}
+
+/// Returns the alias of an inline fragment annotated with `@alias`, if any.
+pub fn get_inline_fragment_alias(inline_fragment: &InlineFragment) -> Option<StringKey> {
+    get_alias(&inline_fragment.directives)
+}
+
+/// Returns the alias of a fragment spread annotated with `@alias`, if any.
+pub fn get_fragment_spread_alias(fragment_spread: &FragmentSpread) -> Option<StringKey> {
+    get_alias(&fragment_spread.directives)
+}
+
+fn get_alias(directives: &[Directive]) -> Option<StringKey> {
+    directives
+        .named(*INLINE_FRAGMENT_ALIAS_DIRECTIVE_NAME)
+        .and_then(get_literal_alias)
+}
+
+fn get_literal_alias(directive: &Directive) -> Option<StringKey> {
+    directive
+        .arguments
+        .named(*INLINE_FRAGMENT_ALIAS_ARG_NAME)
+        .and_then(|arg| match arg.value.item {
+            Value::Constant(ConstantValue::String(alias)) => Some(alias),
+            _ => None,
+        })
+}
+
+struct InlineFragmentAliasValidator;
+
+impl Validator for InlineFragmentAliasValidator {
+    const NAME: &'static str = "InlineFragmentAliasValidator";
+    const VALIDATE_ARGUMENTS: bool = false;
+    const VALIDATE_DIRECTIVES: bool = false;
+
+    fn validate_inline_fragment(&mut self, fragment: &InlineFragment) -> DiagnosticsResult<()> {
+        if let Some(directive) = fragment
+            .directives
+            .named(*INLINE_FRAGMENT_ALIAS_DIRECTIVE_NAME)
+        {
+            if fragment.type_condition.is_none() {
+                return Err(vec![Diagnostic::error(
+                    ValidationMessage::InlineFragmentAliasWithoutTypeCondition {
+                        directive_name: *INLINE_FRAGMENT_ALIAS_DIRECTIVE_NAME,
+                    },
+                    directive.name.location,
+                )]);
+            }
+            validate_literal_alias(directive)?;
+        }
+        self.default_validate_inline_fragment(fragment)
+    }
+
+    fn validate_fragment_spread(&mut self, spread: &FragmentSpread) -> DiagnosticsResult<()> {
+        if let Some(directive) = spread
+            .directives
+            .named(*INLINE_FRAGMENT_ALIAS_DIRECTIVE_NAME)
+        {
+            validate_literal_alias(directive)?;
+        }
+        Ok(())
+    }
+}
+
+fn validate_literal_alias(directive: &Directive) -> DiagnosticsResult<()> {
+    if get_literal_alias(directive).is_none() {
+        return Err(vec![Diagnostic::error(
+            ValidationMessage::LiteralStringArgumentExpectedForDirective {
+                arg_name: *INLINE_FRAGMENT_ALIAS_ARG_NAME,
+                directive_name: *INLINE_FRAGMENT_ALIAS_DIRECTIVE_NAME,
+            },
+            directive.name.location,
+        )]);
+    }
+    Ok(())
+}
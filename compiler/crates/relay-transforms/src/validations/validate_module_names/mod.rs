@@ -11,6 +11,8 @@ use graphql_syntax::OperationKind;
 
 mod extract_module_name;
 
+pub use extract_module_name::extract_module_name;
+
 pub fn validate_module_names(program: &Program) -> DiagnosticsResult<()> {
     (ValidateModuleNames {}).validate_program(program)
 }
@@ -25,8 +27,7 @@ impl Validator for ValidateModuleNames {
     fn validate_operation(&mut self, operation: &OperationDefinition) -> DiagnosticsResult<()> {
         let operation_name = operation.name.item.to_string();
         let path = operation.name.location.source_location().path();
-        let module_name =
-            extract_module_name::extract_module_name(path).expect("Unable to extract module name.");
+        let module_name = extract_module_name(path).expect("Unable to extract module name.");
         let (operation_type_suffix, pluralized_string) = match operation.kind {
             OperationKind::Query => ("Query", "Queries"),
             OperationKind::Mutation => ("Mutation", "Mutations"),
@@ -58,8 +59,7 @@ impl Validator for ValidateModuleNames {
     fn validate_fragment(&mut self, fragment: &FragmentDefinition) -> DiagnosticsResult<()> {
         let fragment_name = fragment.name.item.to_string();
         let path = fragment.name.location.source_location().path();
-        let module_name =
-            extract_module_name::extract_module_name(path).expect("Unable to extract module name.");
+        let module_name = extract_module_name(path).expect("Unable to extract module name.");
 
         if !fragment_name.starts_with(&module_name) {
             return Err(vec![Diagnostic::error(
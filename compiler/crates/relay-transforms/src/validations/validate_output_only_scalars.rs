@@ -0,0 +1,101 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::{Diagnostic, DiagnosticsResult};
+use fnv::FnvHashSet;
+use graphql_ir::{Program, ValidationMessage};
+use interner::StringKey;
+use schema::{Schema, Type};
+
+/// Validates that scalars configured as output only are not used in the type
+/// of a variable, either directly or as the type of a (nested) field of an
+/// input object. Fragment arguments are variables, too.
+pub fn validate_output_only_scalars(
+    program: &Program,
+    output_only_scalars: &[StringKey],
+) -> DiagnosticsResult<()> {
+    let schema = &program.schema;
+    let output_only_scalars: FnvHashSet<Type> = output_only_scalars
+        .iter()
+        .filter_map(|name| schema.get_type(*name))
+        .filter(|type_| type_.is_scalar())
+        .collect();
+    if output_only_scalars.is_empty() {
+        return Ok(());
+    }
+
+    let variable_definitions = program
+        .operations()
+        .flat_map(|operation| operation.variable_definitions.iter())
+        .chain(
+            program
+                .fragments()
+                .flat_map(|fragment| fragment.variable_definitions.iter()),
+        );
+    let mut errors = Vec::new();
+    for variable_definition in variable_definitions {
+        let variable_type = variable_definition.type_.inner();
+        let mut input_field_path = Vec::new();
+        if let Some(scalar) = find_output_only_scalar(
+            schema,
+            variable_type,
+            &output_only_scalars,
+            &mut input_field_path,
+            &mut Default::default(),
+        ) {
+            if !input_field_path.is_empty() {
+                input_field_path.insert(0, schema.get_type_name(variable_type));
+            }
+            errors.push(Diagnostic::error(
+                ValidationMessage::InputPositionNotAllowedForScalar {
+                    scalar_name: schema.get_type_name(scalar),
+                    variable_name: variable_definition.name.item,
+                    input_field_path,
+                },
+                variable_definition.name.location,
+            ));
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Finds the first output only scalar reachable from `type_` through the
+/// fields of input objects, `input_field_path` is set to the fields leading to
+/// that scalar.
+fn find_output_only_scalar(
+    schema: &Schema,
+    type_: Type,
+    output_only_scalars: &FnvHashSet<Type>,
+    input_field_path: &mut Vec<StringKey>,
+    visited_input_objects: &mut FnvHashSet<Type>,
+) -> Option<Type> {
+    if output_only_scalars.contains(&type_) {
+        return Some(type_);
+    }
+    if let Type::InputObject(id) = type_ {
+        if visited_input_objects.insert(type_) {
+            for field in schema.input_object(id).fields.iter() {
+                input_field_path.push(field.name);
+                if let Some(scalar) = find_output_only_scalar(
+                    schema,
+                    field.type_.inner(),
+                    output_only_scalars,
+                    input_field_path,
+                    visited_input_objects,
+                ) {
+                    return Some(scalar);
+                }
+                input_field_path.pop();
+            }
+        }
+    }
+    None
+}
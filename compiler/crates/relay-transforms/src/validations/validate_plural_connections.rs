@@ -0,0 +1,180 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use crate::connections::{extract_connection_directive, ConnectionConstants};
+use crate::handle_fields::DYNAMIC_KEY_ARG_NAME;
+use crate::relay_directive::{PLURAL_ARG_NAME, RELAY_DIRECTIVE_NAME};
+use common::{Diagnostic, Location, NamedItem};
+use fnv::FnvHashMap;
+use graphql_ir::{
+    ConstantValue, Directive, FragmentDefinition, FragmentSpread, LinkedField, OperationDefinition,
+    Program, Selection, ValidationMessage, Value, Visitor,
+};
+use interner::StringKey;
+
+/// Lints `@connection` fields without a `dynamicKey_UNSTABLE` in fragments
+/// that are used plurally: fragments spread below a list field, and
+/// `@relay(plural: true)` fragments. All items of the list would then share
+/// the same connection key at runtime.
+///
+/// The returned diagnostics are warnings and should not fail the build.
+pub fn validate_plural_connections(program: &Program) -> Vec<Diagnostic> {
+    let mut validator = PluralConnectionValidation::new(program);
+    validator.visit_program(program);
+    validator.warnings
+}
+
+#[derive(Clone, Copy)]
+struct ConnectionWithoutDynamicKey {
+    directive_name: StringKey,
+    field_name: StringKey,
+    location: Location,
+}
+
+struct PluralConnectionValidation<'program> {
+    program: &'program Program,
+    connection_constants: ConnectionConstants,
+    /// The first `@connection` without a dynamic key reachable from each
+    /// fragment, following nested fragment spreads.
+    fragment_connections: FnvHashMap<StringKey, Option<ConnectionWithoutDynamicKey>>,
+    /// Whether the current document is a `@relay(plural: true)` fragment.
+    in_plural_fragment: bool,
+    /// Whether the current selection is nested below a list field.
+    in_list: bool,
+    warnings: Vec<Diagnostic>,
+}
+
+impl<'program> PluralConnectionValidation<'program> {
+    fn new(program: &'program Program) -> Self {
+        Self {
+            program,
+            connection_constants: ConnectionConstants::default(),
+            fragment_connections: Default::default(),
+            in_plural_fragment: false,
+            in_list: false,
+            warnings: Vec::new(),
+        }
+    }
+
+    fn get_connection_without_dynamic_key(
+        &self,
+        field: &LinkedField,
+    ) -> Option<ConnectionWithoutDynamicKey> {
+        let directive = extract_connection_directive(&field.directives, self.connection_constants)?;
+        if directive.arguments.named(*DYNAMIC_KEY_ARG_NAME).is_some() {
+            return None;
+        }
+        Some(ConnectionWithoutDynamicKey {
+            directive_name: directive.name.item,
+            field_name: self.program.schema.field(field.definition.item).name,
+            location: field.alias_or_name_location(),
+        })
+    }
+
+    fn find_fragment_connection(
+        &mut self,
+        fragment_name: StringKey,
+    ) -> Option<ConnectionWithoutDynamicKey> {
+        if let Some(connection) = self.fragment_connections.get(&fragment_name) {
+            return *connection;
+        }
+        // Guard against cycles, these are reported by other validations.
+        self.fragment_connections.insert(fragment_name, None);
+        let program = self.program;
+        let connection = program
+            .fragment(fragment_name)
+            .and_then(|fragment| self.find_connection(&fragment.selections));
+        self.fragment_connections.insert(fragment_name, connection);
+        connection
+    }
+
+    fn find_connection(&mut self, selections: &[Selection]) -> Option<ConnectionWithoutDynamicKey> {
+        selections.iter().find_map(|selection| match selection {
+            Selection::LinkedField(field) => self
+                .get_connection_without_dynamic_key(field)
+                .or_else(|| self.find_connection(&field.selections)),
+            Selection::InlineFragment(fragment) => self.find_connection(&fragment.selections),
+            Selection::Condition(condition) => self.find_connection(&condition.selections),
+            Selection::FragmentSpread(spread) => {
+                self.find_fragment_connection(spread.fragment.item)
+            }
+            Selection::ScalarField(_) => None,
+        })
+    }
+}
+
+fn is_plural_fragment(directives: &[Directive]) -> bool {
+    let plural_arg = directives
+        .named(*RELAY_DIRECTIVE_NAME)
+        .and_then(|directive| directive.arguments.named(*PLURAL_ARG_NAME));
+    matches!(
+        plural_arg.map(|arg| &arg.value.item),
+        Some(Value::Constant(ConstantValue::Boolean(true)))
+    )
+}
+
+impl Visitor for PluralConnectionValidation<'_> {
+    const NAME: &'static str = "PluralConnectionValidation";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_operation(&mut self, operation: &OperationDefinition) {
+        self.in_plural_fragment = false;
+        self.in_list = false;
+        self.default_visit_operation(operation);
+    }
+
+    fn visit_fragment(&mut self, fragment: &FragmentDefinition) {
+        self.in_plural_fragment = is_plural_fragment(&fragment.directives);
+        self.in_list = false;
+        self.default_visit_fragment(fragment);
+    }
+
+    fn visit_linked_field(&mut self, field: &LinkedField) {
+        if self.in_plural_fragment {
+            if let Some(connection) = self.get_connection_without_dynamic_key(field) {
+                self.warnings.push(Diagnostic::warning(
+                    ValidationMessage::PluralConnectionWithoutDynamicKey {
+                        connection_directive_name: connection.directive_name,
+                        connection_field_name: connection.field_name,
+                        dynamic_key_arg_name: *DYNAMIC_KEY_ARG_NAME,
+                    },
+                    connection.location,
+                ));
+            }
+        }
+        let was_in_list = self.in_list;
+        self.in_list = was_in_list
+            || self
+                .program
+                .schema
+                .field(field.definition.item)
+                .type_
+                .is_list();
+        self.default_visit_linked_field(field);
+        self.in_list = was_in_list;
+    }
+
+    fn visit_fragment_spread(&mut self, spread: &FragmentSpread) {
+        if self.in_plural_fragment || self.in_list {
+            if let Some(connection) = self.find_fragment_connection(spread.fragment.item) {
+                self.warnings.push(
+                    Diagnostic::warning(
+                        ValidationMessage::PluralFragmentSpreadWithConnectionWithoutDynamicKey {
+                            fragment_name: spread.fragment.item,
+                            connection_directive_name: connection.directive_name,
+                            connection_field_name: connection.field_name,
+                            dynamic_key_arg_name: *DYNAMIC_KEY_ARG_NAME,
+                        },
+                        spread.fragment.location,
+                    )
+                    .annotate("connection field", connection.location),
+                );
+            }
+        }
+    }
+}
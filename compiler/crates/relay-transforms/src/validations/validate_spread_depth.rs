@@ -0,0 +1,97 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::{Diagnostic, DiagnosticsResult};
+use fnv::FnvHashMap;
+use graphql_ir::{Program, Selection, ValidationMessage};
+use interner::StringKey;
+
+/// Validates that no definition spreads fragments nested deeper than
+/// `max_depth`, following the spreads of spread fragments (including spreads
+/// with `@module`). Each chain is reported once, at the definition where it
+/// starts to exceed the limit, and not again for the definitions spreading it.
+pub fn validate_spread_depth(program: &Program, max_depth: usize) -> DiagnosticsResult<()> {
+    let mut validator = SpreadDepthValidation {
+        program,
+        fragment_chains: Default::default(),
+    };
+    let mut errors = Vec::new();
+    let definitions = program
+        .operations()
+        .map(|operation| (operation.name, &operation.selections))
+        .chain(
+            program
+                .fragments()
+                .map(|fragment| (fragment.name, &fragment.selections)),
+        );
+    for (name, selections) in definitions {
+        let chain = validator.deepest_chain(selections);
+        if chain.len() == max_depth + 1 {
+            let mut full_chain = vec![name.item];
+            full_chain.extend(chain.iter());
+            errors.push(Diagnostic::error(
+                ValidationMessage::FragmentSpreadDepthExceeded {
+                    definition_name: name.item,
+                    max_depth,
+                    chain: full_chain,
+                },
+                name.location,
+            ));
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+struct SpreadDepthValidation<'program> {
+    program: &'program Program,
+    /// The deepest chain of fragment spreads starting at each fragment,
+    /// excluding the fragment itself.
+    fragment_chains: FnvHashMap<StringKey, Vec<StringKey>>,
+}
+
+impl SpreadDepthValidation<'_> {
+    fn fragment_chain(&mut self, fragment_name: StringKey) -> Vec<StringKey> {
+        if let Some(chain) = self.fragment_chains.get(&fragment_name) {
+            return chain.clone();
+        }
+        // Guard against cycles, these are reported by other validations.
+        self.fragment_chains.insert(fragment_name, Vec::new());
+        let program = self.program;
+        let chain = program
+            .fragment(fragment_name)
+            .map_or_else(Vec::new, |fragment| {
+                self.deepest_chain(&fragment.selections)
+            });
+        self.fragment_chains.insert(fragment_name, chain.clone());
+        chain
+    }
+
+    fn deepest_chain(&mut self, selections: &[Selection]) -> Vec<StringKey> {
+        let mut deepest = Vec::new();
+        for selection in selections {
+            let chain = match selection {
+                Selection::FragmentSpread(spread) => {
+                    let mut chain = vec![spread.fragment.item];
+                    chain.extend(self.fragment_chain(spread.fragment.item));
+                    chain
+                }
+                Selection::LinkedField(field) => self.deepest_chain(&field.selections),
+                Selection::InlineFragment(fragment) => self.deepest_chain(&fragment.selections),
+                Selection::Condition(condition) => self.deepest_chain(&condition.selections),
+                Selection::ScalarField(_) => continue,
+            };
+            if chain.len() > deepest.len() {
+                deepest = chain;
+            }
+        }
+        deepest
+    }
+}
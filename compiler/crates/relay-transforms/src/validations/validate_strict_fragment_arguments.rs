@@ -0,0 +1,79 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::{Diagnostic, DiagnosticsResult};
+use fnv::FnvHashSet;
+use graphql_ir::{
+    FragmentDefinition, OperationDefinition, Program, ValidationMessage, Validator, Variable,
+};
+use interner::StringKey;
+
+/// Validates that fragments only reference variables defined in their
+/// `@argumentDefinitions` and not the global variables of the operations
+/// they are used in. Fragments of the base project are not validated.
+pub fn validate_strict_fragment_arguments(
+    program: &Program,
+    base_fragment_names: &FnvHashSet<StringKey>,
+) -> DiagnosticsResult<()> {
+    let mut validator = StrictFragmentArguments {
+        base_fragment_names,
+        current_fragment: None,
+    };
+    validator.validate_program(program)
+}
+
+struct StrictFragmentArguments<'a> {
+    base_fragment_names: &'a FnvHashSet<StringKey>,
+    /// The name and the argument names of the fragment being validated.
+    current_fragment: Option<(StringKey, Vec<StringKey>)>,
+}
+
+impl Validator for StrictFragmentArguments<'_> {
+    const NAME: &'static str = "StrictFragmentArguments";
+    const VALIDATE_ARGUMENTS: bool = true;
+    const VALIDATE_DIRECTIVES: bool = true;
+
+    fn validate_operation(&mut self, _: &OperationDefinition) -> DiagnosticsResult<()> {
+        Ok(())
+    }
+
+    fn validate_fragment(&mut self, fragment: &FragmentDefinition) -> DiagnosticsResult<()> {
+        // `used_global_variables` is inferred when building the IR, only
+        // fragments using global variables need to be visited.
+        if fragment.used_global_variables.is_empty()
+            || self.base_fragment_names.contains(&fragment.name.item)
+        {
+            return Ok(());
+        }
+        self.current_fragment = Some((
+            fragment.name.item,
+            fragment
+                .variable_definitions
+                .iter()
+                .map(|definition| definition.name.item)
+                .collect(),
+        ));
+        let result = self.default_validate_fragment(fragment);
+        self.current_fragment = None;
+        result
+    }
+
+    fn validate_variable(&mut self, variable: &Variable) -> DiagnosticsResult<()> {
+        let (fragment_name, argument_names) = self.current_fragment.as_ref().unwrap();
+        if argument_names.contains(&variable.name.item) {
+            Ok(())
+        } else {
+            Err(vec![Diagnostic::error(
+                ValidationMessage::UndefinedFragmentArgument {
+                    fragment_name: *fragment_name,
+                    variable_name: variable.name.item,
+                },
+                variable.name.location,
+            )])
+        }
+    }
+}
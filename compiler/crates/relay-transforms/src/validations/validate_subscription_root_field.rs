@@ -0,0 +1,109 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::{Diagnostic, DiagnosticsResult, Location};
+use graphql_ir::{
+    FragmentDefinition, OperationDefinition, Program, Selection, ValidationMessage, Validator,
+};
+use graphql_syntax::OperationKind;
+use interner::StringKey;
+
+/// GraphQL requires subscriptions to select a single root field. Fields
+/// selected in inline fragments and fragment spreads at the root count as
+/// root fields too, fields with the same response key are merged into one.
+pub fn validate_subscription_root_field(program: &Program) -> DiagnosticsResult<()> {
+    let mut validator = SubscriptionRootFieldValidation { program };
+    validator.validate_program(program)
+}
+
+struct SubscriptionRootFieldValidation<'program> {
+    program: &'program Program,
+}
+
+impl SubscriptionRootFieldValidation<'_> {
+    /// Collects the response key and location of each root field, in the
+    /// order they're selected.
+    fn collect_root_fields(
+        &self,
+        selections: &[Selection],
+        visited_fragments: &mut Vec<StringKey>,
+        root_fields: &mut Vec<(StringKey, Location)>,
+    ) {
+        let schema = &self.program.schema;
+        for selection in selections {
+            let (response_key, location) = match selection {
+                Selection::ScalarField(field) => {
+                    if field.definition.item == schema.typename_field() {
+                        continue;
+                    }
+                    (field.alias_or_name(schema), field.definition.location)
+                }
+                Selection::LinkedField(field) => {
+                    (field.alias_or_name(schema), field.definition.location)
+                }
+                Selection::InlineFragment(fragment) => {
+                    self.collect_root_fields(&fragment.selections, visited_fragments, root_fields);
+                    continue;
+                }
+                Selection::Condition(condition) => {
+                    self.collect_root_fields(&condition.selections, visited_fragments, root_fields);
+                    continue;
+                }
+                Selection::FragmentSpread(spread) => {
+                    if !visited_fragments.contains(&spread.fragment.item) {
+                        visited_fragments.push(spread.fragment.item);
+                        if let Some(fragment) = self.program.fragment(spread.fragment.item) {
+                            self.collect_root_fields(
+                                &fragment.selections,
+                                visited_fragments,
+                                root_fields,
+                            );
+                        }
+                    }
+                    continue;
+                }
+            };
+            if !root_fields.iter().any(|(key, _)| *key == response_key) {
+                root_fields.push((response_key, location));
+            }
+        }
+    }
+}
+
+impl Validator for SubscriptionRootFieldValidation<'_> {
+    const NAME: &'static str = "validate_subscription_root_field";
+    const VALIDATE_ARGUMENTS: bool = false;
+    const VALIDATE_DIRECTIVES: bool = false;
+
+    fn validate_operation(&mut self, operation: &OperationDefinition) -> DiagnosticsResult<()> {
+        if operation.kind != OperationKind::Subscription {
+            return Ok(());
+        }
+        let mut root_fields = Vec::new();
+        self.collect_root_fields(&operation.selections, &mut Vec::new(), &mut root_fields);
+        if root_fields.len() <= 1 {
+            return Ok(());
+        }
+        let first_field_location = root_fields[0].1;
+        Err(root_fields[1..]
+            .iter()
+            .map(|(_, location)| {
+                Diagnostic::error(
+                    ValidationMessage::SubscriptionMultipleRootFields {
+                        subscription_name: operation.name.item,
+                    },
+                    *location,
+                )
+                .annotate("first root field", first_field_location)
+            })
+            .collect())
+    }
+
+    fn validate_fragment(&mut self, _: &FragmentDefinition) -> DiagnosticsResult<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use crate::refetchable_fragment::CONSTANTS as REFETCHABLE_CONSTANTS;
+use common::{Diagnostic, NamedItem};
+use fnv::FnvHashSet;
+use graphql_ir::{Program, Selection, ValidationMessage};
+use interner::StringKey;
+
+/// Lints fragments that are not reachable from any operation or
+/// `@refetchable` fragment (which gets its own generated query) of the
+/// program. Fragments of the base project are meant to be used by dependent
+/// projects and are never reported.
+///
+/// The returned diagnostics are warnings and should not fail the build.
+pub fn validate_unused_fragments(
+    program: &Program,
+    base_fragment_names: &FnvHashSet<StringKey>,
+) -> Vec<Diagnostic> {
+    let mut reachable_fragments = FnvHashSet::default();
+    let mut stack = Vec::new();
+    for operation in program.operations() {
+        collect_fragment_spreads(&operation.selections, &mut stack);
+    }
+    for fragment in program.fragments() {
+        if fragment
+            .directives
+            .named(REFETCHABLE_CONSTANTS.refetchable_name)
+            .is_some()
+        {
+            stack.push(fragment.name.item);
+        }
+    }
+    while let Some(fragment_name) = stack.pop() {
+        if reachable_fragments.insert(fragment_name) {
+            if let Some(fragment) = program.fragment(fragment_name) {
+                collect_fragment_spreads(&fragment.selections, &mut stack);
+            }
+        }
+    }
+
+    let mut unused_fragments = program
+        .fragments()
+        .filter(|fragment| {
+            !reachable_fragments.contains(&fragment.name.item)
+                && !base_fragment_names.contains(&fragment.name.item)
+        })
+        .collect::<Vec<_>>();
+    unused_fragments.sort_by_key(|fragment| fragment.name.location);
+    unused_fragments
+        .into_iter()
+        .map(|fragment| {
+            Diagnostic::warning(
+                ValidationMessage::UnusedFragment {
+                    fragment_name: fragment.name.item,
+                },
+                fragment.name.location,
+            )
+        })
+        .collect()
+}
+
+fn collect_fragment_spreads(selections: &[Selection], fragment_names: &mut Vec<StringKey>) {
+    for selection in selections {
+        match selection {
+            Selection::FragmentSpread(spread) => fragment_names.push(spread.fragment.item),
+            Selection::LinkedField(field) => {
+                collect_fragment_spreads(&field.selections, fragment_names)
+            }
+            Selection::InlineFragment(fragment) => {
+                collect_fragment_spreads(&fragment.selections, fragment_names)
+            }
+            Selection::Condition(condition) => {
+                collect_fragment_spreads(&condition.selections, fragment_names)
+            }
+            Selection::ScalarField(_) => {}
+        }
+    }
+}
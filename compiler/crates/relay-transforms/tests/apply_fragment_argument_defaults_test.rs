@@ -0,0 +1,20 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<14666b7ea9e40bd71403c4c277d1fe9e>>
+ */
+
+mod apply_fragment_argument_defaults;
+
+use apply_fragment_argument_defaults::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn spread_omits_argument_with_default() {
+    let input = include_str!("apply_fragment_argument_defaults/fixtures/spread-omits-argument-with-default.graphql");
+    let expected = include_str!("apply_fragment_argument_defaults/fixtures/spread-omits-argument-with-default.expected");
+    test_fixture(transform_fixture, "spread-omits-argument-with-default.graphql", "apply_fragment_argument_defaults/fixtures/spread-omits-argument-with-default.expected", input, expected);
+}
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<0dbd89ace1108177b6970f2f1bf82430>>
+ * @generated SignedSource<<ce005ea780c73ab34c5e20d5636c8fda>>
  */
 
 mod declarative_connection;
@@ -54,6 +54,13 @@ fn delete_edge_from_connection() {
     test_fixture(transform_fixture, "delete-edge-from-connection.graphql", "declarative_connection/fixtures/delete-edge-from-connection.expected", input, expected);
 }
 
+#[test]
+fn delete_edge_from_connection_literal() {
+    let input = include_str!("declarative_connection/fixtures/delete-edge-from-connection-literal.graphql");
+    let expected = include_str!("declarative_connection/fixtures/delete-edge-from-connection-literal.expected");
+    test_fixture(transform_fixture, "delete-edge-from-connection-literal.graphql", "declarative_connection/fixtures/delete-edge-from-connection-literal.expected", input, expected);
+}
+
 #[test]
 fn delete_edge_from_connection_on_unsupported_type_invalid() {
     let input = include_str!("declarative_connection/fixtures/delete-edge-from-connection-on-unsupported-type.invalid.graphql");
@@ -68,6 +75,13 @@ fn delete_edge_from_connection_plural() {
     test_fixture(transform_fixture, "delete-edge-from-connection-plural.graphql", "declarative_connection/fixtures/delete-edge-from-connection-plural.expected", input, expected);
 }
 
+#[test]
+fn delete_edge_on_linked_field_invalid() {
+    let input = include_str!("declarative_connection/fixtures/delete-edge-on-linked-field.invalid.graphql");
+    let expected = include_str!("declarative_connection/fixtures/delete-edge-on-linked-field.invalid.expected");
+    test_fixture(transform_fixture, "delete-edge-on-linked-field.invalid.graphql", "declarative_connection/fixtures/delete-edge-on-linked-field.invalid.expected", input, expected);
+}
+
 #[test]
 fn delete_from_store() {
     let input = include_str!("declarative_connection/fixtures/delete-from-store.graphql");
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<fe688696cd2af5dcfdd2c8aedb108888>>
+ * @generated SignedSource<<3f4f3fa595a026b72d8627f5cef3940c>>
  */
 
 mod defer_stream;
@@ -82,6 +82,13 @@ fn fragment_with_stream_duplicate_label_invalid() {
     test_fixture(transform_fixture, "fragment-with-stream-duplicate-label.invalid.graphql", "defer_stream/fixtures/fragment-with-stream-duplicate-label.invalid.expected", input, expected);
 }
 
+#[test]
+fn fragment_with_stream_float_initial_count_invalid() {
+    let input = include_str!("defer_stream/fixtures/fragment-with-stream-float-initial-count.invalid.graphql");
+    let expected = include_str!("defer_stream/fixtures/fragment-with-stream-float-initial-count.invalid.expected");
+    test_fixture(transform_fixture, "fragment-with-stream-float-initial-count.invalid.graphql", "defer_stream/fixtures/fragment-with-stream-float-initial-count.invalid.expected", input, expected);
+}
+
 #[test]
 fn fragment_with_stream_if_arg() {
     let input = include_str!("defer_stream/fixtures/fragment-with-stream-if-arg.graphql");
@@ -103,6 +110,13 @@ fn fragment_with_stream_missing_initial_count_arg_invalid() {
     test_fixture(transform_fixture, "fragment-with-stream-missing-initial-count-arg.invalid.graphql", "defer_stream/fixtures/fragment-with-stream-missing-initial-count-arg.invalid.expected", input, expected);
 }
 
+#[test]
+fn fragment_with_stream_negative_initial_count_invalid() {
+    let input = include_str!("defer_stream/fixtures/fragment-with-stream-negative-initial-count.invalid.graphql");
+    let expected = include_str!("defer_stream/fixtures/fragment-with-stream-negative-initial-count.invalid.expected");
+    test_fixture(transform_fixture, "fragment-with-stream-negative-initial-count.invalid.graphql", "defer_stream/fixtures/fragment-with-stream-negative-initial-count.invalid.expected", input, expected);
+}
+
 #[test]
 fn fragment_with_stream_on_scalar_field_invalid() {
     let input = include_str!("defer_stream/fixtures/fragment-with-stream-on-scalar-field.invalid.graphql");
@@ -158,3 +172,10 @@ fn query_with_stream() {
     let expected = include_str!("defer_stream/fixtures/query-with-stream.expected");
     test_fixture(transform_fixture, "query-with-stream.graphql", "defer_stream/fixtures/query-with-stream.expected", input, expected);
 }
+
+#[test]
+fn query_with_stream_float_variable_initial_count_invalid() {
+    let input = include_str!("defer_stream/fixtures/query-with-stream-float-variable-initial-count.invalid.graphql");
+    let expected = include_str!("defer_stream/fixtures/query-with-stream-float-variable-initial-count.invalid.expected");
+    test_fixture(transform_fixture, "query-with-stream-float-variable-initial-count.invalid.graphql", "defer_stream/fixtures/query-with-stream-float-variable-initial-count.invalid.expected", input, expected);
+}
@@ -12,11 +12,17 @@ use graphql_syntax::parse_executable;
 use graphql_test_helpers::diagnostics_to_sorted_string;
 use relay_test_schema::get_test_schema;
 use relay_transforms::disallow_reserved_aliases;
+use schema::build_schema;
+use std::sync::Arc;
 
 pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let parts: Vec<_> = fixture.content.split("%schema%").collect();
+    let (base, schema) = match parts.as_slice() {
+        [base, schema_sdl] => (*base, Arc::new(build_schema(schema_sdl).unwrap())),
+        _ => (fixture.content, get_test_schema()),
+    };
     let source_location = SourceLocationKey::standalone(fixture.file_name);
-    let schema = get_test_schema();
-    let ast = parse_executable(fixture.content, source_location).unwrap();
+    let ast = parse_executable(base, source_location).unwrap();
     let ir = build(&schema, &ast.definitions).unwrap();
     let program = Program::from_definitions(schema, ir);
     disallow_reserved_aliases(&program)
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<7cea6a16cf3cd6d8bd25cdc373b3b02d>>
+ * @generated SignedSource<<d0b316695f99c6756fb96c6841e63095>>
  */
 
 mod disallow_reserved_aliases;
@@ -26,6 +26,20 @@ fn id_alias_with_errors_invalid() {
     test_fixture(transform_fixture, "id-alias-with-errors.invalid.graphql", "disallow_reserved_aliases/fixtures/id-alias-with-errors.invalid.expected", input, expected);
 }
 
+#[test]
+fn node_id_field_name() {
+    let input = include_str!("disallow_reserved_aliases/fixtures/node-id-field-name.graphql");
+    let expected = include_str!("disallow_reserved_aliases/fixtures/node-id-field-name.expected");
+    test_fixture(transform_fixture, "node-id-field-name.graphql", "disallow_reserved_aliases/fixtures/node-id-field-name.expected", input, expected);
+}
+
+#[test]
+fn node_id_field_name_invalid() {
+    let input = include_str!("disallow_reserved_aliases/fixtures/node-id-field-name.invalid.graphql");
+    let expected = include_str!("disallow_reserved_aliases/fixtures/node-id-field-name.invalid.expected");
+    test_fixture(transform_fixture, "node-id-field-name.invalid.graphql", "disallow_reserved_aliases/fixtures/node-id-field-name.invalid.expected", input, expected);
+}
+
 #[test]
 fn relay_id_alias_with_errors_invalid() {
     let input = include_str!("disallow_reserved_aliases/fixtures/relay_id-alias-with-errors.invalid.graphql");
@@ -39,3 +53,10 @@ fn typename_alias_with_errors_invalid() {
     let expected = include_str!("disallow_reserved_aliases/fixtures/typename-alias-with-errors.invalid.expected");
     test_fixture(transform_fixture, "typename-alias-with-errors.invalid.graphql", "disallow_reserved_aliases/fixtures/typename-alias-with-errors.invalid.expected", input, expected);
 }
+
+#[test]
+fn typename_aliased_to_id_invalid() {
+    let input = include_str!("disallow_reserved_aliases/fixtures/typename-aliased-to-id.invalid.graphql");
+    let expected = include_str!("disallow_reserved_aliases/fixtures/typename-aliased-to-id.invalid.expected");
+    test_fixture(transform_fixture, "typename-aliased-to-id.invalid.graphql", "disallow_reserved_aliases/fixtures/typename-aliased-to-id.invalid.expected", input, expected);
+}
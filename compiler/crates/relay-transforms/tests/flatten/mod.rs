@@ -11,7 +11,7 @@ use graphql_ir::{build, Program};
 use graphql_syntax::parse_executable;
 use graphql_text_printer::{print_fragment, print_operation};
 use relay_test_schema::get_test_schema_with_extensions;
-use relay_transforms::flatten;
+use relay_transforms::flatten_preserving_inline_fragments;
 use std::sync::Arc;
 
 pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
@@ -23,7 +23,17 @@ directive @serverInlineDirective on INLINE_FRAGMENT"#,
     );
     let ir = build(&schema, &ast.definitions).unwrap();
     let context = Program::from_definitions(Arc::clone(&schema), ir);
-    let flatten_context = flatten(&context, !fixture.content.contains("%for_printing%")).unwrap();
+    let preserved_depth = if fixture.content.contains("%preserve_inline_fragments%") {
+        1
+    } else {
+        0
+    };
+    let flatten_context = flatten_preserving_inline_fragments(
+        &context,
+        !fixture.content.contains("%for_printing%"),
+        preserved_depth,
+    )
+    .unwrap();
 
     assert_eq!(
         context.fragments().count(),
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<7c8946ee2a0749e5ba5fb11bae7a13d1>>
+ * @generated SignedSource<<060c3c42163e68f09eb99512c27f4c3e>>
  */
 
 mod flatten;
@@ -96,6 +96,13 @@ fn match_field_overlap() {
     test_fixture(transform_fixture, "match-field-overlap.graphql", "flatten/fixtures/match-field-overlap.expected", input, expected);
 }
 
+#[test]
+fn preserve_inline_fragments() {
+    let input = include_str!("flatten/fixtures/preserve-inline-fragments.graphql");
+    let expected = include_str!("flatten/fixtures/preserve-inline-fragments.expected");
+    test_fixture(transform_fixture, "preserve-inline-fragments.graphql", "flatten/fixtures/preserve-inline-fragments.expected", input, expected);
+}
+
 #[test]
 fn scalar_handle_field() {
     let input = include_str!("flatten/fixtures/scalar-handle-field.graphql");
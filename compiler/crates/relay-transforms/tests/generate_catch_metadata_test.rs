@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<3feb83317b863dfc403ddad1a60054d3>>
+ */
+
+mod generate_catch_metadata;
+
+use generate_catch_metadata::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn mutation_with_catch() {
+    let input = include_str!("generate_catch_metadata/fixtures/mutation-with-catch.graphql");
+    let expected = include_str!("generate_catch_metadata/fixtures/mutation-with-catch.expected");
+    test_fixture(transform_fixture, "mutation-with-catch.graphql", "generate_catch_metadata/fixtures/mutation-with-catch.expected", input, expected);
+}
+
+#[test]
+fn query_with_catch() {
+    let input = include_str!("generate_catch_metadata/fixtures/query-with-catch.graphql");
+    let expected = include_str!("generate_catch_metadata/fixtures/query-with-catch.expected");
+    test_fixture(transform_fixture, "query-with-catch.graphql", "generate_catch_metadata/fixtures/query-with-catch.expected", input, expected);
+}
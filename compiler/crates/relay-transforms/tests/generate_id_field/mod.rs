@@ -5,10 +5,38 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use common::SourceLocationKey;
 use fixture_tests::Fixture;
+use graphql_ir::{build, Program};
+use graphql_syntax::parse_executable;
 use graphql_test_helpers::apply_transform_for_test;
+use graphql_text_printer::{print_fragment, print_operation};
 use relay_transforms::generate_id_field;
+use schema::build_schema;
+use std::sync::Arc;
 
 pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
-    apply_transform_for_test(fixture, |program| Ok(generate_id_field(program)))
+    let parts: Vec<_> = fixture.content.split("%schema%").collect();
+    if let [base, schema_sdl] = parts.as_slice() {
+        let source_location = SourceLocationKey::standalone(fixture.file_name);
+        let ast = parse_executable(base, source_location).unwrap();
+        let schema = Arc::new(build_schema(schema_sdl).unwrap());
+        let ir = build(&schema, &ast.definitions).unwrap();
+        let program = Program::from_definitions(Arc::clone(&schema), ir);
+        let next_program = generate_id_field(&program);
+
+        let mut printed = next_program
+            .operations()
+            .map(|def| print_operation(&schema, def))
+            .chain(
+                next_program
+                    .fragments()
+                    .map(|def| print_fragment(&schema, def)),
+            )
+            .collect::<Vec<_>>();
+        printed.sort();
+        Ok(printed.join("\n\n"))
+    } else {
+        apply_transform_for_test(fixture, |program| Ok(generate_id_field(program)))
+    }
 }
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<a02dd8945cc8a1ed3e941b601a4785c6>>
+ * @generated SignedSource<<b7e606a71155a8cadb56a571d6d4fca5>>
  */
 
 mod generate_id_field;
@@ -19,6 +19,13 @@ fn abstract_plural() {
     test_fixture(transform_fixture, "abstract-plural.graphql", "generate_id_field/fixtures/abstract-plural.expected", input, expected);
 }
 
+#[test]
+fn node_id_field_name() {
+    let input = include_str!("generate_id_field/fixtures/node-id-field-name.graphql");
+    let expected = include_str!("generate_id_field/fixtures/node-id-field-name.expected");
+    test_fixture(transform_fixture, "node-id-field-name.graphql", "generate_id_field/fixtures/node-id-field-name.expected", input, expected);
+}
+
 #[test]
 fn node_union() {
     let input = include_str!("generate_id_field/fixtures/node-union.graphql");
@@ -10,5 +10,8 @@ use graphql_test_helpers::apply_transform_for_test;
 use relay_transforms::generate_typename;
 
 pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
-    apply_transform_for_test(fixture, |program| Ok(generate_typename(program, false)))
+    let skip_selected_typename = fixture.content.contains("%skip_selected_typename%");
+    apply_transform_for_test(fixture, |program| {
+        Ok(generate_typename(program, false, skip_selected_typename))
+    })
 }
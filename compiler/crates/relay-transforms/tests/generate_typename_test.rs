@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<ff111faf561e95f25e383374baf31e15>>
+ * @generated SignedSource<<b81b6123a5389c72ddc8cb8ed180641c>>
  */
 
 mod generate_typename;
@@ -12,6 +12,13 @@ mod generate_typename;
 use generate_typename::transform_fixture;
 use fixture_tests::test_fixture;
 
+#[test]
+fn skip_selected_typename() {
+    let input = include_str!("generate_typename/fixtures/skip-selected-typename.graphql");
+    let expected = include_str!("generate_typename/fixtures/skip-selected-typename.expected");
+    test_fixture(transform_fixture, "skip-selected-typename.graphql", "generate_typename/fixtures/skip-selected-typename.expected", input, expected);
+}
+
 #[test]
 fn type_name_does_not_exist() {
     let input = include_str!("generate_typename/fixtures/type-name-does-not-exist.graphql");
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<8f017f687ee7cc87942c4c22f19d0722>>
+ * @generated SignedSource<<539b57cd6e7d1471c19ec8ee37adb99b>>
  */
 
 mod match_transform;
@@ -19,6 +19,13 @@ fn direct_js_field_invalid() {
     test_fixture(transform_fixture, "direct-js-field.invalid.graphql", "match_transform/fixtures/direct-js-field.invalid.expected", input, expected);
 }
 
+#[test]
+fn duplicate_module_component_invalid() {
+    let input = include_str!("match_transform/fixtures/duplicate-module-component.invalid.graphql");
+    let expected = include_str!("match_transform/fixtures/duplicate-module-component.invalid.expected");
+    test_fixture(transform_fixture, "duplicate-module-component.invalid.graphql", "match_transform/fixtures/duplicate-module-component.invalid.expected", input, expected);
+}
+
 #[test]
 fn invalid_match_type() {
     let input = include_str!("match_transform/fixtures/invalid-match-type.graphql");
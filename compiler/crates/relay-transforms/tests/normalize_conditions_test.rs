@@ -0,0 +1,34 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<5b8cf7fc002707ecc9d7f5855384b9db>>
+ */
+
+mod normalize_conditions;
+
+use normalize_conditions::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn constant_conditions() {
+    let input = include_str!("normalize_conditions/fixtures/constant-conditions.graphql");
+    let expected = include_str!("normalize_conditions/fixtures/constant-conditions.expected");
+    test_fixture(transform_fixture, "constant-conditions.graphql", "normalize_conditions/fixtures/constant-conditions.expected", input, expected);
+}
+
+#[test]
+fn duplicate_conditions() {
+    let input = include_str!("normalize_conditions/fixtures/duplicate-conditions.graphql");
+    let expected = include_str!("normalize_conditions/fixtures/duplicate-conditions.expected");
+    test_fixture(transform_fixture, "duplicate-conditions.graphql", "normalize_conditions/fixtures/duplicate-conditions.expected", input, expected);
+}
+
+#[test]
+fn nested_conditions() {
+    let input = include_str!("normalize_conditions/fixtures/nested-conditions.graphql");
+    let expected = include_str!("normalize_conditions/fixtures/nested-conditions.expected");
+    test_fixture(transform_fixture, "nested-conditions.graphql", "normalize_conditions/fixtures/nested-conditions.expected", input, expected);
+}
@@ -7,14 +7,18 @@
 
 use fixture_tests::Fixture;
 use relay_transforms::{
-    transform_connections, transform_refetchable_fragment, ConnectionInterface,
+    transform_connections, transform_refetchable_fragment, ConnectionInterface, FeatureFlags,
 };
 
 use graphql_test_helpers::apply_transform_for_test;
 
 pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
     apply_transform_for_test(fixture, |program| {
-        let program = transform_connections(&program, &ConnectionInterface::default());
+        let program = transform_connections(
+            program,
+            &ConnectionInterface::default(),
+            &FeatureFlags::default(),
+        )?;
         let base_fragments = Default::default();
         transform_refetchable_fragment(&program, &base_fragments, false)
     })
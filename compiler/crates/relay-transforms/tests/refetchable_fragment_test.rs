@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<88e4f70c7cddc83e027abd8a24cf0fa9>>
+ * @generated SignedSource<<7d884aef705f71e48a98c424801bc812>>
  */
 
 mod refetchable_fragment;
@@ -54,6 +54,13 @@ fn fragment_on_non_node_fetchable_type() {
     test_fixture(transform_fixture, "fragment-on-non-node-fetchable-type.graphql", "refetchable_fragment/fixtures/fragment-on-non-node-fetchable-type.expected", input, expected);
 }
 
+#[test]
+fn fragment_on_non_node_fetchable_type_with_token() {
+    let input = include_str!("refetchable_fragment/fixtures/fragment-on-non-node-fetchable-type-with-token.graphql");
+    let expected = include_str!("refetchable_fragment/fixtures/fragment-on-non-node-fetchable-type-with-token.expected");
+    test_fixture(transform_fixture, "fragment-on-non-node-fetchable-type-with-token.graphql", "refetchable_fragment/fixtures/fragment-on-non-node-fetchable-type-with-token.expected", input, expected);
+}
+
 #[test]
 fn fragment_on_object_implementing_node_interface() {
     let input = include_str!("refetchable_fragment/fixtures/fragment-on-object-implementing-node-interface.graphql");
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<673e108854b42fe5128e69b9ae6d3180>>
+ * @generated SignedSource<<311952f532c171e6bff0652618fc5bf6>>
  */
 
 mod relay_test_operation;
@@ -25,3 +25,10 @@ fn simple_query() {
     let expected = include_str!("relay_test_operation/fixtures/simple-query.expected");
     test_fixture(transform_fixture, "simple-query.graphql", "relay_test_operation/fixtures/simple-query.expected", input, expected);
 }
+
+#[test]
+fn test_operation_on_fragment_invalid() {
+    let input = include_str!("relay_test_operation/fixtures/test-operation-on-fragment.invalid.graphql");
+    let expected = include_str!("relay_test_operation/fixtures/test-operation-on-fragment.invalid.expected");
+    test_fixture(transform_fixture, "test-operation-on-fragment.invalid.graphql", "relay_test_operation/fixtures/test-operation-on-fragment.invalid.expected", input, expected);
+}
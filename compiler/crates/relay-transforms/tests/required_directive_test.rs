@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<040f8e0233fcbda05849800798fc1c65>>
+ * @generated SignedSource<<89985fc220986bb4901bb86e177be752>>
  */
 
 mod required_directive;
@@ -75,6 +75,27 @@ fn duplicate_linked_field_nullable_parent_invalid() {
     test_fixture(transform_fixture, "duplicate-linked-field-nullable-parent.invalid.graphql", "required_directive/fixtures/duplicate-linked-field-nullable-parent.invalid.expected", input, expected);
 }
 
+#[test]
+fn fragment_spread_different_actions_invalid() {
+    let input = include_str!("required_directive/fixtures/fragment-spread-different-actions.invalid.graphql");
+    let expected = include_str!("required_directive/fixtures/fragment-spread-different-actions.invalid.expected");
+    test_fixture(transform_fixture, "fragment-spread-different-actions.invalid.graphql", "required_directive/fixtures/fragment-spread-different-actions.invalid.expected", input, expected);
+}
+
+#[test]
+fn fragment_spread_with_alias() {
+    let input = include_str!("required_directive/fixtures/fragment-spread-with-alias.graphql");
+    let expected = include_str!("required_directive/fixtures/fragment-spread-with-alias.expected");
+    test_fixture(transform_fixture, "fragment-spread-with-alias.graphql", "required_directive/fixtures/fragment-spread-with-alias.expected", input, expected);
+}
+
+#[test]
+fn fragment_spread_without_alias_invalid() {
+    let input = include_str!("required_directive/fixtures/fragment-spread-without-alias.invalid.graphql");
+    let expected = include_str!("required_directive/fixtures/fragment-spread-without-alias.invalid.expected");
+    test_fixture(transform_fixture, "fragment-spread-without-alias.invalid.graphql", "required_directive/fixtures/fragment-spread-without-alias.invalid.expected", input, expected);
+}
+
 #[test]
 fn fragments_are_isolated() {
     let input = include_str!("required_directive/fixtures/fragments-are-isolated.graphql");
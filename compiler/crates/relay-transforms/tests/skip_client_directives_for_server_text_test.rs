@@ -0,0 +1,20 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<66d2367dea091e86d1d416c1dd31ed06>>
+ */
+
+mod skip_client_directives_for_server_text;
+
+use skip_client_directives_for_server_text::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn module_matched_fragment() {
+    let input = include_str!("skip_client_directives_for_server_text/fixtures/module-matched-fragment.graphql");
+    let expected = include_str!("skip_client_directives_for_server_text/fixtures/module-matched-fragment.expected");
+    test_fixture(transform_fixture, "module-matched-fragment.graphql", "skip_client_directives_for_server_text/fixtures/module-matched-fragment.expected", input, expected);
+}
@@ -10,19 +10,25 @@ use fixture_tests::Fixture;
 use graphql_ir::{build, Program};
 use graphql_syntax::parse_executable;
 use graphql_text_printer::print_operation;
+use interner::Intern;
 use relay_test_schema::{get_test_schema, get_test_schema_with_extensions};
 use relay_transforms::{inline_fragments, skip_redundant_nodes};
 use std::sync::Arc;
 
 pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
     let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let preserved_directives = if fixture.content.contains("%preserve_required%") {
+        vec!["required".intern()]
+    } else {
+        vec![]
+    };
     let parts: Vec<_> = fixture.content.split("%extensions%").collect();
     let mut printed = if let [base, extensions] = parts.as_slice() {
         let ast = parse_executable(base, source_location).unwrap();
         let schema = get_test_schema_with_extensions(extensions);
         let ir = build(&schema, &ast.definitions).unwrap();
         let program = Program::from_definitions(Arc::clone(&schema), ir);
-        let next_program = skip_redundant_nodes(&inline_fragments(&program));
+        let next_program = skip_redundant_nodes(&inline_fragments(&program), &preserved_directives);
         next_program
             .operations()
             .map(|def| print_operation(&schema, def))
@@ -32,7 +38,7 @@ pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
         let ast = parse_executable(fixture.content, source_location).unwrap();
         let ir = build(&schema, &ast.definitions).unwrap();
         let program = Program::from_definitions(Arc::clone(&schema), ir);
-        let next_program = skip_redundant_nodes(&inline_fragments(&program));
+        let next_program = skip_redundant_nodes(&inline_fragments(&program), &preserved_directives);
         next_program
             .operations()
             .map(|def| print_operation(&schema, def))
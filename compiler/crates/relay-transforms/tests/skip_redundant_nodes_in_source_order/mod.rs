@@ -0,0 +1,30 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_ir::{build, Program};
+use graphql_syntax::parse_executable;
+use graphql_text_printer::print_operation;
+use relay_test_schema::get_test_schema;
+use relay_transforms::{inline_fragments, skip_redundant_nodes_in_source_order};
+use std::sync::Arc;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let schema = get_test_schema();
+    let ast = parse_executable(fixture.content, source_location).unwrap();
+    let ir = build(&schema, &ast.definitions).unwrap();
+    let program = Program::from_definitions(Arc::clone(&schema), ir);
+    let next_program = skip_redundant_nodes_in_source_order(&inline_fragments(&program), &[]);
+    let mut printed = next_program
+        .operations()
+        .map(|def| print_operation(&schema, def))
+        .collect::<Vec<_>>();
+    printed.sort();
+    Ok(printed.join("\n\n"))
+}
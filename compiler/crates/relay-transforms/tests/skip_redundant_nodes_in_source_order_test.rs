@@ -0,0 +1,20 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<9767b23e4e943175481c0eccc27fa386>>
+ */
+
+mod skip_redundant_nodes_in_source_order;
+
+use skip_redundant_nodes_in_source_order::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn skips_with_outer_fields_last() {
+    let input = include_str!("skip_redundant_nodes_in_source_order/fixtures/skips-with-outer-fields-last.graphql");
+    let expected = include_str!("skip_redundant_nodes_in_source_order/fixtures/skips-with-outer-fields-last.expected");
+    test_fixture(transform_fixture, "skips-with-outer-fields-last.graphql", "skip_redundant_nodes_in_source_order/fixtures/skips-with-outer-fields-last.expected", input, expected);
+}
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<42b533219d939a67a290c896413619b9>>
+ * @generated SignedSource<<ee61e0edb0f0627282078f2734509bda>>
  */
 
 mod skip_redundant_nodes;
@@ -26,6 +26,13 @@ fn dont_skip_nested_fields_across_fragments() {
     test_fixture(transform_fixture, "dont-skip-nested-fields-across-fragments.graphql", "skip_redundant_nodes/fixtures/dont-skip-nested-fields-across-fragments.expected", input, expected);
 }
 
+#[test]
+fn dont_skip_preserved_required_fields() {
+    let input = include_str!("skip_redundant_nodes/fixtures/dont-skip-preserved-required-fields.graphql");
+    let expected = include_str!("skip_redundant_nodes/fixtures/dont-skip-preserved-required-fields.expected");
+    test_fixture(transform_fixture, "dont-skip-preserved-required-fields.graphql", "skip_redundant_nodes/fixtures/dont-skip-preserved-required-fields.expected", input, expected);
+}
+
 #[test]
 fn dont_skip_with_inline_on_diffent_types() {
     let input = include_str!("skip_redundant_nodes/fixtures/dont-skip-with-inline-on-diffent-types.graphql");
@@ -54,6 +61,13 @@ fn skips_nested_fields() {
     test_fixture(transform_fixture, "skips-nested-fields.graphql", "skip_redundant_nodes/fixtures/skips-nested-fields.expected", input, expected);
 }
 
+#[test]
+fn skips_required_fields() {
+    let input = include_str!("skip_redundant_nodes/fixtures/skips-required-fields.graphql");
+    let expected = include_str!("skip_redundant_nodes/fixtures/skips-required-fields.expected");
+    test_fixture(transform_fixture, "skips-required-fields.graphql", "skip_redundant_nodes/fixtures/skips-required-fields.expected", input, expected);
+}
+
 #[test]
 fn skips_with_client_extensions() {
     let input = include_str!("skip_redundant_nodes/fixtures/skips-with-client-extensions.graphql");
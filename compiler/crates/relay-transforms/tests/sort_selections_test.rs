@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<8b9f953ea6234af2285f7267e7ecec0e>>
+ * @generated SignedSource<<090f2b4a04468f9fa711814525ab583a>>
  */
 
 mod sort_selections;
@@ -12,6 +12,13 @@ mod sort_selections;
 use sort_selections::transform_fixture;
 use fixture_tests::test_fixture;
 
+#[test]
+fn sort_directives() {
+    let input = include_str!("sort_selections/fixtures/sort-directives.graphql");
+    let expected = include_str!("sort_selections/fixtures/sort-directives.expected");
+    test_fixture(transform_fixture, "sort-directives.graphql", "sort_selections/fixtures/sort-directives.expected", input, expected);
+}
+
 #[test]
 fn sort_selections_transform() {
     let input = include_str!("sort_selections/fixtures/sort-selections-transform.graphql");
@@ -11,27 +11,55 @@ use graphql_ir::{build, Program};
 use graphql_syntax::parse_executable;
 use graphql_test_helpers::diagnostics_to_sorted_string;
 use graphql_text_printer::{print_fragment, print_operation};
-use relay_test_schema::get_test_schema;
-use relay_transforms::{transform_connections, validate_connections, ConnectionInterface};
+use interner::Intern;
+use relay_test_schema::{get_test_schema, get_test_schema_with_extensions};
+use relay_transforms::{
+    transform_connections, validate_connections, ConnectionInterface, FeatureFlags,
+};
 use std::sync::Arc;
 
 pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
-    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let parts = fixture.content.split("%extensions%").collect::<Vec<_>>();
+    let (source, schema) = match parts.as_slice() {
+        [source, extensions] => (source, get_test_schema_with_extensions(extensions)),
+        [source] => (source, get_test_schema()),
+        _ => panic!(),
+    };
 
-    let schema = get_test_schema();
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
 
-    let ast = parse_executable(fixture.content, source_location).unwrap();
+    let ast = parse_executable(source, source_location).unwrap();
     let ir = build(&schema, &ast.definitions)
-        .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
+        .map_err(|diagnostics| diagnostics_to_sorted_string(source, &diagnostics))?;
 
     let program = Program::from_definitions(Arc::clone(&schema), ir);
 
-    let connection_interface = ConnectionInterface::default();
+    let connection_interface = if fixture.content.contains("%custom_connection_interface%") {
+        ConnectionInterface {
+            edges: "items".intern(),
+            end_cursor: "end_cursor".intern(),
+            has_next_page: "has_next_page".intern(),
+            has_previous_page: "has_previous_page".intern(),
+            page_info: "page_info".intern(),
+            start_cursor: "start_cursor".intern(),
+            ..Default::default()
+        }
+    } else {
+        ConnectionInterface::default()
+    };
+
+    let feature_flags = FeatureFlags {
+        enable_connection_key_generation: fixture
+            .content
+            .contains("%enable_connection_key_generation%"),
+        ..Default::default()
+    };
 
-    validate_connections(&program, &connection_interface)
-        .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
+    validate_connections(&program, &connection_interface, &feature_flags)
+        .map_err(|diagnostics| diagnostics_to_sorted_string(source, &diagnostics))?;
 
-    let next_program = transform_connections(&program, &connection_interface);
+    let next_program = transform_connections(&program, &connection_interface, &feature_flags)
+        .map_err(|diagnostics| diagnostics_to_sorted_string(source, &diagnostics))?;
 
     let mut printed = next_program
         .operations()
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<1579b412cf0ff94712cf0d01c91de8fc>>
+ * @generated SignedSource<<81080bca1e3bc8cc7c072cd27cc17e8c>>
  */
 
 mod transform_connections;
@@ -19,6 +19,20 @@ fn connection() {
     test_fixture(transform_fixture, "connection.graphql", "transform_connections/fixtures/connection.expected", input, expected);
 }
 
+#[test]
+fn connection_backward_page_info() {
+    let input = include_str!("transform_connections/fixtures/connection-backward-page-info.graphql");
+    let expected = include_str!("transform_connections/fixtures/connection-backward-page-info.expected");
+    test_fixture(transform_fixture, "connection-backward-page-info.graphql", "transform_connections/fixtures/connection-backward-page-info.expected", input, expected);
+}
+
+#[test]
+fn connection_custom_interface() {
+    let input = include_str!("transform_connections/fixtures/connection-custom-interface.graphql");
+    let expected = include_str!("transform_connections/fixtures/connection-custom-interface.expected");
+    test_fixture(transform_fixture, "connection-custom-interface.graphql", "transform_connections/fixtures/connection-custom-interface.expected", input, expected);
+}
+
 #[test]
 fn connection_directions() {
     let input = include_str!("transform_connections/fixtures/connection-directions.graphql");
@@ -26,6 +40,20 @@ fn connection_directions() {
     test_fixture(transform_fixture, "connection-directions.graphql", "transform_connections/fixtures/connection-directions.expected", input, expected);
 }
 
+#[test]
+fn connection_display_only() {
+    let input = include_str!("transform_connections/fixtures/connection-display-only.graphql");
+    let expected = include_str!("transform_connections/fixtures/connection-display-only.expected");
+    test_fixture(transform_fixture, "connection-display-only.graphql", "transform_connections/fixtures/connection-display-only.expected", input, expected);
+}
+
+#[test]
+fn connection_duplicate_key_invalid() {
+    let input = include_str!("transform_connections/fixtures/connection-duplicate-key.invalid.graphql");
+    let expected = include_str!("transform_connections/fixtures/connection-duplicate-key.invalid.expected");
+    test_fixture(transform_fixture, "connection-duplicate-key.invalid.graphql", "transform_connections/fixtures/connection-duplicate-key.invalid.expected", input, expected);
+}
+
 #[test]
 fn connection_empty_filters() {
     let input = include_str!("transform_connections/fixtures/connection-empty-filters.graphql");
@@ -33,6 +61,13 @@ fn connection_empty_filters() {
     test_fixture(transform_fixture, "connection-empty-filters.graphql", "transform_connections/fixtures/connection-empty-filters.expected", input, expected);
 }
 
+#[test]
+fn connection_excluded_filters() {
+    let input = include_str!("transform_connections/fixtures/connection-excluded-filters.graphql");
+    let expected = include_str!("transform_connections/fixtures/connection-excluded-filters.expected");
+    test_fixture(transform_fixture, "connection-excluded-filters.graphql", "transform_connections/fixtures/connection-excluded-filters.expected", input, expected);
+}
+
 #[test]
 fn connection_filters() {
     let input = include_str!("transform_connections/fixtures/connection-filters.graphql");
@@ -47,6 +82,55 @@ fn connection_generate_filters() {
     test_fixture(transform_fixture, "connection-generate-filters.graphql", "transform_connections/fixtures/connection-generate-filters.expected", input, expected);
 }
 
+#[test]
+fn connection_generate_next_edges() {
+    let input = include_str!("transform_connections/fixtures/connection-generate-next-edges.graphql");
+    let expected = include_str!("transform_connections/fixtures/connection-generate-next-edges.expected");
+    test_fixture(transform_fixture, "connection-generate-next-edges.graphql", "transform_connections/fixtures/connection-generate-next-edges.expected", input, expected);
+}
+
+#[test]
+fn connection_generated_key() {
+    let input = include_str!("transform_connections/fixtures/connection-generated-key.graphql");
+    let expected = include_str!("transform_connections/fixtures/connection-generated-key.expected");
+    test_fixture(transform_fixture, "connection-generated-key.graphql", "transform_connections/fixtures/connection-generated-key.expected", input, expected);
+}
+
+#[test]
+fn connection_generated_key_collision_invalid() {
+    let input = include_str!("transform_connections/fixtures/connection-generated-key-collision.invalid.graphql");
+    let expected = include_str!("transform_connections/fixtures/connection-generated-key-collision.invalid.expected");
+    test_fixture(transform_fixture, "connection-generated-key-collision.invalid.graphql", "transform_connections/fixtures/connection-generated-key-collision.invalid.expected", input, expected);
+}
+
+#[test]
+fn connection_in_aliased_inline_fragment() {
+    let input = include_str!("transform_connections/fixtures/connection-in-aliased-inline-fragment.graphql");
+    let expected = include_str!("transform_connections/fixtures/connection-in-aliased-inline-fragment.expected");
+    test_fixture(transform_fixture, "connection-in-aliased-inline-fragment.graphql", "transform_connections/fixtures/connection-in-aliased-inline-fragment.expected", input, expected);
+}
+
+#[test]
+fn connection_nested_under_non_node_field() {
+    let input = include_str!("transform_connections/fixtures/connection-nested-under-non-node-field.graphql");
+    let expected = include_str!("transform_connections/fixtures/connection-nested-under-non-node-field.expected");
+    test_fixture(transform_fixture, "connection-nested-under-non-node-field.graphql", "transform_connections/fixtures/connection-nested-under-non-node-field.expected", input, expected);
+}
+
+#[test]
+fn connection_prefetchable() {
+    let input = include_str!("transform_connections/fixtures/connection-prefetchable.graphql");
+    let expected = include_str!("transform_connections/fixtures/connection-prefetchable.expected");
+    test_fixture(transform_fixture, "connection-prefetchable.graphql", "transform_connections/fixtures/connection-prefetchable.expected", input, expected);
+}
+
+#[test]
+fn connection_same_key_different_parents() {
+    let input = include_str!("transform_connections/fixtures/connection-same-key-different-parents.graphql");
+    let expected = include_str!("transform_connections/fixtures/connection-same-key-different-parents.expected");
+    test_fixture(transform_fixture, "connection-same-key-different-parents.graphql", "transform_connections/fixtures/connection-same-key-different-parents.expected", input, expected);
+}
+
 #[test]
 fn connection_with_aliased_edges_page_info() {
     let input = include_str!("transform_connections/fixtures/connection-with-aliased-edges-page-info.graphql");
@@ -61,6 +145,20 @@ fn connection_with_custom_handler() {
     test_fixture(transform_fixture, "connection-with-custom-handler.graphql", "transform_connections/fixtures/connection-with-custom-handler.expected", input, expected);
 }
 
+#[test]
+fn connection_with_edges_in_inline_fragment() {
+    let input = include_str!("transform_connections/fixtures/connection-with-edges-in-inline-fragment.graphql");
+    let expected = include_str!("transform_connections/fixtures/connection-with-edges-in-inline-fragment.expected");
+    test_fixture(transform_fixture, "connection-with-edges-in-inline-fragment.graphql", "transform_connections/fixtures/connection-with-edges-in-inline-fragment.expected", input, expected);
+}
+
+#[test]
+fn connection_with_edges_on_concrete_type() {
+    let input = include_str!("transform_connections/fixtures/connection-with-edges-on-concrete-type.graphql");
+    let expected = include_str!("transform_connections/fixtures/connection-with-edges-on-concrete-type.expected");
+    test_fixture(transform_fixture, "connection-with-edges-on-concrete-type.graphql", "transform_connections/fixtures/connection-with-edges-on-concrete-type.expected", input, expected);
+}
+
 #[test]
 fn connection_with_page_info() {
     let input = include_str!("transform_connections/fixtures/connection-with-page-info.graphql");
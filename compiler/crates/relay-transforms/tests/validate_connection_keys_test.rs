@@ -0,0 +1,34 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<9297a636e519575cc207489e9c43f77d>>
+ */
+
+mod validate_connection_keys;
+
+use validate_connection_keys::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn key_used_by_base_fragment_invalid() {
+    let input = include_str!("validate_connection_keys/fixtures/key-used-by-base-fragment.invalid.graphql");
+    let expected = include_str!("validate_connection_keys/fixtures/key-used-by-base-fragment.invalid.expected");
+    test_fixture(transform_fixture, "key-used-by-base-fragment.invalid.graphql", "validate_connection_keys/fixtures/key-used-by-base-fragment.invalid.expected", input, expected);
+}
+
+#[test]
+fn key_used_by_other_definition_invalid() {
+    let input = include_str!("validate_connection_keys/fixtures/key-used-by-other-definition.invalid.graphql");
+    let expected = include_str!("validate_connection_keys/fixtures/key-used-by-other-definition.invalid.expected");
+    test_fixture(transform_fixture, "key-used-by-other-definition.invalid.graphql", "validate_connection_keys/fixtures/key-used-by-other-definition.invalid.expected", input, expected);
+}
+
+#[test]
+fn unique_keys() {
+    let input = include_str!("validate_connection_keys/fixtures/unique-keys.graphql");
+    let expected = include_str!("validate_connection_keys/fixtures/unique-keys.expected");
+    test_fixture(transform_fixture, "unique-keys.graphql", "validate_connection_keys/fixtures/unique-keys.expected", input, expected);
+}
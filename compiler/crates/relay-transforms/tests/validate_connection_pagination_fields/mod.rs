@@ -0,0 +1,28 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_ir::{build, Program};
+use graphql_syntax::parse_executable;
+use graphql_test_helpers::diagnostics_to_sorted_string;
+use relay_test_schema::get_test_schema;
+use relay_transforms::{validate_connection_pagination_fields, ConnectionInterface};
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let schema = get_test_schema();
+    let ast = parse_executable(fixture.content, source_location).unwrap();
+    let ir = build(&schema, &ast.definitions).unwrap();
+    let program = Program::from_definitions(schema, ir);
+    let warnings = validate_connection_pagination_fields(&program, &ConnectionInterface::default());
+    if warnings.is_empty() {
+        Ok("OK".to_owned())
+    } else {
+        Ok(diagnostics_to_sorted_string(fixture.content, &warnings))
+    }
+}
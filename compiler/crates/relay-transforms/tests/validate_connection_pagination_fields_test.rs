@@ -0,0 +1,41 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<98b3c3f223282d95d7c72815a264ff1b>>
+ */
+
+mod validate_connection_pagination_fields;
+
+use validate_connection_pagination_fields::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn connection_with_edge_cursor() {
+    let input = include_str!("validate_connection_pagination_fields/fixtures/connection-with-edge-cursor.graphql");
+    let expected = include_str!("validate_connection_pagination_fields/fixtures/connection-with-edge-cursor.expected");
+    test_fixture(transform_fixture, "connection-with-edge-cursor.graphql", "validate_connection_pagination_fields/fixtures/connection-with-edge-cursor.expected", input, expected);
+}
+
+#[test]
+fn connection_with_page_info() {
+    let input = include_str!("validate_connection_pagination_fields/fixtures/connection-with-page-info.graphql");
+    let expected = include_str!("validate_connection_pagination_fields/fixtures/connection-with-page-info.expected");
+    test_fixture(transform_fixture, "connection-with-page-info.graphql", "validate_connection_pagination_fields/fixtures/connection-with-page-info.expected", input, expected);
+}
+
+#[test]
+fn connection_without_pagination_fields() {
+    let input = include_str!("validate_connection_pagination_fields/fixtures/connection-without-pagination-fields.graphql");
+    let expected = include_str!("validate_connection_pagination_fields/fixtures/connection-without-pagination-fields.expected");
+    test_fixture(transform_fixture, "connection-without-pagination-fields.graphql", "validate_connection_pagination_fields/fixtures/connection-without-pagination-fields.expected", input, expected);
+}
+
+#[test]
+fn pagination_fields_without_connection() {
+    let input = include_str!("validate_connection_pagination_fields/fixtures/pagination-fields-without-connection.graphql");
+    let expected = include_str!("validate_connection_pagination_fields/fixtures/pagination-fields-without-connection.expected");
+    test_fixture(transform_fixture, "pagination-fields-without-connection.graphql", "validate_connection_pagination_fields/fixtures/pagination-fields-without-connection.expected", input, expected);
+}
@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<267a8bd3f46f69e0af96a1abc249708e>>
+ */
+
+mod validate_connection_parents;
+
+use validate_connection_parents::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn connection_under_object_without_id_invalid() {
+    let input = include_str!("validate_connection_parents/fixtures/connection-under-object-without-id.invalid.graphql");
+    let expected = include_str!("validate_connection_parents/fixtures/connection-under-object-without-id.invalid.expected");
+    test_fixture(transform_fixture, "connection-under-object-without-id.invalid.graphql", "validate_connection_parents/fixtures/connection-under-object-without-id.invalid.expected", input, expected);
+}
+
+#[test]
+fn connection_with_identifiable_parent() {
+    let input = include_str!("validate_connection_parents/fixtures/connection-with-identifiable-parent.graphql");
+    let expected = include_str!("validate_connection_parents/fixtures/connection-with-identifiable-parent.expected");
+    test_fixture(transform_fixture, "connection-with-identifiable-parent.graphql", "validate_connection_parents/fixtures/connection-with-identifiable-parent.expected", input, expected);
+}
@@ -11,7 +11,7 @@ use graphql_ir::{build, Program};
 use graphql_syntax::parse_executable;
 use graphql_test_helpers::diagnostics_to_sorted_string;
 use relay_test_schema::get_test_schema_with_extensions;
-use relay_transforms::{validate_connections, ConnectionInterface};
+use relay_transforms::{validate_connections, ConnectionInterface, FeatureFlags};
 use std::sync::Arc;
 
 pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
@@ -24,8 +24,12 @@ pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
 
         let ir = build(&schema, &ast.definitions).unwrap();
         let program = Program::from_definitions(Arc::clone(&schema), ir);
-        validate_connections(&program, &ConnectionInterface::default())
-            .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
+        validate_connections(
+            &program,
+            &ConnectionInterface::default(),
+            &FeatureFlags::default(),
+        )
+        .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
 
         Ok("OK".to_string())
     } else {
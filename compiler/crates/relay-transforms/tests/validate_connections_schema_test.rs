@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<331cc424c6be82434d3cf79820d1ae3a>>
+ * @generated SignedSource<<0df802b9c000ea59381222e4b68647f7>>
  */
 
 mod validate_connections_schema;
@@ -12,6 +12,13 @@ mod validate_connections_schema;
 use validate_connections_schema::transform_fixture;
 use fixture_tests::test_fixture;
 
+#[test]
+fn connection_display_only_no_page_info() {
+    let input = include_str!("validate_connections_schema/fixtures/connection-display-only-no-page-info.graphql");
+    let expected = include_str!("validate_connections_schema/fixtures/connection-display-only-no-page-info.expected");
+    test_fixture(transform_fixture, "connection-display-only-no-page-info.graphql", "validate_connections_schema/fixtures/connection-display-only-no-page-info.expected", input, expected);
+}
+
 #[test]
 fn connection_invalid_edge_type_invalid() {
     let input = include_str!("validate_connections_schema/fixtures/connection-invalid-edge-type.invalid.graphql");
@@ -46,3 +53,10 @@ fn connection_invalid_page_info_invalid() {
     let expected = include_str!("validate_connections_schema/fixtures/connection-invalid-page-info.invalid.expected");
     test_fixture(transform_fixture, "connection-invalid-page-info.invalid.graphql", "validate_connections_schema/fixtures/connection-invalid-page-info.invalid.expected", input, expected);
 }
+
+#[test]
+fn connection_refetchable_no_page_info_invalid() {
+    let input = include_str!("validate_connections_schema/fixtures/connection-refetchable-no-page-info.invalid.graphql");
+    let expected = include_str!("validate_connections_schema/fixtures/connection-refetchable-no-page-info.invalid.expected");
+    test_fixture(transform_fixture, "connection-refetchable-no-page-info.invalid.graphql", "validate_connections_schema/fixtures/connection-refetchable-no-page-info.invalid.expected", input, expected);
+}
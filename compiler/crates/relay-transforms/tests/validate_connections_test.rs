@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<981e7da5ea873a593f2fe97a963d1810>>
+ * @generated SignedSource<<47a88d525535a7ba1d8eef96cc8a5b76>>
  */
 
 mod validate_connections;
@@ -33,6 +33,27 @@ fn connection_empty_filters() {
     test_fixture(transform_fixture, "connection-empty-filters.graphql", "validate_connections/fixtures/connection-empty-filters.expected", input, expected);
 }
 
+#[test]
+fn connection_excluded_filters() {
+    let input = include_str!("validate_connections/fixtures/connection-excluded-filters.graphql");
+    let expected = include_str!("validate_connections/fixtures/connection-excluded-filters.expected");
+    test_fixture(transform_fixture, "connection-excluded-filters.graphql", "validate_connections/fixtures/connection-excluded-filters.expected", input, expected);
+}
+
+#[test]
+fn connection_excluded_filters_unknown_argument_invalid() {
+    let input = include_str!("validate_connections/fixtures/connection-excluded-filters-unknown-argument.invalid.graphql");
+    let expected = include_str!("validate_connections/fixtures/connection-excluded-filters-unknown-argument.invalid.expected");
+    test_fixture(transform_fixture, "connection-excluded-filters-unknown-argument.invalid.graphql", "validate_connections/fixtures/connection-excluded-filters-unknown-argument.invalid.expected", input, expected);
+}
+
+#[test]
+fn connection_excluded_filters_with_filters_invalid() {
+    let input = include_str!("validate_connections/fixtures/connection-excluded-filters-with-filters.invalid.graphql");
+    let expected = include_str!("validate_connections/fixtures/connection-excluded-filters-with-filters.invalid.expected");
+    test_fixture(transform_fixture, "connection-excluded-filters-with-filters.invalid.graphql", "validate_connections/fixtures/connection-excluded-filters-with-filters.invalid.expected", input, expected);
+}
+
 #[test]
 fn connection_filters() {
     let input = include_str!("validate_connections/fixtures/connection-filters.graphql");
@@ -54,6 +75,13 @@ fn connection_generate_filters() {
     test_fixture(transform_fixture, "connection-generate-filters.graphql", "validate_connections/fixtures/connection-generate-filters.expected", input, expected);
 }
 
+#[test]
+fn connection_invalid_generate_next_edges_invalid() {
+    let input = include_str!("validate_connections/fixtures/connection-invalid-generate-next-edges.invalid.graphql");
+    let expected = include_str!("validate_connections/fixtures/connection-invalid-generate-next-edges.invalid.expected");
+    test_fixture(transform_fixture, "connection-invalid-generate-next-edges.invalid.graphql", "validate_connections/fixtures/connection-invalid-generate-next-edges.invalid.expected", input, expected);
+}
+
 #[test]
 fn connection_invalid_key_name_invalid() {
     let input = include_str!("validate_connections/fixtures/connection-invalid-key-name.invalid.graphql");
@@ -68,6 +96,13 @@ fn connection_invalid_key_type_invalid() {
     test_fixture(transform_fixture, "connection-invalid-key-type.invalid.graphql", "validate_connections/fixtures/connection-invalid-key-type.invalid.expected", input, expected);
 }
 
+#[test]
+fn connection_invalid_prefetchable_invalid() {
+    let input = include_str!("validate_connections/fixtures/connection-invalid-prefetchable.invalid.graphql");
+    let expected = include_str!("validate_connections/fixtures/connection-invalid-prefetchable.invalid.expected");
+    test_fixture(transform_fixture, "connection-invalid-prefetchable.invalid.graphql", "validate_connections/fixtures/connection-invalid-prefetchable.invalid.expected", input, expected);
+}
+
 #[test]
 fn connection_invalid_type_invalid() {
     let input = include_str!("validate_connections/fixtures/connection-invalid-type.invalid.graphql");
@@ -89,6 +124,27 @@ fn connection_missing_first_arg_invalid() {
     test_fixture(transform_fixture, "connection-missing-first-arg.invalid.graphql", "validate_connections/fixtures/connection-missing-first-arg.invalid.expected", input, expected);
 }
 
+#[test]
+fn connection_missing_key_invalid() {
+    let input = include_str!("validate_connections/fixtures/connection-missing-key.invalid.graphql");
+    let expected = include_str!("validate_connections/fixtures/connection-missing-key.invalid.expected");
+    test_fixture(transform_fixture, "connection-missing-key.invalid.graphql", "validate_connections/fixtures/connection-missing-key.invalid.expected", input, expected);
+}
+
+#[test]
+fn connection_nested_under_include() {
+    let input = include_str!("validate_connections/fixtures/connection-nested-under-include.graphql");
+    let expected = include_str!("validate_connections/fixtures/connection-nested-under-include.expected");
+    test_fixture(transform_fixture, "connection-nested-under-include.graphql", "validate_connections/fixtures/connection-nested-under-include.expected", input, expected);
+}
+
+#[test]
+fn connection_next_to_include() {
+    let input = include_str!("validate_connections/fixtures/connection-next-to-include.graphql");
+    let expected = include_str!("validate_connections/fixtures/connection-next-to-include.expected");
+    test_fixture(transform_fixture, "connection-next-to-include.graphql", "validate_connections/fixtures/connection-next-to-include.expected", input, expected);
+}
+
 #[test]
 fn connection_with_aliased_edges_page_info() {
     let input = include_str!("validate_connections/fixtures/connection-with-aliased-edges-page-info.graphql");
@@ -124,6 +180,20 @@ fn connection_with_variables() {
     test_fixture(transform_fixture, "connection-with-variables.graphql", "validate_connections/fixtures/connection-with-variables.expected", input, expected);
 }
 
+#[test]
+fn connection_within_include_invalid() {
+    let input = include_str!("validate_connections/fixtures/connection-within-include.invalid.graphql");
+    let expected = include_str!("validate_connections/fixtures/connection-within-include.invalid.expected");
+    test_fixture(transform_fixture, "connection-within-include.invalid.graphql", "validate_connections/fixtures/connection-within-include.invalid.expected", input, expected);
+}
+
+#[test]
+fn connection_within_skip_invalid() {
+    let input = include_str!("validate_connections/fixtures/connection-within-skip.invalid.graphql");
+    let expected = include_str!("validate_connections/fixtures/connection-within-skip.invalid.expected");
+    test_fixture(transform_fixture, "connection-within-skip.invalid.graphql", "validate_connections/fixtures/connection-within-skip.invalid.expected", input, expected);
+}
+
 #[test]
 fn stream_connection_with_aliased_edges_invalid() {
     let input = include_str!("validate_connections/fixtures/stream-connection-with-aliased-edges.invalid.graphql");
@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<24f7f5d3d0c07c0c055e0f84d77b0fb4>>
+ */
+
+mod validate_dead_selections;
+
+use validate_dead_selections::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn dead_selections() {
+    let input = include_str!("validate_dead_selections/fixtures/dead-selections.graphql");
+    let expected = include_str!("validate_dead_selections/fixtures/dead-selections.expected");
+    test_fixture(transform_fixture, "dead-selections.graphql", "validate_dead_selections/fixtures/dead-selections.expected", input, expected);
+}
+
+#[test]
+fn live_selections() {
+    let input = include_str!("validate_dead_selections/fixtures/live-selections.graphql");
+    let expected = include_str!("validate_dead_selections/fixtures/live-selections.expected");
+    test_fixture(transform_fixture, "live-selections.graphql", "validate_dead_selections/fixtures/live-selections.expected", input, expected);
+}
@@ -0,0 +1,33 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_ir::{build, Program};
+use graphql_syntax::parse_executable;
+use graphql_test_helpers::diagnostics_to_sorted_string;
+use relay_test_schema::{get_test_schema, get_test_schema_with_extensions};
+use relay_transforms::validate_deprecated_fields;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let parts: Vec<_> = fixture.content.split("%extensions%").collect();
+    let (base, schema) = match parts.as_slice() {
+        [base, extensions] => (base, get_test_schema_with_extensions(extensions)),
+        [base] => (base, get_test_schema()),
+        _ => panic!("Invalid fixture input {}", fixture.content),
+    };
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let ast = parse_executable(base, source_location).unwrap();
+    let ir = build(&schema, &ast.definitions).unwrap();
+    let program = Program::from_definitions(schema, ir);
+    let diagnostics = validate_deprecated_fields(&program);
+    if diagnostics.is_empty() {
+        Ok("OK".to_owned())
+    } else {
+        Ok(diagnostics_to_sorted_string(fixture.content, &diagnostics))
+    }
+}
@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<91b8a29b5e9b7e3257793d15f18a97dd>>
+ */
+
+mod validate_deprecated_fields;
+
+use validate_deprecated_fields::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn deprecated_fields() {
+    let input = include_str!("validate_deprecated_fields/fixtures/deprecated-fields.graphql");
+    let expected = include_str!("validate_deprecated_fields/fixtures/deprecated-fields.expected");
+    test_fixture(transform_fixture, "deprecated-fields.graphql", "validate_deprecated_fields/fixtures/deprecated-fields.expected", input, expected);
+}
+
+#[test]
+fn no_deprecated_fields() {
+    let input = include_str!("validate_deprecated_fields/fixtures/no-deprecated-fields.graphql");
+    let expected = include_str!("validate_deprecated_fields/fixtures/no-deprecated-fields.expected");
+    test_fixture(transform_fixture, "no-deprecated-fields.graphql", "validate_deprecated_fields/fixtures/no-deprecated-fields.expected", input, expected);
+}
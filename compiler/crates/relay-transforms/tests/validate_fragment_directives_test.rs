@@ -0,0 +1,34 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<47919fcb2cc4abdb1e03404ab81de7e7>>
+ */
+
+mod validate_fragment_directives;
+
+use validate_fragment_directives::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn compatible_fragment_directives() {
+    let input = include_str!("validate_fragment_directives/fixtures/compatible-fragment-directives.graphql");
+    let expected = include_str!("validate_fragment_directives/fixtures/compatible-fragment-directives.expected");
+    test_fixture(transform_fixture, "compatible-fragment-directives.graphql", "validate_fragment_directives/fixtures/compatible-fragment-directives.expected", input, expected);
+}
+
+#[test]
+fn inline_and_refetchable_invalid() {
+    let input = include_str!("validate_fragment_directives/fixtures/inline-and-refetchable.invalid.graphql");
+    let expected = include_str!("validate_fragment_directives/fixtures/inline-and-refetchable.invalid.expected");
+    test_fixture(transform_fixture, "inline-and-refetchable.invalid.graphql", "validate_fragment_directives/fixtures/inline-and-refetchable.invalid.expected", input, expected);
+}
+
+#[test]
+fn inline_and_unmasked_invalid() {
+    let input = include_str!("validate_fragment_directives/fixtures/inline-and-unmasked.invalid.graphql");
+    let expected = include_str!("validate_fragment_directives/fixtures/inline-and-unmasked.invalid.expected");
+    test_fixture(transform_fixture, "inline-and-unmasked.invalid.graphql", "validate_fragment_directives/fixtures/inline-and-unmasked.invalid.expected", input, expected);
+}
@@ -0,0 +1,48 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<ce1eb5000c1a877716e36a9b1e4f275c>>
+ */
+
+mod validate_inline_fragment_alias;
+
+use validate_inline_fragment_alias::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn fragment_spread_alias() {
+    let input = include_str!("validate_inline_fragment_alias/fixtures/fragment-spread-alias.graphql");
+    let expected = include_str!("validate_inline_fragment_alias/fixtures/fragment-spread-alias.expected");
+    test_fixture(transform_fixture, "fragment-spread-alias.graphql", "validate_inline_fragment_alias/fixtures/fragment-spread-alias.expected", input, expected);
+}
+
+#[test]
+fn fragment_spread_alias_with_variable_invalid() {
+    let input = include_str!("validate_inline_fragment_alias/fixtures/fragment-spread-alias-with-variable.invalid.graphql");
+    let expected = include_str!("validate_inline_fragment_alias/fixtures/fragment-spread-alias-with-variable.invalid.expected");
+    test_fixture(transform_fixture, "fragment-spread-alias-with-variable.invalid.graphql", "validate_inline_fragment_alias/fixtures/fragment-spread-alias-with-variable.invalid.expected", input, expected);
+}
+
+#[test]
+fn inline_fragment_alias() {
+    let input = include_str!("validate_inline_fragment_alias/fixtures/inline-fragment-alias.graphql");
+    let expected = include_str!("validate_inline_fragment_alias/fixtures/inline-fragment-alias.expected");
+    test_fixture(transform_fixture, "inline-fragment-alias.graphql", "validate_inline_fragment_alias/fixtures/inline-fragment-alias.expected", input, expected);
+}
+
+#[test]
+fn inline_fragment_alias_with_variable_invalid() {
+    let input = include_str!("validate_inline_fragment_alias/fixtures/inline-fragment-alias-with-variable.invalid.graphql");
+    let expected = include_str!("validate_inline_fragment_alias/fixtures/inline-fragment-alias-with-variable.invalid.expected");
+    test_fixture(transform_fixture, "inline-fragment-alias-with-variable.invalid.graphql", "validate_inline_fragment_alias/fixtures/inline-fragment-alias-with-variable.invalid.expected", input, expected);
+}
+
+#[test]
+fn inline_fragment_alias_without_type_condition_invalid() {
+    let input = include_str!("validate_inline_fragment_alias/fixtures/inline-fragment-alias-without-type-condition.invalid.graphql");
+    let expected = include_str!("validate_inline_fragment_alias/fixtures/inline-fragment-alias-without-type-condition.invalid.expected");
+    test_fixture(transform_fixture, "inline-fragment-alias-without-type-condition.invalid.graphql", "validate_inline_fragment_alias/fixtures/inline-fragment-alias-without-type-condition.invalid.expected", input, expected);
+}
@@ -0,0 +1,37 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_ir::{build, Program};
+use graphql_syntax::parse_executable;
+use graphql_test_helpers::diagnostics_to_sorted_string;
+use interner::Intern;
+use relay_test_schema::{get_test_schema, get_test_schema_with_extensions};
+use relay_transforms::validate_output_only_scalars;
+
+/// All fixtures are validated with `JSON` configured as output only scalar.
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+
+    let parts: Vec<_> = fixture.content.split("%extensions%").collect();
+    let (base, schema) = match parts.as_slice() {
+        [base, extensions] => (base, get_test_schema_with_extensions(extensions)),
+        [base] => (base, get_test_schema()),
+        _ => panic!("Invalid fixture input {}", fixture.content),
+    };
+
+    let ast = parse_executable(base, source_location).unwrap();
+    let ir = build(&schema, &ast.definitions)
+        .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
+
+    let program = Program::from_definitions(schema, ir);
+    validate_output_only_scalars(&program, &["JSON".intern()])
+        .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
+
+    Ok("OK".to_owned())
+}
@@ -0,0 +1,34 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<501a56a43490f21cdb566fe84585d482>>
+ */
+
+mod validate_output_only_scalars;
+
+use validate_output_only_scalars::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn other_scalar_variables() {
+    let input = include_str!("validate_output_only_scalars/fixtures/other-scalar-variables.graphql");
+    let expected = include_str!("validate_output_only_scalars/fixtures/other-scalar-variables.expected");
+    test_fixture(transform_fixture, "other-scalar-variables.graphql", "validate_output_only_scalars/fixtures/other-scalar-variables.expected", input, expected);
+}
+
+#[test]
+fn output_only_scalar_in_input_object_invalid() {
+    let input = include_str!("validate_output_only_scalars/fixtures/output-only-scalar-in-input-object.invalid.graphql");
+    let expected = include_str!("validate_output_only_scalars/fixtures/output-only-scalar-in-input-object.invalid.expected");
+    test_fixture(transform_fixture, "output-only-scalar-in-input-object.invalid.graphql", "validate_output_only_scalars/fixtures/output-only-scalar-in-input-object.invalid.expected", input, expected);
+}
+
+#[test]
+fn output_only_scalar_variable_invalid() {
+    let input = include_str!("validate_output_only_scalars/fixtures/output-only-scalar-variable.invalid.graphql");
+    let expected = include_str!("validate_output_only_scalars/fixtures/output-only-scalar-variable.invalid.expected");
+    test_fixture(transform_fixture, "output-only-scalar-variable.invalid.graphql", "validate_output_only_scalars/fixtures/output-only-scalar-variable.invalid.expected", input, expected);
+}
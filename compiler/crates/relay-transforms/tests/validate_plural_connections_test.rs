@@ -0,0 +1,48 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<6123e360d9c502648dfcd4de311b04c6>>
+ */
+
+mod validate_plural_connections;
+
+use validate_plural_connections::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn connection_in_plural_fragment() {
+    let input = include_str!("validate_plural_connections/fixtures/connection-in-plural-fragment.graphql");
+    let expected = include_str!("validate_plural_connections/fixtures/connection-in-plural-fragment.expected");
+    test_fixture(transform_fixture, "connection-in-plural-fragment.graphql", "validate_plural_connections/fixtures/connection-in-plural-fragment.expected", input, expected);
+}
+
+#[test]
+fn fragment_spread_in_list_field() {
+    let input = include_str!("validate_plural_connections/fixtures/fragment-spread-in-list-field.graphql");
+    let expected = include_str!("validate_plural_connections/fixtures/fragment-spread-in-list-field.expected");
+    test_fixture(transform_fixture, "fragment-spread-in-list-field.graphql", "validate_plural_connections/fixtures/fragment-spread-in-list-field.expected", input, expected);
+}
+
+#[test]
+fn fragment_spread_in_singular_field() {
+    let input = include_str!("validate_plural_connections/fixtures/fragment-spread-in-singular-field.graphql");
+    let expected = include_str!("validate_plural_connections/fixtures/fragment-spread-in-singular-field.expected");
+    test_fixture(transform_fixture, "fragment-spread-in-singular-field.graphql", "validate_plural_connections/fixtures/fragment-spread-in-singular-field.expected", input, expected);
+}
+
+#[test]
+fn fragment_spread_with_dynamic_key() {
+    let input = include_str!("validate_plural_connections/fixtures/fragment-spread-with-dynamic-key.graphql");
+    let expected = include_str!("validate_plural_connections/fixtures/fragment-spread-with-dynamic-key.expected");
+    test_fixture(transform_fixture, "fragment-spread-with-dynamic-key.graphql", "validate_plural_connections/fixtures/fragment-spread-with-dynamic-key.expected", input, expected);
+}
+
+#[test]
+fn nested_fragment_spread_in_list_field() {
+    let input = include_str!("validate_plural_connections/fixtures/nested-fragment-spread-in-list-field.graphql");
+    let expected = include_str!("validate_plural_connections/fixtures/nested-fragment-spread-in-list-field.expected");
+    test_fixture(transform_fixture, "nested-fragment-spread-in-list-field.graphql", "validate_plural_connections/fixtures/nested-fragment-spread-in-list-field.expected", input, expected);
+}
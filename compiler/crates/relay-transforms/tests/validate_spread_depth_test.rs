@@ -0,0 +1,34 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<2f719bd4d81217e20d7498a86e933f6f>>
+ */
+
+mod validate_spread_depth;
+
+use validate_spread_depth::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn spread_depth_exceeded_invalid() {
+    let input = include_str!("validate_spread_depth/fixtures/spread-depth-exceeded.invalid.graphql");
+    let expected = include_str!("validate_spread_depth/fixtures/spread-depth-exceeded.invalid.expected");
+    test_fixture(transform_fixture, "spread-depth-exceeded.invalid.graphql", "validate_spread_depth/fixtures/spread-depth-exceeded.invalid.expected", input, expected);
+}
+
+#[test]
+fn spread_depth_exceeded_with_module_invalid() {
+    let input = include_str!("validate_spread_depth/fixtures/spread-depth-exceeded-with-module.invalid.graphql");
+    let expected = include_str!("validate_spread_depth/fixtures/spread-depth-exceeded-with-module.invalid.expected");
+    test_fixture(transform_fixture, "spread-depth-exceeded-with-module.invalid.graphql", "validate_spread_depth/fixtures/spread-depth-exceeded-with-module.invalid.expected", input, expected);
+}
+
+#[test]
+fn spread_depth_within_limit() {
+    let input = include_str!("validate_spread_depth/fixtures/spread-depth-within-limit.graphql");
+    let expected = include_str!("validate_spread_depth/fixtures/spread-depth-within-limit.expected");
+    test_fixture(transform_fixture, "spread-depth-within-limit.graphql", "validate_spread_depth/fixtures/spread-depth-within-limit.expected", input, expected);
+}
@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<ed669223a5ae162128108a367f445b9e>>
+ */
+
+mod validate_strict_fragment_arguments;
+
+use validate_strict_fragment_arguments::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn fragment_arguments() {
+    let input = include_str!("validate_strict_fragment_arguments/fixtures/fragment-arguments.graphql");
+    let expected = include_str!("validate_strict_fragment_arguments/fixtures/fragment-arguments.expected");
+    test_fixture(transform_fixture, "fragment-arguments.graphql", "validate_strict_fragment_arguments/fixtures/fragment-arguments.expected", input, expected);
+}
+
+#[test]
+fn global_variables_invalid() {
+    let input = include_str!("validate_strict_fragment_arguments/fixtures/global-variables.invalid.graphql");
+    let expected = include_str!("validate_strict_fragment_arguments/fixtures/global-variables.invalid.expected");
+    test_fixture(transform_fixture, "global-variables.invalid.graphql", "validate_strict_fragment_arguments/fixtures/global-variables.invalid.expected", input, expected);
+}
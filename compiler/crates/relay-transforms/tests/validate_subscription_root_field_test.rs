@@ -0,0 +1,34 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<dacdd40bd9681c96fc40488c40688fc0>>
+ */
+
+mod validate_subscription_root_field;
+
+use validate_subscription_root_field::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn multiple_root_fields_in_inline_fragment_invalid() {
+    let input = include_str!("validate_subscription_root_field/fixtures/multiple-root-fields-in-inline-fragment.invalid.graphql");
+    let expected = include_str!("validate_subscription_root_field/fixtures/multiple-root-fields-in-inline-fragment.invalid.expected");
+    test_fixture(transform_fixture, "multiple-root-fields-in-inline-fragment.invalid.graphql", "validate_subscription_root_field/fixtures/multiple-root-fields-in-inline-fragment.invalid.expected", input, expected);
+}
+
+#[test]
+fn multiple_root_fields_invalid() {
+    let input = include_str!("validate_subscription_root_field/fixtures/multiple-root-fields.invalid.graphql");
+    let expected = include_str!("validate_subscription_root_field/fixtures/multiple-root-fields.invalid.expected");
+    test_fixture(transform_fixture, "multiple-root-fields.invalid.graphql", "validate_subscription_root_field/fixtures/multiple-root-fields.invalid.expected", input, expected);
+}
+
+#[test]
+fn single_root_field() {
+    let input = include_str!("validate_subscription_root_field/fixtures/single-root-field.graphql");
+    let expected = include_str!("validate_subscription_root_field/fixtures/single-root-field.expected");
+    test_fixture(transform_fixture, "single-root-field.graphql", "validate_subscription_root_field/fixtures/single-root-field.expected", input, expected);
+}
@@ -0,0 +1,46 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use fnv::FnvHashSet;
+use graphql_ir::{build, Program};
+use graphql_syntax::parse_executable;
+use graphql_test_helpers::diagnostics_to_sorted_string;
+use relay_test_schema::get_test_schema;
+use relay_transforms::validate_unused_fragments;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    // Definitions after the optional `%base%` marker belong to the base project.
+    let parts: Vec<_> = fixture.content.split("%base%").collect();
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let base_location = SourceLocationKey::standalone("base.graphql");
+    let schema = get_test_schema();
+    let mut definitions = parse_executable(parts[0], source_location)
+        .unwrap()
+        .definitions;
+    let mut base_fragment_names = FnvHashSet::default();
+    if let Some(base_text) = parts.get(1) {
+        let base_definitions = parse_executable(base_text, base_location)
+            .unwrap()
+            .definitions;
+        base_fragment_names.extend(
+            base_definitions
+                .iter()
+                .filter_map(|definition| definition.name()),
+        );
+        definitions.extend(base_definitions);
+    }
+    let ir = build(&schema, &definitions).unwrap();
+    let program = Program::from_definitions(schema, ir);
+    let warnings = validate_unused_fragments(&program, &base_fragment_names);
+    if warnings.is_empty() {
+        Ok("OK".to_owned())
+    } else {
+        Ok(diagnostics_to_sorted_string(parts[0], &warnings))
+    }
+}
@@ -0,0 +1,34 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<1cba3448a8e6b025016e4601ff101166>>
+ */
+
+mod validate_unused_fragments;
+
+use validate_unused_fragments::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn base_fragments() {
+    let input = include_str!("validate_unused_fragments/fixtures/base-fragments.graphql");
+    let expected = include_str!("validate_unused_fragments/fixtures/base-fragments.expected");
+    test_fixture(transform_fixture, "base-fragments.graphql", "validate_unused_fragments/fixtures/base-fragments.expected", input, expected);
+}
+
+#[test]
+fn refetchable_fragment() {
+    let input = include_str!("validate_unused_fragments/fixtures/refetchable-fragment.graphql");
+    let expected = include_str!("validate_unused_fragments/fixtures/refetchable-fragment.expected");
+    test_fixture(transform_fixture, "refetchable-fragment.graphql", "validate_unused_fragments/fixtures/refetchable-fragment.expected", input, expected);
+}
+
+#[test]
+fn unused_fragments() {
+    let input = include_str!("validate_unused_fragments/fixtures/unused-fragments.graphql");
+    let expected = include_str!("validate_unused_fragments/fixtures/unused-fragments.expected");
+    test_fixture(transform_fixture, "unused-fragments.graphql", "validate_unused_fragments/fixtures/unused-fragments.expected", input, expected);
+}
@@ -49,6 +49,29 @@ pub struct TypegenConfig {
     #[serde(default)]
     pub use_import_type_syntax: bool,
 
+    /// # For Typescript type generation
+    /// When set, the generated types are also written to a standalone
+    /// `<Name>.graphql.types.d.ts` declaration file next to each artifact, so
+    /// that type-only consumers can `import type` them from
+    /// `<Name>.graphql.types` without the runtime code.
+    #[serde(default)]
+    pub declaration_files: bool,
+
+    /// # For Typescript type generation
+    /// When set, each enum used by a document is also exported as a
+    /// `{ A: "A", ... } as const` object, so its values can be iterated at
+    /// runtime, and the enum type is derived from the object. The
+    /// `%future added value` is only part of the type. The compiler rejects it
+    /// with Flow and together with `enumModuleSuffix`.
+    #[serde(default)]
+    pub enum_const_objects: bool,
+
+    /// When set, a `<Name>.graphql.mock.json` file with a fully populated
+    /// example response is written next to each operation artifact, to be
+    /// used as a starting point for mock or optimistic responses in tests.
+    #[serde(default)]
+    pub mock_response_files: bool,
+
     /// A map from GraphQL scalar types to a custom JS type, example:
     /// { "Url": "String" }
     #[serde(default)]
@@ -49,6 +49,8 @@ impl Writer for FlowPrinter {
                 self.write_export_type_equals(writer, name, value)
             }
             AST::ExportFragmentList(names) => self.write_export_list(writer, names),
+            // Enum const objects are only generated for TypeScript.
+            AST::ExportEnumConstObject(_, _) => Ok(()),
             AST::ImportFragmentType(types, from) => self.write_import_type(writer, types, from),
             AST::FragmentReference(fragments) => self.write_intersection(
                 writer,
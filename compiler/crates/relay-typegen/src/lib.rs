@@ -11,6 +11,7 @@
 
 mod config;
 mod flow;
+mod mock_response;
 mod typescript;
 mod writer;
 
@@ -27,10 +28,13 @@ use graphql_ir::{
 use indexmap::{map::Entry, IndexMap, IndexSet};
 use interner::{Intern, StringKey};
 use lazy_static::lazy_static;
+pub use mock_response::generate_mock_response;
 use relay_transforms::{
-    extract_refetch_metadata_from_directive, RefetchableDerivedFromMetadata, RelayDirective,
-    CHILDREN_CAN_BUBBLE_METADATA_KEY, CLIENT_EXTENSION_DIRECTIVE_NAME, MATCH_CONSTANTS,
-    REQUIRED_METADATA_KEY,
+    extract_refetch_metadata_from_directive, get_fragment_spread_alias, get_inline_fragment_alias,
+    RefetchableDerivedFromMetadata, RelayDirective, RelayTestOperationMetadata,
+    RelayTestOperationSelectionTypeInfo, CATCH_DIRECTIVE_NAME, CHILDREN_CAN_BUBBLE_METADATA_KEY,
+    CLIENT_EXTENSION_DIRECTIVE_NAME, MATCH_CONSTANTS, REQUIRED_METADATA_KEY,
+    TEST_OPERATION_DIRECTIVE,
 };
 use schema::{EnumID, ScalarID, Schema, Type, TypeReference};
 use std::fmt::Result;
@@ -40,10 +44,17 @@ use writer::{Prop, AST, SPREAD_KEY};
 lazy_static! {
     static ref RAW_RESPONSE_TYPE_DIRECTIVE_NAME: StringKey = "raw_response_type".intern();
     static ref KEY_RAW_RESPONSE: StringKey = "rawResponse".intern();
+    static ref KEY_ENUM_VALUES: StringKey = "enumValues".intern();
+    static ref KEY_NULLABLE: StringKey = "nullable".intern();
+    static ref KEY_PLURAL: StringKey = "plural".intern();
+    static ref KEY_TYPE: StringKey = "type".intern();
     static ref FRAGMENT_PROP_NAME: StringKey = "__fragmentPropName".intern();
     static ref MODULE_COMPONENT: StringKey = "__module_component".intern();
     static ref VARIABLES: StringKey = "variables".intern();
     static ref RESPONSE: StringKey = "response".intern();
+    static ref KEY_OK: StringKey = "ok".intern();
+    static ref KEY_VALUE: StringKey = "value".intern();
+    static ref KEY_ERRORS: StringKey = "errors".intern();
     pub(crate) static ref KEY_DATA: StringKey = "$data".intern();
     pub(crate) static ref KEY_REF_TYPE: StringKey = "$refType".intern();
     pub(crate) static ref KEY_FRAGMENT_REFS: StringKey = "$fragmentRefs".intern();
@@ -61,12 +72,16 @@ macro_rules! write_ast {
     ($self:ident, $ast:expr) => {{ $self.writer.write(&mut $self.result, &$ast) }};
 }
 
+/// Generates the type of the data a fragment reads, along with the opaque
+/// fragment reference types and the `$data` and `$key` types that are passed
+/// to `useFragment`. The `$key` of a plural fragment is an array of fragment
+/// references.
 pub fn generate_fragment_type(
     fragment: &FragmentDefinition,
     schema: &Schema,
     typegen_config: &TypegenConfig,
 ) -> String {
-    let mut generator = TypeGenerator::new(schema, typegen_config);
+    let mut generator = TypeGenerator::new(schema, typegen_config, create_writer(typegen_config));
     generator.generate_fragment_type(fragment).unwrap();
     generator.result
 }
@@ -77,13 +92,55 @@ pub fn generate_operation_type(
     schema: &Schema,
     typegen_config: &TypegenConfig,
 ) -> String {
-    let mut generator = TypeGenerator::new(schema, typegen_config);
+    let mut generator = TypeGenerator::new(schema, typegen_config, create_writer(typegen_config));
     generator
         .generate_operation_type(typegen_operation, normalization_operation)
         .unwrap();
     generator.result
 }
 
+/// Same as `generate_fragment_type`, but printed as the content of a
+/// standalone TypeScript declaration (`.d.ts`) file.
+pub fn generate_fragment_type_declaration(
+    fragment: &FragmentDefinition,
+    schema: &Schema,
+    typegen_config: &TypegenConfig,
+) -> String {
+    let mut generator = TypeGenerator::new(
+        schema,
+        typegen_config,
+        Box::new(TypeScriptPrinter::new_for_declaration_file()),
+    );
+    generator.generate_fragment_type(fragment).unwrap();
+    generator.result
+}
+
+/// Same as `generate_operation_type`, but printed as the content of a
+/// standalone TypeScript declaration (`.d.ts`) file.
+pub fn generate_operation_type_declaration(
+    typegen_operation: &OperationDefinition,
+    normalization_operation: &OperationDefinition,
+    schema: &Schema,
+    typegen_config: &TypegenConfig,
+) -> String {
+    let mut generator = TypeGenerator::new(
+        schema,
+        typegen_config,
+        Box::new(TypeScriptPrinter::new_for_declaration_file()),
+    );
+    generator
+        .generate_operation_type(typegen_operation, normalization_operation)
+        .unwrap();
+    generator.result
+}
+
+fn create_writer(typegen_config: &TypegenConfig) -> Box<dyn Writer> {
+    match &typegen_config.language {
+        TypegenLanguage::Flow => Box::new(FlowPrinter::new()),
+        TypegenLanguage::TypeScript => Box::new(TypeScriptPrinter::new(typegen_config)),
+    }
+}
+
 enum GeneratedInputObject {
     Pending,
     Resolved(AST),
@@ -108,7 +165,11 @@ struct TypeGenerator<'schema, 'config> {
     writer: Box<dyn Writer>,
 }
 impl<'schema, 'config> TypeGenerator<'schema, 'config> {
-    fn new(schema: &'schema Schema, typegen_config: &'config TypegenConfig) -> Self {
+    fn new(
+        schema: &'schema Schema,
+        typegen_config: &'config TypegenConfig,
+        writer: Box<dyn Writer>,
+    ) -> Self {
         Self {
             result: String::new(),
             schema,
@@ -119,14 +180,7 @@ impl<'schema, 'config> TypeGenerator<'schema, 'config> {
             typegen_config,
             match_fields: Default::default(),
             runtime_imports: RuntimeImports::default(),
-            writer: Self::create_writer(typegen_config),
-        }
-    }
-
-    fn create_writer(typegen_config: &TypegenConfig) -> Box<dyn Writer> {
-        match &typegen_config.language {
-            TypegenLanguage::Flow => Box::new(FlowPrinter::new()),
-            TypegenLanguage::TypeScript => Box::new(TypeScriptPrinter::new(typegen_config)),
+            writer,
         }
     }
 
@@ -152,6 +206,14 @@ impl<'schema, 'config> TypeGenerator<'schema, 'config> {
             None => response_type,
         };
 
+        if typegen_operation
+            .directives
+            .named(*CATCH_DIRECTIVE_NAME)
+            .is_some()
+        {
+            response_type = catch_result_type(response_type);
+        }
+
         let raw_response_type = if has_raw_response_type_directive(normalization_operation) {
             let raw_response_selections =
                 self.raw_response_visit_selections(&normalization_operation.selections);
@@ -216,6 +278,25 @@ impl<'schema, 'config> TypeGenerator<'schema, 'config> {
             })
         }
 
+        if typegen_operation
+            .directives
+            .named(*TEST_OPERATION_DIRECTIVE)
+            .is_some()
+        {
+            let selection_type_info_identifier =
+                format!("{}SelectionTypeInfo", typegen_operation.name.item).intern();
+            let selection_type_info = test_operation_metadata_to_babel(
+                RelayTestOperationMetadata::new(self.schema, &normalization_operation.selections),
+            );
+            write_ast!(
+                self,
+                AST::ExportTypeEquals(
+                    selection_type_info_identifier,
+                    Box::from(selection_type_info),
+                )
+            )?;
+        }
+
         write_ast!(
             self,
             AST::ExportTypeEquals(
@@ -379,6 +460,37 @@ impl<'schema, 'config> TypeGenerator<'schema, 'config> {
     ) {
         let name = fragment_spread.fragment.item;
         self.used_fragments.insert(name);
+        if let Some(alias) = get_fragment_spread_alias(fragment_spread) {
+            // The aliased key holds the fragment reference and is null when the
+            // fragment doesn't match, unless its presence is asserted by @required.
+            let fragment_ref = AST::ExactObject(vec![Prop {
+                key: *KEY_FRAGMENT_REFS,
+                read_only: true,
+                optional: false,
+                value: AST::FragmentReference(vec![name]),
+            }]);
+            let value = if fragment_spread
+                .directives
+                .named(*REQUIRED_METADATA_KEY)
+                .is_some()
+            {
+                fragment_ref
+            } else {
+                AST::Nullable(Box::new(fragment_ref))
+            };
+            type_selections.push(TypeSelection {
+                key: alias,
+                schema_name: None,
+                value: Some(value),
+                node_type: None,
+                conditional: false,
+                concrete_type: None,
+                ref_: None,
+                node_selections: None,
+                document_name: None,
+            });
+            return;
+        }
         type_selections.push(TypeSelection {
             key: format!("__fragments_{}", name).intern(),
             schema_name: None,
@@ -444,6 +556,26 @@ impl<'schema, 'config> TypeGenerator<'schema, 'config> {
             });
             return;
         }
+        if let Some(alias) = get_inline_fragment_alias(inline_fragment) {
+            // The aliased selections are exposed like a nullable linked field of
+            // the narrowed type: null whenever the type condition doesn't match.
+            let type_condition = inline_fragment
+                .type_condition
+                .expect("Expected @alias inline fragments to have a type condition");
+            let selections = self.visit_selections(&inline_fragment.selections);
+            type_selections.push(TypeSelection {
+                key: alias,
+                schema_name: None,
+                value: None,
+                node_type: Some(TypeReference::Named(type_condition)),
+                conditional: false,
+                concrete_type: None,
+                ref_: None,
+                node_selections: Some(selections_to_map(selections, true)),
+                document_name: None,
+            });
+            return;
+        }
         let mut selections = self.visit_selections(&inline_fragment.selections);
         if let Some(type_condition) = inline_fragment.type_condition {
             for selection in &mut selections {
@@ -1071,6 +1203,31 @@ impl<'schema, 'config> TypeGenerator<'schema, 'config> {
                         format!("{}{}", enum_type.name, enum_module_suffix).intern()
                     )
                 )?;
+            } else if self.typegen_config.enum_const_objects
+                && matches!(self.typegen_config.language, TypegenLanguage::TypeScript)
+            {
+                write_ast!(
+                    self,
+                    AST::ExportEnumConstObject(
+                        enum_type.name,
+                        enum_type
+                            .values
+                            .iter()
+                            .map(|enum_value| enum_value.value)
+                            .collect(),
+                    )
+                )?;
+                let members = vec![
+                    AST::RawType(
+                        format!("typeof {name}[keyof typeof {name}]", name = enum_type.name)
+                            .intern(),
+                    ),
+                    AST::StringLiteral(*FUTURE_ENUM_VALUE),
+                ];
+                write_ast!(
+                    self,
+                    AST::ExportTypeEquals(enum_type.name, Box::from(AST::Union(members)))
+                )?;
             } else {
                 let mut members: Vec<AST> = enum_type
                     .values
@@ -1334,6 +1491,98 @@ fn get_old_fragment_type_name(name: StringKey) -> StringKey {
     format!("{}$ref", name).intern()
 }
 
+/// The type of the `relayTestingSelectionTypeInfo` metadata of a
+/// `@relay_test_operation`, keyed by the path of each selection, for typed
+/// mock resolvers in tests.
+fn test_operation_metadata_to_babel(metadata: RelayTestOperationMetadata) -> AST {
+    AST::ExactObject(
+        metadata
+            .selection_type_info
+            .into_iter()
+            .map(|(path, type_info)| Prop {
+                key: format!("\"{}\"", path).intern(),
+                read_only: true,
+                optional: false,
+                value: selection_type_info_to_babel(type_info),
+            })
+            .collect(),
+    )
+}
+
+fn selection_type_info_to_babel(type_info: RelayTestOperationSelectionTypeInfo) -> AST {
+    let boolean_literal = |value: bool| AST::RawType(value.to_string().intern());
+    let enum_values = match type_info.enum_values {
+        Some(enum_values) => AST::ReadOnlyArray(Box::new(AST::Union(
+            enum_values
+                .iter()
+                .map(|enum_value| AST::StringLiteral(enum_value.value))
+                .collect(),
+        ))),
+        None => AST::RawType("null".intern()),
+    };
+    AST::ExactObject(vec![
+        Prop {
+            key: *KEY_ENUM_VALUES,
+            read_only: true,
+            optional: false,
+            value: enum_values,
+        },
+        Prop {
+            key: *KEY_NULLABLE,
+            read_only: true,
+            optional: false,
+            value: boolean_literal(type_info.nullable),
+        },
+        Prop {
+            key: *KEY_PLURAL,
+            read_only: true,
+            optional: false,
+            value: boolean_literal(type_info.plural),
+        },
+        Prop {
+            key: *KEY_TYPE,
+            read_only: true,
+            optional: false,
+            value: AST::StringLiteral(type_info.type_),
+        },
+    ])
+}
+
+/// The response of an operation with `@catch`: either the data of the
+/// operation or the errors that were caught.
+fn catch_result_type(response_type: AST) -> AST {
+    AST::Union(vec![
+        AST::ExactObject(vec![
+            Prop {
+                key: *KEY_OK,
+                read_only: true,
+                optional: false,
+                value: AST::RawType("true".intern()),
+            },
+            Prop {
+                key: *KEY_VALUE,
+                read_only: true,
+                optional: false,
+                value: response_type,
+            },
+        ]),
+        AST::ExactObject(vec![
+            Prop {
+                key: *KEY_OK,
+                read_only: true,
+                optional: false,
+                value: AST::RawType("false".intern()),
+            },
+            Prop {
+                key: *KEY_ERRORS,
+                read_only: true,
+                optional: false,
+                value: AST::ReadOnlyArray(Box::new(AST::Any)),
+            },
+        ]),
+    ])
+}
+
 fn has_raw_response_type_directive(operation: &OperationDefinition) -> bool {
     operation
         .directives
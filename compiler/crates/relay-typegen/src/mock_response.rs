@@ -0,0 +1,197 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use graphql_ir::{LinkedField, OperationDefinition, ScalarField, Selection};
+use schema::{Schema, Type, TypeReference};
+use serde_json::{Map, Value};
+
+/// Builds a fully populated example response for the normalization selections
+/// of an operation, as JSON. Every field is selected with a placeholder value
+/// for its type: lists contain a single item, IDs are unique per response,
+/// enums use their first value, and abstract types are resolved to a default
+/// concrete type, which is also the value of `__typename`. Conditional
+/// selections are always included.
+pub fn generate_mock_response(operation: &OperationDefinition, schema: &Schema) -> String {
+    let mut generator = MockResponseGenerator { schema, next_id: 1 };
+    let data = generator.build_object(&operation.selections, operation.type_);
+    serde_json::to_string_pretty(&data).unwrap()
+}
+
+struct MockResponseGenerator<'schema> {
+    schema: &'schema Schema,
+    next_id: usize,
+}
+
+impl MockResponseGenerator<'_> {
+    fn build_object(&mut self, selections: &[Selection], type_: Type) -> Value {
+        let concrete_type = self.get_concrete_type(selections, type_);
+        let mut object = Map::new();
+        self.build_selections(&mut object, selections, concrete_type);
+        Value::Object(object)
+    }
+
+    fn build_selections(
+        &mut self,
+        object: &mut Map<String, Value>,
+        selections: &[Selection],
+        concrete_type: Type,
+    ) {
+        for selection in selections {
+            match selection {
+                Selection::ScalarField(field) => {
+                    let key = field.alias_or_name(self.schema).lookup().to_string();
+                    if !object.contains_key(&key) {
+                        let value = self.build_scalar_field(field, concrete_type);
+                        object.insert(key, value);
+                    }
+                }
+                Selection::LinkedField(field) => {
+                    let key = field.alias_or_name(self.schema).lookup().to_string();
+                    let value = self.build_linked_field(field);
+                    merge_value(object, key, value);
+                }
+                Selection::InlineFragment(fragment) => {
+                    let matches_type = fragment.type_condition.is_none_or(|type_condition| {
+                        self.schema
+                            .are_overlapping_types(type_condition, concrete_type)
+                    });
+                    if matches_type {
+                        self.build_selections(object, &fragment.selections, concrete_type);
+                    }
+                }
+                Selection::Condition(condition) => {
+                    self.build_selections(object, &condition.selections, concrete_type);
+                }
+                Selection::FragmentSpread(_) => {}
+            }
+        }
+    }
+
+    fn build_scalar_field(&mut self, field: &ScalarField, concrete_type: Type) -> Value {
+        if field.definition.item == self.schema.typename_field() {
+            return Value::String(
+                self.schema
+                    .get_type_name(concrete_type)
+                    .lookup()
+                    .to_string(),
+            );
+        }
+        let field_type = &self.schema.field(field.definition.item).type_;
+        self.build_list_or_item(field_type, |generator| {
+            generator.build_scalar(field_type.inner(), concrete_type)
+        })
+    }
+
+    fn build_linked_field(&mut self, field: &LinkedField) -> Value {
+        let field_type = &self.schema.field(field.definition.item).type_;
+        self.build_list_or_item(field_type, |generator| {
+            generator.build_object(&field.selections, field_type.inner())
+        })
+    }
+
+    fn build_list_or_item(
+        &mut self,
+        type_: &TypeReference,
+        build_item: impl FnOnce(&mut Self) -> Value,
+    ) -> Value {
+        match type_ {
+            TypeReference::NonNull(of) => self.build_list_or_item(of, build_item),
+            TypeReference::List(of) => Value::Array(vec![self.build_list_or_item(of, build_item)]),
+            TypeReference::Named(_) => build_item(self),
+        }
+    }
+
+    fn build_scalar(&mut self, type_: Type, concrete_parent_type: Type) -> Value {
+        match type_ {
+            Type::Enum(id) => self
+                .schema
+                .enum_(id)
+                .values
+                .first()
+                .map_or(Value::Null, |value| {
+                    Value::String(value.value.lookup().to_string())
+                }),
+            Type::Scalar(_) => {
+                let type_name = self.schema.get_type_name(type_).lookup();
+                match type_name {
+                    "ID" => {
+                        let id = format!(
+                            "{}-{}",
+                            self.schema.get_type_name(concrete_parent_type),
+                            self.next_id
+                        );
+                        self.next_id += 1;
+                        Value::String(id)
+                    }
+                    "String" => Value::String("".to_string()),
+                    "Int" => Value::from(0),
+                    "Float" => Value::from(0.0),
+                    "Boolean" => Value::Bool(false),
+                    _ => Value::String(format!("<{}>", type_name)),
+                }
+            }
+            _ => Value::Null,
+        }
+    }
+
+    /// Abstract types are resolved to the first object type selected with an
+    /// inline fragment, or else to the implementation with the first name to
+    /// keep the output stable.
+    fn get_concrete_type(&self, selections: &[Selection], type_: Type) -> Type {
+        let selected_object_type = selections.iter().find_map(|selection| match selection {
+            Selection::InlineFragment(fragment) => match fragment.type_condition {
+                Some(type_condition @ Type::Object(_))
+                    if self.schema.are_overlapping_types(type_condition, type_) =>
+                {
+                    Some(type_condition)
+                }
+                _ => None,
+            },
+            _ => None,
+        });
+        if let Some(selected_object_type) = selected_object_type {
+            return selected_object_type;
+        }
+        let objects = match type_ {
+            Type::Interface(id) => &self.schema.interface(id).implementing_objects,
+            Type::Union(id) => &self.schema.union(id).members,
+            _ => return type_,
+        };
+        objects
+            .iter()
+            .map(|id| Type::Object(*id))
+            .min_by_key(|type_| self.schema.get_type_name(*type_).lookup())
+            .unwrap_or(type_)
+    }
+}
+
+/// The same linked field can be selected multiple times, e.g. in different
+/// inline fragments, the selections of all of them are merged.
+fn merge_value(object: &mut Map<String, Value>, key: String, value: Value) {
+    match (object.get_mut(&key), value) {
+        (Some(Value::Object(existing)), Value::Object(next)) => {
+            for (key, value) in next {
+                merge_value(existing, key, value);
+            }
+        }
+        (Some(Value::Array(existing)), Value::Array(next)) => {
+            for (existing_item, next_item) in existing.iter_mut().zip(next) {
+                if let (Value::Object(existing_item), Value::Object(next_item)) =
+                    (existing_item, next_item)
+                {
+                    for (key, value) in next_item {
+                        merge_value(existing_item, key, value);
+                    }
+                }
+            }
+        }
+        (Some(_), _) => {}
+        (None, value) => {
+            object.insert(key, value);
+        }
+    }
+}
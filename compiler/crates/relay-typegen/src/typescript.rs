@@ -13,6 +13,7 @@ use std::fmt::{Result, Write};
 
 pub struct TypeScriptPrinter {
     use_import_type_syntax: bool,
+    is_declaration_file: bool,
     indentation: usize,
 }
 
@@ -45,6 +46,9 @@ impl Writer for TypeScriptPrinter {
                 self.write_export_type_equals(writer, name, value)
             }
             AST::FragmentReference(fragments) => self.write_fragment_references(writer, fragments),
+            AST::ExportEnumConstObject(name, values) => {
+                self.write_export_enum_const_object(writer, name, values)
+            }
 
             // In Typescript, we don't export & import fragments. We just use the generic FragmentRefs type instead.
             AST::ExportFragmentList(_) => Ok(()),
@@ -59,7 +63,25 @@ impl TypeScriptPrinter {
         Self {
             indentation: 0,
             use_import_type_syntax: config.use_import_type_syntax,
+            is_declaration_file: false,
+        }
+    }
+
+    /// Printer for standalone `.d.ts` files: all definitions are ambient
+    /// (`declare`) and imports are type-only, so no runtime code is emitted.
+    pub fn new_for_declaration_file() -> Self {
+        Self {
+            indentation: 0,
+            use_import_type_syntax: true,
+            is_declaration_file: true,
+        }
+    }
+
+    fn write_declare(&mut self, writer: &mut dyn Write) -> Result {
+        if self.is_declaration_file {
+            write!(writer, "declare ")?;
         }
+        Ok(())
     }
 
     fn write_indentation(&mut self, writer: &mut dyn Write) -> Result {
@@ -231,6 +253,7 @@ impl TypeScriptPrinter {
         name: &StringKey,
         value: &AST,
     ) -> Result {
+        self.write_declare(writer)?;
         write!(writer, "type {} = ", name)?;
         self.write(writer, value)?;
         writeln!(writer, ";")
@@ -242,10 +265,42 @@ impl TypeScriptPrinter {
         name: &StringKey,
         value: &AST,
     ) -> Result {
-        write!(writer, "export type {} = ", name)?;
+        write!(writer, "export ")?;
+        self.write_declare(writer)?;
+        write!(writer, "type {} = ", name)?;
         self.write(writer, value)?;
         writeln!(writer, ";")
     }
+
+    /// Declaration files can't contain the object itself, only the type of
+    /// the constant.
+    fn write_export_enum_const_object(
+        &mut self,
+        writer: &mut dyn Write,
+        name: &StringKey,
+        values: &[StringKey],
+    ) -> Result {
+        if self.is_declaration_file {
+            write!(writer, "export declare const {}: ", name)?;
+            let props = values
+                .iter()
+                .map(|value| Prop {
+                    key: *value,
+                    read_only: true,
+                    optional: false,
+                    value: AST::StringLiteral(*value),
+                })
+                .collect::<Vec<_>>();
+            self.write_object(writer, &props, true)?;
+            writeln!(writer, ";")
+        } else {
+            writeln!(writer, "export const {} = {{", name)?;
+            for value in values {
+                writeln!(writer, "  {}: \"{}\",", value, value)?;
+            }
+            writeln!(writer, "}} as const;")
+        }
+    }
 }
 
 #[cfg(test)]
@@ -265,6 +320,37 @@ mod tests {
         result
     }
 
+    #[test]
+    fn declaration_file_exports() {
+        let mut result = String::new();
+        let mut printer = TypeScriptPrinter::new_for_declaration_file();
+        printer
+            .write(
+                &mut result,
+                &AST::ImportType(vec!["FragmentRefs".intern()], "relay-runtime".intern()),
+            )
+            .unwrap();
+        printer
+            .write(
+                &mut result,
+                &AST::DefineType("Local".intern(), Box::new(AST::Any)),
+            )
+            .unwrap();
+        printer
+            .write(
+                &mut result,
+                &AST::ExportTypeEquals("Exported".intern(), Box::new(AST::String)),
+            )
+            .unwrap();
+        assert_eq!(
+            result,
+            r#"import type { FragmentRefs } from "relay-runtime";
+declare type Local = any;
+export declare type Exported = string;
+"#
+        );
+    }
+
     #[test]
     fn scalar_types() {
         assert_eq!(print_type(&AST::Any), "any".to_string());
@@ -34,6 +34,9 @@ pub enum AST {
     DeclareExportFragment(StringKey, Option<StringKey>),
     ExportFragmentList(Vec<StringKey>),
     ExportTypeEquals(StringKey, Box<AST>),
+    /// An exported `{ A: "A", ... } as const` object with the values of an
+    /// enum, TypeScript only.
+    ExportEnumConstObject(StringKey, Vec<StringKey>),
 }
 
 #[derive(Debug, Clone)]
@@ -11,7 +11,7 @@ use fnv::FnvHashMap;
 use graphql_ir::{build, Program};
 use graphql_syntax::parse_executable;
 use interner::Intern;
-use relay_compiler::apply_transforms;
+use relay_compiler::{apply_transforms, config::ProjectConfig};
 use relay_test_schema::{get_test_schema, get_test_schema_with_extensions};
 use relay_transforms::{ConnectionInterface, FeatureFlags};
 use relay_typegen::{self, TypegenConfig, TypegenLanguage};
@@ -33,13 +33,13 @@ pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
     let ir = build(&schema, &ast.definitions).unwrap();
     let program = Program::from_definitions(Arc::clone(&schema), ir);
     let programs = apply_transforms(
-        "test".intern(),
+        &ProjectConfig::default(),
         Arc::new(program),
         Default::default(),
         &ConnectionInterface::default(),
         Arc::new(FeatureFlags {
-            enable_flight_transform: false,
             enable_required_transform_for_prefix: Some("".intern()),
+            ..Default::default()
         }),
         Arc::new(ConsoleLogger),
     )
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<ad059f9f315399ee827d4acd7926edfe>>
+ * @generated SignedSource<<9696e92a61c6577ef6534c61c8c52678>>
  */
 
 mod generate_flow;
@@ -33,6 +33,13 @@ fn inline_fragment() {
     test_fixture(transform_fixture, "inline-fragment.graphql", "generate_flow/fixtures/inline-fragment.expected", input, expected);
 }
 
+#[test]
+fn inline_fragment_alias() {
+    let input = include_str!("generate_flow/fixtures/inline-fragment-alias.graphql");
+    let expected = include_str!("generate_flow/fixtures/inline-fragment-alias.expected");
+    test_fixture(transform_fixture, "inline-fragment-alias.graphql", "generate_flow/fixtures/inline-fragment-alias.expected", input, expected);
+}
+
 #[test]
 fn linked_field() {
     let input = include_str!("generate_flow/fixtures/linked-field.graphql");
@@ -103,6 +110,20 @@ fn plural_fragment() {
     test_fixture(transform_fixture, "plural-fragment.graphql", "generate_flow/fixtures/plural-fragment.expected", input, expected);
 }
 
+#[test]
+fn plural_fragment_with_required() {
+    let input = include_str!("generate_flow/fixtures/plural-fragment-with-required.graphql");
+    let expected = include_str!("generate_flow/fixtures/plural-fragment-with-required.expected");
+    test_fixture(transform_fixture, "plural-fragment-with-required.graphql", "generate_flow/fixtures/plural-fragment-with-required.expected", input, expected);
+}
+
+#[test]
+fn query_with_catch() {
+    let input = include_str!("generate_flow/fixtures/query-with-catch.graphql");
+    let expected = include_str!("generate_flow/fixtures/query-with-catch.expected");
+    test_fixture(transform_fixture, "query-with-catch.graphql", "generate_flow/fixtures/query-with-catch.expected", input, expected);
+}
+
 #[test]
 fn query_with_handles() {
     let input = include_str!("generate_flow/fixtures/query-with-handles.graphql");
@@ -187,6 +208,13 @@ fn relay_client_id_field() {
     test_fixture(transform_fixture, "relay-client-id-field.graphql", "generate_flow/fixtures/relay-client-id-field.expected", input, expected);
 }
 
+#[test]
+fn relay_test_operation() {
+    let input = include_str!("generate_flow/fixtures/relay-test-operation.graphql");
+    let expected = include_str!("generate_flow/fixtures/relay-test-operation.expected");
+    test_fixture(transform_fixture, "relay-test-operation.graphql", "generate_flow/fixtures/relay-test-operation.expected", input, expected);
+}
+
 #[test]
 fn required() {
     let input = include_str!("generate_flow/fixtures/required.graphql");
@@ -201,6 +229,13 @@ fn required_bubbles_through_inline_fragments_to_fragment() {
     test_fixture(transform_fixture, "required-bubbles-through-inline-fragments-to-fragment.graphql", "generate_flow/fixtures/required-bubbles-through-inline-fragments-to-fragment.expected", input, expected);
 }
 
+#[test]
+fn required_bubbles_to_caught_query() {
+    let input = include_str!("generate_flow/fixtures/required-bubbles-to-caught-query.graphql");
+    let expected = include_str!("generate_flow/fixtures/required-bubbles-to-caught-query.expected");
+    test_fixture(transform_fixture, "required-bubbles-to-caught-query.graphql", "generate_flow/fixtures/required-bubbles-to-caught-query.expected", input, expected);
+}
+
 #[test]
 fn required_bubbles_to_fragment() {
     let input = include_str!("generate_flow/fixtures/required-bubbles-to-fragment.graphql");
@@ -271,6 +306,13 @@ fn required_isolates_concrete_inline_fragments() {
     test_fixture(transform_fixture, "required-isolates-concrete-inline-fragments.graphql", "generate_flow/fixtures/required-isolates-concrete-inline-fragments.expected", input, expected);
 }
 
+#[test]
+fn required_on_aliased_fragment_spread() {
+    let input = include_str!("generate_flow/fixtures/required-on-aliased-fragment-spread.graphql");
+    let expected = include_str!("generate_flow/fixtures/required-on-aliased-fragment-spread.expected");
+    test_fixture(transform_fixture, "required-on-aliased-fragment-spread.graphql", "generate_flow/fixtures/required-on-aliased-fragment-spread.expected", input, expected);
+}
+
 #[test]
 fn required_raw_response_type() {
     let input = include_str!("generate_flow/fixtures/required-raw-response-type.graphql");
@@ -292,6 +334,13 @@ fn required_throw_doesnt_bubbles_to_query() {
     test_fixture(transform_fixture, "required-throw-doesnt-bubbles-to-query.graphql", "generate_flow/fixtures/required-throw-doesnt-bubbles-to-query.expected", input, expected);
 }
 
+#[test]
+fn required_throw_in_caught_query() {
+    let input = include_str!("generate_flow/fixtures/required-throw-in-caught-query.graphql");
+    let expected = include_str!("generate_flow/fixtures/required-throw-in-caught-query.expected");
+    test_fixture(transform_fixture, "required-throw-in-caught-query.graphql", "generate_flow/fixtures/required-throw-in-caught-query.expected", input, expected);
+}
+
 #[test]
 fn required_throws_nested() {
     let input = include_str!("generate_flow/fixtures/required-throws-nested.graphql");
@@ -0,0 +1,50 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::{ConsoleLogger, SourceLocationKey};
+use fixture_tests::Fixture;
+use graphql_ir::{build, Program};
+use graphql_syntax::parse_executable;
+use interner::Intern;
+use relay_compiler::{apply_transforms, config::ProjectConfig};
+use relay_test_schema::get_test_schema;
+use relay_transforms::{ConnectionInterface, FeatureFlags};
+use std::sync::Arc;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let schema = get_test_schema();
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let ast = parse_executable(fixture.content, source_location).unwrap();
+    let ir = build(&schema, &ast.definitions).unwrap();
+    let program = Program::from_definitions(Arc::clone(&schema), ir);
+    let programs = apply_transforms(
+        &ProjectConfig::default(),
+        Arc::new(program),
+        Default::default(),
+        &ConnectionInterface::default(),
+        Arc::new(FeatureFlags {
+            enable_required_transform_for_prefix: Some("".intern()),
+            ..Default::default()
+        }),
+        Arc::new(ConsoleLogger),
+    )
+    .unwrap();
+
+    let mut operations: Vec<_> = programs.normalization.operations().collect();
+    operations.sort_by_key(|operation| operation.name.item);
+    Ok(operations
+        .into_iter()
+        .map(|operation| {
+            format!(
+                "{}:\n{}\n",
+                operation.name.item,
+                relay_typegen::generate_mock_response(operation, &schema)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("-------------------------------------------------------------------------------\n"))
+}
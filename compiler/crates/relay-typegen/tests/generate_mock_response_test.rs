@@ -0,0 +1,20 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<5aa95ef0ec0ab8a0657de77d2c40b055>>
+ */
+
+mod generate_mock_response;
+
+use generate_mock_response::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn mock_response() {
+    let input = include_str!("generate_mock_response/fixtures/mock-response.graphql");
+    let expected = include_str!("generate_mock_response/fixtures/mock-response.expected");
+    test_fixture(transform_fixture, "mock-response.graphql", "generate_mock_response/fixtures/mock-response.expected", input, expected);
+}
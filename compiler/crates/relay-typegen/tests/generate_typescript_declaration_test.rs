@@ -0,0 +1,48 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<8fb6d10e09c26dca163234f377047789>>
+ */
+
+mod generate_typescript_declaration;
+
+use generate_typescript_declaration::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn enum_const_objects() {
+    let input = include_str!("generate_typescript_declaration/fixtures/enum-const-objects.graphql");
+    let expected = include_str!("generate_typescript_declaration/fixtures/enum-const-objects.expected");
+    test_fixture(transform_fixture, "enum-const-objects.graphql", "generate_typescript_declaration/fixtures/enum-const-objects.expected", input, expected);
+}
+
+#[test]
+fn fragment_spread() {
+    let input = include_str!("generate_typescript_declaration/fixtures/fragment-spread.graphql");
+    let expected = include_str!("generate_typescript_declaration/fixtures/fragment-spread.expected");
+    test_fixture(transform_fixture, "fragment-spread.graphql", "generate_typescript_declaration/fixtures/fragment-spread.expected", input, expected);
+}
+
+#[test]
+fn mutation_with_enums_on_fragment() {
+    let input = include_str!("generate_typescript_declaration/fixtures/mutation-with-enums-on-fragment.graphql");
+    let expected = include_str!("generate_typescript_declaration/fixtures/mutation-with-enums-on-fragment.expected");
+    test_fixture(transform_fixture, "mutation-with-enums-on-fragment.graphql", "generate_typescript_declaration/fixtures/mutation-with-enums-on-fragment.expected", input, expected);
+}
+
+#[test]
+fn plural_fragment() {
+    let input = include_str!("generate_typescript_declaration/fixtures/plural-fragment.graphql");
+    let expected = include_str!("generate_typescript_declaration/fixtures/plural-fragment.expected");
+    test_fixture(transform_fixture, "plural-fragment.graphql", "generate_typescript_declaration/fixtures/plural-fragment.expected", input, expected);
+}
+
+#[test]
+fn simple() {
+    let input = include_str!("generate_typescript_declaration/fixtures/simple.graphql");
+    let expected = include_str!("generate_typescript_declaration/fixtures/simple.expected");
+    test_fixture(transform_fixture, "simple.graphql", "generate_typescript_declaration/fixtures/simple.expected", input, expected);
+}
@@ -11,6 +11,8 @@
 
 mod print_fb_schema;
 mod print_schema;
+mod print_schema_json;
 
 pub use print_fb_schema::*;
 pub use print_schema::*;
+pub use print_schema_json::print_schema_json;
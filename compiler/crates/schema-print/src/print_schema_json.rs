@@ -0,0 +1,204 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use schema::*;
+use serde_json::{json, Value};
+
+/// Serializes the schema to JSON, similar to the result of an introspection
+/// query. Types, directives, fields and possible types are sorted by name so
+/// that the output is stable for the same schema, independent of the order of
+/// the schema and extension sources. Arguments and enum values keep their
+/// order from the SDL.
+pub fn print_schema_json(schema: &Schema) -> String {
+    let mut types = schema
+        .get_type_map()
+        .map(|(_, type_)| *type_)
+        .collect::<Vec<_>>();
+    types.sort_by_key(|type_| schema.get_type_name(*type_).lookup());
+    let mut directives = schema.get_directives().collect::<Vec<_>>();
+    directives.sort_by_key(|directive| directive.name.lookup());
+
+    let value = json!({
+        "queryType": root_type_name(schema, schema.query_type()),
+        "mutationType": root_type_name(schema, schema.mutation_type()),
+        "subscriptionType": root_type_name(schema, schema.subscription_type()),
+        "directives": directives
+            .into_iter()
+            .map(|directive| json!({
+                "name": directive.name.lookup(),
+                "locations": directive
+                    .locations
+                    .iter()
+                    .map(|location| location.to_string())
+                    .collect::<Vec<_>>(),
+                "repeatable": directive.repeatable,
+                "isExtension": directive.is_extension,
+                "args": arguments_json(schema, &directive.arguments),
+            }))
+            .collect::<Vec<_>>(),
+        "types": types
+            .into_iter()
+            .map(|type_| type_json(schema, type_))
+            .collect::<Vec<_>>(),
+    });
+    let mut result = serde_json::to_string_pretty(&value).unwrap();
+    result.push('\n');
+    result
+}
+
+fn root_type_name(schema: &Schema, type_: Option<Type>) -> Value {
+    match type_ {
+        Some(type_) => json!(schema.get_type_name(type_).lookup()),
+        None => Value::Null,
+    }
+}
+
+fn type_json(schema: &Schema, type_: Type) -> Value {
+    match type_ {
+        Type::Scalar(id) => {
+            let scalar = schema.scalar(id);
+            json!({
+                "kind": "SCALAR",
+                "name": scalar.name.lookup(),
+                "isExtension": scalar.is_extension,
+                "directives": directive_values_json(&scalar.directives),
+            })
+        }
+        Type::Object(id) => {
+            let object = schema.object(id);
+            json!({
+                "kind": "OBJECT",
+                "name": object.name.lookup(),
+                "isExtension": object.is_extension,
+                "interfaces": interface_names(schema, &object.interfaces),
+                "fields": fields_json(schema, &object.fields),
+                "directives": directive_values_json(&object.directives),
+            })
+        }
+        Type::Interface(id) => {
+            let interface = schema.interface(id);
+            json!({
+                "kind": "INTERFACE",
+                "name": interface.name.lookup(),
+                "isExtension": interface.is_extension,
+                "interfaces": interface_names(schema, &interface.interfaces),
+                "possibleTypes": object_names(schema, &interface.implementing_objects),
+                "fields": fields_json(schema, &interface.fields),
+                "directives": directive_values_json(&interface.directives),
+            })
+        }
+        Type::Union(id) => {
+            let union = schema.union(id);
+            json!({
+                "kind": "UNION",
+                "name": union.name.lookup(),
+                "isExtension": union.is_extension,
+                "possibleTypes": object_names(schema, &union.members),
+                "directives": directive_values_json(&union.directives),
+            })
+        }
+        Type::Enum(id) => {
+            let enum_ = schema.enum_(id);
+            json!({
+                "kind": "ENUM",
+                "name": enum_.name.lookup(),
+                "isExtension": enum_.is_extension,
+                "enumValues": enum_
+                    .values
+                    .iter()
+                    .map(|value| json!({
+                        "name": value.value.lookup(),
+                        "directives": directive_values_json(&value.directives),
+                    }))
+                    .collect::<Vec<_>>(),
+                "directives": directive_values_json(&enum_.directives),
+            })
+        }
+        Type::InputObject(id) => {
+            let input_object = schema.input_object(id);
+            json!({
+                "kind": "INPUT_OBJECT",
+                "name": input_object.name.lookup(),
+                "inputFields": arguments_json(schema, &input_object.fields),
+                "directives": directive_values_json(&input_object.directives),
+            })
+        }
+    }
+}
+
+fn fields_json(schema: &Schema, field_ids: &[FieldID]) -> Vec<Value> {
+    let mut fields = field_ids
+        .iter()
+        .map(|id| schema.field(*id))
+        .collect::<Vec<_>>();
+    fields.sort_by_key(|field| field.name.lookup());
+    fields
+        .into_iter()
+        .map(|field| {
+            json!({
+                "name": field.name.lookup(),
+                "type": schema.get_type_string(&field.type_),
+                "isExtension": field.is_extension,
+                "args": arguments_json(schema, &field.arguments),
+                "directives": directive_values_json(&field.directives),
+            })
+        })
+        .collect()
+}
+
+fn arguments_json(schema: &Schema, arguments: &ArgumentDefinitions) -> Vec<Value> {
+    arguments
+        .iter()
+        .map(|argument| {
+            json!({
+                "name": argument.name.lookup(),
+                "type": schema.get_type_string(&argument.type_),
+                "defaultValue": argument
+                    .default_value
+                    .as_ref()
+                    .map(|value| value.to_string()),
+            })
+        })
+        .collect()
+}
+
+fn directive_values_json(directives: &[DirectiveValue]) -> Vec<Value> {
+    directives
+        .iter()
+        .map(|directive| {
+            json!({
+                "name": directive.name.lookup(),
+                "args": directive
+                    .arguments
+                    .iter()
+                    .map(|argument| json!({
+                        "name": argument.name.lookup(),
+                        "value": argument.value.to_string(),
+                    }))
+                    .collect::<Vec<_>>(),
+            })
+        })
+        .collect()
+}
+
+fn interface_names(schema: &Schema, interfaces: &[InterfaceID]) -> Vec<&'static str> {
+    let mut names = interfaces
+        .iter()
+        .map(|id| schema.interface(*id).name.lookup())
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+    names
+}
+
+fn object_names(schema: &Schema, objects: &[ObjectID]) -> Vec<&'static str> {
+    let mut names = objects
+        .iter()
+        .map(|id| schema.object(*id).name.lookup())
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+    names
+}
@@ -0,0 +1,20 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<ffd2a9631e8f18708e779cc275d346a4>>
+ */
+
+mod print_schema_json;
+
+use print_schema_json::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn schema() {
+    let input = include_str!("print_schema_json/fixtures/schema.graphql");
+    let expected = include_str!("print_schema_json/fixtures/schema.expected");
+    test_fixture(transform_fixture, "schema.graphql", "print_schema_json/fixtures/schema.expected", input, expected);
+}
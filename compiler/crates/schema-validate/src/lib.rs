@@ -7,6 +7,7 @@
 
 mod errors;
 
+use common::{Diagnostic, DiagnosticsResult, Location, SourceLocationKey};
 use errors::*;
 use fnv::{FnvHashMap, FnvHashSet};
 use interner::{Intern, StringKey};
@@ -14,8 +15,8 @@ use lazy_static::lazy_static;
 use rayon::prelude::*;
 use regex::Regex;
 use schema::{
-    EnumID, Field, FieldID, InputObjectID, Interface, Schema, Type, TypeReference, TypeWithFields,
-    UnionID,
+    build_schema_with_extensions, EnumID, Field, FieldID, InputObjectID, Interface, Schema,
+    SchemaConfig, Type, TypeReference, TypeWithFields, UnionID,
 };
 use schema_print::{print_directive, print_type};
 use std::time::Instant;
@@ -46,6 +47,26 @@ pub fn validate(schema: &Schema) -> ValidationContext<'_> {
     validation_context
 }
 
+/// Builds the schema of the server SDLs and validates it, e.g. to lint a
+/// schema before adding it to a project. Returns the errors of building the
+/// schema, like references to undefined types, or else the validation errors.
+pub fn validate_schema<T: AsRef<str>>(sdls: &[(T, SourceLocationKey)]) -> DiagnosticsResult<()> {
+    let schema = build_schema_with_extensions::<_, &str>(sdls, &[], &SchemaConfig::default())?;
+    let mut errors = validate(&schema).errors.into_inner().unwrap();
+    let mut contexts: Vec<_> = errors.keys().copied().collect();
+    contexts.sort_by_key(|context| context.type_name());
+    let diagnostics = contexts
+        .into_iter()
+        .flat_map(|context| errors.remove(&context).unwrap())
+        .map(|error| Diagnostic::error(error, Location::generated()))
+        .collect::<Vec<_>>();
+    if diagnostics.is_empty() {
+        Ok(())
+    } else {
+        Err(diagnostics)
+    }
+}
+
 #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum ValidationContextType {
     TypeNode(StringKey),
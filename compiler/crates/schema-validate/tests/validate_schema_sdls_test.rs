@@ -0,0 +1,34 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<af0d32005daa8ed1e2922e2a99ed3857>>
+ */
+
+mod validate_schema_sdls;
+
+use validate_schema_sdls::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn unimplemented_interface_field_invalid() {
+    let input = include_str!("validate_schema_sdls/fixtures/unimplemented-interface-field.invalid.graphql");
+    let expected = include_str!("validate_schema_sdls/fixtures/unimplemented-interface-field.invalid.expected");
+    test_fixture(transform_fixture, "unimplemented-interface-field.invalid.graphql", "validate_schema_sdls/fixtures/unimplemented-interface-field.invalid.expected", input, expected);
+}
+
+#[test]
+fn unknown_type_in_field_invalid() {
+    let input = include_str!("validate_schema_sdls/fixtures/unknown-type-in-field.invalid.graphql");
+    let expected = include_str!("validate_schema_sdls/fixtures/unknown-type-in-field.invalid.expected");
+    test_fixture(transform_fixture, "unknown-type-in-field.invalid.graphql", "validate_schema_sdls/fixtures/unknown-type-in-field.invalid.expected", input, expected);
+}
+
+#[test]
+fn valid_schema() {
+    let input = include_str!("validate_schema_sdls/fixtures/valid-schema.graphql");
+    let expected = include_str!("validate_schema_sdls/fixtures/valid-schema.expected");
+    test_fixture(transform_fixture, "valid-schema.graphql", "validate_schema_sdls/fixtures/valid-schema.expected", input, expected);
+}
@@ -6,6 +6,7 @@
  */
 
 use crate::errors::SchemaError;
+use crate::schema_config::SchemaConfig;
 use common::{Diagnostic, DiagnosticsResult, Location, Named, NamedItem};
 use graphql_syntax::*;
 use interner::{Intern, StringKey};
@@ -672,6 +673,7 @@ impl Schema {
     pub fn build(
         schema_definitions: &[graphql_syntax::TypeSystemDefinition],
         client_definitions: &[graphql_syntax::TypeSystemDefinition],
+        schema_config: &SchemaConfig,
     ) -> DiagnosticsResult<Self> {
         // Step 1: build the type_map from type names to type keys
         let mut type_map =
@@ -734,7 +736,7 @@ impl Schema {
                 }
                 TypeSystemDefinition::ObjectTypeExtension { .. } => {}
                 TypeSystemDefinition::InterfaceTypeExtension { .. } => {}
-                TypeSystemDefinition::SchemaExtension { .. } => todo!("SchemaExtension"),
+                TypeSystemDefinition::SchemaExtension { .. } => {}
                 TypeSystemDefinition::EnumTypeExtension { .. } => todo!("EnumTypeExtension"),
                 TypeSystemDefinition::UnionTypeExtension { .. } => todo!("UnionTypeExtension"),
                 TypeSystemDefinition::InputObjectTypeExtension { .. } => {
@@ -808,36 +810,38 @@ impl Schema {
             }
         }
 
-        schema.load_defaults();
+        schema.load_defaults(schema_config);
 
         Ok(schema)
     }
 
-    pub fn load_defaults(&mut self) {
-        self.load_default_root_types();
+    pub fn load_defaults(&mut self, schema_config: &SchemaConfig) {
+        self.load_default_root_types(schema_config);
         self.load_default_typename_field();
         self.load_default_fetch_token_field();
         self.load_default_clientid_field();
     }
 
     // In case the schema doesn't define a query, mutation or subscription
-    // type, but there is a Query, Mutation, or Subscription object type
-    // defined, default to those.
+    // type, but there is an object type with the root type name from the
+    // schema config (Query, Mutation, or Subscription by default) defined,
+    // default to those.
     // This is not standard GraphQL behavior, and we might want to remove
     // this at some point.
-    fn load_default_root_types(&mut self) {
+    fn load_default_root_types(&mut self, schema_config: &SchemaConfig) {
         if self.query_type.is_none() {
-            if let Some(Type::Object(id)) = self.type_map.get(&"Query".intern()) {
+            if let Some(Type::Object(id)) = self.type_map.get(&schema_config.query_type_name) {
                 self.query_type = Some(*id);
             }
         }
         if self.mutation_type.is_none() {
-            if let Some(Type::Object(id)) = self.type_map.get(&"Mutation".intern()) {
+            if let Some(Type::Object(id)) = self.type_map.get(&schema_config.mutation_type_name) {
                 self.mutation_type = Some(*id);
             }
         }
         if self.subscription_type.is_none() {
-            if let Some(Type::Object(id)) = self.type_map.get(&"Subscription".intern()) {
+            if let Some(Type::Object(id)) = self.type_map.get(&schema_config.subscription_type_name)
+            {
                 self.subscription_type = Some(*id);
             }
         }
@@ -895,50 +899,7 @@ impl Schema {
                 operation_types,
                 directives: _directives,
             }) => {
-                for OperationTypeDefinition { operation, type_ } in &operation_types.items {
-                    let operation_id = self.build_object_id(type_.value)?;
-                    match operation {
-                        OperationType::Query => {
-                            if let Some(prev_query_type) = self.query_type {
-                                return todo_add_location(
-                                    SchemaError::DuplicateOperationDefinition(
-                                        *operation,
-                                        type_.value,
-                                        self.object(prev_query_type).name,
-                                    ),
-                                );
-                            } else {
-                                self.query_type = Some(operation_id);
-                            }
-                        }
-                        OperationType::Mutation => {
-                            if let Some(prev_mutation_type) = self.mutation_type {
-                                return todo_add_location(
-                                    SchemaError::DuplicateOperationDefinition(
-                                        *operation,
-                                        type_.value,
-                                        self.object(prev_mutation_type).name,
-                                    ),
-                                );
-                            } else {
-                                self.mutation_type = Some(operation_id);
-                            }
-                        }
-                        OperationType::Subscription => {
-                            if let Some(prev_subscription_type) = self.subscription_type {
-                                return todo_add_location(
-                                    SchemaError::DuplicateOperationDefinition(
-                                        *operation,
-                                        type_.value,
-                                        self.object(prev_subscription_type).name,
-                                    ),
-                                );
-                            } else {
-                                self.subscription_type = Some(operation_id);
-                            }
-                        }
-                    }
-                }
+                self.add_operation_types(&operation_types.items)?;
             }
             TypeSystemDefinition::DirectiveDefinition(DirectiveDefinition {
                 name,
@@ -1163,7 +1124,14 @@ impl Schema {
                     return todo_add_location(SchemaError::ExtendUndefinedType(name.value));
                 }
             },
-            TypeSystemDefinition::SchemaExtension { .. } => todo!("SchemaExtension"),
+            TypeSystemDefinition::SchemaExtension(SchemaExtension {
+                operation_types,
+                directives: _directives,
+            }) => {
+                if let Some(operation_types) = operation_types {
+                    self.add_operation_types(&operation_types.items)?;
+                }
+            }
             TypeSystemDefinition::EnumTypeExtension { .. } => todo!("EnumTypeExtension"),
             TypeSystemDefinition::UnionTypeExtension { .. } => todo!("UnionTypeExtension"),
             TypeSystemDefinition::InputObjectTypeExtension { .. } => {
@@ -1174,6 +1142,51 @@ impl Schema {
         Ok(())
     }
 
+    fn add_operation_types(
+        &mut self,
+        operation_types: &[OperationTypeDefinition],
+    ) -> DiagnosticsResult<()> {
+        for OperationTypeDefinition { operation, type_ } in operation_types {
+            let operation_id = self.build_object_id(type_.value)?;
+            match operation {
+                OperationType::Query => {
+                    if let Some(prev_query_type) = self.query_type {
+                        return todo_add_location(SchemaError::DuplicateOperationDefinition(
+                            *operation,
+                            type_.value,
+                            self.object(prev_query_type).name,
+                        ));
+                    } else {
+                        self.query_type = Some(operation_id);
+                    }
+                }
+                OperationType::Mutation => {
+                    if let Some(prev_mutation_type) = self.mutation_type {
+                        return todo_add_location(SchemaError::DuplicateOperationDefinition(
+                            *operation,
+                            type_.value,
+                            self.object(prev_mutation_type).name,
+                        ));
+                    } else {
+                        self.mutation_type = Some(operation_id);
+                    }
+                }
+                OperationType::Subscription => {
+                    if let Some(prev_subscription_type) = self.subscription_type {
+                        return todo_add_location(SchemaError::DuplicateOperationDefinition(
+                            *operation,
+                            type_.value,
+                            self.object(prev_subscription_type).name,
+                        ));
+                    } else {
+                        self.subscription_type = Some(operation_id);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn build_object_id(&mut self, name: StringKey) -> DiagnosticsResult<ObjectID> {
         match self.type_map.get(&name) {
             Some(Type::Object(id)) => Ok(*id),
@@ -1272,6 +1285,7 @@ impl Schema {
                         name: arg_def.name.value,
                         type_: self.build_type_reference(&arg_def.type_)?,
                         default_value: arg_def.default_value.clone(),
+                        directives: self.build_directive_values(&arg_def.directives),
                     })
                 })
                 .collect();
@@ -1709,6 +1723,7 @@ pub struct Argument {
     pub name: StringKey,
     pub type_: TypeReference,
     pub default_value: Option<ConstantValue>,
+    pub directives: Vec<DirectiveValue>,
 }
 
 impl Named for Argument {
@@ -1717,7 +1732,7 @@ impl Named for Argument {
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct ArgumentValue {
     pub name: StringKey,
     pub value: ConstantValue,
@@ -1729,7 +1744,7 @@ impl Named for ArgumentValue {
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct DirectiveValue {
     pub name: StringKey,
     pub arguments: Vec<ArgumentValue>,
@@ -1741,6 +1756,38 @@ impl Named for DirectiveValue {
     }
 }
 
+/// The `@deprecated` directive of a field, argument or enum value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Deprecation {
+    pub reason: Option<StringKey>,
+}
+
+impl Deprecation {
+    pub fn from_directives(directives: &[DirectiveValue]) -> Option<Self> {
+        let directive = directives.named("deprecated".intern())?;
+        let reason = directive
+            .arguments
+            .named("reason".intern())
+            .and_then(|argument| match &argument.value {
+                ConstantValue::String(reason) => Some(reason.value),
+                _ => None,
+            });
+        Some(Self { reason })
+    }
+}
+
+impl Field {
+    pub fn deprecated(&self) -> Option<Deprecation> {
+        Deprecation::from_directives(&self.directives)
+    }
+}
+
+impl Argument {
+    pub fn deprecated(&self) -> Option<Deprecation> {
+        Deprecation::from_directives(&self.directives)
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct EnumValue {
     pub value: StringKey,
@@ -23,6 +23,11 @@ pub enum SchemaError {
     #[error("Cannot extend type '{0}', the type is not defined on the server schema.")]
     ExtendUndefinedType(StringKey),
 
+    #[error(
+        "Cannot redefine field '{0}.{1}' in a schema extension, the field is already defined on the server schema."
+    )]
+    FieldRedefinition(StringKey, StringKey),
+
     #[error("Expected an object type for name '{0}', got '{1:?}'.")]
     ExpectedObjectReference(StringKey, Type),
 
@@ -263,6 +263,8 @@ impl<'fb> FlatBufferSchema<'fb> {
                 _ => None,
             },
             type_: self.parse_type_reference(argument.type_()?)?,
+            // The flatbuffer schema doesn't store the directives of arguments.
+            directives: Vec::new(),
         })
     }
 
@@ -14,51 +14,140 @@
 pub mod definitions;
 mod errors;
 mod flatbuffer;
+mod schema_config;
 
-use common::{DiagnosticsResult, SourceLocationKey};
+use common::{Diagnostic, DiagnosticsResult, Location, SourceLocationKey};
 pub use definitions::{
-    Argument, ArgumentDefinitions, ArgumentValue, Directive, DirectiveValue, Enum, EnumID,
-    EnumValue, Field, FieldID, InputObject, InputObjectID, Interface, InterfaceID, Object,
+    Argument, ArgumentDefinitions, ArgumentValue, Deprecation, Directive, DirectiveValue, Enum,
+    EnumID, EnumValue, Field, FieldID, InputObject, InputObjectID, Interface, InterfaceID, Object,
     ObjectID, Scalar, ScalarID, Schema, Type, TypeReference, TypeWithFields, Union, UnionID,
 };
 pub use errors::{Result, SchemaError};
 pub use flatbuffer::graphqlschema::*;
 use flatbuffer::FlatBufferSchema;
 pub use graphql_syntax::{DirectiveLocation, TypeSystemDefinition};
+use graphql_syntax::{
+    FieldDefinition, Identifier, InterfaceTypeDefinition, InterfaceTypeExtension,
+    ObjectTypeDefinition, ObjectTypeExtension,
+};
+pub use schema_config::SchemaConfig;
+use std::collections::HashMap;
 
 const BUILTINS: &str = include_str!("./builtins.graphql");
 
 pub fn build_schema(sdl: &str) -> DiagnosticsResult<Schema> {
-    build_schema_with_extensions::<_, &str>(&[sdl], &[])
+    build_schema_with_extensions::<_, &str>(
+        &[(sdl, SourceLocationKey::generated())],
+        &[],
+        &SchemaConfig::default(),
+    )
 }
 
 pub fn build_schema_with_extensions<T: AsRef<str>, U: AsRef<str>>(
-    server_sdls: &[T],
-    extension_sdls: &[U],
+    server_sdls: &[(T, SourceLocationKey)],
+    extension_sdls: &[(U, SourceLocationKey)],
+    schema_config: &SchemaConfig,
 ) -> DiagnosticsResult<Schema> {
-    let mut server_definitions = builtins()?;
-    let mut combined_sdl: String = String::new();
-    for server_sdl in server_sdls {
-        combined_sdl.push_str(server_sdl.as_ref());
-        combined_sdl.push_str("\n");
+    let mut server_definitions = builtins()?
+        .into_iter()
+        .map(|definition| (definition, SourceLocationKey::generated()))
+        .collect::<Vec<_>>();
+    for (server_sdl, source_location) in server_sdls {
+        server_definitions.extend(
+            graphql_syntax::parse_schema_document(server_sdl.as_ref(), *source_location)?
+                .definitions
+                .into_iter()
+                .map(|definition| (definition, *source_location)),
+        );
     }
-    server_definitions.extend(
-        graphql_syntax::parse_schema_document(&combined_sdl, SourceLocationKey::generated())?
-            .definitions,
-    );
 
     let mut extension_definitions = Vec::new();
-    for extension_sdl in extension_sdls {
+    for (extension_sdl, source_location) in extension_sdls {
         extension_definitions.extend(
-            graphql_syntax::parse_schema_document(
-                extension_sdl.as_ref(),
-                SourceLocationKey::generated(),
-            )?
-            .definitions,
+            graphql_syntax::parse_schema_document(extension_sdl.as_ref(), *source_location)?
+                .definitions
+                .into_iter()
+                .map(|definition| (definition, *source_location)),
         );
     }
 
-    Schema::build(&server_definitions, &extension_definitions)
+    validate_extension_fields(&server_definitions, &extension_definitions)?;
+
+    Schema::build(
+        &server_definitions
+            .into_iter()
+            .map(|(definition, _)| definition)
+            .collect::<Vec<_>>(),
+        &extension_definitions
+            .into_iter()
+            .map(|(definition, _)| definition)
+            .collect::<Vec<_>>(),
+        schema_config,
+    )
+}
+
+/// Extensions can add fields to server types, but not redefine them. This is
+/// checked before building the schema to report the locations of both
+/// definitions, which are not retained in the schema.
+fn validate_extension_fields(
+    server_definitions: &[(TypeSystemDefinition, SourceLocationKey)],
+    extension_definitions: &[(TypeSystemDefinition, SourceLocationKey)],
+) -> DiagnosticsResult<()> {
+    let mut server_fields = HashMap::new();
+    for (definition, source_location) in server_definitions {
+        if let Some((type_name, fields)) = get_type_fields(definition) {
+            for field in fields {
+                server_fields
+                    .entry((type_name.value, field.name.value))
+                    .or_insert_with(|| Location::new(*source_location, field.name.span));
+            }
+        }
+    }
+
+    let mut errors = Vec::new();
+    for (definition, source_location) in extension_definitions {
+        if let Some((type_name, fields)) = get_type_fields(definition) {
+            for field in fields {
+                if let Some(server_location) =
+                    server_fields.get(&(type_name.value, field.name.value))
+                {
+                    errors.push(
+                        Diagnostic::error(
+                            SchemaError::FieldRedefinition(type_name.value, field.name.value),
+                            Location::new(*source_location, field.name.span),
+                        )
+                        .annotate("Previously defined here", *server_location),
+                    );
+                }
+            }
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn get_type_fields(definition: &TypeSystemDefinition) -> Option<(&Identifier, &[FieldDefinition])> {
+    let (name, fields) = match definition {
+        TypeSystemDefinition::ObjectTypeDefinition(ObjectTypeDefinition {
+            name, fields, ..
+        })
+        | TypeSystemDefinition::ObjectTypeExtension(ObjectTypeExtension { name, fields, .. })
+        | TypeSystemDefinition::InterfaceTypeDefinition(InterfaceTypeDefinition {
+            name,
+            fields,
+            ..
+        })
+        | TypeSystemDefinition::InterfaceTypeExtension(InterfaceTypeExtension {
+            name,
+            fields,
+            ..
+        }) => (name, fields),
+        _ => return None,
+    };
+    Some((name, fields.as_ref().map_or(&[], |fields| &fields.items)))
 }
 
 pub fn build_schema_from_flat_buffer(bytes: &[u8]) -> DiagnosticsResult<FlatBufferSchema<'_>> {
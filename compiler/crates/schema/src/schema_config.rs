@@ -0,0 +1,31 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use interner::{Intern, StringKey};
+use serde::Deserialize;
+
+/// Options for building a schema that aren't part of the SDL.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+pub struct SchemaConfig {
+    /// Names of the root operation types, used when the schema doesn't
+    /// declare them with a `schema { query: ... }` definition, e.g.
+    /// `QueryRoot` instead of `Query`.
+    pub query_type_name: StringKey,
+    pub mutation_type_name: StringKey,
+    pub subscription_type_name: StringKey,
+}
+
+impl Default for SchemaConfig {
+    fn default() -> Self {
+        Self {
+            query_type_name: "Query".intern(),
+            mutation_type_name: "Mutation".intern(),
+            subscription_type_name: "Subscription".intern(),
+        }
+    }
+}
@@ -5,25 +5,51 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use common::SourceLocationKey;
 use fixture_tests::Fixture;
+use graphql_cli::DiagnosticPrinter;
 use graphql_test_helpers::diagnostics_to_sorted_string;
 use schema::{
-    build_schema, build_schema_from_flat_buffer, build_schema_with_extensions, Schema, Type,
+    build_schema, build_schema_from_flat_buffer, build_schema_with_extensions, Schema,
+    SchemaConfig, Type,
 };
 use schema_print::serialize_as_fb;
 use std::collections::BTreeMap;
 
 pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
     let parts: Vec<_> = fixture.content.split("%extensions%").collect();
-    let result = match parts.as_slice() {
-        [base] => build_schema(base),
-        [base, extensions] => build_schema_with_extensions(&[base], &[extensions]),
+    match parts.as_slice() {
+        [base] => build_schema(base)
+            .map(print_schema_and_flat_buffer_schema)
+            .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics)),
+        [base, extensions] => {
+            let base_location = SourceLocationKey::embedded(fixture.file_name, 0);
+            let extensions_location = SourceLocationKey::embedded(fixture.file_name, 1);
+            build_schema_with_extensions(
+                &[(base, base_location)],
+                &[(extensions, extensions_location)],
+                &SchemaConfig::default(),
+            )
+            .map(print_schema_and_flat_buffer_schema)
+            .map_err(|diagnostics| {
+                let printer = DiagnosticPrinter::new(|source_location| {
+                    if source_location == base_location {
+                        Some(base.to_string())
+                    } else if source_location == extensions_location {
+                        Some(extensions.to_string())
+                    } else {
+                        Some(fixture.content.to_string())
+                    }
+                });
+                diagnostics
+                    .iter()
+                    .map(|diagnostic| printer.diagnostic_to_string(diagnostic))
+                    .collect::<Vec<_>>()
+                    .join("\n\n")
+            })
+        }
         _ => panic!("Expected a single extension block"),
-    };
-
-    result
-        .map(print_schema_and_flat_buffer_schema)
-        .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))
+    }
 }
 
 fn print_schema_and_flat_buffer_schema(schema: Schema) -> String {
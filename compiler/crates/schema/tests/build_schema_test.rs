@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<d7c16c29bd10615430a1b09fe261a111>>
+ * @generated SignedSource<<311a5b5de5df758e773d2dc18505ce9f>>
  */
 
 mod build_schema;
@@ -12,6 +12,13 @@ mod build_schema;
 use build_schema::transform_fixture;
 use fixture_tests::test_fixture;
 
+#[test]
+fn deprecations() {
+    let input = include_str!("build_schema/fixtures/deprecations.graphql");
+    let expected = include_str!("build_schema/fixtures/deprecations.expected");
+    test_fixture(transform_fixture, "deprecations.graphql", "build_schema/fixtures/deprecations.expected", input, expected);
+}
+
 #[test]
 fn directives_for_external_types() {
     let input = include_str!("build_schema/fixtures/directives-for-external-types.graphql");
@@ -26,6 +33,13 @@ fn interface_implements_interface() {
     test_fixture(transform_fixture, "interface-implements-interface.graphql", "build_schema/fixtures/interface-implements-interface.expected", input, expected);
 }
 
+#[test]
+fn invalid_extension_redefines_server_field() {
+    let input = include_str!("build_schema/fixtures/invalid-extension-redefines-server-field.graphql");
+    let expected = include_str!("build_schema/fixtures/invalid-extension-redefines-server-field.expected");
+    test_fixture(transform_fixture, "invalid-extension-redefines-server-field.graphql", "build_schema/fixtures/invalid-extension-redefines-server-field.expected", input, expected);
+}
+
 #[test]
 fn invalid_implements_non_interface() {
     let input = include_str!("build_schema/fixtures/invalid-implements-non-interface.graphql");
@@ -47,6 +61,13 @@ fn invalid_object_extension_local_duplicated_fields() {
     test_fixture(transform_fixture, "invalid-object-extension-local-duplicated-fields.graphql", "build_schema/fixtures/invalid-object-extension-local-duplicated-fields.expected", input, expected);
 }
 
+#[test]
+fn invalid_schema_extension_duplicate_subscription() {
+    let input = include_str!("build_schema/fixtures/invalid-schema-extension-duplicate-subscription.graphql");
+    let expected = include_str!("build_schema/fixtures/invalid-schema-extension-duplicate-subscription.expected");
+    test_fixture(transform_fixture, "invalid-schema-extension-duplicate-subscription.graphql", "build_schema/fixtures/invalid-schema-extension-duplicate-subscription.expected", input, expected);
+}
+
 #[test]
 fn invalid_sdl() {
     let input = include_str!("build_schema/fixtures/invalid-sdl.graphql");
@@ -67,3 +88,17 @@ fn kitchen_sink() {
     let expected = include_str!("build_schema/fixtures/kitchen-sink.expected");
     test_fixture(transform_fixture, "kitchen-sink.graphql", "build_schema/fixtures/kitchen-sink.expected", input, expected);
 }
+
+#[test]
+fn object_extension_new_fields() {
+    let input = include_str!("build_schema/fixtures/object-extension-new-fields.graphql");
+    let expected = include_str!("build_schema/fixtures/object-extension-new-fields.expected");
+    test_fixture(transform_fixture, "object-extension-new-fields.graphql", "build_schema/fixtures/object-extension-new-fields.expected", input, expected);
+}
+
+#[test]
+fn schema_extension_subscription() {
+    let input = include_str!("build_schema/fixtures/schema-extension-subscription.graphql");
+    let expected = include_str!("build_schema/fixtures/schema-extension-subscription.expected");
+    test_fixture(transform_fixture, "schema-extension-subscription.graphql", "build_schema/fixtures/schema-extension-subscription.expected", input, expected);
+}
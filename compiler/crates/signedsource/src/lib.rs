@@ -17,9 +17,15 @@ lazy_static! {
     static ref RE: Regex = Regex::new("\x40generated (?:SignedSource<<([a-f0-9]{32})>>)").unwrap();
 }
 
+/// Line endings are normalized to `\n` before hashing, so that the signature
+/// doesn't depend on the platform writing or checking out the file.
 fn hash(data: &str) -> String {
     let mut md5 = Md5::new();
-    md5.input(data);
+    if data.contains('\r') {
+        md5.input(data.replace("\r\n", "\n"));
+    } else {
+        md5.input(data);
+    }
     hex::encode(md5.result())
 }
 fn sign(data: &str) -> String {
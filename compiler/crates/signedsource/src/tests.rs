@@ -59,3 +59,16 @@ fn test_is_valid_signature() {
     assert!(!is_valid_signature(&signed_string));
     assert!(!is_valid_signature("unsinged"));
 }
+
+#[test]
+fn test_sign_file_crlf() {
+    let signed_lf = sign_file(&format!("# {}\ntest\n", SIGNING_TOKEN));
+    let signed_crlf = sign_file(&format!("# {}\r\ntest\r\n", SIGNING_TOKEN));
+    // Only the hash is independent of line endings, the content is unchanged.
+    assert_eq!(signed_crlf, signed_lf.replace("\n", "\r\n"));
+    assert!(is_valid_signature(&signed_lf));
+    assert!(is_valid_signature(&signed_crlf));
+    // Files converted to the line endings of another platform stay valid.
+    assert!(is_valid_signature(&signed_lf.replace("\n", "\r\n")));
+    assert!(is_valid_signature(&signed_crlf.replace("\r\n", "\n")));
+}